@@ -15,19 +15,27 @@ use proc_macro2::TokenStream;
 use quote::quote;
 
 use crate::context::Context;
-use crate::types::AliasDefinition;
+use crate::types::{AliasDefinition, PrimitiveType, Type};
 
 pub fn generate(ctx: &Context, def: &AliasDefinition) -> TokenStream {
+    let vis = ctx.visibility();
     let name = ctx.type_name(def.type_name().name());
     let alias = ctx.rust_type(def.type_name(), def.alias());
     let result = ctx.result_ident(def.type_name());
     let docs = ctx.docs(def.docs());
 
-    let mut derives = vec!["Debug", "Clone", "PartialEq", "PartialOrd"];
+    let double_ord = ctx.is_double_ord(def.alias());
+    let raw_double = ctx.has_raw_double(def.alias());
+
+    let mut derives = vec!["Debug", "Clone"];
+    if !(double_ord && raw_double) {
+        derives.push("PartialEq");
+        derives.push("PartialOrd");
+    }
     if ctx.is_copy(def.alias()) {
         derives.push("Copy");
     }
-    if !ctx.has_double(def.alias()) {
+    if !ctx.has_double(def.alias()) || double_ord && !raw_double {
         derives.push("Eq");
         derives.push("Ord");
         derives.push("Hash");
@@ -37,6 +45,13 @@ pub fn generate(ctx: &Context, def: &AliasDefinition) -> TokenStream {
     }
     let derives = derives.iter().map(|s| s.parse::<TokenStream>().unwrap());
 
+    // Copy types are stored as-is, so the constructor can be evaluated at compile time
+    let const_ = if ctx.is_copy(def.alias()) {
+        quote!(const)
+    } else {
+        quote!()
+    };
+
     let display = if ctx.is_display(def.alias()) {
         quote! {
             impl std::fmt::Display for #name {
@@ -49,15 +64,333 @@ pub fn generate(ctx: &Context, def: &AliasDefinition) -> TokenStream {
         quote!()
     };
 
+    // String aliases convert from and compare against string values so they can be used like plain strings
+    let string_impls = if ctx.is_string(def.alias()) {
+        let from = ctx.from_ident(def.type_name());
+        let string = ctx.string_ident(def.type_name());
+        quote! {
+            impl<'a> #from<&'a str> for #name {
+                #[inline]
+                fn from(v: &'a str) -> #name {
+                    #name(v.to_string())
+                }
+            }
+
+            impl #from<#string> for #name {
+                #[inline]
+                fn from(v: #string) -> #name {
+                    #name(v)
+                }
+            }
+
+            impl std::cmp::PartialEq<str> for #name {
+                #[inline]
+                fn eq(&self, other: &str) -> bool {
+                    self.0 == other
+                }
+            }
+
+            impl<'a> std::cmp::PartialEq<&'a str> for #name {
+                #[inline]
+                fn eq(&self, other: &&'a str) -> bool {
+                    self.0 == *other
+                }
+            }
+
+            impl std::cmp::PartialEq<#string> for #name {
+                #[inline]
+                fn eq(&self, other: &#string) -> bool {
+                    self.0 == *other
+                }
+            }
+
+            impl std::cmp::PartialEq<#name> for str {
+                #[inline]
+                fn eq(&self, other: &#name) -> bool {
+                    *self == other.0
+                }
+            }
+
+            impl<'a> std::cmp::PartialEq<#name> for &'a str {
+                #[inline]
+                fn eq(&self, other: &#name) -> bool {
+                    **self == other.0
+                }
+            }
+
+            impl std::cmp::PartialEq<#name> for #string {
+                #[inline]
+                fn eq(&self, other: &#name) -> bool {
+                    *self == other.0
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    // Aliases of other primitives convert from the wrapped type, and fallibly from the raw representation when the
+    // wrapped type has invariants to check
+    let primitive_impls = match primitive(def.alias()) {
+        Some(PrimitiveType::String) | None => quote!(),
+        Some(primitive) => {
+            let from = ctx.from_ident(def.type_name());
+            let result = ctx.result_ident(def.type_name());
+            let vis = ctx.visibility();
+            let try_from = match primitive {
+                PrimitiveType::Safelong => quote! {
+                    impl #name {
+                        /// Creates a new instance of the type, validating that the value is in range.
+                        #[inline]
+                        #vis fn try_from_i64(
+                            v: i64,
+                        ) -> #result<#name, conjure_object::safe_long::BoundsError> {
+                            conjure_object::SafeLong::new(v).map(#name)
+                        }
+                    }
+                },
+                PrimitiveType::Rid => quote! {
+                    impl #name {
+                        /// Creates a new instance of the type, parsing the resource identifier from a string.
+                        #[inline]
+                        #vis fn try_from_str(
+                            v: &str,
+                        ) -> #result<#name, conjure_object::resource_identifier::ParseError> {
+                            conjure_object::ResourceIdentifier::new(v).map(#name)
+                        }
+                    }
+                },
+                PrimitiveType::Bearertoken => quote! {
+                    impl #name {
+                        /// Creates a new instance of the type, validating the format of the bearer token.
+                        #[inline]
+                        #vis fn try_from_str(
+                            v: &str,
+                        ) -> #result<#name, conjure_object::bearer_token::ParseError> {
+                            conjure_object::BearerToken::new(v).map(#name)
+                        }
+                    }
+                },
+                _ => quote!(),
+            };
+            // Boolean aliases also convert back and can be negated directly so they behave like booleans
+            let bool_impls = match primitive {
+                PrimitiveType::Boolean => quote! {
+                    impl #from<#name> for bool {
+                        #[inline]
+                        fn from(v: #name) -> bool {
+                            v.0
+                        }
+                    }
+
+                    impl std::ops::Not for #name {
+                        type Output = #name;
+
+                        #[inline]
+                        fn not(self) -> #name {
+                            #name(!self.0)
+                        }
+                    }
+                },
+                _ => quote!(),
+            };
+
+            // Numeric aliases aggregate like the wrapped type, which for safelong panics if the result is out of range
+            let numeric_impls = match primitive {
+                PrimitiveType::Integer | PrimitiveType::Double | PrimitiveType::Safelong => {
+                    quote! {
+                        impl std::iter::Sum for #name {
+                            #[inline]
+                            fn sum<I>(iter: I) -> #name
+                            where
+                                I: std::iter::Iterator<Item = #name>,
+                            {
+                                #name(iter.map(|v| v.0).sum())
+                            }
+                        }
+
+                        impl<'a> std::iter::Sum<&'a #name> for #name {
+                            #[inline]
+                            fn sum<I>(iter: I) -> #name
+                            where
+                                I: std::iter::Iterator<Item = &'a #name>,
+                            {
+                                #name(iter.map(|v| v.0).sum())
+                            }
+                        }
+
+                        impl std::iter::Product for #name {
+                            #[inline]
+                            fn product<I>(iter: I) -> #name
+                            where
+                                I: std::iter::Iterator<Item = #name>,
+                            {
+                                #name(iter.map(|v| v.0).product())
+                            }
+                        }
+
+                        impl<'a> std::iter::Product<&'a #name> for #name {
+                            #[inline]
+                            fn product<I>(iter: I) -> #name
+                            where
+                                I: std::iter::Iterator<Item = &'a #name>,
+                            {
+                                #name(iter.map(|v| v.0).product())
+                            }
+                        }
+                    }
+                }
+                _ => quote!(),
+            };
+
+            quote! {
+                impl #from<#alias> for #name {
+                    #[inline]
+                    fn from(v: #alias) -> #name {
+                        #name(v)
+                    }
+                }
+
+                #try_from
+
+                #bool_impls
+
+                #numeric_impls
+            }
+        }
+    };
+
+    // Aliases of types with a PLAIN representation share it, so they can be used in paths, query parameters, and
+    // headers. Parsing from a string also goes through the PLAIN format.
+    let plain_impls = if ctx.is_plain(def.alias()) {
+        let string = ctx.string_ident(def.type_name());
+        quote! {
+            impl conjure_object::PlainEncode for #name {
+                #[inline]
+                fn encode_plain(&self) -> #string {
+                    conjure_object::PlainEncode::encode_plain(&self.0)
+                }
+            }
+
+            impl conjure_object::PlainDecode for #name {
+                #[inline]
+                fn decode_plain(s: &str) -> #result<#name, conjure_object::plain::ParseError> {
+                    conjure_object::PlainDecode::decode_plain(s).map(#name)
+                }
+            }
+
+            impl std::str::FromStr for #name {
+                type Err = conjure_object::plain::ParseError;
+
+                #[inline]
+                fn from_str(s: &str) -> #result<#name, conjure_object::plain::ParseError> {
+                    conjure_object::PlainDecode::decode_plain(s)
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    // Sets are deserialized through a wrapper which rejects duplicate values rather than silently dropping them.
+    let deserialize = if ctx.is_set(def.alias()) {
+        quote! {
+            de::Deserialize::deserialize(d).map(|v: conjure_object::private::UniqueSet_<_>| #name(v.0))
+        }
+    } else if ctx.is_optional_set(def.alias()) {
+        let option = ctx.option_ident(def.type_name());
+        quote! {
+            de::Deserialize::deserialize(d)
+                .map(|v: #option<conjure_object::private::UniqueSet_<_>>| #name(v.map(|v| v.0)))
+        }
+    } else {
+        quote!(de::Deserialize::deserialize(d).map(#name))
+    };
+
+    let serialize = if ctx.formats_datetimes(def.alias()) || ctx.checks_doubles(def.alias()) {
+        let value = ctx.serialize_value(def.alias(), quote!(&self.0));
+        quote!(ser::Serialize::serialize(#value, s))
+    } else {
+        quote!(self.0.serialize(s))
+    };
+
+    let span = ctx.deserialize_span(def.type_name());
+
+    let double_ord_impls = if double_ord && raw_double {
+        let option = ctx.option_ident(def.type_name());
+        let some = ctx.some_ident(def.type_name());
+        let eq = ctx.double_eq(quote!(&self.0), quote!(&other.0));
+        let cmp = ctx.double_cmp(quote!(&self.0), quote!(&other.0));
+        let hash = ctx.double_hash(quote!(&self.0), quote!(hasher));
+        quote! {
+            impl std::cmp::PartialEq for #name {
+                #[inline]
+                fn eq(&self, other: &#name) -> bool {
+                    #eq
+                }
+            }
+
+            impl std::cmp::Eq for #name {}
+
+            impl std::cmp::PartialOrd for #name {
+                #[inline]
+                fn partial_cmp(&self, other: &#name) -> #option<std::cmp::Ordering> {
+                    #some(std::cmp::Ord::cmp(self, other))
+                }
+            }
+
+            impl std::cmp::Ord for #name {
+                #[inline]
+                fn cmp(&self, other: &#name) -> std::cmp::Ordering {
+                    #cmp
+                }
+            }
+
+            impl std::hash::Hash for #name {
+                #[inline]
+                fn hash<H>(&self, hasher: &mut H)
+                where
+                    H: std::hash::Hasher,
+                {
+                    #hash
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
     quote! {
         use conjure_object::serde::{ser, de};
 
         #docs
         #[derive(#(#derives),*)]
-        pub struct #name(pub #alias);
+        #vis struct #name(#vis #alias);
+
+        impl #name {
+            /// Constructs a new instance of the type.
+            #[inline]
+            #vis #const_ fn new(inner: #alias) -> #name {
+                #name(inner)
+            }
+
+            /// Returns the wrapped value.
+            #[inline]
+            #vis fn into_inner(self) -> #alias {
+                self.0
+            }
+        }
 
         #display
 
+        #string_impls
+
+        #primitive_impls
+
+        #plain_impls
+
+        #double_ord_impls
+
         impl std::ops::Deref for #name {
             type Target = #alias;
 
@@ -75,21 +408,32 @@ pub fn generate(ctx: &Context, def: &AliasDefinition) -> TokenStream {
         }
 
         impl ser::Serialize for #name {
+            #[inline]
             fn serialize<S>(&self, s: S) -> #result<S::Ok, S::Error>
             where
                 S: ser::Serializer
             {
-                self.0.serialize(s)
+                #serialize
             }
         }
 
         impl<'de> de::Deserialize<'de> for #name {
+            #[inline]
             fn deserialize<D>(d: D) -> #result<#name, D::Error>
             where
                 D: de::Deserializer<'de>
             {
-                de::Deserialize::deserialize(d).map(#name)
+                #span
+                #deserialize
             }
         }
     }
 }
+
+fn primitive(def: &Type) -> Option<PrimitiveType> {
+    match def {
+        Type::Primitive(def) => Some(*def),
+        Type::External(def) => primitive(def.fallback()),
+        _ => None,
+    }
+}
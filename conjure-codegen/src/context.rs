@@ -15,11 +15,13 @@ use heck::{CamelCase, SnakeCase};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use std::cell::Cell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::types::{
-    ConjureDefinition, Documentation, PrimitiveType, Type, TypeDefinition, TypeName,
+    ConjureDefinition, Documentation, ExternalReference, FieldDefinition, ObjectDefinition,
+    PrimitiveType, Type, TypeDefinition, TypeName, UnionDefinition,
 };
+use crate::{Config, DateTimePrecision, Visibility};
 
 struct TypeContext {
     def: TypeDefinition,
@@ -30,25 +32,66 @@ struct TypeContext {
 pub struct Context {
     types: HashMap<TypeName, TypeContext>,
     exhaustive: bool,
+    field_presence: bool,
+    double_ord: bool,
+    union_double_hash: bool,
+    double_places: Option<u32>,
+    mutable_accessors: bool,
+    flatten_unions: bool,
+    shared_recursion: bool,
+    strict_doubles: bool,
+    tracing: bool,
+    collect_errors: bool,
+    deny_unknown_fields: HashSet<String>,
+    positional_deserialization: HashSet<String>,
+    case_insensitive_fields: HashSet<String>,
+    borrowed_strings: HashSet<String>,
+    compact_fields: HashSet<String>,
+    raw_any_fields: HashSet<String>,
+    visibility: Visibility,
+    custom_types: HashSet<TypeName>,
+    datetime_precision: Option<DateTimePrecision>,
+    extern_packages: HashMap<String, TokenStream>,
+    field_renames: HashMap<String, String>,
+    flattened_fields: HashSet<(String, String)>,
+    field_aliases: HashMap<(String, String), Vec<String>>,
+    write_only_fields: HashSet<(String, String)>,
 }
 
 impl Context {
-    pub fn new(defs: &ConjureDefinition, exhaustive: bool) -> Context {
+    pub fn new(defs: &ConjureDefinition, config: &Config) -> Context {
         let mut context = Context {
             types: HashMap::new(),
-            exhaustive,
+            exhaustive: config.exhaustive,
+            field_presence: config.field_presence,
+            double_ord: config.double_ord,
+            union_double_hash: config.union_double_hash,
+            double_places: config.double_places,
+            mutable_accessors: config.mutable_accessors,
+            flatten_unions: config.flatten_unions,
+            shared_recursion: config.shared_recursion,
+            strict_doubles: config.strict_doubles,
+            tracing: config.tracing,
+            collect_errors: config.collect_errors,
+            deny_unknown_fields: config.deny_unknown_fields.clone(),
+            positional_deserialization: config.positional_deserialization.clone(),
+            case_insensitive_fields: config.case_insensitive_fields.clone(),
+            borrowed_strings: config.borrowed_strings.clone(),
+            compact_fields: config.compact_fields.clone(),
+            raw_any_fields: config.raw_any_fields.clone(),
+            visibility: config.visibility,
+            custom_types: config.custom_types(),
+            datetime_precision: config.datetime_precision,
+            extern_packages: config.extern_packages(),
+            field_renames: config.field_renames.clone(),
+            flattened_fields: config.flattened_fields.clone(),
+            field_aliases: config.field_aliases.clone(),
+            write_only_fields: config.write_only_fields.clone(),
         };
 
         for def in defs.types() {
-            let name = match &def {
-                TypeDefinition::Alias(def) => def.type_name().clone(),
-                TypeDefinition::Enum(def) => def.type_name().clone(),
-                TypeDefinition::Object(def) => def.type_name().clone(),
-                TypeDefinition::Union(def) => def.type_name().clone(),
-            };
-
             context.types.insert(
-                name,
+                definition_name(def).clone(),
                 TypeContext {
                     def: def.clone(),
                     has_double: Cell::new(None),
@@ -64,12 +107,201 @@ impl Context {
         self.exhaustive
     }
 
+    pub fn field_presence(&self) -> bool {
+        self.field_presence
+    }
+
+    pub fn union_double_hash(&self) -> bool {
+        self.union_double_hash
+    }
+
+    pub fn mutable_accessors(&self) -> bool {
+        self.mutable_accessors
+    }
+
+    pub fn collect_errors(&self) -> bool {
+        self.collect_errors
+    }
+
+    /// Returns true if the union has a single variant and is generated as a newtype of that variant's value.
+    pub fn is_flattened(&self, def: &UnionDefinition) -> bool {
+        self.flatten_unions && def.union_().len() == 1
+    }
+
+    pub fn deny_unknown_fields(&self, name: &TypeName) -> bool {
+        self.deny_unknown_fields.contains(name.name())
+    }
+
+    pub fn visibility(&self) -> TokenStream {
+        self.visibility.tokens()
+    }
+
+    pub fn is_extern(&self, name: &TypeName) -> bool {
+        self.extern_path(name).is_some()
+    }
+
+    /// Returns the path to the root module of the crate containing a type, if it isn't generated here.
+    pub fn extern_path(&self, name: &TypeName) -> Option<&TokenStream> {
+        self.extern_packages.get(name.package())
+    }
+
+    /// Returns the path to a generated type from within the module of another generated type.
+    pub fn type_path(&self, name: &TypeName) -> TokenStream {
+        let type_ = self.type_name(name.name());
+        match self.extern_path(name) {
+            Some(path) => quote!(#path::#type_),
+            None => quote!(super::#type_),
+        }
+    }
+
+    pub fn positional_deserialization(&self, name: &TypeName) -> bool {
+        self.positional_deserialization.contains(name.name())
+    }
+
+    /// Returns true if the object matches field names ignoring ASCII case when deserialized.
+    pub fn case_insensitive_fields(&self, name: &TypeName) -> bool {
+        self.case_insensitive_fields.contains(name.name())
+    }
+
+    /// Returns true if the object borrows its strings from the deserializer's input, making it generic over a lifetime.
+    pub fn borrows_strings(&self, name: &TypeName) -> bool {
+        self.borrowed_strings.contains(name.name())
+    }
+
+    /// Returns true if the object stores its string and list fields as boxed slices.
+    pub fn compacts_fields(&self, name: &TypeName) -> bool {
+        self.compact_fields.contains(name.name())
+    }
+
+    /// Returns true if the object field's value is inlined into the object on the wire rather than nested.
+    pub fn is_flattened_field(&self, name: &TypeName, field: &FieldDefinition) -> bool {
+        self.flattened_fields
+            .contains(&(name.name().to_string(), field.field_name().0.clone()))
+    }
+
+    /// Returns true if the object field is deserialized but never serialized.
+    pub fn is_write_only_field(&self, name: &TypeName, field: &FieldDefinition) -> bool {
+        self.write_only_fields
+            .contains(&(name.name().to_string(), field.field_name().0.clone()))
+    }
+
+    /// Returns the additional names the object field is accepted under when deserialized.
+    pub fn field_aliases(&self, name: &TypeName, field: &FieldDefinition) -> &[String] {
+        self.field_aliases
+            .get(&(name.name().to_string(), field.field_name().0.clone()))
+            .map_or(&[], |aliases| aliases)
+    }
+
+    /// Returns true if the object is the value of a flattened field, and so needs to support being inlined.
+    pub fn is_flatten_target(&self, name: &TypeName) -> bool {
+        self.types.values().any(|ctx| match &ctx.def {
+            TypeDefinition::Object(def) => def.fields().iter().any(|f| {
+                self.is_flattened_field(def.type_name(), f)
+                    && match f.type_() {
+                        Type::Reference(def) => def == name,
+                        _ => false,
+                    }
+            }),
+            _ => false,
+        })
+    }
+
+    /// Returns the definition of the object a flattened field refers to.
+    pub fn flattened_object(&self, def: &Type) -> &ObjectDefinition {
+        match def {
+            Type::Reference(name) => match &self.types[name].def {
+                TypeDefinition::Object(def) => def,
+                _ => panic!("flattened field doesn't refer to an object"),
+            },
+            _ => panic!("flattened field doesn't refer to an object"),
+        }
+    }
+
+    /// Returns true if the object stores its `any` fields as unparsed JSON.
+    pub fn raw_any_fields(&self, name: &TypeName) -> bool {
+        self.raw_any_fields.contains(name.name())
+    }
+
+    /// Returns true if the external reference is a user-provided type substituted for an object field.
+    pub fn is_custom(&self, def: &ExternalReference) -> bool {
+        self.custom_types.contains(def.external_reference())
+    }
+
+    pub fn custom_rust_type(&self, def: &ExternalReference) -> TokenStream {
+        def.external_reference().name().parse().unwrap()
+    }
+
+    pub fn formats_datetimes(&self, def: &Type) -> bool {
+        self.datetime_precision.is_some() && self.has_raw_datetime(def)
+    }
+
+    pub fn checks_doubles(&self, def: &Type) -> bool {
+        self.strict_doubles && self.has_raw_double_value(def)
+    }
+
+    /// Returns an expression serializing the referenced value, formatting any datetimes it directly contains with the
+    /// configured precision and rejecting any non-finite doubles if configured to.
+    pub fn serialize_value(&self, def: &Type, value: TokenStream) -> TokenStream {
+        match self.datetime_precision {
+            Some(precision) if self.has_raw_datetime(def) => {
+                let format = precision.tokens();
+                quote!(&conjure_object::private::DateTimeFormat_(#value, #format))
+            }
+            _ if self.checks_doubles(def) => {
+                quote!(&conjure_object::private::FiniteDouble_(#value))
+            }
+            _ => value,
+        }
+    }
+
+    /// Returns statements entering a span around the deserialization of the type, if enabled.
+    pub fn deserialize_span(&self, name: &TypeName) -> TokenStream {
+        if !self.tracing {
+            return quote!();
+        }
+
+        let type_name = name.name();
+        quote! {
+            let span_ = conjure_ext::tracing::trace_span!("deserialize", conjure_type = #type_name);
+            let _guard = span_.enter();
+        }
+    }
+
+    // like datetimes, map keys aren't checked since only values are serialized through the wrapper
+    fn has_raw_double_value(&self, def: &Type) -> bool {
+        match def {
+            Type::Primitive(def) => *def == PrimitiveType::Double,
+            Type::Optional(def) => self.has_raw_double_value(def.item_type()),
+            Type::List(def) => self.has_raw_double_value(def.item_type()),
+            Type::Set(def) => self.has_raw_double_value(def.item_type()),
+            Type::Map(def) => self.has_raw_double_value(def.value_type()),
+            Type::Reference(_) => false,
+            Type::External(def) if self.is_custom(def) => false,
+            Type::External(def) => self.has_raw_double_value(def.fallback()),
+        }
+    }
+
+    // map keys aren't included since only values are formatted
+    fn has_raw_datetime(&self, def: &Type) -> bool {
+        match def {
+            Type::Primitive(def) => *def == PrimitiveType::Datetime,
+            Type::Optional(def) => self.has_raw_datetime(def.item_type()),
+            Type::List(def) => self.has_raw_datetime(def.item_type()),
+            Type::Set(def) => self.has_raw_datetime(def.item_type()),
+            Type::Map(def) => self.has_raw_datetime(def.value_type()),
+            Type::Reference(_) => false,
+            Type::External(def) if self.is_custom(def) => false,
+            Type::External(def) => self.has_raw_datetime(def.fallback()),
+        }
+    }
+
     fn needs_box(&self, def: &Type) -> bool {
         match def {
             Type::Primitive(_) => false,
             Type::Optional(def) => self.needs_box(def.item_type()),
             Type::List(_) | Type::Set(_) | Type::Map(_) => false,
             Type::Reference(def) => self.ref_needs_box(def),
+            Type::External(def) if self.is_custom(def) => false,
             Type::External(def) => self.needs_box(def.fallback()),
         }
     }
@@ -95,6 +327,7 @@ impl Context {
             Type::Set(def) => self.has_double(def.item_type()),
             Type::Map(def) => self.has_double(def.key_type()) || self.has_double(def.value_type()),
             Type::Reference(def) => self.ref_has_double(def),
+            Type::External(def) if self.is_custom(def) => false,
             Type::External(def) => self.has_double(def.fallback()),
         }
     }
@@ -118,6 +351,95 @@ impl Context {
         has_double
     }
 
+    /// Returns true if the type directly contains doubles, rather than through a reference to another type.
+    pub fn has_raw_double(&self, def: &Type) -> bool {
+        match def {
+            Type::Primitive(def) => match *def {
+                PrimitiveType::Double => true,
+                _ => false,
+            },
+            Type::Optional(def) => self.has_raw_double(def.item_type()),
+            Type::List(def) => self.has_raw_double(def.item_type()),
+            Type::Set(def) => self.has_raw_double(def.item_type()),
+            Type::Map(def) => {
+                self.has_raw_double(def.key_type()) || self.has_raw_double(def.value_type())
+            }
+            Type::Reference(_) => false,
+            Type::External(def) if self.is_custom(def) => false,
+            Type::External(def) => self.has_raw_double(def.fallback()),
+        }
+    }
+
+    /// Returns true if the type contains doubles and should implement `Eq`, `Ord`, and `Hash` by comparing them
+    /// bitwise, or by their quantized values if a number of decimal places is configured.
+    ///
+    /// Unions containing doubles are not supported, so neither they nor any type referencing them qualify.
+    pub fn is_double_ord(&self, def: &Type) -> bool {
+        (self.double_ord || self.double_places.is_some())
+            && self.has_double(def)
+            && !self.has_double_union(def, &mut HashSet::new())
+    }
+
+    /// Returns an expression comparing two values containing raw doubles for equality.
+    pub fn double_eq(&self, a: TokenStream, b: TokenStream) -> TokenStream {
+        match self.double_places {
+            Some(places) => {
+                quote!(conjure_object::private::QuantizedDoubleOrd_::eq_(#a, #b, #places))
+            }
+            None => quote!(conjure_object::private::DoubleOrd_::eq_(#a, #b)),
+        }
+    }
+
+    /// Returns an expression ordering two values containing raw doubles.
+    pub fn double_cmp(&self, a: TokenStream, b: TokenStream) -> TokenStream {
+        match self.double_places {
+            Some(places) => {
+                quote!(conjure_object::private::QuantizedDoubleOrd_::cmp_(#a, #b, #places))
+            }
+            None => quote!(conjure_object::private::DoubleOrd_::cmp_(#a, #b)),
+        }
+    }
+
+    /// Returns an expression hashing a value containing raw doubles.
+    pub fn double_hash(&self, value: TokenStream, hasher: TokenStream) -> TokenStream {
+        match self.double_places {
+            Some(places) => {
+                quote!(conjure_object::private::QuantizedDoubleOrd_::hash_(#value, #places, #hasher))
+            }
+            None => quote!(conjure_object::private::DoubleOrd_::hash_(#value, #hasher)),
+        }
+    }
+
+    fn has_double_union<'a>(&'a self, def: &'a Type, visited: &mut HashSet<&'a TypeName>) -> bool {
+        match def {
+            Type::Primitive(_) => false,
+            Type::Optional(def) => self.has_double_union(def.item_type(), visited),
+            Type::List(def) => self.has_double_union(def.item_type(), visited),
+            Type::Set(def) => self.has_double_union(def.item_type(), visited),
+            Type::Map(def) => {
+                self.has_double_union(def.key_type(), visited)
+                    || self.has_double_union(def.value_type(), visited)
+            }
+            Type::Reference(name) => {
+                if !visited.insert(name) {
+                    return false;
+                }
+
+                match &self.types[name].def {
+                    TypeDefinition::Alias(def) => self.has_double_union(def.alias(), visited),
+                    TypeDefinition::Enum(_) => false,
+                    TypeDefinition::Object(def) => def
+                        .fields()
+                        .iter()
+                        .any(|f| self.has_double_union(f.type_(), visited)),
+                    TypeDefinition::Union(_) => self.ref_has_double(name),
+                }
+            }
+            Type::External(def) if self.is_custom(def) => false,
+            Type::External(def) => self.has_double_union(def.fallback(), visited),
+        }
+    }
+
     pub fn is_copy(&self, def: &Type) -> bool {
         match def {
             Type::Primitive(def) => match *def {
@@ -136,6 +458,7 @@ impl Context {
             Type::Optional(def) => self.is_copy(def.item_type()),
             Type::List(_) | Type::Set(_) | Type::Map(_) => false,
             Type::Reference(def) => self.ref_is_copy(def),
+            Type::External(def) if self.is_custom(def) => false,
             Type::External(def) => self.is_copy(def.fallback()),
         }
     }
@@ -149,7 +472,8 @@ impl Context {
 
         let is_copy = match &ctx.def {
             TypeDefinition::Alias(def) => self.is_copy(def.alias()),
-            TypeDefinition::Enum(_) | TypeDefinition::Object(_) | TypeDefinition::Union(_) => false,
+            TypeDefinition::Enum(_) => self.exhaustive,
+            TypeDefinition::Object(_) | TypeDefinition::Union(_) => false,
         };
 
         ctx.is_copy.set(Some(is_copy));
@@ -161,6 +485,7 @@ impl Context {
             Type::Primitive(_) => true,
             Type::Optional(_) | Type::List(_) | Type::Set(_) | Type::Map(_) => false,
             Type::Reference(def) => self.ref_is_required(def),
+            Type::External(def) if self.is_custom(def) => true,
             Type::External(def) => self.is_required(def.fallback()),
         }
     }
@@ -191,6 +516,7 @@ impl Context {
             },
             Type::Optional(_) | Type::List(_) | Type::Set(_) | Type::Map(_) => true,
             Type::Reference(def) => self.ref_is_default(def),
+            Type::External(def) if self.is_custom(def) => false,
             Type::External(def) => self.is_default(def.fallback()),
         }
     }
@@ -219,6 +545,7 @@ impl Context {
             },
             Type::Optional(_) | Type::List(_) | Type::Set(_) | Type::Map(_) => false,
             Type::Reference(def) => self.ref_is_display(def),
+            Type::External(def) if self.is_custom(def) => false,
             Type::External(def) => self.is_display(def.fallback()),
         }
     }
@@ -231,16 +558,44 @@ impl Context {
         }
     }
 
+    pub fn is_plain(&self, def: &Type) -> bool {
+        match def {
+            Type::Primitive(def) => match *def {
+                PrimitiveType::String
+                | PrimitiveType::Datetime
+                | PrimitiveType::Integer
+                | PrimitiveType::Double
+                | PrimitiveType::Safelong
+                | PrimitiveType::Boolean
+                | PrimitiveType::Uuid
+                | PrimitiveType::Rid
+                | PrimitiveType::Bearertoken => true,
+                PrimitiveType::Binary | PrimitiveType::Any => false,
+            },
+            Type::Optional(_) | Type::List(_) | Type::Set(_) | Type::Map(_) => false,
+            Type::Reference(def) => self.ref_is_plain(def),
+            Type::External(def) if self.is_custom(def) => false,
+            Type::External(def) => self.is_plain(def.fallback()),
+        }
+    }
+
+    fn ref_is_plain(&self, name: &TypeName) -> bool {
+        match &self.types[name].def {
+            TypeDefinition::Alias(def) => self.is_plain(def.alias()),
+            TypeDefinition::Enum(_) | TypeDefinition::Object(_) | TypeDefinition::Union(_) => false,
+        }
+    }
+
     pub fn rust_type(&self, this_type: &TypeName, def: &Type) -> TokenStream {
         match def {
             Type::Primitive(def) => match *def {
-                PrimitiveType::String => self.string_ident(this_type),
+                PrimitiveType::String => self.string_type(this_type),
                 PrimitiveType::Datetime => quote!(conjure_object::DateTime<conjure_object::Utc>),
                 PrimitiveType::Integer => quote!(i32),
                 PrimitiveType::Double => quote!(f64),
                 PrimitiveType::Safelong => quote!(conjure_object::SafeLong),
                 PrimitiveType::Binary => quote!(conjure_object::ByteBuf),
-                PrimitiveType::Any => quote!(conjure_object::Value),
+                PrimitiveType::Any => self.any_type(this_type),
                 PrimitiveType::Boolean => quote!(bool),
                 PrimitiveType::Uuid => quote!(conjure_object::Uuid),
                 PrimitiveType::Rid => quote!(conjure_object::ResourceIdentifier),
@@ -265,10 +620,8 @@ impl Context {
                 let value = self.rust_type(this_type, def.value_type());
                 quote!(std::collections::BTreeMap<#key, #value>)
             }
-            Type::Reference(def) => {
-                let name = self.type_name(def.name());
-                quote!(super::#name)
-            }
+            Type::Reference(def) => self.type_path(def),
+            Type::External(def) if self.is_custom(def) => self.custom_rust_type(def),
             Type::External(def) => self.rust_type(this_type, def.fallback()),
         }
     }
@@ -281,41 +634,274 @@ impl Context {
                 quote!(#option<#item>)
             }
             Type::Reference(def) => self.ref_boxed_rust_type(this_type, def),
+            Type::External(def) if self.is_custom(def) => self.custom_rust_type(def),
             Type::External(def) => self.boxed_rust_type(this_type, def.fallback()),
             def => self.rust_type(this_type, def),
         }
     }
 
-    fn ref_boxed_rust_type(&self, this_type: &TypeName, name: &TypeName) -> TokenStream {
-        let ctx = &self.types[name];
+    /// Returns the type a field's value is stored as in the object.
+    ///
+    /// This is the boxed type, except that objects with compact fields store strings and lists as boxed slices.
+    pub fn stored_rust_type(&self, this_type: &TypeName, def: &Type) -> TokenStream {
+        if !self.compacts_fields(this_type) {
+            return self.boxed_rust_type(this_type, def);
+        }
 
-        let needs_box = match &ctx.def {
-            TypeDefinition::Alias(def) => self.needs_box(def.alias()),
-            TypeDefinition::Enum(_) => false,
-            TypeDefinition::Object(_) => match &self.types[this_type].def {
-                TypeDefinition::Union(_) => false,
-                _ => true,
-            },
-            TypeDefinition::Union(_) => true,
-        };
+        match def {
+            Type::Primitive(PrimitiveType::String) => {
+                let box_ = self.box_ident(this_type);
+                quote!(#box_<str>)
+            }
+            Type::List(def) => {
+                let box_ = self.box_ident(this_type);
+                let item = self.rust_type(this_type, def.item_type());
+                quote!(#box_<[#item]>)
+            }
+            Type::Optional(def) => {
+                let option = self.option_ident(this_type);
+                let item = self.stored_rust_type(this_type, def.item_type());
+                quote!(#option<#item>)
+            }
+            Type::External(def) if !self.is_custom(def) => {
+                self.stored_rust_type(this_type, def.fallback())
+            }
+            def => self.boxed_rust_type(this_type, def),
+        }
+    }
 
-        let unboxed = self.type_name(name.name());
-        if needs_box {
-            let box_ = self.box_ident(name);
-            quote!(#box_<super::#unboxed>)
+    /// Returns true if the field's value is stored as a boxed slice rather than as its boxed type.
+    pub fn is_compact(&self, this_type: &TypeName, def: &Type) -> bool {
+        if !self.compacts_fields(this_type) {
+            return false;
+        }
+
+        match def {
+            Type::Primitive(PrimitiveType::String) | Type::List(_) => true,
+            Type::Optional(def) => self.is_compact(this_type, def.item_type()),
+            Type::External(def) if !self.is_custom(def) => {
+                self.is_compact(this_type, def.fallback())
+            }
+            _ => false,
+        }
+    }
+
+    /// Converts a value between a field's boxed and stored types, in either direction.
+    pub fn convert_compact(
+        &self,
+        this_type: &TypeName,
+        def: &Type,
+        value: TokenStream,
+    ) -> TokenStream {
+        if !self.is_compact(this_type, def) {
+            return value;
+        }
+
+        match self.option_inner_type(def) {
+            Some(_) => {
+                let into = self.into_ident(this_type);
+                quote!(#value.map(#into::into))
+            }
+            None => quote!(#value.into()),
+        }
+    }
+
+    /// Returns the function boxing a value of the type, if it's stored boxed in the object.
+    pub fn box_fn(&self, this_type: &TypeName, def: &Type) -> Option<TokenStream> {
+        match def {
+            Type::Reference(name) if self.ref_is_boxed(this_type, name) => {
+                let box_ = self.recursive_box_ident(this_type, name);
+                Some(quote!(#box_::new))
+            }
+            _ => None,
+        }
+    }
+
+    fn ref_boxed_rust_type(&self, this_type: &TypeName, name: &TypeName) -> TokenStream {
+        let unboxed = self.type_path(name);
+        if self.ref_is_boxed(this_type, name) {
+            let box_ = self.recursive_box_ident(this_type, name);
+            quote!(#box_<#unboxed>)
         } else {
-            quote!(super::#unboxed)
+            unboxed
+        }
+    }
+
+    fn ref_is_boxed(&self, this_type: &TypeName, name: &TypeName) -> bool {
+        // union variants only need to be boxed when they're part of a cycle back to the union
+        if let TypeDefinition::Union(_) = &self.types[this_type].def {
+            return match &self.types[name].def {
+                TypeDefinition::Object(_) => false,
+                _ => {
+                    self.ref_needs_box(name)
+                        && self.references(&Type::Reference(name.clone()), this_type)
+                }
+            };
+        }
+
+        self.ref_needs_box(name)
+    }
+
+    // with shared recursion, the boxes breaking cycles through a union are reference counted so clones share them
+    fn recursive_box_ident(&self, this_type: &TypeName, name: &TypeName) -> TokenStream {
+        match &self.types[this_type].def {
+            TypeDefinition::Union(_) if self.shared_recursion => quote!(std::sync::Arc),
+            _ => self.box_ident(name),
+        }
+    }
+
+    pub fn box_value(&self, this_type: &TypeName, def: &Type, value: TokenStream) -> TokenStream {
+        match def {
+            Type::Optional(def) if self.is_boxed(this_type, def.item_type()) => {
+                let item = self.box_value(this_type, def.item_type(), quote!(v));
+                quote!(#value.map(|v| #item))
+            }
+            Type::Reference(def) if self.ref_is_boxed(this_type, def) => {
+                let box_ = self.recursive_box_ident(this_type, this_type);
+                quote!(#box_::new(#value))
+            }
+            Type::External(def) if self.is_custom(def) => value,
+            Type::External(def) => self.box_value(this_type, def.fallback(), value),
+            _ => value,
+        }
+    }
+
+    pub fn is_boxed(&self, this_type: &TypeName, def: &Type) -> bool {
+        match def {
+            Type::Optional(def) => self.is_boxed(this_type, def.item_type()),
+            Type::Reference(def) => self.ref_is_boxed(this_type, def),
+            Type::External(def) if self.is_custom(def) => false,
+            Type::External(def) => self.is_boxed(this_type, def.fallback()),
+            _ => false,
+        }
+    }
+
+    pub fn references(&self, def: &Type, name: &TypeName) -> bool {
+        self.references_inner(def, name, &mut HashSet::new())
+    }
+
+    fn references_inner<'a>(
+        &'a self,
+        def: &'a Type,
+        name: &TypeName,
+        visited: &mut HashSet<&'a TypeName>,
+    ) -> bool {
+        match def {
+            Type::Primitive(_) => false,
+            Type::Optional(def) => self.references_inner(def.item_type(), name, visited),
+            Type::List(def) => self.references_inner(def.item_type(), name, visited),
+            Type::Set(def) => self.references_inner(def.item_type(), name, visited),
+            Type::Map(def) => {
+                self.references_inner(def.key_type(), name, visited)
+                    || self.references_inner(def.value_type(), name, visited)
+            }
+            Type::Reference(def) => {
+                if def == name {
+                    return true;
+                }
+
+                if !visited.insert(def) {
+                    return false;
+                }
+
+                match &self.types[def].def {
+                    TypeDefinition::Alias(def) => self.references_inner(def.alias(), name, visited),
+                    TypeDefinition::Enum(_) => false,
+                    TypeDefinition::Object(def) => def
+                        .fields()
+                        .iter()
+                        .any(|f| self.references_inner(f.type_(), name, visited)),
+                    TypeDefinition::Union(def) => def
+                        .union_()
+                        .iter()
+                        .any(|f| self.references_inner(f.type_(), name, visited)),
+                }
+            }
+            Type::External(def) if self.is_custom(def) => false,
+            Type::External(def) => self.references_inner(def.fallback(), name, visited),
         }
     }
 
     pub fn option_inner_type<'a>(&self, def: &'a Type) -> Option<&'a Type> {
         match def {
             Type::Optional(def) => Some(def.item_type()),
+            Type::External(def) if self.is_custom(def) => None,
             Type::External(def) => self.option_inner_type(def.fallback()),
             _ => None,
         }
     }
 
+    pub fn list_item_type<'a>(&self, def: &'a Type) -> Option<&'a Type> {
+        match def {
+            Type::List(def) => Some(def.item_type()),
+            Type::External(def) if self.is_custom(def) => None,
+            Type::External(def) => self.list_item_type(def.fallback()),
+            _ => None,
+        }
+    }
+
+    pub fn map_types<'a>(&self, def: &'a Type) -> Option<(&'a Type, &'a Type)> {
+        match def {
+            Type::Map(def) => Some((def.key_type(), def.value_type())),
+            Type::External(def) if self.is_custom(def) => None,
+            Type::External(def) => self.map_types(def.fallback()),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the type is an optional of a type which can itself be null, such as an alias of an optional.
+    pub fn is_nested_optional(&self, def: &Type) -> bool {
+        match self.option_inner_type(def) {
+            Some(def) => self.is_nullable(def),
+            None => false,
+        }
+    }
+
+    fn is_nullable(&self, def: &Type) -> bool {
+        match def {
+            Type::Optional(_) => true,
+            Type::Reference(name) => match &self.types[name].def {
+                TypeDefinition::Alias(def) => self.is_nullable(def.alias()),
+                _ => false,
+            },
+            Type::External(def) if self.is_custom(def) => false,
+            Type::External(def) => self.is_nullable(def.fallback()),
+            _ => false,
+        }
+    }
+
+    pub fn is_string(&self, def: &Type) -> bool {
+        match def {
+            Type::Primitive(PrimitiveType::String) => true,
+            Type::External(def) if self.is_custom(def) => false,
+            Type::External(def) => self.is_string(def.fallback()),
+            _ => false,
+        }
+    }
+
+    fn ref_is_string_alias(&self, name: &TypeName) -> bool {
+        match &self.types[name].def {
+            TypeDefinition::Alias(def) => self.is_string(def.alias()),
+            _ => false,
+        }
+    }
+
+    pub fn is_set(&self, def: &Type) -> bool {
+        match def {
+            Type::Set(_) => true,
+            Type::External(def) if self.is_custom(def) => false,
+            Type::External(def) => self.is_set(def.fallback()),
+            _ => false,
+        }
+    }
+
+    pub fn is_optional_set(&self, def: &Type) -> bool {
+        match self.option_inner_type(def) {
+            Some(def) => self.is_set(def),
+            None => false,
+        }
+    }
+
     pub fn borrowed_rust_type(&self, this_type: &TypeName, def: &Type) -> TokenStream {
         match def {
             Type::Primitive(def) => match *def {
@@ -325,7 +911,10 @@ impl Context {
                 PrimitiveType::Double => quote!(f64),
                 PrimitiveType::Safelong => quote!(conjure_object::SafeLong),
                 PrimitiveType::Binary => quote!(&[u8]),
-                PrimitiveType::Any => quote!(&conjure_object::Value),
+                PrimitiveType::Any => {
+                    let any = self.any_type(this_type);
+                    quote!(&#any)
+                }
                 PrimitiveType::Boolean => quote!(bool),
                 PrimitiveType::Uuid => quote!(conjure_object::Uuid),
                 PrimitiveType::Rid => quote!(&conjure_object::ResourceIdentifier),
@@ -350,6 +939,10 @@ impl Context {
                 quote!(&std::collections::BTreeMap<#key, #value>)
             }
             Type::Reference(def) => self.borrowed_rust_type_ref(def),
+            Type::External(def) if self.is_custom(def) => {
+                let type_ = self.custom_rust_type(def);
+                quote!(&#type_)
+            }
             Type::External(def) => self.borrowed_rust_type(this_type, def.fallback()),
         }
     }
@@ -357,8 +950,7 @@ impl Context {
     fn borrowed_rust_type_ref(&self, name: &TypeName) -> TokenStream {
         let ctx = &self.types[name];
 
-        let type_ = self.type_name(name.name());
-        let type_ = quote!(super::#type_);
+        let type_ = self.type_path(name);
         match &ctx.def {
             TypeDefinition::Alias(def) => {
                 if self.is_copy(def.alias()) {
@@ -394,6 +986,7 @@ impl Context {
             Type::List(_) => quote!(&*#value),
             Type::Set(_) | Type::Map(_) => quote!(&#value),
             Type::Reference(def) => self.borrow_rust_type_ref(value, def),
+            Type::External(def) if self.is_custom(def) => quote!(&#value),
             Type::External(def) => self.borrow_rust_type(value, def.fallback()),
         }
     }
@@ -426,7 +1019,7 @@ impl Context {
             Type::Primitive(primitive) => match *primitive {
                 PrimitiveType::String => {
                     let into = self.into_ident(this_type);
-                    let string = self.string_ident(this_type);
+                    let string = self.string_type(this_type);
                     SetterBounds::Generic {
                         argument_bound: quote!(#into<#string>),
                         assign_rhs: quote!(#value_ident.into()),
@@ -442,9 +1035,7 @@ impl Context {
                 }
                 PrimitiveType::Any => SetterBounds::Generic {
                     argument_bound: quote!(conjure_object::serde::Serialize),
-                    assign_rhs: quote! {
-                        conjure_object::serde_value::to_value(#value_ident).expect("value failed to serialize")
-                    },
+                    assign_rhs: self.any_value(this_type, value_ident),
                 },
                 _ => SetterBounds::Simple {
                     argument_type: self.rust_type(this_type, def),
@@ -511,8 +1102,16 @@ impl Context {
                     },
                 }
             }
+            Type::Reference(def) if self.ref_is_string_alias(def) => {
+                let into = self.into_ident(this_type);
+                let type_ = self.type_path(def);
+                SetterBounds::Generic {
+                    argument_bound: quote!(#into<#type_>),
+                    assign_rhs: quote!(#value_ident.into()),
+                }
+            }
             Type::Reference(def) => {
-                let type_ = self.type_name(def.name());
+                let type_ = self.type_path(def);
                 let mut assign_rhs = value_ident;
                 if self.ref_needs_box(def) {
                     let box_ = self.box_ident(this_type);
@@ -520,10 +1119,14 @@ impl Context {
                 }
 
                 SetterBounds::Simple {
-                    argument_type: quote!(super::#type_),
+                    argument_type: type_,
                     assign_rhs,
                 }
             }
+            Type::External(def) if self.is_custom(def) => SetterBounds::Simple {
+                argument_type: self.custom_rust_type(def),
+                assign_rhs: value_ident,
+            },
             Type::External(def) => self.setter_bounds(this_type, def.fallback(), value_ident),
         }
     }
@@ -538,7 +1141,7 @@ impl Context {
             Type::Primitive(primitive) => match *primitive {
                 PrimitiveType::String => {
                     let into = self.into_ident(this_type);
-                    let string = self.string_ident(this_type);
+                    let string = self.string_type(this_type);
                     CollectionSetterBounds::Generic {
                         argument_bound: quote!(#into<#string>),
                         assign_rhs: quote!(#value_ident.into()),
@@ -554,9 +1157,7 @@ impl Context {
                 }
                 PrimitiveType::Any => CollectionSetterBounds::Generic {
                     argument_bound: quote!(conjure_object::serde::Serialize),
-                    assign_rhs: quote! {
-                        conjure_object::serde_value::to_value(#value_ident).expect("value failed to serialize")
-                    },
+                    assign_rhs: self.any_value(this_type, value_ident),
                 },
                 _ => CollectionSetterBounds::Simple {
                     argument_type: self.rust_type(this_type, def),
@@ -597,13 +1198,22 @@ impl Context {
                     assign_rhs: quote!(#value_ident.into_iter().collect()),
                 }
             }
-            Type::Reference(def) => {
-                let type_ = self.type_name(def.name());
-                CollectionSetterBounds::Simple {
-                    argument_type: quote!(super::#type_),
-                    assign_rhs: value_ident,
+            Type::Reference(def) if self.ref_is_string_alias(def) => {
+                let into = self.into_ident(this_type);
+                let type_ = self.type_path(def);
+                CollectionSetterBounds::Generic {
+                    argument_bound: quote!(#into<#type_>),
+                    assign_rhs: quote!(#value_ident.into()),
                 }
             }
+            Type::Reference(def) => CollectionSetterBounds::Simple {
+                argument_type: self.type_path(def),
+                assign_rhs: value_ident,
+            },
+            Type::External(def) if self.is_custom(def) => CollectionSetterBounds::Simple {
+                argument_type: self.custom_rust_type(def),
+                assign_rhs: value_ident,
+            },
             Type::External(def) => {
                 self.collection_setter_bounds(this_type, def.fallback(), value_ident)
             }
@@ -616,6 +1226,7 @@ impl Context {
             Type::Optional(_) => Some(quote!(is_none)),
             Type::List(_) | Type::Set(_) | Type::Map(_) => Some(quote!(is_empty)),
             Type::Reference(def) => self.is_empty_method_ref(def),
+            Type::External(def) if self.is_custom(def) => None,
             Type::External(def) => self.is_empty_method(def.fallback()),
         }
     }
@@ -639,6 +1250,25 @@ impl Context {
         }
     }
 
+    pub fn type_name_consts(&self, name: &TypeName) -> TokenStream {
+        let type_name = name.name();
+        let package = name.package();
+        let vis = self.visibility();
+        // the lifetime can't be elided in the impl of a type with a lifetime parameter
+        let str_ = if self.borrows_strings(name) {
+            quote!(&'static str)
+        } else {
+            quote!(&str)
+        };
+        quote! {
+            /// The Conjure name of the type.
+            #vis const CONJURE_TYPE_NAME: #str_ = #type_name;
+
+            /// The Conjure package of the type.
+            #vis const CONJURE_PACKAGE: #str_ = #package;
+        }
+    }
+
     pub fn box_ident(&self, name: &TypeName) -> TokenStream {
         self.prelude_ident(name, "Box", "std::boxed::Box")
     }
@@ -667,6 +1297,36 @@ impl Context {
         self.prelude_ident(name, "None", "Option::None")
     }
 
+    // objects borrowing strings store them as copy-on-write references to the deserializer's input
+    fn string_type(&self, this_type: &TypeName) -> TokenStream {
+        if self.borrows_strings(this_type) {
+            quote!(std::borrow::Cow<'a, str>)
+        } else {
+            self.string_ident(this_type)
+        }
+    }
+
+    fn any_type(&self, this_type: &TypeName) -> TokenStream {
+        if self.raw_any_fields(this_type) {
+            quote!(conjure_object::RawValue)
+        } else {
+            quote!(conjure_object::Value)
+        }
+    }
+
+    // converts a setter's argument to the stored representation of an `any` value
+    fn any_value(&self, this_type: &TypeName, value_ident: TokenStream) -> TokenStream {
+        if self.raw_any_fields(this_type) {
+            quote! {
+                conjure_object::RawValue::from_serialize(&#value_ident).expect("value failed to serialize")
+            }
+        } else {
+            quote! {
+                conjure_object::serde_value::to_value(#value_ident).expect("value failed to serialize")
+            }
+        }
+    }
+
     pub fn string_ident(&self, name: &TypeName) -> TokenStream {
         self.prelude_ident(name, "String", "std::string::String")
     }
@@ -709,7 +1369,19 @@ impl Context {
     }
 
     pub fn field_name(&self, s: &str) -> Ident {
-        Ident::new(&self.ident_name(s), Span::call_site())
+        let name = match self.field_renames.get(s) {
+            Some(name) => name.clone(),
+            None => self.ident_name(s),
+        };
+        Ident::new(&name, Span::call_site())
+    }
+
+    // Renamed fields' accessors are derived from their new names rather than their Conjure names.
+    fn accessor_base_name(&self, s: &str) -> String {
+        match self.field_renames.get(s) {
+            Some(name) => name.clone(),
+            None => s.to_snake_case(),
+        }
     }
 
     fn ident_name(&self, s: &str) -> String {
@@ -736,6 +1408,32 @@ impl Context {
         s
     }
 
+    pub fn mut_accessor_name(&self, s: &str) -> Ident {
+        let name = format!("{}_mut", self.accessor_base_name(s));
+        Ident::new(&name, Span::call_site())
+    }
+
+    pub fn require_accessor_name(&self, s: &str) -> Ident {
+        let name = format!("require_{}", self.accessor_base_name(s));
+        Ident::new(&name, Span::call_site())
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn into_accessor_name(&self, s: &str) -> Ident {
+        let name = format!("into_{}", self.accessor_base_name(s));
+        Ident::new(&name, Span::call_site())
+    }
+
+    pub fn conversion_name(&self, source: &TypeName) -> Ident {
+        let name = format!("try_from_{}", source.name().to_snake_case());
+        Ident::new(&name, Span::call_site())
+    }
+
+    pub fn strategy_name(&self, name: &TypeName) -> Ident {
+        let name = format!("arb_{}", name.name().to_snake_case());
+        Ident::new(&name, Span::call_site())
+    }
+
     pub fn type_name(&self, name: &str) -> Ident {
         let mut name = name.to_camel_case();
 
@@ -790,3 +1488,12 @@ pub enum CollectionSetterBounds {
         assign_rhs: TokenStream,
     },
 }
+
+pub fn definition_name(def: &TypeDefinition) -> &TypeName {
+    match def {
+        TypeDefinition::Alias(def) => def.type_name(),
+        TypeDefinition::Enum(def) => def.type_name(),
+        TypeDefinition::Object(def) => def.type_name(),
+        TypeDefinition::Union(def) => def.type_name(),
+    }
+}
@@ -11,7 +11,7 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::quote;
 
 use crate::context::Context;
@@ -31,6 +31,7 @@ pub fn generate(ctx: &Context, def: &EnumDefinition) -> TokenStream {
 }
 
 fn generate_enum(ctx: &Context, def: &EnumDefinition) -> TokenStream {
+    let vis = ctx.visibility();
     let root_docs = ctx.docs(def.docs());
     let name = ctx.type_name(def.type_name().name());
     let result = ctx.result_ident(def.type_name());
@@ -63,6 +64,20 @@ fn generate_enum(ctx: &Context, def: &EnumDefinition) -> TokenStream {
         quote!(#name::Unknown(v) => &*v,)
     };
 
+    let ordinal_arms = def.values().iter().map(|v| {
+        let variant = ctx.type_name(v.value());
+        let ordinal = Literal::u32_unsuffixed(conjure_object::private::enum_ordinal(v.value()));
+        quote! {
+            #name::#variant => #ordinal,
+        }
+    });
+
+    let ordinal_other = if ctx.exhaustive() {
+        quote!()
+    } else {
+        quote!(#name::Unknown(v) => conjure_object::private::enum_ordinal(v),)
+    };
+
     let visit_str_arms = def.values().iter().map(|v| {
         let value = v.value();
         let variant = ctx.type_name(value);
@@ -92,10 +107,19 @@ fn generate_enum(ctx: &Context, def: &EnumDefinition) -> TokenStream {
         }
     };
 
+    let copy = if ctx.exhaustive() {
+        quote!(Copy,)
+    } else {
+        quote!()
+    };
+
+    let type_name_consts = ctx.type_name_consts(def.type_name());
+    let span = ctx.deserialize_span(def.type_name());
+
     quote! {
         #root_docs
-        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-        pub enum #name {
+        #[derive(Debug, Clone, #copy PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #vis enum #name {
             #(
                 #variants,
             )*
@@ -103,14 +127,35 @@ fn generate_enum(ctx: &Context, def: &EnumDefinition) -> TokenStream {
         }
 
         impl #name {
+            #type_name_consts
+
             /// Returns the string representation of the enum.
             #[inline]
-            pub fn as_str(&self) -> &str {
+            #vis fn as_str(&self) -> &str {
                 match self {
                     #(#as_str_arms)*
                     #as_str_other
                 }
             }
+
+            /// Returns a stable numeric identifier of the enum.
+            ///
+            /// The ordinal is a hash of the string representation rather than the position of the variant, so it is
+            /// unaffected by reordering the enum's definition. Distinct variants are not guaranteed to have distinct
+            /// ordinals.
+            #[inline]
+            #vis fn ordinal(&self) -> u32 {
+                match self {
+                    #(#ordinal_arms)*
+                    #ordinal_other
+                }
+            }
+
+            /// Compares the enum to another by their string representations rather than by declaration order.
+            #[inline]
+            #vis fn cmp_by_name(&self, other: &#name) -> std::cmp::Ordering {
+                self.as_str().cmp(other.as_str())
+            }
         }
 
         impl fmt::Display for #name {
@@ -133,6 +178,7 @@ fn generate_enum(ctx: &Context, def: &EnumDefinition) -> TokenStream {
             where
                 D: de::Deserializer<'de>
             {
+                #span
                 d.deserialize_str(Visitor_)
             }
         }
@@ -168,6 +214,7 @@ fn unknown(ctx: &Context, def: &EnumDefinition) -> TokenStream {
 }
 
 fn generate_unknown(ctx: &Context, def: &EnumDefinition) -> TokenStream {
+    let vis = ctx.visibility();
     if ctx.exhaustive() {
         return quote!();
     }
@@ -183,7 +230,7 @@ fn generate_unknown(ctx: &Context, def: &EnumDefinition) -> TokenStream {
     quote! {
         #[doc = #doc]
         #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-        pub struct #unknown(#box_<str>);
+        #vis struct #unknown(#box_<str>);
 
         impl std::ops::Deref for #unknown {
             type Target = str;
@@ -0,0 +1,144 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::context::Context;
+use crate::objects;
+use crate::types::{ErrorDefinition, FieldDefinition, ObjectDefinition};
+
+pub fn generate(ctx: &Context, def: &ErrorDefinition) -> TokenStream {
+    let object = generate_object(ctx, def);
+    let error = generate_error(ctx, def);
+
+    quote! {
+        #object
+        #error
+    }
+}
+
+// Errors are represented as objects containing both their safe and unsafe parameters.
+fn generate_object(ctx: &Context, def: &ErrorDefinition) -> TokenStream {
+    let object = ObjectDefinition::builder()
+        .type_name(def.error_name().clone())
+        .fields(def.safe_args().iter().chain(def.unsafe_args()).cloned())
+        .docs(def.docs().cloned())
+        .build();
+
+    objects::generate(ctx, &object)
+}
+
+fn generate_error(ctx: &Context, def: &ErrorDefinition) -> TokenStream {
+    let vis = ctx.visibility();
+    let name = ctx.type_name(def.error_name().name());
+    let error_name = format!("{}:{}", def.namespace(), def.error_name().name());
+    let code = def.code().as_str();
+
+    let code_method = method_name(ctx, def, "code");
+    let name_method = method_name(ctx, def, "name");
+    let safe_params_method = method_name(ctx, def, "safe_params");
+    let unsafe_params_method = method_name(ctx, def, "unsafe_params");
+
+    let from = ctx.from_ident(def.error_name());
+
+    let safe_params = generate_params(ctx, def.safe_args());
+    let unsafe_params = generate_params(ctx, def.unsafe_args());
+
+    quote! {
+        impl #name {
+            /// Returns the error's code.
+            #[inline]
+            #vis fn #code_method(&self) -> &'static str {
+                #code
+            }
+
+            /// Returns the error's name.
+            #[inline]
+            #vis fn #name_method(&self) -> &'static str {
+                #error_name
+            }
+
+            /// Returns the error's safe parameters.
+            #vis fn #safe_params_method(
+                &self,
+            ) -> std::collections::BTreeMap<&'static str, conjure_object::Value> {
+                #safe_params
+            }
+
+            /// Returns the error's unsafe parameters.
+            #vis fn #unsafe_params_method(
+                &self,
+            ) -> std::collections::BTreeMap<&'static str, conjure_object::Value> {
+                #unsafe_params
+            }
+        }
+
+        impl fmt::Display for #name {
+            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                conjure_object::private::fmt_error(
+                    fmt,
+                    self.#name_method(),
+                    &self.#safe_params_method(),
+                )
+            }
+        }
+
+        impl std::error::Error for #name {}
+
+        impl #from<#name> for conjure_object::SerializableError {
+            fn from(error: #name) -> conjure_object::SerializableError {
+                conjure_object::private::serializable_error(
+                    error.#code_method(),
+                    error.#name_method(),
+                    error.#safe_params_method(),
+                    error.#unsafe_params_method(),
+                )
+            }
+        }
+    }
+}
+
+fn generate_params(ctx: &Context, args: &[FieldDefinition]) -> TokenStream {
+    if args.is_empty() {
+        return quote!(std::collections::BTreeMap::new());
+    }
+
+    let inserts = args.iter().map(|f| {
+        let key = &f.field_name().0;
+        let field = ctx.field_name(f.field_name());
+        quote! {
+            params.insert(#key, conjure_object::private::error_param(&self.#field));
+        }
+    });
+
+    quote! {
+        let mut params = std::collections::BTreeMap::new();
+        #(#inserts)*
+        params
+    }
+}
+
+fn method_name(ctx: &Context, def: &ErrorDefinition, name: &str) -> TokenStream {
+    let collides = def
+        .safe_args()
+        .iter()
+        .chain(def.unsafe_args())
+        .any(|f| ctx.field_name(f.field_name()) == name);
+
+    if collides {
+        format!("{}_", name).parse().unwrap()
+    } else {
+        name.parse().unwrap()
+    }
+}
@@ -13,6 +13,10 @@ pub struct AliasAsMapKeyExample {
     uuids: std::collections::BTreeMap<super::UuidAliasExample, super::ManyFieldExample>,
 }
 impl AliasAsMapKeyExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "AliasAsMapKeyExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Returns a new builder."]
     #[inline]
     pub fn builder() -> Builder {
@@ -60,6 +64,174 @@ impl AliasAsMapKeyExample {
     ) -> &std::collections::BTreeMap<super::UuidAliasExample, super::ManyFieldExample> {
         &self.uuids
     }
+    #[doc = "Returns the `strings` field, consuming the object."]
+    #[inline]
+    pub fn into_strings(
+        self,
+    ) -> std::collections::BTreeMap<super::StringAliasExample, super::ManyFieldExample> {
+        self.strings
+    }
+    #[doc = "Returns the `rids` field, consuming the object."]
+    #[inline]
+    pub fn into_rids(
+        self,
+    ) -> std::collections::BTreeMap<super::RidAliasExample, super::ManyFieldExample> {
+        self.rids
+    }
+    #[doc = "Returns the `bearertokens` field, consuming the object."]
+    #[inline]
+    pub fn into_bearertokens(
+        self,
+    ) -> std::collections::BTreeMap<super::BearerTokenAliasExample, super::ManyFieldExample> {
+        self.bearertokens
+    }
+    #[doc = "Returns the `integers` field, consuming the object."]
+    #[inline]
+    pub fn into_integers(
+        self,
+    ) -> std::collections::BTreeMap<super::IntegerAliasExample, super::ManyFieldExample> {
+        self.integers
+    }
+    #[doc = "Returns the `safelongs` field, consuming the object."]
+    #[inline]
+    pub fn into_safelongs(
+        self,
+    ) -> std::collections::BTreeMap<super::SafeLongAliasExample, super::ManyFieldExample> {
+        self.safelongs
+    }
+    #[doc = "Returns the `datetimes` field, consuming the object."]
+    #[inline]
+    pub fn into_datetimes(
+        self,
+    ) -> std::collections::BTreeMap<super::DateTimeAliasExample, super::ManyFieldExample> {
+        self.datetimes
+    }
+    #[doc = "Returns the `uuids` field, consuming the object."]
+    #[inline]
+    pub fn into_uuids(
+        self,
+    ) -> std::collections::BTreeMap<super::UuidAliasExample, super::ManyFieldExample> {
+        self.uuids
+    }
+    #[doc = "Returns an iterator over the entries of the `strings` field."]
+    #[inline]
+    pub fn strings_iter(
+        &self,
+    ) -> impl Iterator<Item = (&super::StringAliasExample, &super::ManyFieldExample)> {
+        self.strings.iter()
+    }
+    #[doc = "Returns an iterator over the keys of the `strings` field."]
+    #[inline]
+    pub fn strings_keys(&self) -> impl Iterator<Item = &super::StringAliasExample> {
+        self.strings.keys()
+    }
+    #[doc = "Returns an iterator over the values of the `strings` field."]
+    #[inline]
+    pub fn strings_values(&self) -> impl Iterator<Item = &super::ManyFieldExample> {
+        self.strings.values()
+    }
+    #[doc = "Returns an iterator over the entries of the `rids` field."]
+    #[inline]
+    pub fn rids_iter(
+        &self,
+    ) -> impl Iterator<Item = (&super::RidAliasExample, &super::ManyFieldExample)> {
+        self.rids.iter()
+    }
+    #[doc = "Returns an iterator over the keys of the `rids` field."]
+    #[inline]
+    pub fn rids_keys(&self) -> impl Iterator<Item = &super::RidAliasExample> {
+        self.rids.keys()
+    }
+    #[doc = "Returns an iterator over the values of the `rids` field."]
+    #[inline]
+    pub fn rids_values(&self) -> impl Iterator<Item = &super::ManyFieldExample> {
+        self.rids.values()
+    }
+    #[doc = "Returns an iterator over the entries of the `bearertokens` field."]
+    #[inline]
+    pub fn bearertokens_iter(
+        &self,
+    ) -> impl Iterator<Item = (&super::BearerTokenAliasExample, &super::ManyFieldExample)> {
+        self.bearertokens.iter()
+    }
+    #[doc = "Returns an iterator over the keys of the `bearertokens` field."]
+    #[inline]
+    pub fn bearertokens_keys(&self) -> impl Iterator<Item = &super::BearerTokenAliasExample> {
+        self.bearertokens.keys()
+    }
+    #[doc = "Returns an iterator over the values of the `bearertokens` field."]
+    #[inline]
+    pub fn bearertokens_values(&self) -> impl Iterator<Item = &super::ManyFieldExample> {
+        self.bearertokens.values()
+    }
+    #[doc = "Returns an iterator over the entries of the `integers` field."]
+    #[inline]
+    pub fn integers_iter(
+        &self,
+    ) -> impl Iterator<Item = (&super::IntegerAliasExample, &super::ManyFieldExample)> {
+        self.integers.iter()
+    }
+    #[doc = "Returns an iterator over the keys of the `integers` field."]
+    #[inline]
+    pub fn integers_keys(&self) -> impl Iterator<Item = &super::IntegerAliasExample> {
+        self.integers.keys()
+    }
+    #[doc = "Returns an iterator over the values of the `integers` field."]
+    #[inline]
+    pub fn integers_values(&self) -> impl Iterator<Item = &super::ManyFieldExample> {
+        self.integers.values()
+    }
+    #[doc = "Returns an iterator over the entries of the `safelongs` field."]
+    #[inline]
+    pub fn safelongs_iter(
+        &self,
+    ) -> impl Iterator<Item = (&super::SafeLongAliasExample, &super::ManyFieldExample)> {
+        self.safelongs.iter()
+    }
+    #[doc = "Returns an iterator over the keys of the `safelongs` field."]
+    #[inline]
+    pub fn safelongs_keys(&self) -> impl Iterator<Item = &super::SafeLongAliasExample> {
+        self.safelongs.keys()
+    }
+    #[doc = "Returns an iterator over the values of the `safelongs` field."]
+    #[inline]
+    pub fn safelongs_values(&self) -> impl Iterator<Item = &super::ManyFieldExample> {
+        self.safelongs.values()
+    }
+    #[doc = "Returns an iterator over the entries of the `datetimes` field."]
+    #[inline]
+    pub fn datetimes_iter(
+        &self,
+    ) -> impl Iterator<Item = (&super::DateTimeAliasExample, &super::ManyFieldExample)> {
+        self.datetimes.iter()
+    }
+    #[doc = "Returns an iterator over the keys of the `datetimes` field."]
+    #[inline]
+    pub fn datetimes_keys(&self) -> impl Iterator<Item = &super::DateTimeAliasExample> {
+        self.datetimes.keys()
+    }
+    #[doc = "Returns an iterator over the values of the `datetimes` field."]
+    #[inline]
+    pub fn datetimes_values(&self) -> impl Iterator<Item = &super::ManyFieldExample> {
+        self.datetimes.values()
+    }
+    #[doc = "Returns an iterator over the entries of the `uuids` field."]
+    #[inline]
+    pub fn uuids_iter(
+        &self,
+    ) -> impl Iterator<Item = (&super::UuidAliasExample, &super::ManyFieldExample)> {
+        self.uuids.iter()
+    }
+    #[doc = "Returns an iterator over the keys of the `uuids` field."]
+    #[inline]
+    pub fn uuids_keys(&self) -> impl Iterator<Item = &super::UuidAliasExample> {
+        self.uuids.keys()
+    }
+    #[doc = "Returns an iterator over the values of the `uuids` field."]
+    #[inline]
+    pub fn uuids_values(&self) -> impl Iterator<Item = &super::ManyFieldExample> {
+        self.uuids.values()
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -87,12 +259,11 @@ impl Builder {
         self.strings.extend(strings);
         self
     }
-    pub fn insert_strings(
-        &mut self,
-        key: super::StringAliasExample,
-        value: super::ManyFieldExample,
-    ) -> &mut Self {
-        self.strings.insert(key, value);
+    pub fn insert_strings<K>(&mut self, key: K, value: super::ManyFieldExample) -> &mut Self
+    where
+        K: Into<super::StringAliasExample>,
+    {
+        self.strings.insert(key.into(), value);
         self
     }
     pub fn rids<T>(&mut self, rids: T) -> &mut Self
@@ -227,6 +398,35 @@ impl Builder {
         self.uuids.insert(key, value);
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if !other.strings.is_empty() {
+            self.strings = other.strings;
+        }
+        if !other.rids.is_empty() {
+            self.rids = other.rids;
+        }
+        if !other.bearertokens.is_empty() {
+            self.bearertokens = other.bearertokens;
+        }
+        if !other.integers.is_empty() {
+            self.integers = other.integers;
+        }
+        if !other.safelongs.is_empty() {
+            self.safelongs = other.safelongs;
+        }
+        if !other.datetimes.is_empty() {
+            self.datetimes = other.datetimes;
+        }
+        if !other.uuids.is_empty() {
+            self.uuids = other.uuids;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -337,6 +537,24 @@ impl<'de> de::Deserialize<'de> for AliasAsMapKeyExample {
             Visitor_,
         )
     }
+    fn deserialize_in_place<D>(d: D, place: &mut AliasAsMapKeyExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct(
+            "AliasAsMapKeyExample",
+            &[
+                "strings",
+                "rids",
+                "bearertokens",
+                "integers",
+                "safelongs",
+                "datetimes",
+                "uuids",
+            ],
+            InPlaceVisitor_(place),
+        )
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -357,13 +575,48 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut uuids = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::Strings => strings = Some(map_.next_value()?),
-                Field_::Rids => rids = Some(map_.next_value()?),
-                Field_::Bearertokens => bearertokens = Some(map_.next_value()?),
-                Field_::Integers => integers = Some(map_.next_value()?),
-                Field_::Safelongs => safelongs = Some(map_.next_value()?),
-                Field_::Datetimes => datetimes = Some(map_.next_value()?),
-                Field_::Uuids => uuids = Some(map_.next_value()?),
+                Field_::Strings => {
+                    if strings.is_some() {
+                        return Err(de::Error::duplicate_field("strings"));
+                    }
+                    strings = Some(map_.next_value()?);
+                }
+                Field_::Rids => {
+                    if rids.is_some() {
+                        return Err(de::Error::duplicate_field("rids"));
+                    }
+                    rids = Some(map_.next_value()?);
+                }
+                Field_::Bearertokens => {
+                    if bearertokens.is_some() {
+                        return Err(de::Error::duplicate_field("bearertokens"));
+                    }
+                    bearertokens = Some(map_.next_value()?);
+                }
+                Field_::Integers => {
+                    if integers.is_some() {
+                        return Err(de::Error::duplicate_field("integers"));
+                    }
+                    integers = Some(map_.next_value()?);
+                }
+                Field_::Safelongs => {
+                    if safelongs.is_some() {
+                        return Err(de::Error::duplicate_field("safelongs"));
+                    }
+                    safelongs = Some(map_.next_value()?);
+                }
+                Field_::Datetimes => {
+                    if datetimes.is_some() {
+                        return Err(de::Error::duplicate_field("datetimes"));
+                    }
+                    datetimes = Some(map_.next_value()?);
+                }
+                Field_::Uuids => {
+                    if uuids.is_some() {
+                        return Err(de::Error::duplicate_field("uuids"));
+                    }
+                    uuids = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -408,6 +661,104 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut AliasAsMapKeyExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut strings = false;
+        let mut rids = false;
+        let mut bearertokens = false;
+        let mut integers = false;
+        let mut safelongs = false;
+        let mut datetimes = false;
+        let mut uuids = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::Strings => {
+                    if strings {
+                        return Err(de::Error::duplicate_field("strings"));
+                    }
+                    place_.strings = map_.next_value()?;
+                    strings = true;
+                }
+                Field_::Rids => {
+                    if rids {
+                        return Err(de::Error::duplicate_field("rids"));
+                    }
+                    place_.rids = map_.next_value()?;
+                    rids = true;
+                }
+                Field_::Bearertokens => {
+                    if bearertokens {
+                        return Err(de::Error::duplicate_field("bearertokens"));
+                    }
+                    place_.bearertokens = map_.next_value()?;
+                    bearertokens = true;
+                }
+                Field_::Integers => {
+                    if integers {
+                        return Err(de::Error::duplicate_field("integers"));
+                    }
+                    place_.integers = map_.next_value()?;
+                    integers = true;
+                }
+                Field_::Safelongs => {
+                    if safelongs {
+                        return Err(de::Error::duplicate_field("safelongs"));
+                    }
+                    place_.safelongs = map_.next_value()?;
+                    safelongs = true;
+                }
+                Field_::Datetimes => {
+                    if datetimes {
+                        return Err(de::Error::duplicate_field("datetimes"));
+                    }
+                    place_.datetimes = map_.next_value()?;
+                    datetimes = true;
+                }
+                Field_::Uuids => {
+                    if uuids {
+                        return Err(de::Error::duplicate_field("uuids"));
+                    }
+                    place_.uuids = map_.next_value()?;
+                    uuids = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !strings {
+            place_.strings = Default::default();
+        }
+        if !rids {
+            place_.rids = Default::default();
+        }
+        if !bearertokens {
+            place_.bearertokens = Default::default();
+        }
+        if !integers {
+            place_.integers = Default::default();
+        }
+        if !safelongs {
+            place_.safelongs = Default::default();
+        }
+        if !datetimes {
+            place_.datetimes = Default::default();
+        }
+        if !uuids {
+            place_.uuids = Default::default();
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     Strings,
     Rids,
@@ -449,3 +800,20 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `strings` field."]
+    pub const STRINGS: &str = "strings";
+    #[doc = "The wire name of the `rids` field."]
+    pub const RIDS: &str = "rids";
+    #[doc = "The wire name of the `bearertokens` field."]
+    pub const BEARERTOKENS: &str = "bearertokens";
+    #[doc = "The wire name of the `integers` field."]
+    pub const INTEGERS: &str = "integers";
+    #[doc = "The wire name of the `safelongs` field."]
+    pub const SAFELONGS: &str = "safelongs";
+    #[doc = "The wire name of the `datetimes` field."]
+    pub const DATETIMES: &str = "datetimes";
+    #[doc = "The wire name of the `uuids` field."]
+    pub const UUIDS: &str = "uuids";
+}
@@ -6,6 +6,10 @@ pub struct AnyExample {
     any: conjure_object::Value,
 }
 impl AnyExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "AnyExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
     pub fn new<T>(any: T) -> AnyExample
@@ -25,6 +29,11 @@ impl AnyExample {
     pub fn any(&self) -> &conjure_object::Value {
         &self.any
     }
+    #[doc = "Returns the `any` field, consuming the object."]
+    #[inline]
+    pub fn into_any(self) -> conjure_object::Value {
+        self.any
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -41,6 +50,17 @@ impl Builder {
             Some(conjure_object::serde_value::to_value(any).expect("value failed to serialize"));
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.any.is_some() {
+            self.any = other.any;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -60,6 +80,7 @@ impl From<AnyExample> for Builder {
     }
 }
 impl ser::Serialize for AnyExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -71,12 +92,19 @@ impl ser::Serialize for AnyExample {
     }
 }
 impl<'de> de::Deserialize<'de> for AnyExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<AnyExample, D::Error>
     where
         D: de::Deserializer<'de>,
     {
         d.deserialize_struct("AnyExample", &["any"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut AnyExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct("AnyExample", &["any"], InPlaceVisitor_(place))
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -91,7 +119,12 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut any = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::Any => any = Some(map_.next_value()?),
+                Field_::Any => {
+                    if any.is_some() {
+                        return Err(de::Error::duplicate_field("any"));
+                    }
+                    any = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -104,6 +137,38 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(AnyExample { any })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut AnyExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut any = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::Any => {
+                    if any {
+                        return Err(de::Error::duplicate_field("any"));
+                    }
+                    place_.any = map_.next_value()?;
+                    any = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !any {
+            return Err(de::Error::missing_field("any"));
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     Any,
     Unknown_,
@@ -133,3 +198,8 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `any` field."]
+    pub const ANY: &str = "any";
+}
@@ -6,6 +6,10 @@ pub struct AnyMapExample {
     items: std::collections::BTreeMap<String, conjure_object::Value>,
 }
 impl AnyMapExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "AnyMapExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
     pub fn new<T>(items: T) -> AnyMapExample
@@ -25,6 +29,26 @@ impl AnyMapExample {
     pub fn items(&self) -> &std::collections::BTreeMap<String, conjure_object::Value> {
         &self.items
     }
+    #[doc = "Returns the `items` field, consuming the object."]
+    #[inline]
+    pub fn into_items(self) -> std::collections::BTreeMap<String, conjure_object::Value> {
+        self.items
+    }
+    #[doc = "Returns an iterator over the entries of the `items` field."]
+    #[inline]
+    pub fn items_iter(&self) -> impl Iterator<Item = (&String, &conjure_object::Value)> {
+        self.items.iter()
+    }
+    #[doc = "Returns an iterator over the keys of the `items` field."]
+    #[inline]
+    pub fn items_keys(&self) -> impl Iterator<Item = &String> {
+        self.items.keys()
+    }
+    #[doc = "Returns an iterator over the values of the `items` field."]
+    #[inline]
+    pub fn items_values(&self) -> impl Iterator<Item = &conjure_object::Value> {
+        self.items.values()
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -56,6 +80,17 @@ impl Builder {
         );
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if !other.items.is_empty() {
+            self.items = other.items;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -75,6 +110,7 @@ impl From<AnyMapExample> for Builder {
     }
 }
 impl ser::Serialize for AnyMapExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -92,12 +128,19 @@ impl ser::Serialize for AnyMapExample {
     }
 }
 impl<'de> de::Deserialize<'de> for AnyMapExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<AnyMapExample, D::Error>
     where
         D: de::Deserializer<'de>,
     {
         d.deserialize_struct("AnyMapExample", &["items"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut AnyMapExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct("AnyMapExample", &["items"], InPlaceVisitor_(place))
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -112,7 +155,12 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut items = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::Items => items = Some(map_.next_value()?),
+                Field_::Items => {
+                    if items.is_some() {
+                        return Err(de::Error::duplicate_field("items"));
+                    }
+                    items = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -125,6 +173,38 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(AnyMapExample { items })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut AnyMapExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut items = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::Items => {
+                    if items {
+                        return Err(de::Error::duplicate_field("items"));
+                    }
+                    place_.items = map_.next_value()?;
+                    items = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !items {
+            place_.items = Default::default();
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     Items,
     Unknown_,
@@ -154,3 +234,8 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `items` field."]
+    pub const ITEMS: &str = "items";
+}
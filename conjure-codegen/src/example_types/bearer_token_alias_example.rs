@@ -1,6 +1,52 @@
 use conjure_object::serde::{de, ser};
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub struct BearerTokenAliasExample(pub conjure_object::BearerToken);
+impl BearerTokenAliasExample {
+    #[doc = r" Constructs a new instance of the type."]
+    #[inline]
+    pub fn new(inner: conjure_object::BearerToken) -> BearerTokenAliasExample {
+        BearerTokenAliasExample(inner)
+    }
+    #[doc = r" Returns the wrapped value."]
+    #[inline]
+    pub fn into_inner(self) -> conjure_object::BearerToken {
+        self.0
+    }
+}
+impl From<conjure_object::BearerToken> for BearerTokenAliasExample {
+    #[inline]
+    fn from(v: conjure_object::BearerToken) -> BearerTokenAliasExample {
+        BearerTokenAliasExample(v)
+    }
+}
+impl BearerTokenAliasExample {
+    #[doc = r" Creates a new instance of the type, validating the format of the bearer token."]
+    #[inline]
+    pub fn try_from_str(
+        v: &str,
+    ) -> Result<BearerTokenAliasExample, conjure_object::bearer_token::ParseError> {
+        conjure_object::BearerToken::new(v).map(BearerTokenAliasExample)
+    }
+}
+impl conjure_object::PlainEncode for BearerTokenAliasExample {
+    #[inline]
+    fn encode_plain(&self) -> String {
+        conjure_object::PlainEncode::encode_plain(&self.0)
+    }
+}
+impl conjure_object::PlainDecode for BearerTokenAliasExample {
+    #[inline]
+    fn decode_plain(s: &str) -> Result<BearerTokenAliasExample, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s).map(BearerTokenAliasExample)
+    }
+}
+impl std::str::FromStr for BearerTokenAliasExample {
+    type Err = conjure_object::plain::ParseError;
+    #[inline]
+    fn from_str(s: &str) -> Result<BearerTokenAliasExample, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s)
+    }
+}
 impl std::ops::Deref for BearerTokenAliasExample {
     type Target = conjure_object::BearerToken;
     #[inline]
@@ -15,6 +61,7 @@ impl std::ops::DerefMut for BearerTokenAliasExample {
     }
 }
 impl ser::Serialize for BearerTokenAliasExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -23,6 +70,7 @@ impl ser::Serialize for BearerTokenAliasExample {
     }
 }
 impl<'de> de::Deserialize<'de> for BearerTokenAliasExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<BearerTokenAliasExample, D::Error>
     where
         D: de::Deserializer<'de>,
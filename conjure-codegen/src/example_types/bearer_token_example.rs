@@ -6,6 +6,10 @@ pub struct BearerTokenExample {
     bearer_token_value: conjure_object::BearerToken,
 }
 impl BearerTokenExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "BearerTokenExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
     pub fn new(bearer_token_value: conjure_object::BearerToken) -> BearerTokenExample {
@@ -22,6 +26,11 @@ impl BearerTokenExample {
     pub fn bearer_token_value(&self) -> &conjure_object::BearerToken {
         &self.bearer_token_value
     }
+    #[doc = "Returns the `bearer_token_value` field, consuming the object."]
+    #[inline]
+    pub fn into_bearer_token_value(self) -> conjure_object::BearerToken {
+        self.bearer_token_value
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -38,6 +47,17 @@ impl Builder {
         self.bearer_token_value = Some(bearer_token_value);
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.bearer_token_value.is_some() {
+            self.bearer_token_value = other.bearer_token_value;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -62,6 +82,7 @@ impl From<BearerTokenExample> for Builder {
     }
 }
 impl ser::Serialize for BearerTokenExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -73,12 +94,23 @@ impl ser::Serialize for BearerTokenExample {
     }
 }
 impl<'de> de::Deserialize<'de> for BearerTokenExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<BearerTokenExample, D::Error>
     where
         D: de::Deserializer<'de>,
     {
         d.deserialize_struct("BearerTokenExample", &["bearerTokenValue"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut BearerTokenExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct(
+            "BearerTokenExample",
+            &["bearerTokenValue"],
+            InPlaceVisitor_(place),
+        )
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -93,7 +125,12 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut bearer_token_value = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::BearerTokenValue => bearer_token_value = Some(map_.next_value()?),
+                Field_::BearerTokenValue => {
+                    if bearer_token_value.is_some() {
+                        return Err(de::Error::duplicate_field("bearerTokenValue"));
+                    }
+                    bearer_token_value = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -106,6 +143,38 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(BearerTokenExample { bearer_token_value })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut BearerTokenExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut bearer_token_value = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::BearerTokenValue => {
+                    if bearer_token_value {
+                        return Err(de::Error::duplicate_field("bearerTokenValue"));
+                    }
+                    place_.bearer_token_value = map_.next_value()?;
+                    bearer_token_value = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !bearer_token_value {
+            return Err(de::Error::missing_field("bearerTokenValue"));
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     BearerTokenValue,
     Unknown_,
@@ -135,3 +204,8 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `bearerTokenValue` field."]
+    pub const BEARER_TOKEN_VALUE: &str = "bearerTokenValue";
+}
@@ -1,6 +1,24 @@
 use conjure_object::serde::{de, ser};
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
 pub struct BinaryAliasExample(pub conjure_object::ByteBuf);
+impl BinaryAliasExample {
+    #[doc = r" Constructs a new instance of the type."]
+    #[inline]
+    pub fn new(inner: conjure_object::ByteBuf) -> BinaryAliasExample {
+        BinaryAliasExample(inner)
+    }
+    #[doc = r" Returns the wrapped value."]
+    #[inline]
+    pub fn into_inner(self) -> conjure_object::ByteBuf {
+        self.0
+    }
+}
+impl From<conjure_object::ByteBuf> for BinaryAliasExample {
+    #[inline]
+    fn from(v: conjure_object::ByteBuf) -> BinaryAliasExample {
+        BinaryAliasExample(v)
+    }
+}
 impl std::ops::Deref for BinaryAliasExample {
     type Target = conjure_object::ByteBuf;
     #[inline]
@@ -15,6 +33,7 @@ impl std::ops::DerefMut for BinaryAliasExample {
     }
 }
 impl ser::Serialize for BinaryAliasExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -23,6 +42,7 @@ impl ser::Serialize for BinaryAliasExample {
     }
 }
 impl<'de> de::Deserialize<'de> for BinaryAliasExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<BinaryAliasExample, D::Error>
     where
         D: de::Deserializer<'de>,
@@ -6,6 +6,10 @@ pub struct BinaryExample {
     binary: conjure_object::ByteBuf,
 }
 impl BinaryExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "BinaryExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
     pub fn new<T>(binary: T) -> BinaryExample
@@ -25,6 +29,11 @@ impl BinaryExample {
     pub fn binary(&self) -> &[u8] {
         &*self.binary
     }
+    #[doc = "Returns the `binary` field, consuming the object."]
+    #[inline]
+    pub fn into_binary(self) -> conjure_object::ByteBuf {
+        self.binary
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -40,6 +49,17 @@ impl Builder {
         self.binary = Some(binary.into().into());
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.binary.is_some() {
+            self.binary = other.binary;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -61,6 +81,7 @@ impl From<BinaryExample> for Builder {
     }
 }
 impl ser::Serialize for BinaryExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -72,12 +93,19 @@ impl ser::Serialize for BinaryExample {
     }
 }
 impl<'de> de::Deserialize<'de> for BinaryExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<BinaryExample, D::Error>
     where
         D: de::Deserializer<'de>,
     {
         d.deserialize_struct("BinaryExample", &["binary"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut BinaryExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct("BinaryExample", &["binary"], InPlaceVisitor_(place))
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -92,7 +120,12 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut binary = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::Binary => binary = Some(map_.next_value()?),
+                Field_::Binary => {
+                    if binary.is_some() {
+                        return Err(de::Error::duplicate_field("binary"));
+                    }
+                    binary = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -105,6 +138,38 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(BinaryExample { binary })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut BinaryExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut binary = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::Binary => {
+                    if binary {
+                        return Err(de::Error::duplicate_field("binary"));
+                    }
+                    place_.binary = map_.next_value()?;
+                    binary = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !binary {
+            return Err(de::Error::missing_field("binary"));
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     Binary,
     Unknown_,
@@ -134,3 +199,8 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `binary` field."]
+    pub const BINARY: &str = "binary";
+}
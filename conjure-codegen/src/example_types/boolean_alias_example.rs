@@ -1,11 +1,61 @@
 use conjure_object::serde::{de, ser};
 #[derive(Debug, Clone, PartialEq, PartialOrd, Copy, Eq, Ord, Hash, Default)]
 pub struct BooleanAliasExample(pub bool);
+impl BooleanAliasExample {
+    #[doc = r" Constructs a new instance of the type."]
+    #[inline]
+    pub const fn new(inner: bool) -> BooleanAliasExample {
+        BooleanAliasExample(inner)
+    }
+    #[doc = r" Returns the wrapped value."]
+    #[inline]
+    pub fn into_inner(self) -> bool {
+        self.0
+    }
+}
 impl std::fmt::Display for BooleanAliasExample {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(&self.0, fmt)
     }
 }
+impl From<bool> for BooleanAliasExample {
+    #[inline]
+    fn from(v: bool) -> BooleanAliasExample {
+        BooleanAliasExample(v)
+    }
+}
+impl From<BooleanAliasExample> for bool {
+    #[inline]
+    fn from(v: BooleanAliasExample) -> bool {
+        v.0
+    }
+}
+impl std::ops::Not for BooleanAliasExample {
+    type Output = BooleanAliasExample;
+    #[inline]
+    fn not(self) -> BooleanAliasExample {
+        BooleanAliasExample(!self.0)
+    }
+}
+impl conjure_object::PlainEncode for BooleanAliasExample {
+    #[inline]
+    fn encode_plain(&self) -> String {
+        conjure_object::PlainEncode::encode_plain(&self.0)
+    }
+}
+impl conjure_object::PlainDecode for BooleanAliasExample {
+    #[inline]
+    fn decode_plain(s: &str) -> Result<BooleanAliasExample, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s).map(BooleanAliasExample)
+    }
+}
+impl std::str::FromStr for BooleanAliasExample {
+    type Err = conjure_object::plain::ParseError;
+    #[inline]
+    fn from_str(s: &str) -> Result<BooleanAliasExample, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s)
+    }
+}
 impl std::ops::Deref for BooleanAliasExample {
     type Target = bool;
     #[inline]
@@ -20,6 +70,7 @@ impl std::ops::DerefMut for BooleanAliasExample {
     }
 }
 impl ser::Serialize for BooleanAliasExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -28,6 +79,7 @@ impl ser::Serialize for BooleanAliasExample {
     }
 }
 impl<'de> de::Deserialize<'de> for BooleanAliasExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<BooleanAliasExample, D::Error>
     where
         D: de::Deserializer<'de>,
@@ -6,9 +6,13 @@ pub struct BooleanExample {
     coin: bool,
 }
 impl BooleanExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "BooleanExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
-    pub fn new(coin: bool) -> BooleanExample {
+    pub const fn new(coin: bool) -> BooleanExample {
         BooleanExample { coin: coin }
     }
     #[doc = r" Returns a new builder."]
@@ -33,6 +37,17 @@ impl Builder {
         self.coin = Some(coin);
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.coin.is_some() {
+            self.coin = other.coin;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -54,6 +69,7 @@ impl From<BooleanExample> for Builder {
     }
 }
 impl ser::Serialize for BooleanExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -65,12 +81,19 @@ impl ser::Serialize for BooleanExample {
     }
 }
 impl<'de> de::Deserialize<'de> for BooleanExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<BooleanExample, D::Error>
     where
         D: de::Deserializer<'de>,
     {
         d.deserialize_struct("BooleanExample", &["coin"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut BooleanExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct("BooleanExample", &["coin"], InPlaceVisitor_(place))
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -85,7 +108,12 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut coin = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::Coin => coin = Some(map_.next_value()?),
+                Field_::Coin => {
+                    if coin.is_some() {
+                        return Err(de::Error::duplicate_field("coin"));
+                    }
+                    coin = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -98,6 +126,38 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(BooleanExample { coin })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut BooleanExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut coin = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::Coin => {
+                    if coin {
+                        return Err(de::Error::duplicate_field("coin"));
+                    }
+                    place_.coin = map_.next_value()?;
+                    coin = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !coin {
+            return Err(de::Error::missing_field("coin"));
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     Coin,
     Unknown_,
@@ -127,3 +187,8 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `coin` field."]
+    pub const COIN: &str = "coin";
+}
@@ -7,6 +7,10 @@ pub struct CovariantListExample {
     external_items: Vec<String>,
 }
 impl CovariantListExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "CovariantListExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
     pub fn new<T, U>(items: T, external_items: U) -> CovariantListExample
@@ -19,6 +23,19 @@ impl CovariantListExample {
             external_items: external_items.into_iter().collect(),
         }
     }
+    #[doc = r" Constructs an instance of the type from a tuple of its fields, in declaration order."]
+    #[inline]
+    pub fn from_parts(parts: (Vec<conjure_object::Value>, Vec<String>)) -> CovariantListExample {
+        CovariantListExample {
+            items: parts.0,
+            external_items: parts.1,
+        }
+    }
+    #[doc = r" Returns a tuple of the object's fields, in declaration order, consuming the object."]
+    #[inline]
+    pub fn into_parts(self) -> (Vec<conjure_object::Value>, Vec<String>) {
+        (self.items, self.external_items)
+    }
     #[doc = r" Returns a new builder."]
     #[inline]
     pub fn builder() -> Builder {
@@ -32,6 +49,16 @@ impl CovariantListExample {
     pub fn external_items(&self) -> &[String] {
         &*self.external_items
     }
+    #[doc = "Returns the `items` field, consuming the object."]
+    #[inline]
+    pub fn into_items(self) -> Vec<conjure_object::Value> {
+        self.items
+    }
+    #[doc = "Returns the `external_items` field, consuming the object."]
+    #[inline]
+    pub fn into_external_items(self) -> Vec<String> {
+        self.external_items
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -82,6 +109,20 @@ impl Builder {
         self.external_items.push(value.into());
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if !other.items.is_empty() {
+            self.items = other.items;
+        }
+        if !other.external_items.is_empty() {
+            self.external_items = other.external_items;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -139,6 +180,16 @@ impl<'de> de::Deserialize<'de> for CovariantListExample {
             Visitor_,
         )
     }
+    fn deserialize_in_place<D>(d: D, place: &mut CovariantListExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct(
+            "CovariantListExample",
+            &["items", "externalItems"],
+            InPlaceVisitor_(place),
+        )
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -154,8 +205,18 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut external_items = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::Items => items = Some(map_.next_value()?),
-                Field_::ExternalItems => external_items = Some(map_.next_value()?),
+                Field_::Items => {
+                    if items.is_some() {
+                        return Err(de::Error::duplicate_field("items"));
+                    }
+                    items = Some(map_.next_value()?);
+                }
+                Field_::ExternalItems => {
+                    if external_items.is_some() {
+                        return Err(de::Error::duplicate_field("externalItems"));
+                    }
+                    external_items = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -175,6 +236,51 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut CovariantListExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut items = false;
+        let mut external_items = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::Items => {
+                    if items {
+                        return Err(de::Error::duplicate_field("items"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(&mut place_.items))?;
+                    items = true;
+                }
+                Field_::ExternalItems => {
+                    if external_items {
+                        return Err(de::Error::duplicate_field("externalItems"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(
+                        &mut place_.external_items,
+                    ))?;
+                    external_items = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !items {
+            place_.items.clear();
+        }
+        if !external_items {
+            place_.external_items.clear();
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     Items,
     ExternalItems,
@@ -206,3 +312,10 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `items` field."]
+    pub const ITEMS: &str = "items";
+    #[doc = "The wire name of the `externalItems` field."]
+    pub const EXTERNAL_ITEMS: &str = "externalItems";
+}
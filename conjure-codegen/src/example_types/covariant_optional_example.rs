@@ -6,6 +6,10 @@ pub struct CovariantOptionalExample {
     item: Option<conjure_object::Value>,
 }
 impl CovariantOptionalExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "CovariantOptionalExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
     pub fn new<T>(item: T) -> CovariantOptionalExample
@@ -27,6 +31,22 @@ impl CovariantOptionalExample {
     pub fn item(&self) -> Option<&conjure_object::Value> {
         self.item.as_ref().map(|o| &*o)
     }
+    #[doc = "Returns the `item` field, consuming the object."]
+    #[inline]
+    pub fn into_item(self) -> Option<conjure_object::Value> {
+        self.item
+    }
+    #[doc = "Returns the `item` field, or an error if it is absent."]
+    #[inline]
+    pub fn require_item(&self) -> Result<&conjure_object::Value, conjure_object::MissingField> {
+        match &self.item {
+            Some(o) => Ok(&*o),
+            None => Err(conjure_object::MissingField::new(
+                "CovariantOptionalExample",
+                "item",
+            )),
+        }
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -40,6 +60,17 @@ impl Builder {
         self.item = item.into();
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if !other.item.is_none() {
+            self.item = other.item;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -59,6 +90,7 @@ impl From<CovariantOptionalExample> for Builder {
     }
 }
 impl ser::Serialize for CovariantOptionalExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -76,12 +108,23 @@ impl ser::Serialize for CovariantOptionalExample {
     }
 }
 impl<'de> de::Deserialize<'de> for CovariantOptionalExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<CovariantOptionalExample, D::Error>
     where
         D: de::Deserializer<'de>,
     {
         d.deserialize_struct("CovariantOptionalExample", &["item"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut CovariantOptionalExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct(
+            "CovariantOptionalExample",
+            &["item"],
+            InPlaceVisitor_(place),
+        )
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -96,7 +139,12 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut item = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::Item => item = Some(map_.next_value()?),
+                Field_::Item => {
+                    if item.is_some() {
+                        return Err(de::Error::duplicate_field("item"));
+                    }
+                    item = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -109,6 +157,38 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(CovariantOptionalExample { item })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut CovariantOptionalExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut item = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::Item => {
+                    if item {
+                        return Err(de::Error::duplicate_field("item"));
+                    }
+                    place_.item = map_.next_value()?;
+                    item = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !item {
+            place_.item = Default::default();
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     Item,
     Unknown_,
@@ -138,3 +218,8 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `item` field."]
+    pub const ITEM: &str = "item";
+}
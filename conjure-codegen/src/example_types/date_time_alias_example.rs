@@ -1,11 +1,48 @@
 use conjure_object::serde::{de, ser};
 #[derive(Debug, Clone, PartialEq, PartialOrd, Copy, Eq, Ord, Hash)]
 pub struct DateTimeAliasExample(pub conjure_object::DateTime<conjure_object::Utc>);
+impl DateTimeAliasExample {
+    #[doc = r" Constructs a new instance of the type."]
+    #[inline]
+    pub const fn new(inner: conjure_object::DateTime<conjure_object::Utc>) -> DateTimeAliasExample {
+        DateTimeAliasExample(inner)
+    }
+    #[doc = r" Returns the wrapped value."]
+    #[inline]
+    pub fn into_inner(self) -> conjure_object::DateTime<conjure_object::Utc> {
+        self.0
+    }
+}
 impl std::fmt::Display for DateTimeAliasExample {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(&self.0, fmt)
     }
 }
+impl From<conjure_object::DateTime<conjure_object::Utc>> for DateTimeAliasExample {
+    #[inline]
+    fn from(v: conjure_object::DateTime<conjure_object::Utc>) -> DateTimeAliasExample {
+        DateTimeAliasExample(v)
+    }
+}
+impl conjure_object::PlainEncode for DateTimeAliasExample {
+    #[inline]
+    fn encode_plain(&self) -> String {
+        conjure_object::PlainEncode::encode_plain(&self.0)
+    }
+}
+impl conjure_object::PlainDecode for DateTimeAliasExample {
+    #[inline]
+    fn decode_plain(s: &str) -> Result<DateTimeAliasExample, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s).map(DateTimeAliasExample)
+    }
+}
+impl std::str::FromStr for DateTimeAliasExample {
+    type Err = conjure_object::plain::ParseError;
+    #[inline]
+    fn from_str(s: &str) -> Result<DateTimeAliasExample, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s)
+    }
+}
 impl std::ops::Deref for DateTimeAliasExample {
     type Target = conjure_object::DateTime<conjure_object::Utc>;
     #[inline]
@@ -20,6 +57,7 @@ impl std::ops::DerefMut for DateTimeAliasExample {
     }
 }
 impl ser::Serialize for DateTimeAliasExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -28,6 +66,7 @@ impl ser::Serialize for DateTimeAliasExample {
     }
 }
 impl<'de> de::Deserialize<'de> for DateTimeAliasExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<DateTimeAliasExample, D::Error>
     where
         D: de::Deserializer<'de>,
@@ -6,9 +6,13 @@ pub struct DateTimeExample {
     datetime: conjure_object::DateTime<conjure_object::Utc>,
 }
 impl DateTimeExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "DateTimeExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
-    pub fn new(datetime: conjure_object::DateTime<conjure_object::Utc>) -> DateTimeExample {
+    pub const fn new(datetime: conjure_object::DateTime<conjure_object::Utc>) -> DateTimeExample {
         DateTimeExample { datetime: datetime }
     }
     #[doc = r" Returns a new builder."]
@@ -36,6 +40,17 @@ impl Builder {
         self.datetime = Some(datetime);
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.datetime.is_some() {
+            self.datetime = other.datetime;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -57,6 +72,7 @@ impl From<DateTimeExample> for Builder {
     }
 }
 impl ser::Serialize for DateTimeExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -68,12 +84,19 @@ impl ser::Serialize for DateTimeExample {
     }
 }
 impl<'de> de::Deserialize<'de> for DateTimeExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<DateTimeExample, D::Error>
     where
         D: de::Deserializer<'de>,
     {
         d.deserialize_struct("DateTimeExample", &["datetime"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut DateTimeExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct("DateTimeExample", &["datetime"], InPlaceVisitor_(place))
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -88,7 +111,12 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut datetime = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::Datetime => datetime = Some(map_.next_value()?),
+                Field_::Datetime => {
+                    if datetime.is_some() {
+                        return Err(de::Error::duplicate_field("datetime"));
+                    }
+                    datetime = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -101,6 +129,38 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(DateTimeExample { datetime })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut DateTimeExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut datetime = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::Datetime => {
+                    if datetime {
+                        return Err(de::Error::duplicate_field("datetime"));
+                    }
+                    place_.datetime = map_.next_value()?;
+                    datetime = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !datetime {
+            return Err(de::Error::missing_field("datetime"));
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     Datetime,
     Unknown_,
@@ -130,3 +190,8 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `datetime` field."]
+    pub const DATETIME: &str = "datetime";
+}
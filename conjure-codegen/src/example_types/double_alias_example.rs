@@ -1,11 +1,84 @@
 use conjure_object::serde::{de, ser};
 #[derive(Debug, Clone, PartialEq, PartialOrd, Copy, Default)]
 pub struct DoubleAliasExample(pub f64);
+impl DoubleAliasExample {
+    #[doc = r" Constructs a new instance of the type."]
+    #[inline]
+    pub const fn new(inner: f64) -> DoubleAliasExample {
+        DoubleAliasExample(inner)
+    }
+    #[doc = r" Returns the wrapped value."]
+    #[inline]
+    pub fn into_inner(self) -> f64 {
+        self.0
+    }
+}
 impl std::fmt::Display for DoubleAliasExample {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(&self.0, fmt)
     }
 }
+impl From<f64> for DoubleAliasExample {
+    #[inline]
+    fn from(v: f64) -> DoubleAliasExample {
+        DoubleAliasExample(v)
+    }
+}
+impl std::iter::Sum for DoubleAliasExample {
+    #[inline]
+    fn sum<I>(iter: I) -> DoubleAliasExample
+    where
+        I: std::iter::Iterator<Item = DoubleAliasExample>,
+    {
+        DoubleAliasExample(iter.map(|v| v.0).sum())
+    }
+}
+impl<'a> std::iter::Sum<&'a DoubleAliasExample> for DoubleAliasExample {
+    #[inline]
+    fn sum<I>(iter: I) -> DoubleAliasExample
+    where
+        I: std::iter::Iterator<Item = &'a DoubleAliasExample>,
+    {
+        DoubleAliasExample(iter.map(|v| v.0).sum())
+    }
+}
+impl std::iter::Product for DoubleAliasExample {
+    #[inline]
+    fn product<I>(iter: I) -> DoubleAliasExample
+    where
+        I: std::iter::Iterator<Item = DoubleAliasExample>,
+    {
+        DoubleAliasExample(iter.map(|v| v.0).product())
+    }
+}
+impl<'a> std::iter::Product<&'a DoubleAliasExample> for DoubleAliasExample {
+    #[inline]
+    fn product<I>(iter: I) -> DoubleAliasExample
+    where
+        I: std::iter::Iterator<Item = &'a DoubleAliasExample>,
+    {
+        DoubleAliasExample(iter.map(|v| v.0).product())
+    }
+}
+impl conjure_object::PlainEncode for DoubleAliasExample {
+    #[inline]
+    fn encode_plain(&self) -> String {
+        conjure_object::PlainEncode::encode_plain(&self.0)
+    }
+}
+impl conjure_object::PlainDecode for DoubleAliasExample {
+    #[inline]
+    fn decode_plain(s: &str) -> Result<DoubleAliasExample, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s).map(DoubleAliasExample)
+    }
+}
+impl std::str::FromStr for DoubleAliasExample {
+    type Err = conjure_object::plain::ParseError;
+    #[inline]
+    fn from_str(s: &str) -> Result<DoubleAliasExample, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s)
+    }
+}
 impl std::ops::Deref for DoubleAliasExample {
     type Target = f64;
     #[inline]
@@ -20,6 +93,7 @@ impl std::ops::DerefMut for DoubleAliasExample {
     }
 }
 impl ser::Serialize for DoubleAliasExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -28,6 +102,7 @@ impl ser::Serialize for DoubleAliasExample {
     }
 }
 impl<'de> de::Deserialize<'de> for DoubleAliasExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<DoubleAliasExample, D::Error>
     where
         D: de::Deserializer<'de>,
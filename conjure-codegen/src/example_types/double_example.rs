@@ -6,9 +6,13 @@ pub struct DoubleExample {
     double_value: f64,
 }
 impl DoubleExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "DoubleExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
-    pub fn new(double_value: f64) -> DoubleExample {
+    pub const fn new(double_value: f64) -> DoubleExample {
         DoubleExample {
             double_value: double_value,
         }
@@ -35,6 +39,17 @@ impl Builder {
         self.double_value = Some(double_value);
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.double_value.is_some() {
+            self.double_value = other.double_value;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -59,6 +74,7 @@ impl From<DoubleExample> for Builder {
     }
 }
 impl ser::Serialize for DoubleExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -70,12 +86,19 @@ impl ser::Serialize for DoubleExample {
     }
 }
 impl<'de> de::Deserialize<'de> for DoubleExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<DoubleExample, D::Error>
     where
         D: de::Deserializer<'de>,
     {
         d.deserialize_struct("DoubleExample", &["doubleValue"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut DoubleExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct("DoubleExample", &["doubleValue"], InPlaceVisitor_(place))
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -90,7 +113,12 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut double_value = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::DoubleValue => double_value = Some(map_.next_value()?),
+                Field_::DoubleValue => {
+                    if double_value.is_some() {
+                        return Err(de::Error::duplicate_field("doubleValue"));
+                    }
+                    double_value = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -103,6 +131,38 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(DoubleExample { double_value })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut DoubleExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut double_value = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::DoubleValue => {
+                    if double_value {
+                        return Err(de::Error::duplicate_field("doubleValue"));
+                    }
+                    place_.double_value = map_.next_value()?;
+                    double_value = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !double_value {
+            return Err(de::Error::missing_field("doubleValue"));
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     DoubleValue,
     Unknown_,
@@ -132,3 +192,8 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `doubleValue` field."]
+    pub const DOUBLE_VALUE: &str = "doubleValue";
+}
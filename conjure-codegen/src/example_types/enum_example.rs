@@ -9,6 +9,10 @@ pub enum EnumExample {
     Unknown(Unknown),
 }
 impl EnumExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "EnumExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Returns the string representation of the enum."]
     #[inline]
     pub fn as_str(&self) -> &str {
@@ -18,6 +22,24 @@ impl EnumExample {
             EnumExample::Unknown(v) => &*v,
         }
     }
+    #[doc = r" Returns a stable numeric identifier of the enum."]
+    #[doc = r""]
+    #[doc = r" The ordinal is a hash of the string representation rather than the position of the variant, so it is"]
+    #[doc = r" unaffected by reordering the enum's definition. Distinct variants are not guaranteed to have distinct"]
+    #[doc = r" ordinals."]
+    #[inline]
+    pub fn ordinal(&self) -> u32 {
+        match self {
+            EnumExample::One => 4244713743,
+            EnumExample::Two => 1080008777,
+            EnumExample::Unknown(v) => conjure_object::private::enum_ordinal(v),
+        }
+    }
+    #[doc = r" Compares the enum to another by their string representations rather than by declaration order."]
+    #[inline]
+    pub fn cmp_by_name(&self, other: &EnumExample) -> std::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
 }
 impl fmt::Display for EnumExample {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
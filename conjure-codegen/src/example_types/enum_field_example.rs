@@ -6,6 +6,10 @@ pub struct EnumFieldExample {
     enum_: super::EnumExample,
 }
 impl EnumFieldExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "EnumFieldExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
     pub fn new(enum_: super::EnumExample) -> EnumFieldExample {
@@ -20,6 +24,11 @@ impl EnumFieldExample {
     pub fn enum_(&self) -> &super::EnumExample {
         &self.enum_
     }
+    #[doc = "Returns the `enum_` field, consuming the object."]
+    #[inline]
+    pub fn into_enum(self) -> super::EnumExample {
+        self.enum_
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -33,6 +42,17 @@ impl Builder {
         self.enum_ = Some(enum_);
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.enum_.is_some() {
+            self.enum_ = other.enum_;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -54,6 +74,7 @@ impl From<EnumFieldExample> for Builder {
     }
 }
 impl ser::Serialize for EnumFieldExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -65,12 +86,19 @@ impl ser::Serialize for EnumFieldExample {
     }
 }
 impl<'de> de::Deserialize<'de> for EnumFieldExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<EnumFieldExample, D::Error>
     where
         D: de::Deserializer<'de>,
     {
         d.deserialize_struct("EnumFieldExample", &["enum"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut EnumFieldExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct("EnumFieldExample", &["enum"], InPlaceVisitor_(place))
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -85,7 +113,12 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut enum_ = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::Enum => enum_ = Some(map_.next_value()?),
+                Field_::Enum => {
+                    if enum_.is_some() {
+                        return Err(de::Error::duplicate_field("enum"));
+                    }
+                    enum_ = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -98,6 +131,38 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(EnumFieldExample { enum_ })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut EnumFieldExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut enum_ = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::Enum => {
+                    if enum_ {
+                        return Err(de::Error::duplicate_field("enum"));
+                    }
+                    place_.enum_ = map_.next_value()?;
+                    enum_ = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !enum_ {
+            return Err(de::Error::missing_field("enum"));
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     Enum,
     Unknown_,
@@ -127,3 +192,8 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `enum` field."]
+    pub const ENUM_: &str = "enum";
+}
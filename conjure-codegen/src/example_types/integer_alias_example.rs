@@ -1,11 +1,84 @@
 use conjure_object::serde::{de, ser};
 #[derive(Debug, Clone, PartialEq, PartialOrd, Copy, Eq, Ord, Hash, Default)]
 pub struct IntegerAliasExample(pub i32);
+impl IntegerAliasExample {
+    #[doc = r" Constructs a new instance of the type."]
+    #[inline]
+    pub const fn new(inner: i32) -> IntegerAliasExample {
+        IntegerAliasExample(inner)
+    }
+    #[doc = r" Returns the wrapped value."]
+    #[inline]
+    pub fn into_inner(self) -> i32 {
+        self.0
+    }
+}
 impl std::fmt::Display for IntegerAliasExample {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(&self.0, fmt)
     }
 }
+impl From<i32> for IntegerAliasExample {
+    #[inline]
+    fn from(v: i32) -> IntegerAliasExample {
+        IntegerAliasExample(v)
+    }
+}
+impl std::iter::Sum for IntegerAliasExample {
+    #[inline]
+    fn sum<I>(iter: I) -> IntegerAliasExample
+    where
+        I: std::iter::Iterator<Item = IntegerAliasExample>,
+    {
+        IntegerAliasExample(iter.map(|v| v.0).sum())
+    }
+}
+impl<'a> std::iter::Sum<&'a IntegerAliasExample> for IntegerAliasExample {
+    #[inline]
+    fn sum<I>(iter: I) -> IntegerAliasExample
+    where
+        I: std::iter::Iterator<Item = &'a IntegerAliasExample>,
+    {
+        IntegerAliasExample(iter.map(|v| v.0).sum())
+    }
+}
+impl std::iter::Product for IntegerAliasExample {
+    #[inline]
+    fn product<I>(iter: I) -> IntegerAliasExample
+    where
+        I: std::iter::Iterator<Item = IntegerAliasExample>,
+    {
+        IntegerAliasExample(iter.map(|v| v.0).product())
+    }
+}
+impl<'a> std::iter::Product<&'a IntegerAliasExample> for IntegerAliasExample {
+    #[inline]
+    fn product<I>(iter: I) -> IntegerAliasExample
+    where
+        I: std::iter::Iterator<Item = &'a IntegerAliasExample>,
+    {
+        IntegerAliasExample(iter.map(|v| v.0).product())
+    }
+}
+impl conjure_object::PlainEncode for IntegerAliasExample {
+    #[inline]
+    fn encode_plain(&self) -> String {
+        conjure_object::PlainEncode::encode_plain(&self.0)
+    }
+}
+impl conjure_object::PlainDecode for IntegerAliasExample {
+    #[inline]
+    fn decode_plain(s: &str) -> Result<IntegerAliasExample, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s).map(IntegerAliasExample)
+    }
+}
+impl std::str::FromStr for IntegerAliasExample {
+    type Err = conjure_object::plain::ParseError;
+    #[inline]
+    fn from_str(s: &str) -> Result<IntegerAliasExample, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s)
+    }
+}
 impl std::ops::Deref for IntegerAliasExample {
     type Target = i32;
     #[inline]
@@ -20,6 +93,7 @@ impl std::ops::DerefMut for IntegerAliasExample {
     }
 }
 impl ser::Serialize for IntegerAliasExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -28,6 +102,7 @@ impl ser::Serialize for IntegerAliasExample {
     }
 }
 impl<'de> de::Deserialize<'de> for IntegerAliasExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<IntegerAliasExample, D::Error>
     where
         D: de::Deserializer<'de>,
@@ -6,9 +6,13 @@ pub struct IntegerExample {
     integer: i32,
 }
 impl IntegerExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "IntegerExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
-    pub fn new(integer: i32) -> IntegerExample {
+    pub const fn new(integer: i32) -> IntegerExample {
         IntegerExample { integer: integer }
     }
     #[doc = r" Returns a new builder."]
@@ -33,6 +37,17 @@ impl Builder {
         self.integer = Some(integer);
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.integer.is_some() {
+            self.integer = other.integer;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -54,6 +69,7 @@ impl From<IntegerExample> for Builder {
     }
 }
 impl ser::Serialize for IntegerExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -65,12 +81,19 @@ impl ser::Serialize for IntegerExample {
     }
 }
 impl<'de> de::Deserialize<'de> for IntegerExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<IntegerExample, D::Error>
     where
         D: de::Deserializer<'de>,
     {
         d.deserialize_struct("IntegerExample", &["integer"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut IntegerExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct("IntegerExample", &["integer"], InPlaceVisitor_(place))
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -85,7 +108,12 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut integer = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::Integer => integer = Some(map_.next_value()?),
+                Field_::Integer => {
+                    if integer.is_some() {
+                        return Err(de::Error::duplicate_field("integer"));
+                    }
+                    integer = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -98,6 +126,38 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(IntegerExample { integer })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut IntegerExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut integer = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::Integer => {
+                    if integer {
+                        return Err(de::Error::duplicate_field("integer"));
+                    }
+                    place_.integer = map_.next_value()?;
+                    integer = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !integer {
+            return Err(de::Error::missing_field("integer"));
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     Integer,
     Unknown_,
@@ -127,3 +187,8 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `integer` field."]
+    pub const INTEGER: &str = "integer";
+}
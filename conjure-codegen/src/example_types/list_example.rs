@@ -8,6 +8,10 @@ pub struct ListExample {
     double_items: Vec<f64>,
 }
 impl ListExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "ListExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
     pub fn new<T, U, V>(items: T, primitive_items: U, double_items: V) -> ListExample
@@ -22,6 +26,20 @@ impl ListExample {
             double_items: double_items.into_iter().collect(),
         }
     }
+    #[doc = r" Constructs an instance of the type from a tuple of its fields, in declaration order."]
+    #[inline]
+    pub fn from_parts(parts: (Vec<String>, Vec<i32>, Vec<f64>)) -> ListExample {
+        ListExample {
+            items: parts.0,
+            primitive_items: parts.1,
+            double_items: parts.2,
+        }
+    }
+    #[doc = r" Returns a tuple of the object's fields, in declaration order, consuming the object."]
+    #[inline]
+    pub fn into_parts(self) -> (Vec<String>, Vec<i32>, Vec<f64>) {
+        (self.items, self.primitive_items, self.double_items)
+    }
     #[doc = r" Returns a new builder."]
     #[inline]
     pub fn builder() -> Builder {
@@ -39,6 +57,21 @@ impl ListExample {
     pub fn double_items(&self) -> &[f64] {
         &*self.double_items
     }
+    #[doc = "Returns the `items` field, consuming the object."]
+    #[inline]
+    pub fn into_items(self) -> Vec<String> {
+        self.items
+    }
+    #[doc = "Returns the `primitive_items` field, consuming the object."]
+    #[inline]
+    pub fn into_primitive_items(self) -> Vec<i32> {
+        self.primitive_items
+    }
+    #[doc = "Returns the `double_items` field, consuming the object."]
+    #[inline]
+    pub fn into_double_items(self) -> Vec<f64> {
+        self.double_items
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -104,6 +137,23 @@ impl Builder {
         self.double_items.push(value);
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if !other.items.is_empty() {
+            self.items = other.items;
+        }
+        if !other.primitive_items.is_empty() {
+            self.primitive_items = other.primitive_items;
+        }
+        if !other.double_items.is_empty() {
+            self.double_items = other.double_items;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -170,6 +220,16 @@ impl<'de> de::Deserialize<'de> for ListExample {
             Visitor_,
         )
     }
+    fn deserialize_in_place<D>(d: D, place: &mut ListExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct(
+            "ListExample",
+            &["items", "primitiveItems", "doubleItems"],
+            InPlaceVisitor_(place),
+        )
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -186,9 +246,24 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut double_items = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::Items => items = Some(map_.next_value()?),
-                Field_::PrimitiveItems => primitive_items = Some(map_.next_value()?),
-                Field_::DoubleItems => double_items = Some(map_.next_value()?),
+                Field_::Items => {
+                    if items.is_some() {
+                        return Err(de::Error::duplicate_field("items"));
+                    }
+                    items = Some(map_.next_value()?);
+                }
+                Field_::PrimitiveItems => {
+                    if primitive_items.is_some() {
+                        return Err(de::Error::duplicate_field("primitiveItems"));
+                    }
+                    primitive_items = Some(map_.next_value()?);
+                }
+                Field_::DoubleItems => {
+                    if double_items.is_some() {
+                        return Err(de::Error::duplicate_field("doubleItems"));
+                    }
+                    double_items = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -213,6 +288,64 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut ListExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut items = false;
+        let mut primitive_items = false;
+        let mut double_items = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::Items => {
+                    if items {
+                        return Err(de::Error::duplicate_field("items"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(&mut place_.items))?;
+                    items = true;
+                }
+                Field_::PrimitiveItems => {
+                    if primitive_items {
+                        return Err(de::Error::duplicate_field("primitiveItems"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(
+                        &mut place_.primitive_items,
+                    ))?;
+                    primitive_items = true;
+                }
+                Field_::DoubleItems => {
+                    if double_items {
+                        return Err(de::Error::duplicate_field("doubleItems"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(
+                        &mut place_.double_items,
+                    ))?;
+                    double_items = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !items {
+            place_.items.clear();
+        }
+        if !primitive_items {
+            place_.primitive_items.clear();
+        }
+        if !double_items {
+            place_.double_items.clear();
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     Items,
     PrimitiveItems,
@@ -246,3 +379,12 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `items` field."]
+    pub const ITEMS: &str = "items";
+    #[doc = "The wire name of the `primitiveItems` field."]
+    pub const PRIMITIVE_ITEMS: &str = "primitiveItems";
+    #[doc = "The wire name of the `doubleItems` field."]
+    pub const DOUBLE_ITEMS: &str = "doubleItems";
+}
@@ -13,6 +13,10 @@ pub struct ManyFieldExample {
     alias: super::StringAliasExample,
 }
 impl ManyFieldExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "ManyFieldExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Returns a new builder."]
     #[inline]
     pub fn builder() -> Builder {
@@ -58,6 +62,62 @@ impl ManyFieldExample {
     pub fn alias(&self) -> &super::StringAliasExample {
         &self.alias
     }
+    #[doc = "Returns the `string` field, consuming the object."]
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.string
+    }
+    #[doc = "Returns the `optional_item` field, consuming the object."]
+    #[inline]
+    pub fn into_optional_item(self) -> Option<String> {
+        self.optional_item
+    }
+    #[doc = "Returns the `items` field, consuming the object."]
+    #[inline]
+    pub fn into_items(self) -> Vec<String> {
+        self.items
+    }
+    #[doc = "Returns the `set` field, consuming the object."]
+    #[inline]
+    pub fn into_set(self) -> std::collections::BTreeSet<String> {
+        self.set
+    }
+    #[doc = "Returns the `map` field, consuming the object."]
+    #[inline]
+    pub fn into_map(self) -> std::collections::BTreeMap<String, String> {
+        self.map
+    }
+    #[doc = "Returns the `alias` field, consuming the object."]
+    #[inline]
+    pub fn into_alias(self) -> super::StringAliasExample {
+        self.alias
+    }
+    #[doc = "Returns the `optional_item` field, or an error if it is absent."]
+    #[inline]
+    pub fn require_optional_item(&self) -> Result<&str, conjure_object::MissingField> {
+        match &self.optional_item {
+            Some(o) => Ok(&**o),
+            None => Err(conjure_object::MissingField::new(
+                "ManyFieldExample",
+                "optionalItem",
+            )),
+        }
+    }
+    #[doc = "Returns an iterator over the entries of the `map` field."]
+    #[inline]
+    pub fn map_iter(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.map.iter()
+    }
+    #[doc = "Returns an iterator over the keys of the `map` field."]
+    #[inline]
+    pub fn map_keys(&self) -> impl Iterator<Item = &String> {
+        self.map.keys()
+    }
+    #[doc = "Returns an iterator over the values of the `map` field."]
+    #[inline]
+    pub fn map_values(&self) -> impl Iterator<Item = &String> {
+        self.map.values()
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -181,9 +241,43 @@ impl Builder {
     #[doc = "docs for alias field"]
     #[doc = r""]
     #[doc = r" Required."]
+    pub fn alias<T>(&mut self, alias: T) -> &mut Self
+    where
+        T: Into<super::StringAliasExample>,
+    {
+        self.alias = Some(alias.into());
+        self
+    }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
     #[inline]
-    pub fn alias(&mut self, alias: super::StringAliasExample) -> &mut Self {
-        self.alias = Some(alias);
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.string.is_some() {
+            self.string = other.string;
+        }
+        if other.integer.is_some() {
+            self.integer = other.integer;
+        }
+        if other.double_value.is_some() {
+            self.double_value = other.double_value;
+        }
+        if !other.optional_item.is_none() {
+            self.optional_item = other.optional_item;
+        }
+        if !other.items.is_empty() {
+            self.items = other.items;
+        }
+        if !other.set.is_empty() {
+            self.set = other.set;
+        }
+        if !other.map.is_empty() {
+            self.map = other.map;
+        }
+        if other.alias.is_some() {
+            self.alias = other.alias;
+        }
         self
     }
     #[doc = r" Constructs a new instance of the type."]
@@ -285,6 +379,25 @@ impl<'de> de::Deserialize<'de> for ManyFieldExample {
             Visitor_,
         )
     }
+    fn deserialize_in_place<D>(d: D, place: &mut ManyFieldExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct(
+            "ManyFieldExample",
+            &[
+                "string",
+                "integer",
+                "doubleValue",
+                "optionalItem",
+                "items",
+                "set",
+                "map",
+                "alias",
+            ],
+            InPlaceVisitor_(place),
+        )
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -306,14 +419,60 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut alias = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::String => string = Some(map_.next_value()?),
-                Field_::Integer => integer = Some(map_.next_value()?),
-                Field_::DoubleValue => double_value = Some(map_.next_value()?),
-                Field_::OptionalItem => optional_item = Some(map_.next_value()?),
-                Field_::Items => items = Some(map_.next_value()?),
-                Field_::Set => set = Some(map_.next_value()?),
-                Field_::Map => map = Some(map_.next_value()?),
-                Field_::Alias => alias = Some(map_.next_value()?),
+                Field_::String => {
+                    if string.is_some() {
+                        return Err(de::Error::duplicate_field("string"));
+                    }
+                    string = Some(map_.next_value()?);
+                }
+                Field_::Integer => {
+                    if integer.is_some() {
+                        return Err(de::Error::duplicate_field("integer"));
+                    }
+                    integer = Some(map_.next_value()?);
+                }
+                Field_::DoubleValue => {
+                    if double_value.is_some() {
+                        return Err(de::Error::duplicate_field("doubleValue"));
+                    }
+                    double_value = Some(map_.next_value()?);
+                }
+                Field_::OptionalItem => {
+                    if optional_item.is_some() {
+                        return Err(de::Error::duplicate_field("optionalItem"));
+                    }
+                    optional_item = Some(
+                        map_.next_value::<conjure_object::private::OptionalString_<_>>()
+                            .map(|v| v.0)?,
+                    );
+                }
+                Field_::Items => {
+                    if items.is_some() {
+                        return Err(de::Error::duplicate_field("items"));
+                    }
+                    items = Some(map_.next_value()?);
+                }
+                Field_::Set => {
+                    if set.is_some() {
+                        return Err(de::Error::duplicate_field("set"));
+                    }
+                    set = Some(
+                        map_.next_value::<conjure_object::private::UniqueSet_<_>>()
+                            .map(|v| v.0)?,
+                    );
+                }
+                Field_::Map => {
+                    if map.is_some() {
+                        return Err(de::Error::duplicate_field("map"));
+                    }
+                    map = Some(map_.next_value()?);
+                }
+                Field_::Alias => {
+                    if alias.is_some() {
+                        return Err(de::Error::duplicate_field("alias"));
+                    }
+                    alias = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -363,6 +522,121 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut ManyFieldExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut string = false;
+        let mut integer = false;
+        let mut double_value = false;
+        let mut optional_item = false;
+        let mut items = false;
+        let mut set = false;
+        let mut map = false;
+        let mut alias = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::String => {
+                    if string {
+                        return Err(de::Error::duplicate_field("string"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(
+                        &mut place_.string,
+                    ))?;
+                    string = true;
+                }
+                Field_::Integer => {
+                    if integer {
+                        return Err(de::Error::duplicate_field("integer"));
+                    }
+                    place_.integer = map_.next_value()?;
+                    integer = true;
+                }
+                Field_::DoubleValue => {
+                    if double_value {
+                        return Err(de::Error::duplicate_field("doubleValue"));
+                    }
+                    place_.double_value = map_.next_value()?;
+                    double_value = true;
+                }
+                Field_::OptionalItem => {
+                    if optional_item {
+                        return Err(de::Error::duplicate_field("optionalItem"));
+                    }
+                    place_.optional_item = map_
+                        .next_value::<conjure_object::private::OptionalString_<_>>()
+                        .map(|v| v.0)?;
+                    optional_item = true;
+                }
+                Field_::Items => {
+                    if items {
+                        return Err(de::Error::duplicate_field("items"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(&mut place_.items))?;
+                    items = true;
+                }
+                Field_::Set => {
+                    if set {
+                        return Err(de::Error::duplicate_field("set"));
+                    }
+                    place_.set = map_
+                        .next_value::<conjure_object::private::UniqueSet_<_>>()
+                        .map(|v| v.0)?;
+                    set = true;
+                }
+                Field_::Map => {
+                    if map {
+                        return Err(de::Error::duplicate_field("map"));
+                    }
+                    place_.map = map_.next_value()?;
+                    map = true;
+                }
+                Field_::Alias => {
+                    if alias {
+                        return Err(de::Error::duplicate_field("alias"));
+                    }
+                    place_.alias = map_.next_value()?;
+                    alias = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !string {
+            return Err(de::Error::missing_field("string"));
+        }
+        if !integer {
+            return Err(de::Error::missing_field("integer"));
+        }
+        if !double_value {
+            return Err(de::Error::missing_field("doubleValue"));
+        }
+        if !optional_item {
+            place_.optional_item = Default::default();
+        }
+        if !items {
+            place_.items.clear();
+        }
+        if !set {
+            place_.set = Default::default();
+        }
+        if !map {
+            place_.map = Default::default();
+        }
+        if !alias {
+            return Err(de::Error::missing_field("alias"));
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     String,
     Integer,
@@ -406,3 +680,22 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `string` field."]
+    pub const STRING: &str = "string";
+    #[doc = "The wire name of the `integer` field."]
+    pub const INTEGER: &str = "integer";
+    #[doc = "The wire name of the `doubleValue` field."]
+    pub const DOUBLE_VALUE: &str = "doubleValue";
+    #[doc = "The wire name of the `optionalItem` field."]
+    pub const OPTIONAL_ITEM: &str = "optionalItem";
+    #[doc = "The wire name of the `items` field."]
+    pub const ITEMS: &str = "items";
+    #[doc = "The wire name of the `set` field."]
+    pub const SET: &str = "set";
+    #[doc = "The wire name of the `map` field."]
+    pub const MAP: &str = "map";
+    #[doc = "The wire name of the `alias` field."]
+    pub const ALIAS: &str = "alias";
+}
@@ -1,6 +1,20 @@
 use conjure_object::serde::{de, ser};
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
 pub struct MapAliasExample(pub std::collections::BTreeMap<String, conjure_object::Value>);
+impl MapAliasExample {
+    #[doc = r" Constructs a new instance of the type."]
+    #[inline]
+    pub fn new(
+        inner: std::collections::BTreeMap<String, conjure_object::Value>,
+    ) -> MapAliasExample {
+        MapAliasExample(inner)
+    }
+    #[doc = r" Returns the wrapped value."]
+    #[inline]
+    pub fn into_inner(self) -> std::collections::BTreeMap<String, conjure_object::Value> {
+        self.0
+    }
+}
 impl std::ops::Deref for MapAliasExample {
     type Target = std::collections::BTreeMap<String, conjure_object::Value>;
     #[inline]
@@ -15,6 +29,7 @@ impl std::ops::DerefMut for MapAliasExample {
     }
 }
 impl ser::Serialize for MapAliasExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -23,6 +38,7 @@ impl ser::Serialize for MapAliasExample {
     }
 }
 impl<'de> de::Deserialize<'de> for MapAliasExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<MapAliasExample, D::Error>
     where
         D: de::Deserializer<'de>,
@@ -1,11 +1,44 @@
 use conjure_object::serde::{de, ser};
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
 pub struct NestedStringAliasExample(pub super::StringAliasExample);
+impl NestedStringAliasExample {
+    #[doc = r" Constructs a new instance of the type."]
+    #[inline]
+    pub fn new(inner: super::StringAliasExample) -> NestedStringAliasExample {
+        NestedStringAliasExample(inner)
+    }
+    #[doc = r" Returns the wrapped value."]
+    #[inline]
+    pub fn into_inner(self) -> super::StringAliasExample {
+        self.0
+    }
+}
 impl std::fmt::Display for NestedStringAliasExample {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(&self.0, fmt)
     }
 }
+impl conjure_object::PlainEncode for NestedStringAliasExample {
+    #[inline]
+    fn encode_plain(&self) -> String {
+        conjure_object::PlainEncode::encode_plain(&self.0)
+    }
+}
+impl conjure_object::PlainDecode for NestedStringAliasExample {
+    #[inline]
+    fn decode_plain(
+        s: &str,
+    ) -> Result<NestedStringAliasExample, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s).map(NestedStringAliasExample)
+    }
+}
+impl std::str::FromStr for NestedStringAliasExample {
+    type Err = conjure_object::plain::ParseError;
+    #[inline]
+    fn from_str(s: &str) -> Result<NestedStringAliasExample, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s)
+    }
+}
 impl std::ops::Deref for NestedStringAliasExample {
     type Target = super::StringAliasExample;
     #[inline]
@@ -20,6 +53,7 @@ impl std::ops::DerefMut for NestedStringAliasExample {
     }
 }
 impl ser::Serialize for NestedStringAliasExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -28,6 +62,7 @@ impl ser::Serialize for NestedStringAliasExample {
     }
 }
 impl<'de> de::Deserialize<'de> for NestedStringAliasExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<NestedStringAliasExample, D::Error>
     where
         D: de::Deserializer<'de>,
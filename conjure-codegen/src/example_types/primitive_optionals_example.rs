@@ -12,6 +12,10 @@ pub struct PrimitiveOptionalsExample {
     uuid: Option<conjure_object::Uuid>,
 }
 impl PrimitiveOptionalsExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "PrimitiveOptionalsExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Returns a new builder."]
     #[inline]
     pub fn builder() -> Builder {
@@ -45,6 +49,99 @@ impl PrimitiveOptionalsExample {
     pub fn uuid(&self) -> Option<conjure_object::Uuid> {
         self.uuid.as_ref().map(|o| *o)
     }
+    #[doc = "Returns the `rid` field, consuming the object."]
+    #[inline]
+    pub fn into_rid(self) -> Option<conjure_object::ResourceIdentifier> {
+        self.rid
+    }
+    #[doc = "Returns the `bearertoken` field, consuming the object."]
+    #[inline]
+    pub fn into_bearertoken(self) -> Option<conjure_object::BearerToken> {
+        self.bearertoken
+    }
+    #[doc = "Returns the `num` field, or an error if it is absent."]
+    #[inline]
+    pub fn require_num(&self) -> Result<f64, conjure_object::MissingField> {
+        match &self.num {
+            Some(o) => Ok(*o),
+            None => Err(conjure_object::MissingField::new(
+                "PrimitiveOptionalsExample",
+                "num",
+            )),
+        }
+    }
+    #[doc = "Returns the `bool` field, or an error if it is absent."]
+    #[inline]
+    pub fn require_bool(&self) -> Result<bool, conjure_object::MissingField> {
+        match &self.bool {
+            Some(o) => Ok(*o),
+            None => Err(conjure_object::MissingField::new(
+                "PrimitiveOptionalsExample",
+                "bool",
+            )),
+        }
+    }
+    #[doc = "Returns the `integer` field, or an error if it is absent."]
+    #[inline]
+    pub fn require_integer(&self) -> Result<i32, conjure_object::MissingField> {
+        match &self.integer {
+            Some(o) => Ok(*o),
+            None => Err(conjure_object::MissingField::new(
+                "PrimitiveOptionalsExample",
+                "integer",
+            )),
+        }
+    }
+    #[doc = "Returns the `safelong` field, or an error if it is absent."]
+    #[inline]
+    pub fn require_safelong(
+        &self,
+    ) -> Result<conjure_object::SafeLong, conjure_object::MissingField> {
+        match &self.safelong {
+            Some(o) => Ok(*o),
+            None => Err(conjure_object::MissingField::new(
+                "PrimitiveOptionalsExample",
+                "safelong",
+            )),
+        }
+    }
+    #[doc = "Returns the `rid` field, or an error if it is absent."]
+    #[inline]
+    pub fn require_rid(
+        &self,
+    ) -> Result<&conjure_object::ResourceIdentifier, conjure_object::MissingField> {
+        match &self.rid {
+            Some(o) => Ok(&*o),
+            None => Err(conjure_object::MissingField::new(
+                "PrimitiveOptionalsExample",
+                "rid",
+            )),
+        }
+    }
+    #[doc = "Returns the `bearertoken` field, or an error if it is absent."]
+    #[inline]
+    pub fn require_bearertoken(
+        &self,
+    ) -> Result<&conjure_object::BearerToken, conjure_object::MissingField> {
+        match &self.bearertoken {
+            Some(o) => Ok(&*o),
+            None => Err(conjure_object::MissingField::new(
+                "PrimitiveOptionalsExample",
+                "bearertoken",
+            )),
+        }
+    }
+    #[doc = "Returns the `uuid` field, or an error if it is absent."]
+    #[inline]
+    pub fn require_uuid(&self) -> Result<conjure_object::Uuid, conjure_object::MissingField> {
+        match &self.uuid {
+            Some(o) => Ok(*o),
+            None => Err(conjure_object::MissingField::new(
+                "PrimitiveOptionalsExample",
+                "uuid",
+            )),
+        }
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -106,6 +203,35 @@ impl Builder {
         self.uuid = uuid.into();
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if !other.num.is_none() {
+            self.num = other.num;
+        }
+        if !other.bool.is_none() {
+            self.bool = other.bool;
+        }
+        if !other.integer.is_none() {
+            self.integer = other.integer;
+        }
+        if !other.safelong.is_none() {
+            self.safelong = other.safelong;
+        }
+        if !other.rid.is_none() {
+            self.rid = other.rid;
+        }
+        if !other.bearertoken.is_none() {
+            self.bearertoken = other.bearertoken;
+        }
+        if !other.uuid.is_none() {
+            self.uuid = other.uuid;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -216,6 +342,24 @@ impl<'de> de::Deserialize<'de> for PrimitiveOptionalsExample {
             Visitor_,
         )
     }
+    fn deserialize_in_place<D>(d: D, place: &mut PrimitiveOptionalsExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct(
+            "PrimitiveOptionalsExample",
+            &[
+                "num",
+                "bool",
+                "integer",
+                "safelong",
+                "rid",
+                "bearertoken",
+                "uuid",
+            ],
+            InPlaceVisitor_(place),
+        )
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -236,13 +380,48 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut uuid = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::Num => num = Some(map_.next_value()?),
-                Field_::Bool => bool = Some(map_.next_value()?),
-                Field_::Integer => integer = Some(map_.next_value()?),
-                Field_::Safelong => safelong = Some(map_.next_value()?),
-                Field_::Rid => rid = Some(map_.next_value()?),
-                Field_::Bearertoken => bearertoken = Some(map_.next_value()?),
-                Field_::Uuid => uuid = Some(map_.next_value()?),
+                Field_::Num => {
+                    if num.is_some() {
+                        return Err(de::Error::duplicate_field("num"));
+                    }
+                    num = Some(map_.next_value()?);
+                }
+                Field_::Bool => {
+                    if bool.is_some() {
+                        return Err(de::Error::duplicate_field("bool"));
+                    }
+                    bool = Some(map_.next_value()?);
+                }
+                Field_::Integer => {
+                    if integer.is_some() {
+                        return Err(de::Error::duplicate_field("integer"));
+                    }
+                    integer = Some(map_.next_value()?);
+                }
+                Field_::Safelong => {
+                    if safelong.is_some() {
+                        return Err(de::Error::duplicate_field("safelong"));
+                    }
+                    safelong = Some(map_.next_value()?);
+                }
+                Field_::Rid => {
+                    if rid.is_some() {
+                        return Err(de::Error::duplicate_field("rid"));
+                    }
+                    rid = Some(map_.next_value()?);
+                }
+                Field_::Bearertoken => {
+                    if bearertoken.is_some() {
+                        return Err(de::Error::duplicate_field("bearertoken"));
+                    }
+                    bearertoken = Some(map_.next_value()?);
+                }
+                Field_::Uuid => {
+                    if uuid.is_some() {
+                        return Err(de::Error::duplicate_field("uuid"));
+                    }
+                    uuid = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -287,6 +466,104 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut PrimitiveOptionalsExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut num = false;
+        let mut bool = false;
+        let mut integer = false;
+        let mut safelong = false;
+        let mut rid = false;
+        let mut bearertoken = false;
+        let mut uuid = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::Num => {
+                    if num {
+                        return Err(de::Error::duplicate_field("num"));
+                    }
+                    place_.num = map_.next_value()?;
+                    num = true;
+                }
+                Field_::Bool => {
+                    if bool {
+                        return Err(de::Error::duplicate_field("bool"));
+                    }
+                    place_.bool = map_.next_value()?;
+                    bool = true;
+                }
+                Field_::Integer => {
+                    if integer {
+                        return Err(de::Error::duplicate_field("integer"));
+                    }
+                    place_.integer = map_.next_value()?;
+                    integer = true;
+                }
+                Field_::Safelong => {
+                    if safelong {
+                        return Err(de::Error::duplicate_field("safelong"));
+                    }
+                    place_.safelong = map_.next_value()?;
+                    safelong = true;
+                }
+                Field_::Rid => {
+                    if rid {
+                        return Err(de::Error::duplicate_field("rid"));
+                    }
+                    place_.rid = map_.next_value()?;
+                    rid = true;
+                }
+                Field_::Bearertoken => {
+                    if bearertoken {
+                        return Err(de::Error::duplicate_field("bearertoken"));
+                    }
+                    place_.bearertoken = map_.next_value()?;
+                    bearertoken = true;
+                }
+                Field_::Uuid => {
+                    if uuid {
+                        return Err(de::Error::duplicate_field("uuid"));
+                    }
+                    place_.uuid = map_.next_value()?;
+                    uuid = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !num {
+            place_.num = Default::default();
+        }
+        if !bool {
+            place_.bool = Default::default();
+        }
+        if !integer {
+            place_.integer = Default::default();
+        }
+        if !safelong {
+            place_.safelong = Default::default();
+        }
+        if !rid {
+            place_.rid = Default::default();
+        }
+        if !bearertoken {
+            place_.bearertoken = Default::default();
+        }
+        if !uuid {
+            place_.uuid = Default::default();
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     Num,
     Bool,
@@ -328,3 +605,20 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `num` field."]
+    pub const NUM: &str = "num";
+    #[doc = "The wire name of the `bool` field."]
+    pub const BOOL: &str = "bool";
+    #[doc = "The wire name of the `integer` field."]
+    pub const INTEGER: &str = "integer";
+    #[doc = "The wire name of the `safelong` field."]
+    pub const SAFELONG: &str = "safelong";
+    #[doc = "The wire name of the `rid` field."]
+    pub const RID: &str = "rid";
+    #[doc = "The wire name of the `bearertoken` field."]
+    pub const BEARERTOKEN: &str = "bearertoken";
+    #[doc = "The wire name of the `uuid` field."]
+    pub const UUID: &str = "uuid";
+}
@@ -1,6 +1,18 @@
 use conjure_object::serde::{de, ser};
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub struct ReferenceAliasExample(pub super::AnyExample);
+impl ReferenceAliasExample {
+    #[doc = r" Constructs a new instance of the type."]
+    #[inline]
+    pub fn new(inner: super::AnyExample) -> ReferenceAliasExample {
+        ReferenceAliasExample(inner)
+    }
+    #[doc = r" Returns the wrapped value."]
+    #[inline]
+    pub fn into_inner(self) -> super::AnyExample {
+        self.0
+    }
+}
 impl std::ops::Deref for ReferenceAliasExample {
     type Target = super::AnyExample;
     #[inline]
@@ -15,6 +27,7 @@ impl std::ops::DerefMut for ReferenceAliasExample {
     }
 }
 impl ser::Serialize for ReferenceAliasExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -23,6 +36,7 @@ impl ser::Serialize for ReferenceAliasExample {
     }
 }
 impl<'de> de::Deserialize<'de> for ReferenceAliasExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<ReferenceAliasExample, D::Error>
     where
         D: de::Deserializer<'de>,
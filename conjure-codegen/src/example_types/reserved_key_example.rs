@@ -10,6 +10,10 @@ pub struct ReservedKeyExample {
     memoized_hash_code: i32,
 }
 impl ReservedKeyExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "ReservedKeyExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Returns a new builder."]
     #[inline]
     pub fn builder() -> Builder {
@@ -35,6 +39,21 @@ impl ReservedKeyExample {
     pub fn memoized_hash_code(&self) -> i32 {
         self.memoized_hash_code
     }
+    #[doc = "Returns the `package` field, consuming the object."]
+    #[inline]
+    pub fn into_package(self) -> String {
+        self.package
+    }
+    #[doc = "Returns the `interface` field, consuming the object."]
+    #[inline]
+    pub fn into_interface(self) -> String {
+        self.interface
+    }
+    #[doc = "Returns the `field_name_with_dashes` field, consuming the object."]
+    #[inline]
+    pub fn into_field_name_with_dashes(self) -> String {
+        self.field_name_with_dashes
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -89,6 +108,29 @@ impl Builder {
         self.memoized_hash_code = Some(memoized_hash_code);
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.package.is_some() {
+            self.package = other.package;
+        }
+        if other.interface.is_some() {
+            self.interface = other.interface;
+        }
+        if other.field_name_with_dashes.is_some() {
+            self.field_name_with_dashes = other.field_name_with_dashes;
+        }
+        if other.primitve_field_name_with_dashes.is_some() {
+            self.primitve_field_name_with_dashes = other.primitve_field_name_with_dashes;
+        }
+        if other.memoized_hash_code.is_some() {
+            self.memoized_hash_code = other.memoized_hash_code;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -161,6 +203,22 @@ impl<'de> de::Deserialize<'de> for ReservedKeyExample {
             Visitor_,
         )
     }
+    fn deserialize_in_place<D>(d: D, place: &mut ReservedKeyExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct(
+            "ReservedKeyExample",
+            &[
+                "package",
+                "interface",
+                "field-name-with-dashes",
+                "primitve-field-name-with-dashes",
+                "memoizedHashCode",
+            ],
+            InPlaceVisitor_(place),
+        )
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -179,13 +237,38 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut memoized_hash_code = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::Package => package = Some(map_.next_value()?),
-                Field_::Interface => interface = Some(map_.next_value()?),
-                Field_::FieldNameWithDashes => field_name_with_dashes = Some(map_.next_value()?),
+                Field_::Package => {
+                    if package.is_some() {
+                        return Err(de::Error::duplicate_field("package"));
+                    }
+                    package = Some(map_.next_value()?);
+                }
+                Field_::Interface => {
+                    if interface.is_some() {
+                        return Err(de::Error::duplicate_field("interface"));
+                    }
+                    interface = Some(map_.next_value()?);
+                }
+                Field_::FieldNameWithDashes => {
+                    if field_name_with_dashes.is_some() {
+                        return Err(de::Error::duplicate_field("field-name-with-dashes"));
+                    }
+                    field_name_with_dashes = Some(map_.next_value()?);
+                }
                 Field_::PrimitveFieldNameWithDashes => {
-                    primitve_field_name_with_dashes = Some(map_.next_value()?)
+                    if primitve_field_name_with_dashes.is_some() {
+                        return Err(de::Error::duplicate_field(
+                            "primitve-field-name-with-dashes",
+                        ));
+                    }
+                    primitve_field_name_with_dashes = Some(map_.next_value()?);
+                }
+                Field_::MemoizedHashCode => {
+                    if memoized_hash_code.is_some() {
+                        return Err(de::Error::duplicate_field("memoizedHashCode"));
+                    }
+                    memoized_hash_code = Some(map_.next_value()?);
                 }
-                Field_::MemoizedHashCode => memoized_hash_code = Some(map_.next_value()?),
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -220,6 +303,90 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut ReservedKeyExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut package = false;
+        let mut interface = false;
+        let mut field_name_with_dashes = false;
+        let mut primitve_field_name_with_dashes = false;
+        let mut memoized_hash_code = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::Package => {
+                    if package {
+                        return Err(de::Error::duplicate_field("package"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(
+                        &mut place_.package,
+                    ))?;
+                    package = true;
+                }
+                Field_::Interface => {
+                    if interface {
+                        return Err(de::Error::duplicate_field("interface"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(
+                        &mut place_.interface,
+                    ))?;
+                    interface = true;
+                }
+                Field_::FieldNameWithDashes => {
+                    if field_name_with_dashes {
+                        return Err(de::Error::duplicate_field("field-name-with-dashes"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(
+                        &mut place_.field_name_with_dashes,
+                    ))?;
+                    field_name_with_dashes = true;
+                }
+                Field_::PrimitveFieldNameWithDashes => {
+                    if primitve_field_name_with_dashes {
+                        return Err(de::Error::duplicate_field(
+                            "primitve-field-name-with-dashes",
+                        ));
+                    }
+                    place_.primitve_field_name_with_dashes = map_.next_value()?;
+                    primitve_field_name_with_dashes = true;
+                }
+                Field_::MemoizedHashCode => {
+                    if memoized_hash_code {
+                        return Err(de::Error::duplicate_field("memoizedHashCode"));
+                    }
+                    place_.memoized_hash_code = map_.next_value()?;
+                    memoized_hash_code = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !package {
+            return Err(de::Error::missing_field("package"));
+        }
+        if !interface {
+            return Err(de::Error::missing_field("interface"));
+        }
+        if !field_name_with_dashes {
+            return Err(de::Error::missing_field("field-name-with-dashes"));
+        }
+        if !primitve_field_name_with_dashes {
+            return Err(de::Error::missing_field("primitve-field-name-with-dashes"));
+        }
+        if !memoized_hash_code {
+            return Err(de::Error::missing_field("memoizedHashCode"));
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     Package,
     Interface,
@@ -257,3 +424,16 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `package` field."]
+    pub const PACKAGE: &str = "package";
+    #[doc = "The wire name of the `interface` field."]
+    pub const INTERFACE: &str = "interface";
+    #[doc = "The wire name of the `field-name-with-dashes` field."]
+    pub const FIELD_NAME_WITH_DASHES: &str = "field-name-with-dashes";
+    #[doc = "The wire name of the `primitve-field-name-with-dashes` field."]
+    pub const PRIMITVE_FIELD_NAME_WITH_DASHES: &str = "primitve-field-name-with-dashes";
+    #[doc = "The wire name of the `memoizedHashCode` field."]
+    pub const MEMOIZED_HASH_CODE: &str = "memoizedHashCode";
+}
@@ -1,11 +1,57 @@
 use conjure_object::serde::{de, ser};
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub struct RidAliasExample(pub conjure_object::ResourceIdentifier);
+impl RidAliasExample {
+    #[doc = r" Constructs a new instance of the type."]
+    #[inline]
+    pub fn new(inner: conjure_object::ResourceIdentifier) -> RidAliasExample {
+        RidAliasExample(inner)
+    }
+    #[doc = r" Returns the wrapped value."]
+    #[inline]
+    pub fn into_inner(self) -> conjure_object::ResourceIdentifier {
+        self.0
+    }
+}
 impl std::fmt::Display for RidAliasExample {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(&self.0, fmt)
     }
 }
+impl From<conjure_object::ResourceIdentifier> for RidAliasExample {
+    #[inline]
+    fn from(v: conjure_object::ResourceIdentifier) -> RidAliasExample {
+        RidAliasExample(v)
+    }
+}
+impl RidAliasExample {
+    #[doc = r" Creates a new instance of the type, parsing the resource identifier from a string."]
+    #[inline]
+    pub fn try_from_str(
+        v: &str,
+    ) -> Result<RidAliasExample, conjure_object::resource_identifier::ParseError> {
+        conjure_object::ResourceIdentifier::new(v).map(RidAliasExample)
+    }
+}
+impl conjure_object::PlainEncode for RidAliasExample {
+    #[inline]
+    fn encode_plain(&self) -> String {
+        conjure_object::PlainEncode::encode_plain(&self.0)
+    }
+}
+impl conjure_object::PlainDecode for RidAliasExample {
+    #[inline]
+    fn decode_plain(s: &str) -> Result<RidAliasExample, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s).map(RidAliasExample)
+    }
+}
+impl std::str::FromStr for RidAliasExample {
+    type Err = conjure_object::plain::ParseError;
+    #[inline]
+    fn from_str(s: &str) -> Result<RidAliasExample, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s)
+    }
+}
 impl std::ops::Deref for RidAliasExample {
     type Target = conjure_object::ResourceIdentifier;
     #[inline]
@@ -20,6 +66,7 @@ impl std::ops::DerefMut for RidAliasExample {
     }
 }
 impl ser::Serialize for RidAliasExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -28,6 +75,7 @@ impl ser::Serialize for RidAliasExample {
     }
 }
 impl<'de> de::Deserialize<'de> for RidAliasExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<RidAliasExample, D::Error>
     where
         D: de::Deserializer<'de>,
@@ -6,6 +6,10 @@ pub struct RidExample {
     rid_value: conjure_object::ResourceIdentifier,
 }
 impl RidExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "RidExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
     pub fn new(rid_value: conjure_object::ResourceIdentifier) -> RidExample {
@@ -22,6 +26,11 @@ impl RidExample {
     pub fn rid_value(&self) -> &conjure_object::ResourceIdentifier {
         &self.rid_value
     }
+    #[doc = "Returns the `rid_value` field, consuming the object."]
+    #[inline]
+    pub fn into_rid_value(self) -> conjure_object::ResourceIdentifier {
+        self.rid_value
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -35,6 +44,17 @@ impl Builder {
         self.rid_value = Some(rid_value);
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.rid_value.is_some() {
+            self.rid_value = other.rid_value;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -56,6 +76,7 @@ impl From<RidExample> for Builder {
     }
 }
 impl ser::Serialize for RidExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -67,12 +88,19 @@ impl ser::Serialize for RidExample {
     }
 }
 impl<'de> de::Deserialize<'de> for RidExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<RidExample, D::Error>
     where
         D: de::Deserializer<'de>,
     {
         d.deserialize_struct("RidExample", &["ridValue"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut RidExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct("RidExample", &["ridValue"], InPlaceVisitor_(place))
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -87,7 +115,12 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut rid_value = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::RidValue => rid_value = Some(map_.next_value()?),
+                Field_::RidValue => {
+                    if rid_value.is_some() {
+                        return Err(de::Error::duplicate_field("ridValue"));
+                    }
+                    rid_value = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -100,6 +133,38 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(RidExample { rid_value })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut RidExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut rid_value = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::RidValue => {
+                    if rid_value {
+                        return Err(de::Error::duplicate_field("ridValue"));
+                    }
+                    place_.rid_value = map_.next_value()?;
+                    rid_value = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !rid_value {
+            return Err(de::Error::missing_field("ridValue"));
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     RidValue,
     Unknown_,
@@ -129,3 +194,8 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `ridValue` field."]
+    pub const RID_VALUE: &str = "ridValue";
+}
@@ -1,11 +1,93 @@
 use conjure_object::serde::{de, ser};
 #[derive(Debug, Clone, PartialEq, PartialOrd, Copy, Eq, Ord, Hash, Default)]
 pub struct SafeLongAliasExample(pub conjure_object::SafeLong);
+impl SafeLongAliasExample {
+    #[doc = r" Constructs a new instance of the type."]
+    #[inline]
+    pub const fn new(inner: conjure_object::SafeLong) -> SafeLongAliasExample {
+        SafeLongAliasExample(inner)
+    }
+    #[doc = r" Returns the wrapped value."]
+    #[inline]
+    pub fn into_inner(self) -> conjure_object::SafeLong {
+        self.0
+    }
+}
 impl std::fmt::Display for SafeLongAliasExample {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(&self.0, fmt)
     }
 }
+impl From<conjure_object::SafeLong> for SafeLongAliasExample {
+    #[inline]
+    fn from(v: conjure_object::SafeLong) -> SafeLongAliasExample {
+        SafeLongAliasExample(v)
+    }
+}
+impl SafeLongAliasExample {
+    #[doc = r" Creates a new instance of the type, validating that the value is in range."]
+    #[inline]
+    pub fn try_from_i64(
+        v: i64,
+    ) -> Result<SafeLongAliasExample, conjure_object::safe_long::BoundsError> {
+        conjure_object::SafeLong::new(v).map(SafeLongAliasExample)
+    }
+}
+impl std::iter::Sum for SafeLongAliasExample {
+    #[inline]
+    fn sum<I>(iter: I) -> SafeLongAliasExample
+    where
+        I: std::iter::Iterator<Item = SafeLongAliasExample>,
+    {
+        SafeLongAliasExample(iter.map(|v| v.0).sum())
+    }
+}
+impl<'a> std::iter::Sum<&'a SafeLongAliasExample> for SafeLongAliasExample {
+    #[inline]
+    fn sum<I>(iter: I) -> SafeLongAliasExample
+    where
+        I: std::iter::Iterator<Item = &'a SafeLongAliasExample>,
+    {
+        SafeLongAliasExample(iter.map(|v| v.0).sum())
+    }
+}
+impl std::iter::Product for SafeLongAliasExample {
+    #[inline]
+    fn product<I>(iter: I) -> SafeLongAliasExample
+    where
+        I: std::iter::Iterator<Item = SafeLongAliasExample>,
+    {
+        SafeLongAliasExample(iter.map(|v| v.0).product())
+    }
+}
+impl<'a> std::iter::Product<&'a SafeLongAliasExample> for SafeLongAliasExample {
+    #[inline]
+    fn product<I>(iter: I) -> SafeLongAliasExample
+    where
+        I: std::iter::Iterator<Item = &'a SafeLongAliasExample>,
+    {
+        SafeLongAliasExample(iter.map(|v| v.0).product())
+    }
+}
+impl conjure_object::PlainEncode for SafeLongAliasExample {
+    #[inline]
+    fn encode_plain(&self) -> String {
+        conjure_object::PlainEncode::encode_plain(&self.0)
+    }
+}
+impl conjure_object::PlainDecode for SafeLongAliasExample {
+    #[inline]
+    fn decode_plain(s: &str) -> Result<SafeLongAliasExample, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s).map(SafeLongAliasExample)
+    }
+}
+impl std::str::FromStr for SafeLongAliasExample {
+    type Err = conjure_object::plain::ParseError;
+    #[inline]
+    fn from_str(s: &str) -> Result<SafeLongAliasExample, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s)
+    }
+}
 impl std::ops::Deref for SafeLongAliasExample {
     type Target = conjure_object::SafeLong;
     #[inline]
@@ -20,6 +102,7 @@ impl std::ops::DerefMut for SafeLongAliasExample {
     }
 }
 impl ser::Serialize for SafeLongAliasExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -28,6 +111,7 @@ impl ser::Serialize for SafeLongAliasExample {
     }
 }
 impl<'de> de::Deserialize<'de> for SafeLongAliasExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<SafeLongAliasExample, D::Error>
     where
         D: de::Deserializer<'de>,
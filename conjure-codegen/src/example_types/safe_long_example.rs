@@ -6,9 +6,13 @@ pub struct SafeLongExample {
     safe_long_value: conjure_object::SafeLong,
 }
 impl SafeLongExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "SafeLongExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
-    pub fn new(safe_long_value: conjure_object::SafeLong) -> SafeLongExample {
+    pub const fn new(safe_long_value: conjure_object::SafeLong) -> SafeLongExample {
         SafeLongExample {
             safe_long_value: safe_long_value,
         }
@@ -35,6 +39,17 @@ impl Builder {
         self.safe_long_value = Some(safe_long_value);
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.safe_long_value.is_some() {
+            self.safe_long_value = other.safe_long_value;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -59,6 +74,7 @@ impl From<SafeLongExample> for Builder {
     }
 }
 impl ser::Serialize for SafeLongExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -70,12 +86,23 @@ impl ser::Serialize for SafeLongExample {
     }
 }
 impl<'de> de::Deserialize<'de> for SafeLongExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<SafeLongExample, D::Error>
     where
         D: de::Deserializer<'de>,
     {
         d.deserialize_struct("SafeLongExample", &["safeLongValue"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut SafeLongExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct(
+            "SafeLongExample",
+            &["safeLongValue"],
+            InPlaceVisitor_(place),
+        )
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -90,7 +117,12 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut safe_long_value = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::SafeLongValue => safe_long_value = Some(map_.next_value()?),
+                Field_::SafeLongValue => {
+                    if safe_long_value.is_some() {
+                        return Err(de::Error::duplicate_field("safeLongValue"));
+                    }
+                    safe_long_value = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -103,6 +135,38 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(SafeLongExample { safe_long_value })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut SafeLongExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut safe_long_value = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::SafeLongValue => {
+                    if safe_long_value {
+                        return Err(de::Error::duplicate_field("safeLongValue"));
+                    }
+                    place_.safe_long_value = map_.next_value()?;
+                    safe_long_value = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !safe_long_value {
+            return Err(de::Error::missing_field("safeLongValue"));
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     SafeLongValue,
     Unknown_,
@@ -132,3 +196,8 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `safeLongValue` field."]
+    pub const SAFE_LONG_VALUE: &str = "safeLongValue";
+}
@@ -6,6 +6,10 @@ pub struct SetExample {
     items: std::collections::BTreeSet<String>,
 }
 impl SetExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "SetExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
     pub fn new<T>(items: T) -> SetExample
@@ -25,6 +29,31 @@ impl SetExample {
     pub fn items(&self) -> &std::collections::BTreeSet<String> {
         &self.items
     }
+    #[doc = "Returns the `items` field, consuming the object."]
+    #[inline]
+    pub fn into_items(self) -> std::collections::BTreeSet<String> {
+        self.items
+    }
+}
+impl std::iter::FromIterator<String> for SetExample {
+    #[inline]
+    fn from_iter<T>(iter: T) -> SetExample
+    where
+        T: IntoIterator<Item = String>,
+    {
+        SetExample {
+            items: iter.into_iter().collect(),
+        }
+    }
+}
+impl std::iter::Extend<String> for SetExample {
+    #[inline]
+    fn extend<T>(&mut self, iter: T)
+    where
+        T: IntoIterator<Item = String>,
+    {
+        self.items.extend(iter)
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -52,6 +81,17 @@ impl Builder {
         self.items.insert(value.into());
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if !other.items.is_empty() {
+            self.items = other.items;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -71,6 +111,7 @@ impl From<SetExample> for Builder {
     }
 }
 impl ser::Serialize for SetExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -88,12 +129,19 @@ impl ser::Serialize for SetExample {
     }
 }
 impl<'de> de::Deserialize<'de> for SetExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<SetExample, D::Error>
     where
         D: de::Deserializer<'de>,
     {
         d.deserialize_struct("SetExample", &["items"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut SetExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct("SetExample", &["items"], InPlaceVisitor_(place))
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -108,7 +156,15 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut items = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::Items => items = Some(map_.next_value()?),
+                Field_::Items => {
+                    if items.is_some() {
+                        return Err(de::Error::duplicate_field("items"));
+                    }
+                    items = Some(
+                        map_.next_value::<conjure_object::private::UniqueSet_<_>>()
+                            .map(|v| v.0)?,
+                    );
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -121,6 +177,40 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(SetExample { items })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut SetExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut items = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::Items => {
+                    if items {
+                        return Err(de::Error::duplicate_field("items"));
+                    }
+                    place_.items = map_
+                        .next_value::<conjure_object::private::UniqueSet_<_>>()
+                        .map(|v| v.0)?;
+                    items = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !items {
+            place_.items = Default::default();
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     Items,
     Unknown_,
@@ -150,3 +240,8 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `items` field."]
+    pub const ITEMS: &str = "items";
+}
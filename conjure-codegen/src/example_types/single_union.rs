@@ -2,12 +2,18 @@ use conjure_object::private::{UnionField_, UnionTypeField_};
 use conjure_object::serde::ser::SerializeMap as SerializeMap_;
 use conjure_object::serde::{de, ser};
 use std::fmt;
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SingleUnion {
     Foo(String),
     #[doc = r" An unknown variant."]
     Unknown(Unknown),
 }
+impl SingleUnion {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "SingleUnion";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
+}
 impl ser::Serialize for SingleUnion {
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
@@ -27,6 +33,29 @@ impl ser::Serialize for SingleUnion {
         map.end()
     }
 }
+impl std::cmp::PartialOrd for SingleUnion {
+    #[inline]
+    fn partial_cmp(&self, other: &SingleUnion) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl std::cmp::Ord for SingleUnion {
+    fn cmp(&self, other: &SingleUnion) -> std::cmp::Ordering {
+        match (self, other) {
+            (SingleUnion::Foo(a), SingleUnion::Foo(b)) => a.cmp(b),
+            (SingleUnion::Unknown(a), SingleUnion::Unknown(b)) => a.cmp(b),
+            _ => {
+                fn index(v: &SingleUnion) -> usize {
+                    match v {
+                        SingleUnion::Foo(_) => 0,
+                        SingleUnion::Unknown(_) => 1,
+                    }
+                }
+                index(self).cmp(&index(other))
+            }
+        }
+    }
+}
 impl<'de> de::Deserialize<'de> for SingleUnion {
     fn deserialize<D>(d: D) -> Result<SingleUnion, D::Error>
     where
@@ -155,4 +184,15 @@ impl Unknown {
     pub fn type_(&self) -> &str {
         &self.type_
     }
+    #[doc = r" Returns the unknown variant's value."]
+    #[inline]
+    pub fn value(&self) -> &conjure_object::Value {
+        &self.value
+    }
+}
+impl From<Unknown> for (String, conjure_object::Value) {
+    #[inline]
+    fn from(v: Unknown) -> (String, conjure_object::Value) {
+        (v.type_.into(), v.value)
+    }
 }
@@ -1,11 +1,90 @@
 use conjure_object::serde::{de, ser};
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
 pub struct StringAliasExample(pub String);
+impl StringAliasExample {
+    #[doc = r" Constructs a new instance of the type."]
+    #[inline]
+    pub fn new(inner: String) -> StringAliasExample {
+        StringAliasExample(inner)
+    }
+    #[doc = r" Returns the wrapped value."]
+    #[inline]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
 impl std::fmt::Display for StringAliasExample {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(&self.0, fmt)
     }
 }
+impl<'a> From<&'a str> for StringAliasExample {
+    #[inline]
+    fn from(v: &'a str) -> StringAliasExample {
+        StringAliasExample(v.to_string())
+    }
+}
+impl From<String> for StringAliasExample {
+    #[inline]
+    fn from(v: String) -> StringAliasExample {
+        StringAliasExample(v)
+    }
+}
+impl std::cmp::PartialEq<str> for StringAliasExample {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+impl<'a> std::cmp::PartialEq<&'a str> for StringAliasExample {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.0 == *other
+    }
+}
+impl std::cmp::PartialEq<String> for StringAliasExample {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        self.0 == *other
+    }
+}
+impl std::cmp::PartialEq<StringAliasExample> for str {
+    #[inline]
+    fn eq(&self, other: &StringAliasExample) -> bool {
+        *self == other.0
+    }
+}
+impl<'a> std::cmp::PartialEq<StringAliasExample> for &'a str {
+    #[inline]
+    fn eq(&self, other: &StringAliasExample) -> bool {
+        **self == other.0
+    }
+}
+impl std::cmp::PartialEq<StringAliasExample> for String {
+    #[inline]
+    fn eq(&self, other: &StringAliasExample) -> bool {
+        *self == other.0
+    }
+}
+impl conjure_object::PlainEncode for StringAliasExample {
+    #[inline]
+    fn encode_plain(&self) -> String {
+        conjure_object::PlainEncode::encode_plain(&self.0)
+    }
+}
+impl conjure_object::PlainDecode for StringAliasExample {
+    #[inline]
+    fn decode_plain(s: &str) -> Result<StringAliasExample, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s).map(StringAliasExample)
+    }
+}
+impl std::str::FromStr for StringAliasExample {
+    type Err = conjure_object::plain::ParseError;
+    #[inline]
+    fn from_str(s: &str) -> Result<StringAliasExample, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s)
+    }
+}
 impl std::ops::Deref for StringAliasExample {
     type Target = String;
     #[inline]
@@ -20,6 +99,7 @@ impl std::ops::DerefMut for StringAliasExample {
     }
 }
 impl ser::Serialize for StringAliasExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -28,6 +108,7 @@ impl ser::Serialize for StringAliasExample {
     }
 }
 impl<'de> de::Deserialize<'de> for StringAliasExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<StringAliasExample, D::Error>
     where
         D: de::Deserializer<'de>,
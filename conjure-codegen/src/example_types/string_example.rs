@@ -6,6 +6,10 @@ pub struct StringExample {
     string: String,
 }
 impl StringExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "StringExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
     pub fn new<T>(string: T) -> StringExample
@@ -25,6 +29,11 @@ impl StringExample {
     pub fn string(&self) -> &str {
         &*self.string
     }
+    #[doc = "Returns the `string` field, consuming the object."]
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.string
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -40,6 +49,17 @@ impl Builder {
         self.string = Some(string.into());
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.string.is_some() {
+            self.string = other.string;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -61,6 +81,7 @@ impl From<StringExample> for Builder {
     }
 }
 impl ser::Serialize for StringExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -72,12 +93,19 @@ impl ser::Serialize for StringExample {
     }
 }
 impl<'de> de::Deserialize<'de> for StringExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<StringExample, D::Error>
     where
         D: de::Deserializer<'de>,
     {
         d.deserialize_struct("StringExample", &["string"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut StringExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct("StringExample", &["string"], InPlaceVisitor_(place))
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -92,7 +120,12 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut string = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::String => string = Some(map_.next_value()?),
+                Field_::String => {
+                    if string.is_some() {
+                        return Err(de::Error::duplicate_field("string"));
+                    }
+                    string = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -105,6 +138,40 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(StringExample { string })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut StringExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut string = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::String => {
+                    if string {
+                        return Err(de::Error::duplicate_field("string"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(
+                        &mut place_.string,
+                    ))?;
+                    string = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !string {
+            return Err(de::Error::missing_field("string"));
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     String,
     Unknown_,
@@ -134,3 +201,8 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `string` field."]
+    pub const STRING: &str = "string";
+}
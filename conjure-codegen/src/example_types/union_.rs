@@ -2,13 +2,19 @@ use conjure_object::private::{UnionField_, UnionTypeField_};
 use conjure_object::serde::ser::SerializeMap as SerializeMap_;
 use conjure_object::serde::{de, ser};
 use std::fmt;
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Union {
     Foo(String),
     Bar(i32),
     #[doc = r" An unknown variant."]
     Unknown(Unknown),
 }
+impl Union {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "Union";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
+}
 impl ser::Serialize for Union {
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
@@ -32,6 +38,31 @@ impl ser::Serialize for Union {
         map.end()
     }
 }
+impl std::cmp::PartialOrd for Union {
+    #[inline]
+    fn partial_cmp(&self, other: &Union) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl std::cmp::Ord for Union {
+    fn cmp(&self, other: &Union) -> std::cmp::Ordering {
+        match (self, other) {
+            (Union::Foo(a), Union::Foo(b)) => a.cmp(b),
+            (Union::Bar(a), Union::Bar(b)) => a.cmp(b),
+            (Union::Unknown(a), Union::Unknown(b)) => a.cmp(b),
+            _ => {
+                fn index(v: &Union) -> usize {
+                    match v {
+                        Union::Foo(_) => 0,
+                        Union::Bar(_) => 1,
+                        Union::Unknown(_) => 2,
+                    }
+                }
+                index(self).cmp(&index(other))
+            }
+        }
+    }
+}
 impl<'de> de::Deserialize<'de> for Union {
     fn deserialize<D>(d: D) -> Result<Union, D::Error>
     where
@@ -171,4 +202,15 @@ impl Unknown {
     pub fn type_(&self) -> &str {
         &self.type_
     }
+    #[doc = r" Returns the unknown variant's value."]
+    #[inline]
+    pub fn value(&self) -> &conjure_object::Value {
+        &self.value
+    }
+}
+impl From<Unknown> for (String, conjure_object::Value) {
+    #[inline]
+    fn from(v: Unknown) -> (String, conjure_object::Value) {
+        (v.type_.into(), v.value)
+    }
 }
@@ -2,7 +2,7 @@ use conjure_object::private::{UnionField_, UnionTypeField_};
 use conjure_object::serde::ser::SerializeMap as SerializeMap_;
 use conjure_object::serde::{de, ser};
 use std::fmt;
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum UnionTypeExample {
     #[doc = "Docs for when UnionTypeExample is of type StringExample."]
     StringExample(super::StringExample),
@@ -15,6 +15,12 @@ pub enum UnionTypeExample {
     #[doc = r" An unknown variant."]
     Unknown(Unknown),
 }
+impl UnionTypeExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "UnionTypeExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
+}
 impl ser::Serialize for UnionTypeExample {
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
@@ -58,6 +64,44 @@ impl ser::Serialize for UnionTypeExample {
         map.end()
     }
 }
+impl std::cmp::PartialOrd for UnionTypeExample {
+    #[inline]
+    fn partial_cmp(&self, other: &UnionTypeExample) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl std::cmp::Ord for UnionTypeExample {
+    fn cmp(&self, other: &UnionTypeExample) -> std::cmp::Ordering {
+        match (self, other) {
+            (UnionTypeExample::StringExample(a), UnionTypeExample::StringExample(b)) => a.cmp(b),
+            (UnionTypeExample::Set(a), UnionTypeExample::Set(b)) => a.cmp(b),
+            (
+                UnionTypeExample::ThisFieldIsAnInteger(a),
+                UnionTypeExample::ThisFieldIsAnInteger(b),
+            ) => a.cmp(b),
+            (UnionTypeExample::AlsoAnInteger(a), UnionTypeExample::AlsoAnInteger(b)) => a.cmp(b),
+            (UnionTypeExample::If(a), UnionTypeExample::If(b)) => a.cmp(b),
+            (UnionTypeExample::New(a), UnionTypeExample::New(b)) => a.cmp(b),
+            (UnionTypeExample::Interface(a), UnionTypeExample::Interface(b)) => a.cmp(b),
+            (UnionTypeExample::Unknown(a), UnionTypeExample::Unknown(b)) => a.cmp(b),
+            _ => {
+                fn index(v: &UnionTypeExample) -> usize {
+                    match v {
+                        UnionTypeExample::StringExample(_) => 0,
+                        UnionTypeExample::Set(_) => 1,
+                        UnionTypeExample::ThisFieldIsAnInteger(_) => 2,
+                        UnionTypeExample::AlsoAnInteger(_) => 3,
+                        UnionTypeExample::If(_) => 4,
+                        UnionTypeExample::New(_) => 5,
+                        UnionTypeExample::Interface(_) => 6,
+                        UnionTypeExample::Unknown(_) => 7,
+                    }
+                }
+                index(self).cmp(&index(other))
+            }
+        }
+    }
+}
 impl<'de> de::Deserialize<'de> for UnionTypeExample {
     fn deserialize<D>(d: D) -> Result<UnionTypeExample, D::Error>
     where
@@ -252,4 +296,15 @@ impl Unknown {
     pub fn type_(&self) -> &str {
         &self.type_
     }
+    #[doc = r" Returns the unknown variant's value."]
+    #[inline]
+    pub fn value(&self) -> &conjure_object::Value {
+        &self.value
+    }
+}
+impl From<Unknown> for (String, conjure_object::Value) {
+    #[inline]
+    fn from(v: Unknown) -> (String, conjure_object::Value) {
+        (v.type_.into(), v.value)
+    }
 }
@@ -1,11 +1,48 @@
 use conjure_object::serde::{de, ser};
 #[derive(Debug, Clone, PartialEq, PartialOrd, Copy, Eq, Ord, Hash)]
 pub struct UuidAliasExample(pub conjure_object::Uuid);
+impl UuidAliasExample {
+    #[doc = r" Constructs a new instance of the type."]
+    #[inline]
+    pub const fn new(inner: conjure_object::Uuid) -> UuidAliasExample {
+        UuidAliasExample(inner)
+    }
+    #[doc = r" Returns the wrapped value."]
+    #[inline]
+    pub fn into_inner(self) -> conjure_object::Uuid {
+        self.0
+    }
+}
 impl std::fmt::Display for UuidAliasExample {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(&self.0, fmt)
     }
 }
+impl From<conjure_object::Uuid> for UuidAliasExample {
+    #[inline]
+    fn from(v: conjure_object::Uuid) -> UuidAliasExample {
+        UuidAliasExample(v)
+    }
+}
+impl conjure_object::PlainEncode for UuidAliasExample {
+    #[inline]
+    fn encode_plain(&self) -> String {
+        conjure_object::PlainEncode::encode_plain(&self.0)
+    }
+}
+impl conjure_object::PlainDecode for UuidAliasExample {
+    #[inline]
+    fn decode_plain(s: &str) -> Result<UuidAliasExample, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s).map(UuidAliasExample)
+    }
+}
+impl std::str::FromStr for UuidAliasExample {
+    type Err = conjure_object::plain::ParseError;
+    #[inline]
+    fn from_str(s: &str) -> Result<UuidAliasExample, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s)
+    }
+}
 impl std::ops::Deref for UuidAliasExample {
     type Target = conjure_object::Uuid;
     #[inline]
@@ -20,6 +57,7 @@ impl std::ops::DerefMut for UuidAliasExample {
     }
 }
 impl ser::Serialize for UuidAliasExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -28,6 +66,7 @@ impl ser::Serialize for UuidAliasExample {
     }
 }
 impl<'de> de::Deserialize<'de> for UuidAliasExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<UuidAliasExample, D::Error>
     where
         D: de::Deserializer<'de>,
@@ -6,9 +6,13 @@ pub struct UuidExample {
     uuid: conjure_object::Uuid,
 }
 impl UuidExample {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "UuidExample";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.product";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
-    pub fn new(uuid: conjure_object::Uuid) -> UuidExample {
+    pub const fn new(uuid: conjure_object::Uuid) -> UuidExample {
         UuidExample { uuid: uuid }
     }
     #[doc = r" Returns a new builder."]
@@ -33,6 +37,17 @@ impl Builder {
         self.uuid = Some(uuid);
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.uuid.is_some() {
+            self.uuid = other.uuid;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -54,6 +69,7 @@ impl From<UuidExample> for Builder {
     }
 }
 impl ser::Serialize for UuidExample {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -65,12 +81,19 @@ impl ser::Serialize for UuidExample {
     }
 }
 impl<'de> de::Deserialize<'de> for UuidExample {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<UuidExample, D::Error>
     where
         D: de::Deserializer<'de>,
     {
         d.deserialize_struct("UuidExample", &["uuid"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut UuidExample) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct("UuidExample", &["uuid"], InPlaceVisitor_(place))
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -85,7 +108,12 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut uuid = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::Uuid => uuid = Some(map_.next_value()?),
+                Field_::Uuid => {
+                    if uuid.is_some() {
+                        return Err(de::Error::duplicate_field("uuid"));
+                    }
+                    uuid = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -98,6 +126,38 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(UuidExample { uuid })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut UuidExample);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut uuid = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::Uuid => {
+                    if uuid {
+                        return Err(de::Error::duplicate_field("uuid"));
+                    }
+                    place_.uuid = map_.next_value()?;
+                    uuid = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !uuid {
+            return Err(de::Error::missing_field("uuid"));
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     Uuid,
     Unknown_,
@@ -127,3 +187,8 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `uuid` field."]
+    pub const UUID: &str = "uuid";
+}
@@ -77,14 +77,14 @@
 //! Conjure objects turn into Rust structs along with builders used to construct them:
 //!
 //! ```rust
-//! # use conjure_codegen::example_types::{ManyFieldExample, StringAliasExample};
+//! # use conjure_codegen::example_types::ManyFieldExample;
 //! let object = ManyFieldExample::builder()
 //!     .string("foo")
 //!     .integer(123)
 //!     .double_value(3.14)
 //!     .optional_item("bar".to_string())
 //!     .items(vec!["hello".to_string(), "world".to_string()])
-//!     .alias(StringAliasExample("foobar".to_string()))
+//!     .alias("foobar")
 //!     .build();
 //!
 //! assert_eq!(object.string(), "foo");
@@ -100,9 +100,96 @@
 //! assert_eq!(object.coin(), true);
 //! ```
 //!
+//! Objects with 2 to 4 fields can also be converted to and from a tuple of their field values with `into_parts` and
+//! `from_parts`.
+//!
+//! Partially populated builders can be combined with `merge`, which is useful to layer values over a set of defaults:
+//!
+//! ```rust
+//! # use conjure_codegen::example_types::ManyFieldExample;
+//! let mut defaults = ManyFieldExample::builder();
+//! defaults.string("foo").integer(123).double_value(3.14).alias("foobar");
+//!
+//! let mut overrides = ManyFieldExample::builder();
+//! overrides.integer(456);
+//!
+//! let object = defaults.merge(overrides).build();
+//!
+//! assert_eq!(object.string(), "foo");
+//! assert_eq!(object.integer(), 456);
+//! ```
+//!
 //! The generated structs implement `Debug`, `Clone`, `PartialEq`, `PartialOrd`, `Serialize`, and `Deserialize`. They
-//! also implement `Eq`, `Ord`, and `Hash` if they do not contain a `double` value, and `Copy` if they consist entirely
-//! of copyable primitive types.
+//! also implement `Eq`, `Ord`, and `Hash` if they do not contain a `double` value (or if the `double_ord` or
+//! `quantized_doubles` option is enabled), and `Copy` if they consist entirely of copyable primitive types, exhaustive
+//! enums, and aliases of those types. Objects with a single `list` or `set` field also implement `FromIterator` and
+//! `Extend` for its items.
+//!
+//! Fields which are not `Copy` can be moved out of an object without cloning through `into_` accessors, such as
+//! `into_string` for a field named `string`. Map fields can also be iterated over through `_iter`, `_keys`, and
+//! `_values` accessors, such as `map_iter` for a field named `map`, without depending on the map type.
+//!
+//! Optional fields also have `require_` accessors returning a `Result` rather than an `Option`, with a
+//! `conjure_object::MissingField` error naming the field if it is absent. This allows them to be unwrapped with `?`:
+//!
+//! ```
+//! # use conjure_codegen::example_types::ManyFieldExample;
+//! # use conjure_object::MissingField;
+//! fn optional_item(object: &ManyFieldExample) -> Result<&str, MissingField> {
+//!     let item = object.require_optional_item()?;
+//!     Ok(item)
+//! }
+//!
+//! let object = ManyFieldExample::builder()
+//!     .string("foo")
+//!     .integer(123)
+//!     .double_value(3.14)
+//!     .alias("bar")
+//!     .build();
+//!
+//! let error = optional_item(&object).unwrap_err();
+//! assert_eq!(error.field_name(), "optionalItem");
+//! ```
+//!
+//! Fields are serialized in the order they are declared in the IDL, regardless of the order they were set in. Empty
+//! optional and collection fields are omitted.
+//!
+//! A `set<T>` field or alias, including an optional one, fails to deserialize if its values contain duplicates. Sets
+//! nested directly within another collection, such as in a `list<set<T>>` field, are not checked, and duplicates
+//! within them are silently merged.
+//!
+//! The wire names of an object's fields are available as constants in a `fields` module alongside it, named after the
+//! field's accessor:
+//!
+//! ```rust
+//! use conjure_codegen::example_types::many_field_example;
+//!
+//! assert_eq!(many_field_example::fields::DOUBLE_VALUE, "doubleValue");
+//! ```
+//!
+//! A doubly-optional field, such as an `optional<T>` of an alias of `optional<T>`, distinguishes a missing field from
+//! one explicitly set to `null`: the former deserializes to `None`, and the latter to `Some(None)`.
+//!
+//! Objects configured with `Config::borrowed_strings` are generic over a lifetime and store their strings as
+//! `Cow<'a, str>`. When deserialized from a buffer such as with `conjure_serde::from_bytes`, their `string`,
+//! `optional<string>` and `list<string>` fields borrow from the buffer unless the JSON string contains escape
+//! sequences.
+//!
+//! Objects configured with `Config::compact_fields` store their `string` and `list<T>` fields as `Box<str>` and
+//! `Box<[T]>`, saving the spare capacity of `String` and `Vec<T>` for objects which aren't modified after being
+//! deserialized.
+//!
+//! Objects configured with `Config::raw_any_fields` store their `any` values as `conjure_object::RawValue`s, which
+//! hold the values' JSON text unparsed and reserialize it unchanged.
+//!
+//! Fields configured with `Config::flatten_field` hold a nested object whose entries are inlined into the parent
+//! object on the wire, in the manner of serde's `#[serde(flatten)]`.
+//!
+//! Fields configured with `Config::alias_field` are also accepted under other names when deserialized, in the manner of
+//! serde's `#[serde(alias)]`, which allows a field to be renamed without breaking older clients.
+//!
+//! Fields configured with `Config::write_only_field` are deserialized as usual but omitted when serialized, for values
+//! such as secrets which must not be echoed back.
 //!
 //! ## Unions
 //!
@@ -128,9 +215,17 @@
 //! }
 //! ```
 //!
+//! The payload of an unknown variant is available as a `conjure_object::Value` through its `value` method, and the
+//! unknown variant can be converted into a `(String, Value)` pair of its type name and payload.
+//!
 //! The generated enums implement `Debug`, `Clone`, `PartialEq`, `PartialOrd`, `Serialize`, and `Deserialize`. They
-//! also implement `Eq`, `Ord`, and `Hash` if they do not contain a `double` value. Union variants which are themselves
-//! unions are boxed in the generated enum to avoid self-referential type definitions.
+//! also implement `Eq`, `Ord`, and `Hash` if they do not contain a `double` value, or `Eq` and `Hash` if they do and
+//! the `union_double_hash` option is enabled. Union variants which recursively contain the union itself are boxed in
+//! the generated enum to avoid self-referential type definitions, or stored in an `Arc` with
+//! `Config::shared_recursion` so that clones share them. Other variants are stored directly.
+//!
+//! With `Config::flatten_single_variant_unions`, a union with a single variant is instead generated as a newtype
+//! struct with a public field holding the variant's value. It serializes identically to the enum form.
 //!
 //! ## Enums
 //!
@@ -148,7 +243,11 @@
 //! ```
 //!
 //! The generated enums implement `Debug`, `Clone`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `Hash`, `Display`,
-//! `Serialize`, and `Deserialize`.
+//! `Serialize`, and `Deserialize`. Exhaustive enums also implement `Copy`.
+//!
+//! Enums are ordered by the declaration order of their variants in the IDL, with unknown variants sorting after all
+//! known variants by their string representations. Values can instead be compared by their string representations
+//! alone with `cmp_by_name`, such as to sort them alphabetically.
 //!
 //! ## Aliases
 //!
@@ -161,9 +260,26 @@
 //! ```
 //!
 //! The generated structs implement `Deref`, `DerefMut`, `Debug`, `Clone`, `PartialEq`, `PartialOrd`, `Serialize`, and
-//! `Deserialize`. They also implement `Eq`, `Ord`, and `Hash` if they do not contain a `double` value, `Copy` if they
-//! wrap a copyable primitive type, `Default` if they wrap a type implementing `Default`, and `Display` if they wrap a
-//! type implementing `Display`.
+//! `Deserialize`. They also implement `Eq`, `Ord`, and `Hash` if they do not contain a `double` value (or if the
+//! `double_ord` or `quantized_doubles` option is enabled), `Copy` if they wrap a copyable primitive type, `Default` if
+//! they wrap a type implementing `Default`, and `Display` if they wrap a type implementing `Display`. Aliases of
+//! `string` also implement `From<&str>` and `From<String>`, can be compared directly against `str`, `&str`, and
+//! `String` values, and builder setters for fields of those types accept any value convertible into the alias. Aliases
+//! of other primitive types implement `From` for the wrapped type, and aliases of `safelong`, `rid`, and `bearertoken`
+//! additionally have `try_from_i64` or `try_from_str` constructors, validating the value. Aliases of `boolean` can also be
+//! converted into `bool` and negated with `!`. Aliases of `integer`, `double`, and `safelong` implement `Sum` and
+//! `Product` like the wrapped type; for `safelong` this panics if the result is out of range. Aliases of primitive
+//! types other than `binary` and `any` implement `PlainEncode` and `PlainDecode`, sharing the PLAIN format used for the
+//! wrapped type in paths, query parameters, and headers, and implement `FromStr` by decoding that format.
+//!
+//! ## Errors
+//!
+//! Conjure errors turn into Rust structs containing both their safe and unsafe parameters, generated in the same way
+//! as objects. In addition to the traits implemented by objects, they implement `Display` and `Error`. The `Display`
+//! implementation renders the error's name, `Namespace:ErrorName`, along with its safe parameters; unsafe parameters
+//! are never included. The error's code and name are available through the `code` and `name` methods, and its
+//! parameters through the `safe_params` and `unsafe_params` methods. Errors can also be converted into a
+//! `conjure_object::SerializableError` with a new instance ID, which is the form sent in the body of an error response.
 #![warn(clippy::all, missing_docs)]
 #![doc(html_root_url = "https://docs.rs/conjure-codegen/0.1")]
 #![recursion_limit = "256"]
@@ -171,19 +287,28 @@
 use failure::{bail, Error, ResultExt};
 use proc_macro2::TokenStream;
 use quote::quote;
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::ffi::{OsStr, OsString};
 use std::fs;
+use std::iter;
 use std::path::Path;
 use std::process::Command;
 
 use crate::context::Context;
-use crate::types::{ConjureDefinition, TypeDefinition};
+use crate::types::{
+    field_definition, object_definition, ConjureDefinition, ExternalReference, PrimitiveType, Type,
+    TypeDefinition, TypeName, UnionDefinition,
+};
 
 mod aliases;
 mod context;
 mod enums;
+mod errors;
 mod objects;
+mod remote;
+mod schemas;
+mod strategies;
 #[allow(dead_code, clippy::all)]
 mod types;
 mod unions;
@@ -200,6 +325,35 @@ pub struct Config {
     rustfmt: OsString,
     run_rustfmt: bool,
     exhaustive: bool,
+    strategies: bool,
+    json_schemas: bool,
+    field_presence: bool,
+    double_ord: bool,
+    union_double_hash: bool,
+    double_places: Option<u32>,
+    mutable_accessors: bool,
+    union_iterators: bool,
+    serde_remote: bool,
+    flatten_unions: bool,
+    shared_recursion: bool,
+    strict_doubles: bool,
+    tracing: bool,
+    collect_errors: bool,
+    union_conversions: Vec<(String, String)>,
+    deny_unknown_fields: HashSet<String>,
+    positional_deserialization: HashSet<String>,
+    case_insensitive_fields: HashSet<String>,
+    borrowed_strings: HashSet<String>,
+    compact_fields: HashSet<String>,
+    raw_any_fields: HashSet<String>,
+    field_types: Vec<(String, String, String)>,
+    field_renames: HashMap<String, String>,
+    flattened_fields: HashSet<(String, String)>,
+    field_aliases: HashMap<(String, String), Vec<String>>,
+    write_only_fields: HashSet<(String, String)>,
+    extern_packages: HashMap<String, String>,
+    visibility: Visibility,
+    datetime_precision: Option<DateTimePrecision>,
 }
 
 impl Default for Config {
@@ -215,6 +369,35 @@ impl Config {
             rustfmt: env::var_os("RUSTFMT").unwrap_or_else(|| OsString::from("rustfmt")),
             run_rustfmt: true,
             exhaustive: false,
+            strategies: false,
+            json_schemas: false,
+            field_presence: false,
+            double_ord: false,
+            union_double_hash: false,
+            double_places: None,
+            mutable_accessors: false,
+            union_iterators: false,
+            serde_remote: false,
+            flatten_unions: false,
+            shared_recursion: false,
+            strict_doubles: false,
+            tracing: false,
+            collect_errors: false,
+            union_conversions: vec![],
+            deny_unknown_fields: HashSet::new(),
+            positional_deserialization: HashSet::new(),
+            case_insensitive_fields: HashSet::new(),
+            borrowed_strings: HashSet::new(),
+            compact_fields: HashSet::new(),
+            raw_any_fields: HashSet::new(),
+            field_types: vec![],
+            field_renames: HashMap::new(),
+            flattened_fields: HashSet::new(),
+            field_aliases: HashMap::new(),
+            write_only_fields: HashSet::new(),
+            extern_packages: HashMap::new(),
+            visibility: Visibility::Public,
+            datetime_precision: None,
         }
     }
 
@@ -229,6 +412,390 @@ impl Config {
         self
     }
 
+    /// Controls the generation of proptest strategies for the generated types.
+    ///
+    /// If enabled, a `strategies` module will be generated containing an `arb_<type name>` function for each type
+    /// returning a `proptest` `Strategy` which generates values of that type. Crates containing the generated code must
+    /// enable the `proptest` Cargo feature of `conjure-object`.
+    ///
+    /// Generated values are never infinitely nested, so union variants which lead back to the union itself are never
+    /// generated, and code generation fails if that applies to every variant of a union. Generated doubles are never
+    /// NaN, so generated values are always equal to themselves.
+    ///
+    /// Defaults to `false`.
+    pub fn strategies(&mut self, strategies: bool) -> &mut Config {
+        self.strategies = strategies;
+        self
+    }
+
+    /// Controls the generation of `schemars` `JsonSchema` implementations for the generated types.
+    ///
+    /// If enabled, each type will implement `JsonSchema`, describing its JSON wire format. Crates containing the
+    /// generated code must depend on `conjure-ext` with its `schemars` Cargo feature enabled.
+    ///
+    /// Defaults to `false`.
+    pub fn json_schemas(&mut self, json_schemas: bool) -> &mut Config {
+        self.json_schemas = json_schemas;
+        self
+    }
+
+    /// Controls the instrumentation of deserialization with `tracing` spans.
+    ///
+    /// If enabled, the `Deserialize` implementation of each type enters a trace-level span named `deserialize`, with
+    /// a `conjure_type` field holding the type's name, for the duration of its deserialization. This can be used to
+    /// find the types responsible for slow or large payloads. Crates containing the generated code must depend on
+    /// `conjure-ext` with its `tracing` Cargo feature enabled.
+    ///
+    /// Defaults to `false`.
+    pub fn tracing(&mut self, tracing: bool) -> &mut Config {
+        self.tracing = tracing;
+        self
+    }
+
+    /// Controls the tracking of which fields of objects were present when deserialized.
+    ///
+    /// If enabled, each object's module will contain a `FieldsPresent` type recording the fields which appeared in the
+    /// serialized representation of the object, accessible via the object's `present_fields` method. Objects which were
+    /// not deserialized report that no fields were present. The presence information is ignored by comparisons and
+    /// hashing.
+    ///
+    /// Defaults to `false`.
+    pub fn field_presence(&mut self, field_presence: bool) -> &mut Config {
+        self.field_presence = field_presence;
+        self
+    }
+
+    /// Controls the generation of `Eq`, `Ord`, and `Hash` implementations for types containing `double` values.
+    ///
+    /// If enabled, objects and aliases containing `double` values will implement those traits by comparing and hashing
+    /// the doubles by their bit patterns in the IEEE 754 total order. As a result, `NaN` compares equal to itself and
+    /// `-0.0` is not equal to `0.0`. `PartialEq` and `PartialOrd` are implemented consistently with the new ordering.
+    /// Unions containing `double` values, and types containing those unions, are not affected.
+    ///
+    /// Defaults to `false`.
+    pub fn double_ord(&mut self, double_ord: bool) -> &mut Config {
+        self.double_ord = double_ord;
+        self
+    }
+
+    /// Controls the generation of `Eq` and `Hash` implementations for unions containing `double` values.
+    ///
+    /// If enabled, those unions will implement `Eq`, and will implement `Hash` by hashing the variant and, for variants
+    /// not containing `double` values, their payloads. The payloads of variants containing `double` values are not
+    /// hashed, so all values of such a variant collide, but values which compare equal always hash equally. Equality
+    /// itself is unchanged, so a value holding a `NaN` is still not equal to itself.
+    ///
+    /// Defaults to `false`.
+    pub fn union_double_hash(&mut self, union_double_hash: bool) -> &mut Config {
+        self.union_double_hash = union_double_hash;
+        self
+    }
+
+    /// Generates `Eq`, `Ord`, and `Hash` implementations for types containing `double` values which compare the
+    /// doubles rounded to the specified number of decimal places.
+    ///
+    /// This is intended for doubles holding fixed precision quantities like currency amounts. Types are affected as
+    /// with the `double_ord` option, which this takes precedence over. Doubles are rounded half away from zero after
+    /// being scaled by `10^places`, and the results are compared as with `double_ord`, except that `-0.0` and `0.0`
+    /// are equal.
+    ///
+    /// Values are quantized independently, so two doubles very close to a rounding boundary may compare unequal even
+    /// though doubles further apart on the same side of it compare equal. Since most decimal fractions have no exact
+    /// binary representation, a value written as lying exactly on a boundary may round in either direction. Doubles
+    /// too large to be rounded without overflowing are compared unchanged.
+    ///
+    /// Disabled by default.
+    pub fn quantized_doubles(&mut self, places: u32) -> &mut Config {
+        self.double_places = Some(places);
+        self
+    }
+
+    /// Controls the generation of mutable accessors for object fields.
+    ///
+    /// If enabled, objects will have a `<field>_mut` method for each field returning a mutable reference to its value,
+    /// allowing fields to be modified in place rather than rebuilding the object through its builder.
+    ///
+    /// Defaults to `false`.
+    pub fn mutable_accessors(&mut self, mutable_accessors: bool) -> &mut Config {
+        self.mutable_accessors = mutable_accessors;
+        self
+    }
+
+    /// Controls the generation of iterator extension traits for unions.
+    ///
+    /// If enabled, each union's module will contain a `<union>IterExt` trait implemented for all iterators over values
+    /// of the union. The trait has a method for each variant, named by pluralizing the variant's name, which filters
+    /// the iterator down to the values of that variant.
+    ///
+    /// Defaults to `false`.
+    pub fn union_iterators(&mut self, union_iterators: bool) -> &mut Config {
+        self.union_iterators = union_iterators;
+        self
+    }
+
+    /// Controls the generation of functions for serializing foreign types in the wire format of the generated types.
+    ///
+    /// If enabled, each type will have `serialize_remote` and `deserialize_remote` functions which can be used with
+    /// serde's `serialize_with` and `deserialize_with` attributes on fields of other types. The field's type must be
+    /// convertible from a reference into the generated type and from the generated type via `From`. Objects which
+    /// borrow strings do not have the functions.
+    ///
+    /// Defaults to `false`.
+    pub fn serde_remote(&mut self, serde_remote: bool) -> &mut Config {
+        self.serde_remote = serde_remote;
+        self
+    }
+
+    /// Controls the generation of unions with a single variant as newtypes.
+    ///
+    /// If enabled, a union with exactly one variant will be generated as a newtype struct wrapping that variant's value
+    /// rather than as an enum. Its wire format is unchanged, but since the newtype has no `Unknown` variant, values of
+    /// other variants fail to deserialize regardless of the `exhaustive` setting.
+    ///
+    /// Defaults to `false`.
+    pub fn flatten_single_variant_unions(
+        &mut self,
+        flatten_single_variant_unions: bool,
+    ) -> &mut Config {
+        self.flatten_unions = flatten_single_variant_unions;
+        self
+    }
+
+    /// Controls the use of reference counting for recursive union variants.
+    ///
+    /// Union variants which form a cycle back to the union are normally stored in a `Box`, so cloning a value copies
+    /// the entire tree beneath it. If enabled, they are stored in an `Arc` instead, and clones share their subtrees.
+    /// The wire format is unchanged.
+    ///
+    /// Defaults to `false`.
+    pub fn shared_recursion(&mut self, shared_recursion: bool) -> &mut Config {
+        self.shared_recursion = shared_recursion;
+        self
+    }
+
+    /// Adds a conversion between two union types.
+    ///
+    /// A `try_from_<source>` method will be generated for the target union, such as `Target::try_from_source` for a
+    /// source union named `Source`. Variants of the source union are converted to the variant of the target union with
+    /// the same name, and conversion fails, returning the source value, for variants which are not present in the
+    /// target union. Unknown variants of non-exhaustive unions always fail to convert.
+    ///
+    /// Code generation will fail if either type is not a union, or if a variant present in both unions has a different
+    /// type in each.
+    pub fn union_conversion(&mut self, source: &str, target: &str) -> &mut Config {
+        self.union_conversions
+            .push((source.to_string(), target.to_string()));
+        self
+    }
+
+    /// Causes an object type to reject unknown fields when deserialized.
+    ///
+    /// By default, generated objects ignore unknown fields unless deserialized by a deserializer which rejects them,
+    /// such as `conjure_serde`'s `ServerDeserializer`. Objects configured with this method will always fail to
+    /// deserialize if an unknown field is present.
+    pub fn deny_unknown_fields(&mut self, type_name: &str) -> &mut Config {
+        self.deny_unknown_fields.insert(type_name.to_string());
+        self
+    }
+
+    /// Causes an object type to also accept a positional form when deserialized.
+    ///
+    /// In addition to the standard map form, objects configured with this method will deserialize from a sequence of
+    /// their field values in declaration order, as produced by some legacy services. Trailing elements may be omitted for
+    /// optional and collection fields. Serialization always uses the map form.
+    pub fn positional_deserialization(&mut self, type_name: &str) -> &mut Config {
+        self.positional_deserialization.insert(type_name.to_string());
+        self
+    }
+
+    /// Causes an object type to match field names ignoring ASCII case when deserialized.
+    ///
+    /// Objects configured with this method will accept fields whose names differ from the declared names only in case,
+    /// as produced by some legacy services, such as `FieldName` or `FIELDNAME` for a field named `fieldName`.
+    /// Serialization always uses the declared names.
+    ///
+    /// Code generation will fail if the type is not an object, or if two of its fields have names differing only in
+    /// case.
+    pub fn case_insensitive_fields(&mut self, type_name: &str) -> &mut Config {
+        self.case_insensitive_fields.insert(type_name.to_string());
+        self
+    }
+
+    /// Causes an object type to borrow its strings from the deserializer's input.
+    ///
+    /// The object will be generic over the lifetime of the input, with its strings stored as `Cow<'a, str>`. Its
+    /// `string`, `optional<string>` and `list<string>` fields borrow from the input where possible, avoiding a copy;
+    /// strings within other collections are always owned.
+    ///
+    /// Code generation will fail if the type is not an object with a string field, or if it is referenced by another
+    /// type.
+    pub fn borrowed_strings(&mut self, type_name: &str) -> &mut Config {
+        self.borrowed_strings.insert(type_name.to_string());
+        self
+    }
+
+    /// Causes an object type to store its string and list fields as boxed slices.
+    ///
+    /// The object's `string` and `list<T>` fields, and optional fields of those types, will be stored as `Box<str>` and
+    /// `Box<[T]>` rather than `String` and `Vec<T>`, which don't carry spare capacity. This reduces the memory used by
+    /// large numbers of deserialized objects which aren't modified. Accessors still return `&str` and `&[T]`, builders
+    /// still accept strings and iterators, and the wire format is unchanged. Values within other collections are
+    /// stored as usual. The object does not implement `FromIterator` or `Extend` for a compact list field.
+    ///
+    /// Code generation will fail if the type is not an object, or if it also borrows its strings.
+    pub fn compact_fields(&mut self, type_name: &str) -> &mut Config {
+        self.compact_fields.insert(type_name.to_string());
+        self
+    }
+
+    /// Causes an object type to store its `any` fields as unparsed JSON.
+    ///
+    /// The object's `any` values will be stored as `conjure_object::RawValue`s rather than `conjure_object::Value`s.
+    /// Their JSON text is kept exactly as it appeared in the input and is only parsed on request, which avoids the cost
+    /// of parsing and reserializing values an object merely passes through.
+    ///
+    /// Code generation will fail if the type is not an object with an `any` field.
+    pub fn raw_any_fields(&mut self, type_name: &str) -> &mut Config {
+        self.raw_any_fields.insert(type_name.to_string());
+        self
+    }
+
+    /// Substitutes a user-provided type for a field of an object.
+    ///
+    /// The field will be stored as the Rust type at the provided absolute path, for example `crate::url::Url`, and its
+    /// wire representation is entirely controlled by that type's `Serialize` and `Deserialize` implementations. This
+    /// allows fields with custom handling, such as a `string` field which should be parsed as a URL, to be routed
+    /// through a wrapper type. The field is always treated as required, and the type must implement each trait derived
+    /// by the object. If strategies or JSON schemas are generated, it must also implement `Arbitrary` or `JsonSchema`.
+    ///
+    /// Code generation will fail if the type is not an object with the specified field.
+    pub fn field_type(&mut self, type_name: &str, field_name: &str, rust_type: &str) -> &mut Config {
+        self.field_types.push((
+            type_name.to_string(),
+            field_name.to_string(),
+            rust_type.to_string(),
+        ));
+        self
+    }
+
+    /// Renames the Rust identifier generated for fields with a Conjure name.
+    ///
+    /// Fields, accessors, builder setters, and arguments generated for the Conjure field name will use the provided
+    /// Rust identifier in place of the one derived from the name, for example `kind` rather than `type_` for `type`.
+    /// The rename applies to every field with that name, and does not change the name used on the wire. The identifier
+    /// must be a valid Rust identifier which is not a keyword.
+    pub fn rename_field(&mut self, conjure_name: &str, rust_name: &str) -> &mut Config {
+        self.field_renames
+            .insert(conjure_name.to_string(), rust_name.to_string());
+        self
+    }
+
+    /// Causes an object field to be inlined into its object on the wire.
+    ///
+    /// The field's value is still stored as a nested object, but the entries of that object are serialized directly
+    /// into the parent object rather than under the field's key, and are read back from the parent's entries when
+    /// deserialized. Missing or invalid entries of the nested object are reported as they would be for the parent's own
+    /// fields. Unknown entries are handled according to the parent object, so are rejected if it denies unknown fields
+    /// and ignored otherwise.
+    ///
+    /// Code generation will fail if the field is not a required reference to an object generated in this crate, if
+    /// that object has flattened fields of its own or borrows its strings, if the parent object is deserialized
+    /// positionally, or if the nested object's fields conflict with the parent's.
+    pub fn flatten_field(&mut self, type_name: &str, field_name: &str) -> &mut Config {
+        self.flattened_fields
+            .insert((type_name.to_string(), field_name.to_string()));
+        self
+    }
+
+    /// Causes an object field to also accept another name when deserialized.
+    ///
+    /// This supports migrating a field to a new name, where values may still arrive under its old name. The field is
+    /// read from either name, with a value present under both reported as a duplicate field. Serialization always uses
+    /// the declared name. A field may have multiple aliases.
+    ///
+    /// Code generation will fail if the field is not an object field, if it is flattened, or if the alias conflicts with
+    /// the name or another alias of a field in the same object.
+    pub fn alias_field(&mut self, type_name: &str, field_name: &str, alias: &str) -> &mut Config {
+        self.field_aliases
+            .entry((type_name.to_string(), field_name.to_string()))
+            .or_default()
+            .push(alias.to_string());
+        self
+    }
+
+    /// Causes an object field to be accepted when deserialized but never serialized.
+    ///
+    /// This is intended for values such as secrets which a service accepts but must not echo back. The field is stored
+    /// and deserialized as usual, but is omitted when the object is serialized, so the serialized form of an object
+    /// with a required write-only field can't be deserialized back into it. Generated JSON schemas mark the field as
+    /// `writeOnly`.
+    ///
+    /// Code generation will fail if the field is not an object field, or if it is flattened.
+    pub fn write_only_field(&mut self, type_name: &str, field_name: &str) -> &mut Config {
+        self.write_only_fields
+            .insert((type_name.to_string(), field_name.to_string()));
+        self
+    }
+
+    /// Maps a Conjure package to types generated into another crate.
+    ///
+    /// Types in the package will not be generated, and references to them will instead use the provided absolute path,
+    /// for example `other_crate::conjure`, to the root module of that crate's generated code. This allows definitions
+    /// spanning multiple packages to be split across crates. The other crate must have been generated with a compatible
+    /// configuration - for example, if strategies or JSON schemas are generated here, they must be generated there as
+    /// well.
+    pub fn extern_package(&mut self, package: &str, path: &str) -> &mut Config {
+        self.extern_packages
+            .insert(package.to_string(), path.to_string());
+        self
+    }
+
+    /// Controls the visibility of generated items.
+    ///
+    /// Setting this to `Visibility::Crate` allows generated types to be embedded in a crate without exposing them in
+    /// its public API. Since the items may then go unused, the module containing the generated code may need to allow
+    /// the `dead_code` and `unused_imports` lints.
+    ///
+    /// Defaults to `Visibility::Public`.
+    pub fn visibility(&mut self, visibility: Visibility) -> &mut Config {
+        self.visibility = visibility;
+        self
+    }
+
+    /// Controls the serialization of non-finite `double` values.
+    ///
+    /// Conjure encodes `NaN` and the infinities as the strings `"NaN"`, `"Infinity"`, and `"-Infinity"`. If enabled,
+    /// serializing a generated type containing one of those values instead fails with an error. Doubles used as map keys
+    /// are not affected, and deserialization still accepts the strings.
+    ///
+    /// Defaults to `false`.
+    pub fn strict_doubles(&mut self, strict_doubles: bool) -> &mut Config {
+        self.strict_doubles = strict_doubles;
+        self
+    }
+
+    /// Controls support for reporting every error encountered while deserializing generated objects.
+    ///
+    /// If enabled, objects deserialized by `conjure_serde::json::client_from_slice_collecting` continue past fields
+    /// with invalid values and report every missing required field rather than stopping at the first error. This adds
+    /// overhead to the deserialization of each field, even when errors aren't being collected.
+    ///
+    /// Defaults to `false`.
+    pub fn collect_errors(&mut self, collect_errors: bool) -> &mut Config {
+        self.collect_errors = collect_errors;
+        self
+    }
+
+    /// Sets the fractional second precision of serialized `datetime` values.
+    ///
+    /// By default, datetimes are serialized with as many fractional second digits as needed to represent them exactly.
+    /// If a precision is set, they will instead always be serialized with that many digits, truncating any additional
+    /// precision. Datetimes used as map keys are not affected. Deserialization accepts any precision regardless.
+    pub fn datetime_precision(&mut self, datetime_precision: DateTimePrecision) -> &mut Config {
+        self.datetime_precision = Some(datetime_precision);
+        self
+    }
+
     /// Controls the use of rustfmt to format generated source code.
     ///
     /// Defaults to `true`.
@@ -264,7 +831,40 @@ impl Config {
             bail!("unsupported IR version {}", defs.version());
         }
 
-        let modules = self.create_modules(&defs);
+        let defs = self.apply_field_types(&defs)?;
+
+        for path in self.extern_packages.values() {
+            if path.parse::<TokenStream>().is_err() {
+                bail!("extern package path {} is not a valid Rust path", path);
+            }
+        }
+
+        self.check_borrowed_strings(&defs)?;
+        self.check_raw_any_fields(&defs)?;
+        self.check_flattened_fields(&defs)?;
+        self.check_field_aliases(&defs)?;
+        self.check_write_only_fields(&defs)?;
+        self.check_case_insensitive_fields(&defs)?;
+        self.check_compact_fields(&defs)?;
+
+        for (source, _) in &self.union_conversions {
+            let is_union = defs.types().iter().any(|d| match d {
+                TypeDefinition::Union(d) => d.type_name().name() == source,
+                _ => false,
+            });
+            if !is_union {
+                bail!("union conversion source {} is not a union type", source);
+            }
+        }
+
+        let modules = self.create_modules(&defs)?;
+
+        let strategies = if self.strategies {
+            let context = Context::new(&defs, self);
+            Some(strategies::generate(&context, &defs)?)
+        } else {
+            None
+        };
 
         fs::create_dir_all(out_dir)
             .with_context(|_| format!("error creating directory {}", out_dir.display()))?;
@@ -276,7 +876,20 @@ impl Config {
             )?;
         }
 
-        let root_module = self.create_root_module(&modules);
+        let strategies_module = match strategies {
+            Some(strategies) => {
+                let module_name = if modules.iter().any(|m| m.module_name == "strategies") {
+                    "strategies_"
+                } else {
+                    "strategies"
+                };
+                self.write_module(&out_dir.join(format!("{}.rs", module_name)), &strategies)?;
+                Some(module_name)
+            }
+            None => None,
+        };
+
+        let root_module = self.create_root_module(&modules, strategies_module);
         self.write_module(&out_dir.join("mod.rs"), &root_module)?;
 
         Ok(())
@@ -301,44 +914,505 @@ impl Config {
         Ok(())
     }
 
-    fn create_modules(&self, defs: &ConjureDefinition) -> Vec<Module> {
-        let context = Context::new(&defs, self.exhaustive);
+    fn create_modules(&self, defs: &ConjureDefinition) -> Result<Vec<Module>, Error> {
+        let context = Context::new(&defs, self);
 
         let mut modules = vec![];
 
         for def in defs.types() {
-            let (type_name, contents) = match def {
+            if context.is_extern(context::definition_name(def)) {
+                continue;
+            }
+
+            let mut exports = vec![];
+            let (type_name, mut contents) = match def {
                 TypeDefinition::Enum(def) => (def.type_name(), enums::generate(&context, def)),
                 TypeDefinition::Alias(def) => (def.type_name(), aliases::generate(&context, def)),
-                TypeDefinition::Union(def) => (def.type_name(), unions::generate(&context, def)),
+                TypeDefinition::Union(def) => {
+                    let mut contents = unions::generate(&context, def);
+                    contents.extend(self.union_conversions(&context, defs, def)?);
+                    if self.union_iterators {
+                        contents.extend(unions::generate_iter_ext(&context, def));
+                        exports.push(unions::iter_ext_name(&context, def).to_string());
+                    }
+                    (def.type_name(), contents)
+                }
                 TypeDefinition::Object(def) => (def.type_name(), objects::generate(&context, def)),
             };
 
+            if self.json_schemas {
+                contents.extend(schemas::generate(&context, def));
+            }
+
+            if self.serde_remote {
+                contents.extend(remote::generate(&context, def));
+            }
+
             let module = Module {
                 module_name: context.module_name(type_name),
                 type_name: context.type_name(type_name.name()).to_string(),
+                exports,
                 contents,
             };
             modules.push(module);
         }
 
-        modules
+        for def in defs.errors() {
+            if context.is_extern(def.error_name()) {
+                continue;
+            }
+
+            let module = Module {
+                module_name: context.module_name(def.error_name()),
+                type_name: context.type_name(def.error_name().name()).to_string(),
+                exports: vec![],
+                contents: errors::generate(&context, def),
+            };
+            modules.push(module);
+        }
+
+        Ok(modules)
+    }
+
+    // Substituted fields are represented as external references to the user's type, falling back to the original type.
+    fn apply_field_types(&self, defs: &ConjureDefinition) -> Result<ConjureDefinition, Error> {
+        for (type_name, field_name, rust_type) in &self.field_types {
+            let is_field = defs.types().iter().any(|d| match d {
+                TypeDefinition::Object(d) => {
+                    d.type_name().name() == type_name
+                        && d.fields().iter().any(|f| f.field_name().0 == *field_name)
+                }
+                _ => false,
+            });
+            if !is_field {
+                bail!("field type target {}.{} is not an object field", type_name, field_name);
+            }
+
+            if rust_type.parse::<TokenStream>().is_err() {
+                bail!("field type {} is not a valid Rust type", rust_type);
+            }
+        }
+
+        let types = defs.types().iter().map(|def| {
+            let def = match def {
+                TypeDefinition::Object(def) => def,
+                def => return def.clone(),
+            };
+
+            let fields = def.fields().iter().map(|field| {
+                let rust_type = self.field_types.iter().find(|(type_name, field_name, _)| {
+                    type_name == def.type_name().name() && *field_name == field.field_name().0
+                });
+                match rust_type {
+                    Some((_, _, rust_type)) => field_definition::Builder::from(field.clone())
+                        .type_(Type::External(ExternalReference::new(
+                            TypeName::new(rust_type.as_str(), ""),
+                            field.type_().clone(),
+                        )))
+                        .build(),
+                    None => field.clone(),
+                }
+            });
+
+            TypeDefinition::Object(
+                object_definition::Builder::from(def.clone())
+                    .fields(fields)
+                    .build(),
+            )
+        });
+
+        Ok(ConjureDefinition::builder()
+            .version(defs.version())
+            .errors(defs.errors().iter().cloned())
+            .types(types)
+            .services(defs.services().iter().cloned())
+            .build())
+    }
+
+    // Objects borrowing strings are generic over a lifetime, which the types referring to them would need as well.
+    fn check_borrowed_strings(&self, defs: &ConjureDefinition) -> Result<(), Error> {
+        let custom_types = self.custom_types();
+        let is_string = |def: &Type| is_primitive(def, PrimitiveType::String, &custom_types);
+
+        for type_name in &self.borrowed_strings {
+            let has_string_field = defs.types().iter().any(|d| match d {
+                TypeDefinition::Object(d) => {
+                    d.type_name().name() == type_name
+                        && d.fields()
+                            .iter()
+                            .any(|f| contains_type(f.type_(), &is_string))
+                }
+                _ => false,
+            });
+            if !has_string_field {
+                bail!(
+                    "borrowed strings type {} is not an object with a string field",
+                    type_name
+                );
+            }
+
+            let is_reference = |def: &Type| match def {
+                Type::Reference(def) => def.name() == type_name,
+                _ => false,
+            };
+            let referenced = defs.types().iter().any(|d| match d {
+                TypeDefinition::Alias(d) => contains_type(d.alias(), &is_reference),
+                TypeDefinition::Enum(_) => false,
+                TypeDefinition::Object(d) => d
+                    .fields()
+                    .iter()
+                    .any(|f| contains_type(f.type_(), &is_reference)),
+                TypeDefinition::Union(d) => d
+                    .union_()
+                    .iter()
+                    .any(|f| contains_type(f.type_(), &is_reference)),
+            }) || defs.errors().iter().any(|d| {
+                d.safe_args()
+                    .iter()
+                    .chain(d.unsafe_args())
+                    .any(|f| contains_type(f.type_(), &is_reference))
+            });
+            if referenced {
+                bail!(
+                    "borrowed strings type {} is referenced by another type",
+                    type_name
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_raw_any_fields(&self, defs: &ConjureDefinition) -> Result<(), Error> {
+        let custom_types = self.custom_types();
+        let is_any = |def: &Type| is_primitive(def, PrimitiveType::Any, &custom_types);
+
+        for type_name in &self.raw_any_fields {
+            let has_any_field = defs.types().iter().any(|d| match d {
+                TypeDefinition::Object(d) => {
+                    d.type_name().name() == type_name
+                        && d.fields().iter().any(|f| contains_type(f.type_(), &is_any))
+                }
+                _ => false,
+            });
+            if !has_any_field {
+                bail!(
+                    "raw any fields type {} is not an object with an any field",
+                    type_name
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_flattened_fields(&self, defs: &ConjureDefinition) -> Result<(), Error> {
+        let object = |type_name: &TypeName| {
+            defs.types().iter().find_map(|d| match d {
+                TypeDefinition::Object(d) if d.type_name() == type_name => Some(d),
+                _ => None,
+            })
+        };
+
+        for (type_name, field_name) in &self.flattened_fields {
+            let parent = defs.types().iter().find_map(|d| match d {
+                TypeDefinition::Object(d) if d.type_name().name() == type_name => Some(d),
+                _ => None,
+            });
+            let field =
+                parent.and_then(|d| d.fields().iter().find(|f| f.field_name().0 == *field_name));
+            let (parent, field) = match (parent, field) {
+                (Some(parent), Some(field)) => (parent, field),
+                _ => bail!(
+                    "flattened field {}.{} is not an object field",
+                    type_name,
+                    field_name
+                ),
+            };
+
+            let child = match field.type_() {
+                Type::Reference(name) if !self.extern_packages.contains_key(name.package()) => {
+                    object(name)
+                }
+                _ => None,
+            };
+            let child = match child {
+                Some(child) => child,
+                None => bail!(
+                    "flattened field {}.{} is not a required object",
+                    type_name,
+                    field_name
+                ),
+            };
+
+            if self
+                .flattened_fields
+                .iter()
+                .any(|(t, _)| t == child.type_name().name())
+            {
+                bail!(
+                    "flattened field {}.{} refers to an object with flattened fields",
+                    type_name,
+                    field_name
+                );
+            }
+
+            if self.positional_deserialization.contains(type_name) {
+                bail!(
+                    "flattened field {}.{} is in a positionally deserialized object",
+                    type_name,
+                    field_name
+                );
+            }
+
+            if self.borrowed_strings.contains(child.type_name().name()) {
+                bail!(
+                    "flattened field {}.{} refers to an object which borrows strings",
+                    type_name,
+                    field_name
+                );
+            }
+
+            // the nested object's entries share a namespace with the parent's and any other flattened objects'
+            let mut keys = vec![];
+            for f in parent.fields() {
+                let flattened = self
+                    .flattened_fields
+                    .contains(&(type_name.clone(), f.field_name().0.clone()));
+                match f.type_() {
+                    Type::Reference(name) if flattened => {
+                        if let Some(d) = object(name) {
+                            keys.extend(d.fields().iter().map(|f| &f.field_name().0));
+                        }
+                    }
+                    _ => keys.push(&f.field_name().0),
+                }
+            }
+            for f in child.fields() {
+                if keys.iter().filter(|k| ***k == f.field_name().0).count() > 1 {
+                    bail!(
+                        "flattened field {}.{} conflicts with field {}",
+                        type_name,
+                        field_name,
+                        f.field_name().0
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_field_aliases(&self, defs: &ConjureDefinition) -> Result<(), Error> {
+        for ((type_name, field_name), aliases) in &self.field_aliases {
+            let object = defs.types().iter().find_map(|d| match d {
+                TypeDefinition::Object(d) if d.type_name().name() == type_name => Some(d),
+                _ => None,
+            });
+            let field =
+                object.and_then(|d| d.fields().iter().find(|f| f.field_name().0 == *field_name));
+            let object = match (object, field) {
+                (Some(object), Some(_)) => object,
+                _ => bail!(
+                    "aliased field {}.{} is not an object field",
+                    type_name,
+                    field_name
+                ),
+            };
+
+            if self
+                .flattened_fields
+                .contains(&(type_name.clone(), field_name.clone()))
+            {
+                bail!("aliased field {}.{} is flattened", type_name, field_name);
+            }
+
+            // aliases share a namespace with the object's field names and the aliases of its other fields
+            let case_insensitive = self.case_insensitive_fields.contains(type_name);
+            let normalize = |key: &str| {
+                if case_insensitive {
+                    key.to_ascii_lowercase()
+                } else {
+                    key.to_string()
+                }
+            };
+            let mut keys = vec![];
+            for f in object.fields() {
+                keys.push(normalize(&f.field_name().0));
+                if let Some(aliases) = self
+                    .field_aliases
+                    .get(&(type_name.clone(), f.field_name().0.clone()))
+                {
+                    keys.extend(aliases.iter().map(|a| normalize(a)));
+                }
+            }
+            for alias in aliases {
+                let alias = normalize(alias);
+                if keys.iter().filter(|k| **k == alias).count() > 1 {
+                    bail!(
+                        "alias {} of field {}.{} conflicts with another field",
+                        alias,
+                        type_name,
+                        field_name
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_write_only_fields(&self, defs: &ConjureDefinition) -> Result<(), Error> {
+        for (type_name, field_name) in &self.write_only_fields {
+            let is_field = defs.types().iter().any(|d| match d {
+                TypeDefinition::Object(d) => {
+                    d.type_name().name() == type_name
+                        && d.fields().iter().any(|f| f.field_name().0 == *field_name)
+                }
+                _ => false,
+            });
+            if !is_field {
+                bail!(
+                    "write-only field {}.{} is not an object field",
+                    type_name,
+                    field_name
+                );
+            }
+
+            if self
+                .flattened_fields
+                .contains(&(type_name.clone(), field_name.clone()))
+            {
+                bail!("write-only field {}.{} is flattened", type_name, field_name);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_case_insensitive_fields(&self, defs: &ConjureDefinition) -> Result<(), Error> {
+        for type_name in &self.case_insensitive_fields {
+            let object = defs.types().iter().find_map(|d| match d {
+                TypeDefinition::Object(d) if d.type_name().name() == type_name => Some(d),
+                _ => None,
+            });
+            let object = match object {
+                Some(object) => object,
+                None => bail!(
+                    "case insensitive fields type {} is not an object",
+                    type_name
+                ),
+            };
+
+            let mut names = HashSet::new();
+            for field in object.fields() {
+                if !names.insert(field.field_name().0.to_ascii_lowercase()) {
+                    bail!(
+                        "field {}.{} differs from another field only in case",
+                        type_name,
+                        field.field_name().0
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_compact_fields(&self, defs: &ConjureDefinition) -> Result<(), Error> {
+        for type_name in &self.compact_fields {
+            let is_object = defs.types().iter().any(|d| match d {
+                TypeDefinition::Object(d) => d.type_name().name() == type_name,
+                _ => false,
+            });
+            if !is_object {
+                bail!("compact fields type {} is not an object", type_name);
+            }
+
+            if self.borrowed_strings.contains(type_name) {
+                bail!("compact fields type {} also borrows its strings", type_name);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn extern_packages(&self) -> HashMap<String, TokenStream> {
+        self.extern_packages
+            .iter()
+            .map(|(package, path)| (package.clone(), path.parse().unwrap()))
+            .collect()
+    }
+
+    fn custom_types(&self) -> HashSet<TypeName> {
+        self.field_types
+            .iter()
+            .map(|(_, _, rust_type)| TypeName::new(rust_type.as_str(), ""))
+            .collect()
+    }
+
+    fn union_conversions(
+        &self,
+        context: &Context,
+        defs: &ConjureDefinition,
+        def: &UnionDefinition,
+    ) -> Result<TokenStream, Error> {
+        let mut conversions = TokenStream::new();
+
+        for (source, target) in &self.union_conversions {
+            if source != def.type_name().name() {
+                continue;
+            }
+
+            let target_def = defs.types().iter().find_map(|d| match d {
+                TypeDefinition::Union(d) if d.type_name().name() == target => Some(d),
+                _ => None,
+            });
+            let target_def = match target_def {
+                Some(target_def) => target_def,
+                None => bail!("union conversion target {} is not a union type", target),
+            };
+
+            conversions.extend(unions::generate_conversion(context, def, target_def)?);
+        }
+
+        Ok(conversions)
     }
 
-    fn create_root_module(&self, modules: &[Module]) -> TokenStream {
+    fn create_root_module(
+        &self,
+        modules: &[Module],
+        strategies_module: Option<&str>,
+    ) -> TokenStream {
+        let vis = self.visibility.tokens();
+
         let uses = modules.iter().map(|m| {
             let module_name = m.module_name.parse::<TokenStream>().unwrap();
             let type_name = m.type_name.parse::<TokenStream>().unwrap();
+            let exports = m.exports.iter().map(|e| e.parse::<TokenStream>().unwrap());
+            let vis_repeat = iter::repeat(&vis);
+            let module_name_repeat = iter::repeat(&module_name);
             quote! {
                 #[doc(inline)]
-                pub use self::#module_name::#type_name;
+                #vis use self::#module_name::#type_name;
+                #(
+                    #[doc(inline)]
+                    #vis_repeat use self::#module_name_repeat::#exports;
+                )*
             }
         });
 
         let mods = modules.iter().map(|m| {
             let module_name = m.module_name.parse::<TokenStream>().unwrap();
             quote! {
-                pub mod #module_name;
+                #vis mod #module_name;
+            }
+        });
+
+        let strategies_mod = strategies_module.map(|m| {
+            let module_name = m.parse::<TokenStream>().unwrap();
+            quote! {
+                #vis mod #module_name;
             }
         });
 
@@ -346,12 +1420,83 @@ impl Config {
             #(#uses)*
 
             #(#mods)*
+
+            #strategies_mod
         }
     }
 }
 
+/// The visibility of generated items.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Visibility {
+    /// Items are visible everywhere, as `pub`.
+    Public,
+    /// Items are visible only within the crate containing them, as `pub(crate)`.
+    Crate,
+}
+
+impl Visibility {
+    fn tokens(self) -> TokenStream {
+        match self {
+            Visibility::Public => quote!(pub),
+            Visibility::Crate => quote!(pub(crate)),
+        }
+    }
+}
+
+// Returns true if the type or any type nested within it matches the predicate. External types aren't descended into.
+fn contains_type(def: &Type, f: &dyn Fn(&Type) -> bool) -> bool {
+    if f(def) {
+        return true;
+    }
+
+    match def {
+        Type::Optional(def) => contains_type(def.item_type(), f),
+        Type::List(def) => contains_type(def.item_type(), f),
+        Type::Set(def) => contains_type(def.item_type(), f),
+        Type::Map(def) => contains_type(def.key_type(), f) || contains_type(def.value_type(), f),
+        Type::Primitive(_) | Type::Reference(_) | Type::External(_) => false,
+    }
+}
+
+// User-provided field types are stored as-is, but other external types are represented by their fallback.
+fn is_primitive(def: &Type, primitive: PrimitiveType, custom_types: &HashSet<TypeName>) -> bool {
+    match def {
+        Type::Primitive(def) => *def == primitive,
+        Type::External(def) if !custom_types.contains(def.external_reference()) => {
+            contains_type(def.fallback(), &|def| is_primitive(def, primitive, custom_types))
+        }
+        _ => false,
+    }
+}
+
 struct Module {
     module_name: String,
     type_name: String,
+    exports: Vec<String>,
     contents: TokenStream,
 }
+
+/// The fractional second precision of serialized datetimes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DateTimePrecision {
+    /// Whole seconds, with no fractional digits.
+    Seconds,
+    /// Milliseconds, with 3 fractional digits.
+    Millis,
+    /// Microseconds, with 6 fractional digits.
+    Micros,
+    /// Nanoseconds, with 9 fractional digits.
+    Nanos,
+}
+
+impl DateTimePrecision {
+    fn tokens(self) -> TokenStream {
+        match self {
+            DateTimePrecision::Seconds => quote!(conjure_object::chrono::SecondsFormat::Secs),
+            DateTimePrecision::Millis => quote!(conjure_object::chrono::SecondsFormat::Millis),
+            DateTimePrecision::Micros => quote!(conjure_object::chrono::SecondsFormat::Micros),
+            DateTimePrecision::Nanos => quote!(conjure_object::chrono::SecondsFormat::Nanos),
+        }
+    }
+}
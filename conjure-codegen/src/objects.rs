@@ -11,13 +11,15 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::quote;
 use std::collections::HashSet;
 use std::iter;
 
 use crate::context::{CollectionSetterBounds, CollectionType, Context, SetterBounds};
-use crate::types::{FieldDefinition, ObjectDefinition};
+use crate::types::{FieldDefinition, ObjectDefinition, PrimitiveType, Type};
+
+const MAX_INLINE_FIELDS: usize = 1;
 
 pub fn generate(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
     let object = generate_object(ctx, def);
@@ -25,6 +27,13 @@ pub fn generate(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
     let serialize = generate_serialize(ctx, def);
     let deserialize = generate_deserialize(ctx, def);
     let field = generate_field(ctx, def);
+    let fields_present = generate_fields_present(ctx, def);
+    let field_names = generate_field_names(ctx, def);
+    let flattened = if ctx.is_flatten_target(def.type_name()) {
+        generate_flattened(ctx, def)
+    } else {
+        quote!()
+    };
 
     quote! {
         use conjure_object::serde::{ser, de};
@@ -36,16 +45,32 @@ pub fn generate(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
         #serialize
         #deserialize
         #field
+        #fields_present
+        #field_names
+        #flattened
     }
 }
 
 fn generate_object(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    let vis = ctx.visibility();
     let docs = ctx.docs(def.docs());
     let name = ctx.type_name(def.type_name().name());
     let default = ctx.default_ident(def.type_name());
 
-    let mut derives = vec!["Debug", "Clone", "PartialEq", "PartialOrd"];
-    if !def.fields().iter().any(|v| ctx.has_double(v.type_())) {
+    // error types are generated as objects but aren't registered in the context, so look at the fields directly
+    let double_ord = def.fields().iter().any(|v| ctx.has_double(v.type_()))
+        && def
+            .fields()
+            .iter()
+            .all(|v| !ctx.has_double(v.type_()) || ctx.is_double_ord(v.type_()));
+    let raw_double = def.fields().iter().any(|v| ctx.has_raw_double(v.type_()));
+
+    let mut derives = vec!["Debug", "Clone"];
+    if !(double_ord && raw_double) {
+        derives.push("PartialEq");
+        derives.push("PartialOrd");
+    }
+    if !def.fields().iter().any(|v| ctx.has_double(v.type_())) || double_ord && !raw_double {
         derives.push("Eq");
         derives.push("Ord");
         derives.push("Hash");
@@ -59,15 +84,23 @@ fn generate_object(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
     let boxed_types = &def
         .fields()
         .iter()
-        .map(|s| ctx.boxed_rust_type(def.type_name(), s.type_()))
+        .map(|s| ctx.stored_rust_type(def.type_name(), s.type_()))
         .collect::<Vec<_>>();
 
+    let type_name_consts = ctx.type_name_consts(def.type_name());
+
     let constructor = if fields.len() < 4 {
         generate_constructor(ctx, def)
     } else {
         quote!()
     };
 
+    let parts = if fields.len() >= 2 && fields.len() <= 4 {
+        generate_parts(ctx, def)
+    } else {
+        quote!()
+    };
+
     let accessors = def.fields().iter().map(|s| {
         let docs = ctx.docs(s.docs());
         let name = ctx.field_name(s.field_name());
@@ -77,43 +110,365 @@ fn generate_object(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
         quote!(
             #docs
             #[inline]
-            pub fn #name(&self) -> #ret_type {
+            #vis fn #name(&self) -> #ret_type {
                 #borrow
             }
         )
     });
 
-    let builder_method = if fields.iter().any(|f| f == "builder") {
-        quote!(builder_)
+    // Copy fields are already returned by value from their accessors
+    let into_accessors = def.fields().iter().map(|s| {
+        if ctx.is_copy(s.type_()) {
+            return quote!();
+        }
+
+        let name = ctx.field_name(s.field_name());
+        let into_name = ctx.into_accessor_name(s.field_name());
+        let docs = format!("Returns the `{}` field, consuming the object.", name);
+        let (ret_type, value) = match s.type_() {
+            Type::Reference(_) if ctx.is_boxed(def.type_name(), s.type_()) => (
+                ctx.rust_type(def.type_name(), s.type_()),
+                quote!(*self.#name),
+            ),
+            _ => (
+                ctx.stored_rust_type(def.type_name(), s.type_()),
+                quote!(self.#name),
+            ),
+        };
+
+        quote!(
+            #[doc = #docs]
+            #[inline]
+            #vis fn #into_name(self) -> #ret_type {
+                #value
+            }
+        )
+    });
+
+    let require_accessors = def.fields().iter().map(|s| {
+        let item_type = match s.type_() {
+            Type::Optional(def) => def.item_type(),
+            _ => return quote!(),
+        };
+
+        let name = ctx.field_name(s.field_name());
+        let require_name = ctx.require_accessor_name(s.field_name());
+        let docs = format!("Returns the `{}` field, or an error if it is absent.", name);
+        let result = ctx.result_ident(def.type_name());
+        let ok = ctx.ok_ident(def.type_name());
+        let err = ctx.err_ident(def.type_name());
+        let some = ctx.some_ident(def.type_name());
+        let none = ctx.none_ident(def.type_name());
+        let ret_type = ctx.borrowed_rust_type(def.type_name(), item_type);
+        let borrow = ctx.borrow_rust_type(quote!(*o), item_type);
+        let type_name = def.type_name().name();
+        let field_name = &**s.field_name();
+
+        quote!(
+            #[doc = #docs]
+            #[inline]
+            #vis fn #require_name(&self) -> #result<#ret_type, conjure_object::MissingField> {
+                match &self.#name {
+                    #some(o) => #ok(#borrow),
+                    #none => #err(conjure_object::MissingField::new(#type_name, #field_name)),
+                }
+            }
+        )
+    });
+
+    let mut_accessors = def.fields().iter().map(|s| {
+        if !ctx.mutable_accessors() {
+            return quote!();
+        }
+
+        let name = ctx.field_name(s.field_name());
+        let mut_name = ctx.mut_accessor_name(s.field_name());
+        let docs = format!("Returns a mutable reference to the `{}` field.", name);
+        let (ret_type, borrow) = match s.type_() {
+            Type::Reference(_) if ctx.is_boxed(def.type_name(), s.type_()) => (
+                ctx.rust_type(def.type_name(), s.type_()),
+                quote!(&mut *self.#name),
+            ),
+            _ => (
+                ctx.stored_rust_type(def.type_name(), s.type_()),
+                quote!(&mut self.#name),
+            ),
+        };
+
+        quote!(
+            #[doc = #docs]
+            #[inline]
+            #vis fn #mut_name(&mut self) -> &mut #ret_type {
+                #borrow
+            }
+        )
+    });
+
+    let map_accessors = def
+        .fields()
+        .iter()
+        .map(|s| generate_map_accessors(ctx, def, s));
+
+    let builder_method = builder_method(ctx, def);
+
+    let builder_type = builder_type(ctx, def);
+
+    let (present_field, present_accessor) = if ctx.field_presence() {
+        let fields_present = fields_present_type(ctx, def);
+        let present_fields_method = present_fields_method(ctx, def);
+        (
+            quote!(present_fields_: conjure_object::private::Ignored_<#fields_present>,),
+            quote! {
+                /// Returns the fields which were present when the object was deserialized.
+                #[inline]
+                #vis fn #present_fields_method(&self) -> #fields_present {
+                    self.present_fields_.0
+                }
+            },
+        )
     } else {
-        quote!(builder)
+        (quote!(), quote!())
     };
 
-    let builder_type = builder_type(ctx, def);
+    let double_ord_impls = if double_ord && raw_double {
+        generate_double_ord(ctx, def)
+    } else {
+        quote!()
+    };
+
+    let collection_impls = generate_collection_impls(ctx, def);
+
+    let lifetime = lifetime(ctx, def, quote!('a));
 
     quote! {
         #docs
         #[derive(#(#derives),*)]
-        pub struct #name {
+        #vis struct #name #lifetime {
             #(
                 #fields: #boxed_types,
             )*
+            #present_field
         }
 
-        impl #name {
+        impl #lifetime #name #lifetime {
+            #type_name_consts
+
             #constructor
 
+            #parts
+
             /// Returns a new builder.
             #[inline]
-            pub fn #builder_method() -> #builder_type {
+            #vis fn #builder_method() -> #builder_type #lifetime {
                 #default::default()
             }
 
             #(#accessors)*
+
+            #(#into_accessors)*
+
+            #(#require_accessors)*
+
+            #(#mut_accessors)*
+
+            #(#map_accessors)*
+
+            #present_accessor
+        }
+
+        #double_ord_impls
+
+        #collection_impls
+    }
+}
+
+// Map fields can also be iterated over without exposing the `BTreeMap` they're stored in.
+fn generate_map_accessors(
+    ctx: &Context,
+    def: &ObjectDefinition,
+    field: &FieldDefinition,
+) -> TokenStream {
+    let (key_type, value_type) = match ctx.map_types(field.type_()) {
+        Some(types) => types,
+        None => return quote!(),
+    };
+
+    let vis = ctx.visibility();
+    let name = ctx.field_name(field.field_name());
+    let key = ctx.rust_type(def.type_name(), key_type);
+    let value = ctx.rust_type(def.type_name(), value_type);
+
+    // avoid conflicts with the accessors of other fields, such as `foo_keys` for a field named `fooKeys`
+    let method = |suffix: &str| {
+        let mut method = format!("{}_{}", name, suffix);
+        if def
+            .fields()
+            .iter()
+            .any(|f| ctx.field_name(f.field_name()) == method)
+        {
+            method.push('_');
+        }
+        Ident::new(&method, Span::call_site())
+    };
+    let iter = method("iter");
+    let keys = method("keys");
+    let values = method("values");
+
+    let iter_docs = format!(
+        "Returns an iterator over the entries of the `{}` field.",
+        name
+    );
+    let keys_docs = format!("Returns an iterator over the keys of the `{}` field.", name);
+    let values_docs = format!(
+        "Returns an iterator over the values of the `{}` field.",
+        name
+    );
+
+    quote! {
+        #[doc = #iter_docs]
+        #[inline]
+        #vis fn #iter(&self) -> impl Iterator<Item = (&#key, &#value)> {
+            self.#name.iter()
+        }
+
+        #[doc = #keys_docs]
+        #[inline]
+        #vis fn #keys(&self) -> impl Iterator<Item = &#key> {
+            self.#name.keys()
+        }
+
+        #[doc = #values_docs]
+        #[inline]
+        #vis fn #values(&self) -> impl Iterator<Item = &#value> {
+            self.#name.values()
+        }
+    }
+}
+
+fn generate_double_ord(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    let name = ctx.type_name(def.type_name().name());
+    let option = ctx.option_ident(def.type_name());
+    let some = ctx.some_ident(def.type_name());
+    let lifetime = lifetime(ctx, def, quote!('a));
+
+    let mut eqs = vec![];
+    let mut cmps = vec![];
+    let mut hashes = vec![];
+    for field in def.fields() {
+        let field_name = ctx.field_name(field.field_name());
+        if ctx.has_raw_double(field.type_()) {
+            eqs.push(ctx.double_eq(quote!(&self.#field_name), quote!(&other.#field_name)));
+            cmps.push(ctx.double_cmp(quote!(&self.#field_name), quote!(&other.#field_name)));
+            let hash = ctx.double_hash(quote!(&self.#field_name), quote!(hasher));
+            hashes.push(quote!(#hash;));
+        } else {
+            eqs.push(quote!(self.#field_name == other.#field_name));
+            cmps.push(quote!(std::cmp::Ord::cmp(&self.#field_name, &other.#field_name)));
+            hashes.push(quote!(std::hash::Hash::hash(&self.#field_name, hasher);));
+        }
+    }
+
+    quote! {
+        impl #lifetime std::cmp::PartialEq for #name #lifetime {
+            fn eq(&self, other: &#name #lifetime) -> bool {
+                #(#eqs)&&*
+            }
+        }
+
+        impl #lifetime std::cmp::Eq for #name #lifetime {}
+
+        impl #lifetime std::cmp::PartialOrd for #name #lifetime {
+            #[inline]
+            fn partial_cmp(&self, other: &#name #lifetime) -> #option<std::cmp::Ordering> {
+                #some(std::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        impl #lifetime std::cmp::Ord for #name #lifetime {
+            fn cmp(&self, other: &#name #lifetime) -> std::cmp::Ordering {
+                std::cmp::Ordering::Equal
+                    #(.then_with(|| #cmps))*
+            }
+        }
+
+        impl #lifetime std::hash::Hash for #name #lifetime {
+            fn hash<H>(&self, hasher: &mut H)
+            where
+                H: std::hash::Hasher,
+            {
+                #(#hashes)*
+            }
+        }
+    }
+}
+
+// Objects wrapping a single list or set can be collected into and extended like the collection itself.
+fn generate_collection_impls(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    if def.fields().len() != 1 {
+        return quote!();
+    }
+    let field = &def.fields()[0];
+    // boxed slices can't be extended in place
+    if ctx.is_compact(def.type_name(), field.type_()) {
+        return quote!();
+    }
+    let item_type = match field.type_() {
+        Type::List(def) => def.item_type(),
+        Type::Set(def) => def.item_type(),
+        _ => return quote!(),
+    };
+
+    let name = ctx.type_name(def.type_name().name());
+    let into_iterator = ctx.into_iterator_ident(def.type_name());
+    let field_name = ctx.field_name(field.field_name());
+    let item = ctx.rust_type(def.type_name(), item_type);
+    let lifetime = lifetime(ctx, def, quote!('a));
+
+    let present_fields = if ctx.field_presence() {
+        let fields_present = fields_present_type(ctx, def);
+        let words = fields_present_words(def);
+        quote! {
+            present_fields_: conjure_object::private::Ignored_(#fields_present([0; #words])),
+        }
+    } else {
+        quote!()
+    };
+
+    quote! {
+        impl #lifetime std::iter::FromIterator<#item> for #name #lifetime {
+            #[inline]
+            fn from_iter<T>(iter: T) -> #name #lifetime
+            where
+                T: #into_iterator<Item = #item>,
+            {
+                #name {
+                    #field_name: iter.into_iter().collect(),
+                    #present_fields
+                }
+            }
+        }
+
+        impl #lifetime std::iter::Extend<#item> for #name #lifetime {
+            #[inline]
+            fn extend<T>(&mut self, iter: T)
+            where
+                T: #into_iterator<Item = #item>,
+            {
+                self.#field_name.extend(iter)
+            }
         }
     }
 }
 
+// Objects which borrow strings from the deserializer's input are generic over its lifetime.
+fn lifetime(ctx: &Context, def: &ObjectDefinition, lifetime: TokenStream) -> TokenStream {
+    if ctx.borrows_strings(def.type_name()) {
+        quote!(<#lifetime>)
+    } else {
+        quote!()
+    }
+}
+
 fn fields(ctx: &Context, def: &ObjectDefinition) -> Vec<Ident> {
     def.fields()
         .iter()
@@ -121,6 +476,46 @@ fn fields(ctx: &Context, def: &ObjectDefinition) -> Vec<Ident> {
         .collect()
 }
 
+pub fn builder_method(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    if fields(ctx, def).iter().any(|f| f == "builder") {
+        quote!(builder_)
+    } else {
+        quote!(builder)
+    }
+}
+
+fn merge_method(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    if fields(ctx, def).iter().any(|f| f == "merge") {
+        quote!(merge_)
+    } else {
+        quote!(merge)
+    }
+}
+
+pub fn build_method(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    if fields(ctx, def).iter().any(|f| f == "build") {
+        quote!(build_)
+    } else {
+        quote!(build)
+    }
+}
+
+fn present_fields_method(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    if fields(ctx, def).iter().any(|f| f == "present_fields") {
+        quote!(present_fields_)
+    } else {
+        quote!(present_fields)
+    }
+}
+
+fn fields_present_type(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    if ctx.type_name(def.type_name().name()) == "FieldsPresent" {
+        quote!(FieldsPresent_)
+    } else {
+        quote!(FieldsPresent)
+    }
+}
+
 fn builder_type(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
     if ctx.type_name(def.type_name().name()) == "Builder" {
         quote!(Builder_)
@@ -130,6 +525,7 @@ fn builder_type(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
 }
 
 fn generate_constructor(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    let vis = ctx.visibility();
     let some = ctx.some_ident(def.type_name());
     let name = ctx.type_name(def.type_name().name());
     let mut param_it = vec![quote!(T), quote!(U), quote!(V)].into_iter();
@@ -148,9 +544,10 @@ fn generate_constructor(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
         match ctx.setter_bounds(def.type_name(), field_type, quote!(#arg_name)) {
             SetterBounds::Simple {
                 argument_type,
-                mut assign_rhs,
+                assign_rhs,
             } => {
                 arguments.push(quote!(#arg_name: #argument_type));
+                let mut assign_rhs = ctx.convert_compact(def.type_name(), field_type, assign_rhs);
                 if optional {
                     assign_rhs = quote!(#some(#assign_rhs));
                 }
@@ -158,12 +555,13 @@ fn generate_constructor(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
             }
             SetterBounds::Generic {
                 argument_bound,
-                mut assign_rhs,
+                assign_rhs,
             } => {
                 let param = param_it.next().unwrap();
                 parameters.push(param.clone());
                 arguments.push(quote!(#arg_name: #param));
                 where_clauses.push(quote!(#param: #argument_bound));
+                let mut assign_rhs = ctx.convert_compact(def.type_name(), field_type, assign_rhs);
                 if optional {
                     assign_rhs = quote!(#some(#assign_rhs));
                 }
@@ -195,16 +593,37 @@ fn generate_constructor(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
         quote!(where #(#where_clauses,)*)
     };
 
+    if ctx.field_presence() {
+        let fields_present = fields_present_type(ctx, def);
+        let words = fields_present_words(def);
+        assignments.push(quote! {
+            present_fields_: conjure_object::private::Ignored_(#fields_present([0; #words]))
+        });
+    }
+
     let new_ = if def.fields().iter().any(|f| **f.field_name() == "new") {
         quote!(new_)
     } else {
         quote!(new)
     };
 
+    // Copy types are passed and stored as-is, so the constructor can be evaluated at compile time
+    let const_ = if def
+        .fields()
+        .iter()
+        .all(|f| ctx.is_copy(f.type_()) && !ctx.is_boxed(def.type_name(), f.type_()))
+    {
+        quote!(const)
+    } else {
+        quote!()
+    };
+
+    let lifetime = lifetime(ctx, def, quote!('a));
+
     quote! {
         /// Constructs a new instance of the type.
         #[inline]
-        pub fn #new_ #parameters(#(#arguments,)*) -> #name
+        #vis #const_ fn #new_ #parameters(#(#arguments,)*) -> #name #lifetime
         #where_clauses
         {
             #name {
@@ -214,7 +633,80 @@ fn generate_constructor(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
     }
 }
 
+// Boxed references are moved in and out of their boxes, matching the `into_` accessors.
+fn generate_parts(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    let vis = ctx.visibility();
+    let name = ctx.type_name(def.type_name().name());
+
+    let mut types = vec![];
+    let mut values = vec![];
+    let mut assignments = vec![];
+    for (i, field) in def.fields().iter().enumerate() {
+        let field_name = ctx.field_name(field.field_name());
+        let index = Literal::usize_unsuffixed(i);
+        match ctx.box_fn(def.type_name(), field.type_()) {
+            Some(box_) => {
+                types.push(ctx.rust_type(def.type_name(), field.type_()));
+                values.push(quote!(*self.#field_name));
+                assignments.push(quote!(#field_name: #box_(parts.#index)));
+            }
+            None => {
+                types.push(ctx.stored_rust_type(def.type_name(), field.type_()));
+                values.push(quote!(self.#field_name));
+                assignments.push(quote!(#field_name: parts.#index));
+            }
+        }
+    }
+
+    if ctx.field_presence() {
+        let fields_present = fields_present_type(ctx, def);
+        let words = fields_present_words(def);
+        assignments.push(quote! {
+            present_fields_: conjure_object::private::Ignored_(#fields_present([0; #words]))
+        });
+    }
+
+    // avoid conflicts with field accessors, such as `into_parts` for a field named `parts`
+    let conflicts = |method: &str| {
+        def.fields().iter().any(|f| {
+            ctx.field_name(f.field_name()) == method
+                || ctx.into_accessor_name(f.field_name()) == method
+        })
+    };
+    let into_parts = if conflicts("into_parts") {
+        quote!(into_parts_)
+    } else {
+        quote!(into_parts)
+    };
+    let from_parts = if conflicts("from_parts") {
+        quote!(from_parts_)
+    } else {
+        quote!(from_parts)
+    };
+
+    let types = &types;
+    let types2 = types;
+    let lifetime = lifetime(ctx, def, quote!('a));
+
+    quote! {
+        /// Constructs an instance of the type from a tuple of its fields, in declaration order.
+        #[inline]
+        #vis fn #from_parts(parts: (#(#types,)*)) -> #name #lifetime {
+            #name {
+                #(#assignments),*
+            }
+        }
+
+        /// Returns a tuple of the object's fields, in declaration order, consuming the object.
+        #[inline]
+        #vis fn #into_parts(self) -> (#(#types2,)*) {
+            (#(#values,)*)
+        }
+    }
+}
+
 fn generate_builder(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    let vis = ctx.visibility();
     let name = ctx.type_name(def.type_name().name());
     let builder_type = builder_type(ctx, def);
     let option = ctx.option_ident(def.type_name());
@@ -237,60 +729,101 @@ fn generate_builder(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
         .iter()
         .map(|f| generate_setter(ctx, def, f, &field_names));
 
-    let build_method = if fields.iter().any(|f| f == "build") {
-        quote!(build_)
+    let merge_method = merge_method(ctx, def);
+
+    // empty builders don't read from the other builder at all
+    let other = if def.fields().is_empty() {
+        quote!(_other)
     } else {
-        quote!(build)
+        quote!(other)
     };
 
+    let merges = def.fields().iter().map(|f| {
+        let var = ctx.field_name(f.field_name());
+        let is_set = match ctx.is_empty_method(f.type_()) {
+            Some(is_empty) => quote!(!other.#var.#is_empty()),
+            None => quote!(other.#var.is_some()),
+        };
+        quote! {
+            if #is_set {
+                self.#var = other.#var;
+            }
+        }
+    });
+
+    let build_method = build_method(ctx, def);
+
+    // builders store compact fields in their usual types so they can be built up incrementally
     let build_rhs = def.fields().iter().map(|f| {
         let var = ctx.field_name(f.field_name());
-        if ctx.is_required(f.type_()) {
+        let value = if ctx.is_required(f.type_()) {
             let msg = format!("field {} was not set", var);
             quote!(self.#var.clone().expect(#msg))
         } else {
             quote!(self.#var.clone())
-        }
+        };
+        ctx.convert_compact(def.type_name(), f.type_(), value)
     });
 
+    let build_present_fields = if ctx.field_presence() {
+        let default = ctx.default_ident(def.type_name());
+        quote!(present_fields_: #default::default(),)
+    } else {
+        quote!()
+    };
+
     let from_rhs = def.fields().iter().map(|f| {
         let var = ctx.field_name(f.field_name());
+        let value = ctx.convert_compact(def.type_name(), f.type_(), quote!(_v.#var));
         if ctx.is_required(f.type_()) {
-            quote!(#some(_v.#var))
+            quote!(#some(#value))
         } else {
-            quote!(_v.#var)
+            value
         }
     });
 
+    let lifetime = lifetime(ctx, def, quote!('a));
+
     quote! {
         #[derive(Debug, Clone, Default)]
-        pub struct #builder_type {
+        #vis struct #builder_type #lifetime {
             #(
                 #fields: #boxed_types,
             )*
         }
 
-        impl #builder_type {
+        impl #lifetime #builder_type #lifetime {
             #(#setters)*
 
+            /// Merges the fields set in another builder into this one.
+            ///
+            /// Fields set in `other` take precedence over those set in this builder. Collection fields are considered
+            /// set if they are nonempty.
+            #[inline]
+            #vis fn #merge_method(&mut self, #other: #builder_type #lifetime) -> &mut Self {
+                #(#merges)*
+                self
+            }
+
             /// Constructs a new instance of the type.
             ///
             /// # Panics
             ///
             /// Panics if a required field was not set.
             #[inline]
-            pub fn #build_method(&self) -> #name {
+            #vis fn #build_method(&self) -> #name #lifetime {
                 #name {
                     #(
                         #fields: #build_rhs,
                     )*
+                    #build_present_fields
                 }
             }
         }
 
-        impl #from<#name> for #builder_type {
+        impl #lifetime #from<#name #lifetime> for #builder_type #lifetime {
             #[inline]
-            fn from(_v: #name) -> #builder_type {
+            fn from(_v: #name #lifetime) -> #builder_type #lifetime {
                 #builder_type {
                     #(
                         #fields: #from_rhs,
@@ -307,6 +840,7 @@ fn generate_setter(
     field: &FieldDefinition,
     field_names: &HashSet<String>,
 ) -> TokenStream {
+    let vis = ctx.visibility();
     let some = ctx.some_ident(def.type_name());
 
     let docs = ctx.docs(field.docs());
@@ -334,7 +868,7 @@ fn generate_setter(
                 #docs
                 #required
                 #[inline]
-                pub fn #name(&mut self, #name: #argument_type) -> &mut Self {
+                #vis fn #name(&mut self, #name: #argument_type) -> &mut Self {
                     self.#name = #assign_rhs;
                     self
                 }
@@ -350,7 +884,7 @@ fn generate_setter(
             quote! {
                 #docs
                 #required
-                pub fn #name<T>(&mut self, #name: T) -> &mut Self
+                #vis fn #name<T>(&mut self, #name: T) -> &mut Self
                 where
                     T: #argument_bound
                 {
@@ -393,7 +927,7 @@ fn generate_setter(
                     };
                     quote! {
                         #docs
-                        pub fn #single_name #params(&mut self, value: #type_) -> &mut Self
+                        #vis fn #single_name #params(&mut self, value: #type_) -> &mut Self
                         #where_
                         {
                             self.#name.push(#assign_rhs);
@@ -424,7 +958,7 @@ fn generate_setter(
                     };
                     quote! {
                         #docs
-                        pub fn #single_name #params(&mut self, value: #type_) -> &mut Self
+                        #vis fn #single_name #params(&mut self, value: #type_) -> &mut Self
                         #where_
                         {
                             self.#name.insert(#assign_rhs);
@@ -485,7 +1019,7 @@ fn generate_setter(
 
                     quote! {
                         #docs
-                        pub fn #single_name #params(&mut self, key: #key_type, value: #value_type) -> &mut Self
+                        #vis fn #single_name #params(&mut self, key: #key_type, value: #value_type) -> &mut Self
                         #wheres
                         {
                             self.#name.insert(#key_assign_rhs, #value_assign_rhs);
@@ -497,7 +1031,7 @@ fn generate_setter(
 
             quote! {
                 #docs
-                pub fn #name<T>(&mut self, #name: T) -> &mut Self
+                #vis fn #name<T>(&mut self, #name: T) -> &mut Self
                 where
                     T: #argument_bound
                 {
@@ -506,7 +1040,7 @@ fn generate_setter(
                 }
 
                 #docs
-                pub fn #extend_name<T>(&mut self, #name: T) -> &mut Self
+                #vis fn #extend_name<T>(&mut self, #name: T) -> &mut Self
                 where
                     T: #argument_bound
                 {
@@ -520,19 +1054,82 @@ fn generate_setter(
     }
 }
 
+// The serde entry points of objects with few fields are small enough that inlining them is worthwhile.
+fn serde_inline(def: &ObjectDefinition) -> TokenStream {
+    if def.fields().len() <= MAX_INLINE_FIELDS {
+        quote!(#[inline])
+    } else {
+        quote!()
+    }
+}
+
 fn generate_serialize(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
     let name = ctx.type_name(def.type_name().name());
     let result = ctx.result_ident(def.type_name());
     let some = ctx.some_ident(def.type_name());
 
-    let mut size = 0;
-    let mut empty_checks = vec![];
-    let mut serialize_calls = vec![];
-    for field in def.fields() {
-        let field_name = ctx.field_name(field.field_name());
-        let key = &field.field_name().0;
+    let (size, empty_checks, serialize_calls) = serialize_entries(ctx, def);
 
-        match ctx.is_empty_method(field.type_()) {
+    let size_mut = if empty_checks.is_empty() {
+        quote!()
+    } else {
+        quote!(mut)
+    };
+
+    let map_mut = if serialize_calls.is_empty() {
+        quote!()
+    } else {
+        quote!(mut)
+    };
+
+    let inline = serde_inline(def);
+    let lifetime = lifetime(ctx, def, quote!('a));
+
+    quote! {
+        impl #lifetime ser::Serialize for #name #lifetime {
+            #inline
+            fn serialize<S>(&self, s: S) -> #result<S::Ok, S::Error>
+            where
+                S: ser::Serializer,
+            {
+                let #size_mut size = #size;
+                #(#empty_checks)*
+
+                let #map_mut map = s.serialize_map(#some(size))?;
+                #(#serialize_calls)*
+                map.end()
+            }
+        }
+    }
+}
+
+// Returns the number of entries which are always written, the statements adding the conditionally written entries to
+// `size`, and the statements writing the entries to `map`. Write-only fields have no entries.
+fn serialize_entries(
+    ctx: &Context,
+    def: &ObjectDefinition,
+) -> (usize, Vec<TokenStream>, Vec<TokenStream>) {
+    let mut size = 0;
+    let mut empty_checks = vec![];
+    let mut serialize_calls = vec![];
+    // entries are always written in IDL declaration order so the output is stable across codegen runs
+    for field in def.fields() {
+        if ctx.is_write_only_field(def.type_name(), field) {
+            continue;
+        }
+
+        let field_name = ctx.field_name(field.field_name());
+
+        if ctx.is_flattened_field(def.type_name(), field) {
+            empty_checks.push(quote!(size += self.#field_name.flattened_len_();));
+            serialize_calls.push(quote!(self.#field_name.serialize_flattened_(&mut map)?;));
+            continue;
+        }
+
+        let key = &field.field_name().0;
+        let value = ctx.serialize_value(field.type_(), quote!(&self.#field_name));
+
+        match ctx.is_empty_method(field.type_()) {
             Some(is_empty) => {
                 let check_name = format!("skip_{}", field_name)
                     .parse::<TokenStream>()
@@ -548,7 +1145,7 @@ fn generate_serialize(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
 
                 let serialize_call = quote! {
                     if !#check_name {
-                        map.serialize_entry(&#key, &self.#field_name)?;
+                        map.serialize_entry(&#key, #value)?;
                     }
                 };
                 serialize_calls.push(serialize_call);
@@ -557,40 +1154,14 @@ fn generate_serialize(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
                 size += 1;
 
                 let serialize_call = quote! {
-                    map.serialize_entry(&#key, &self.#field_name)?;
+                    map.serialize_entry(&#key, #value)?;
                 };
                 serialize_calls.push(serialize_call);
             }
         }
     }
 
-    let size_mut = if size == def.fields().len() {
-        quote!()
-    } else {
-        quote!(mut)
-    };
-
-    let map_mut = if def.fields().is_empty() {
-        quote!()
-    } else {
-        quote!(mut)
-    };
-
-    quote! {
-        impl ser::Serialize for #name {
-            fn serialize<S>(&self, s: S) -> #result<S::Ok, S::Error>
-            where
-                S: ser::Serializer,
-            {
-                let #size_mut size = #size;
-                #(#empty_checks)*
-
-                let #map_mut map = s.serialize_map(#some(size))?;
-                #(#serialize_calls)*
-                map.end()
-            }
-        }
-    }
+    (size, empty_checks, serialize_calls)
 }
 
 fn generate_deserialize(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
@@ -609,112 +1180,1136 @@ fn generate_deserialize(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
         .map(|f| ctx.field_name(f.field_name()))
         .collect::<Vec<_>>();
     let fields2 = fields;
+    let direct_fields = &def
+        .fields()
+        .iter()
+        .filter(|f| !ctx.is_flattened_field(def.type_name(), f))
+        .map(|f| ctx.field_name(f.field_name()))
+        .collect::<Vec<_>>();
+    let direct_fields2 = direct_fields;
+
+    let field_names = &wire_keys(ctx, def);
+
+    let field_arms = direct_fields_enumerated(ctx, def).map(|(i, f)| {
+        let variant = ctx.type_name(f.field_name());
+        let field = ctx.field_name(f.field_name());
+        let key = &f.field_name().0;
+        let mark_present = if ctx.field_presence() {
+            let word = Literal::usize_unsuffixed(i / 64);
+            let bit = Literal::usize_unsuffixed(i % 64);
+            quote!(present_fields_.0[#word] |= 1 << #bit;)
+        } else {
+            quote!()
+        };
+        let assign = if ctx.collect_errors() {
+            let value = collect_next_value(ctx, def, f);
+            quote! {
+                match #value? {
+                    #some(value_) => {
+                        #field = #some(value_);
+                        #mark_present
+                    }
+                    #none => errors_.invalid_field(#key),
+                }
+            }
+        } else {
+            let value = next_value(ctx, def, f);
+            quote! {
+                #field = #some(#value?);
+                #mark_present
+            }
+        };
+        quote! {
+            Field_::#variant => {
+                if #field.is_some() {
+                    return #err(de::Error::duplicate_field(#key));
+                }
+                #assign
+            }
+        }
+    });
+
+    let (present_fields_init, present_fields_value) = if ctx.field_presence() {
+        let fields_present = fields_present_type(ctx, def);
+        let words = fields_present_words(def);
+        let mut_ = if def.fields().is_empty() {
+            quote!()
+        } else {
+            quote!(mut)
+        };
+        (
+            quote!(let #mut_ present_fields_ = #fields_present([0; #words]);),
+            quote!(present_fields_: conjure_object::private::Ignored_(present_fields_),),
+        )
+    } else {
+        (quote!(), quote!())
+    };
+
+    let flattened_fields = def
+        .fields()
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| ctx.is_flattened_field(def.type_name(), f))
+        .collect::<Vec<_>>();
+    let flattened_visitors = &flattened_fields
+        .iter()
+        .map(|(_, f)| flattened_visitor(ctx, f))
+        .collect::<Vec<_>>();
 
-    let field_names = def.fields().iter().map(|f| &f.field_name().0);
+    let unknown_arm = if !flattened_fields.is_empty() {
+        // entries unknown to the object itself may belong to one of its flattened objects
+        let visit_entries = flattened_visitors.iter().map(|visitor| {
+            if ctx.collect_errors() {
+                quote!(#visitor.visit_entry_(&field_, &mut map_, &mut errors_)?)
+            } else {
+                quote!(#visitor.visit_entry_(&field_, &mut map_)?)
+            }
+        });
+        let fallback = if ctx.deny_unknown_fields(def.type_name()) {
+            let field_names = wire_keys(ctx, def);
+            quote!(return #err(de::Error::unknown_field(&field_, &[#(#field_names, )*]));)
+        } else {
+            quote!(map_.next_value::<de::IgnoredAny>()?;)
+        };
+        quote! {
+            Field_::Unknown_(field_) => {
+                if !(#(#visit_entries)||*) {
+                    #fallback
+                }
+            }
+        }
+    } else if ctx.deny_unknown_fields(def.type_name()) {
+        let field_names = wire_keys(ctx, def);
+        quote! {
+            Field_::Unknown_(field_) => {
+                return #err(de::Error::unknown_field(&field_, &[#(#field_names, )*]));
+            }
+        }
+    } else {
+        quote! {
+            Field_::Unknown_ => {
+                map_.next_value::<de::IgnoredAny>()?;
+            }
+        }
+    };
 
-    let field_variants = def.fields().iter().map(|f| ctx.type_name(f.field_name()));
+    let flattened_init =
+        flattened_fields
+            .iter()
+            .zip(flattened_visitors)
+            .map(|((_, f), visitor)| {
+                let type_ = ctx.rust_type(def.type_name(), f.type_());
+                quote!(let mut #visitor = <#type_>::flattened_visitor_();)
+            });
+    let flattened_init = &quote!(#(#flattened_init)*);
+
+    let flattened_finish =
+        flattened_fields
+            .iter()
+            .zip(flattened_visitors)
+            .map(|((i, f), visitor)| {
+                let field = ctx.field_name(f.field_name());
+                let mark_present = if ctx.field_presence() {
+                    let word = Literal::usize_unsuffixed(i / 64);
+                    let bit = Literal::usize_unsuffixed(i % 64);
+                    quote! {
+                        if #field.is_some() {
+                            present_fields_.0[#word] |= 1 << #bit;
+                        }
+                    }
+                } else {
+                    quote!()
+                };
+                let box_ = ctx.box_fn(def.type_name(), f.type_());
+                let value = if ctx.collect_errors() {
+                    let box_ = box_.map(|box_| quote!(.map(#box_)));
+                    quote!(#visitor.finish_(&mut errors_)#box_)
+                } else {
+                    let value = quote!(#visitor.finish_::<A::Error>()?);
+                    let value = match box_ {
+                        Some(box_) => quote!(#box_(#value)),
+                        None => value,
+                    };
+                    quote!(#some(#value))
+                };
+                quote! {
+                    let #field = #value;
+                    #mark_present
+                }
+            });
+    let flattened_finish = &quote!(#(#flattened_finish)*);
 
     let repeat_none = iter::repeat(&none);
-    let repeat_some = iter::repeat(&some);
 
-    let repeat_none2 = iter::repeat(&none);
-    let repeat_some2 = iter::repeat(&some);
+    let value = quote! {
+        #name {
+            #(#fields,)*
+            #present_fields_value
+        }
+    };
 
-    let missing_fields = def.fields().iter().map(|f| {
-        if ctx.is_required(f.type_()) {
+    let finish = if ctx.collect_errors() {
+        collecting_finish(ctx, def, &value)
+    } else {
+        let repeat_none2 = iter::repeat(&none);
+        let repeat_some2 = iter::repeat(&some);
+        let missing_fields = def.fields().iter().map(|f| {
+            if ctx.is_required(f.type_()) {
+                let field_name = &f.field_name().0;
+                quote!(return #err(de::Error::missing_field(#field_name)))
+            } else {
+                let default = ctx.default_ident(def.type_name());
+                quote!(#default::default())
+            }
+        });
+        quote! {
+            #(
+                let #fields = match #fields2 {
+                    #repeat_some2(v) => v,
+                    #repeat_none2 => #missing_fields,
+                };
+            )*
+
+            #ok(#value)
+        }
+    };
+
+    let in_place_field_arms = direct_fields_enumerated(ctx, def).map(|(i, f)| {
+        let variant = ctx.type_name(f.field_name());
+        let field = ctx.field_name(f.field_name());
+        let key = &f.field_name().0;
+        let assign = if deserializes_in_place(f.type_()) && borrowed_string(ctx, def, f).is_none() {
+            quote!(map_.next_value_seed(conjure_object::private::InPlaceSeed_(&mut place_.#field))?;)
+        } else {
+            let value = next_value(ctx, def, f);
+            quote!(place_.#field = #value?;)
+        };
+        let mark_present = if ctx.field_presence() {
+            let word = Literal::usize_unsuffixed(i / 64);
+            let bit = Literal::usize_unsuffixed(i % 64);
+            quote!(present_fields_.0[#word] |= 1 << #bit;)
+        } else {
+            quote!()
+        };
+        quote! {
+            Field_::#variant => {
+                if #field {
+                    return #err(de::Error::duplicate_field(#key));
+                }
+                #assign
+                #field = true;
+                #mark_present
+            }
+        }
+    });
+
+    let in_place_missing_fields = direct_fields_enumerated(ctx, def).map(|(_, f)| {
+        let field = ctx.field_name(f.field_name());
+        let reset = if ctx.is_required(f.type_()) {
             let field_name = &f.field_name().0;
-            quote!(return #err(de::Error::missing_field(#field_name)))
+            quote!(return #err(de::Error::missing_field(#field_name));)
+        } else if deserializes_in_place(f.type_()) && !ctx.is_compact(def.type_name(), f.type_()) {
+            quote!(place_.#field.clear();)
         } else {
             let default = ctx.default_ident(def.type_name());
-            quote!(#default::default())
+            quote!(place_.#field = #default::default();)
+        };
+        quote! {
+            if !#field {
+                #reset
+            }
         }
     });
 
+    let errors_init = if !ctx.collect_errors() {
+        quote!()
+    } else if def.fields().is_empty() {
+        quote!(let errors_ = conjure_object::private::FieldErrors_::default();)
+    } else {
+        quote!(let mut errors_ = conjure_object::private::FieldErrors_::default();)
+    };
+
+    let (place_init, present_fields_assign) = if ctx.field_presence() {
+        (
+            quote!(let place_ = self.0;),
+            quote!(place_.present_fields_ = conjure_object::private::Ignored_(present_fields_);),
+        )
+    } else if def.fields().is_empty() {
+        (quote!(), quote!())
+    } else {
+        (quote!(let place_ = self.0;), quote!())
+    };
+
+    let field_names2 = field_names;
+
+    let (in_place_errors_init, in_place_flattened_assign) = if flattened_fields.is_empty() {
+        (quote!(), quote!())
+    } else if ctx.collect_errors() {
+        let flattened = &flattened_fields
+            .iter()
+            .map(|(_, f)| ctx.field_name(f.field_name()))
+            .collect::<Vec<_>>();
+        let flattened2 = flattened;
+        let flattened3 = flattened;
+        let flattened4 = flattened;
+        let repeat_some = iter::repeat(&some);
+        (
+            quote!(let mut errors_ = conjure_object::private::FieldErrors_::default();),
+            quote! {
+                #flattened_finish
+                match (#(#flattened,)*) {
+                    (#(#repeat_some(#flattened2),)*) => match errors_.finish(()) {
+                        #ok(()) => {
+                            #(place_.#flattened3 = #flattened4;)*
+                        }
+                        #err(errors_) => return #err(errors_.into_error()),
+                    },
+                    _ => return #err(errors_.into_error()),
+                }
+            },
+        )
+    } else {
+        let assigns = flattened_fields
+            .iter()
+            .zip(flattened_visitors)
+            .map(|((_, f), visitor)| {
+                let field = ctx.field_name(f.field_name());
+                let value = quote!(#visitor.finish_::<A::Error>()?);
+                let value = match ctx.box_fn(def.type_name(), f.type_()) {
+                    Some(box_) => quote!(#box_(#value)),
+                    None => value,
+                };
+                quote!(place_.#field = #value;)
+            });
+        (quote!(), quote!(#(#assigns)*))
+    };
+
+    let positional = ctx.positional_deserialization(def.type_name());
+    let expecting = if positional { "map or sequence" } else { "map" };
+    let (visit_seq, in_place_visit_seq) = if positional {
+        let value = if ctx.collect_errors() {
+            quote!(Visitor_
+                .visit_seq(seq_)?
+                .map_err(conjure_object::private::FieldErrors_::into_error::<A::Error>)?)
+        } else {
+            quote!(Visitor_.visit_seq(seq_)?)
+        };
+        (
+            generate_visit_seq(ctx, def),
+            quote! {
+                fn visit_seq<A>(self, seq_: A) -> #result<(), A::Error>
+                where
+                    A: de::SeqAccess<'de>
+                {
+                    *self.0 = #value;
+                    #ok(())
+                }
+            },
+        )
+    } else {
+        (quote!(), quote!())
+    };
+
+    let inline = serde_inline(def);
+    let span = ctx.deserialize_span(def.type_name());
+
+    // a borrowing object has the lifetime of the deserializer's input
+    let lifetime = lifetime(ctx, def, quote!('de));
+    let in_place_lifetime = if ctx.borrows_strings(def.type_name()) {
+        quote!(<'a, 'de>)
+    } else {
+        quote!(<'a>)
+    };
+
+    // when collecting errors, the visitor produces either the object or the errors of its fields
+    let (value_type, deserialize) = if ctx.collect_errors() {
+        (
+            quote!(#result<#name #lifetime, conjure_object::private::FieldErrors_>),
+            quote! {
+                conjure_object::private::deserialize_object_(
+                    d,
+                    #name_str,
+                    &[#(#field_names, )*],
+                    Visitor_,
+                )
+            },
+        )
+    } else {
+        (
+            quote!(#name #lifetime),
+            quote!(d.deserialize_struct(#name_str, &[#(#field_names, )*], Visitor_)),
+        )
+    };
+
     quote! {
-        impl<'de> de::Deserialize<'de> for #name {
-            fn deserialize<D>(d: D) -> #result<#name, D::Error>
+        impl<'de> de::Deserialize<'de> for #name #lifetime {
+            #inline
+            fn deserialize<D>(d: D) -> #result<#name #lifetime, D::Error>
+            where
+                D: de::Deserializer<'de>
+            {
+                #span
+                #deserialize
+            }
+
+            fn deserialize_in_place<D>(d: D, place: &mut #name #lifetime) -> #result<(), D::Error>
             where
                 D: de::Deserializer<'de>
             {
-                d.deserialize_struct(#name_str, &[#(#field_names, )*], Visitor_)
+                #span
+                d.deserialize_struct(#name_str, &[#(#field_names2, )*], InPlaceVisitor_(place))
             }
         }
 
         struct Visitor_;
 
         impl<'de> de::Visitor<'de> for Visitor_ {
-            type Value = #name;
+            type Value = #value_type;
 
             fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-                fmt.write_str("map")
+                fmt.write_str(#expecting)
             }
 
-            fn visit_map<A>(self, mut map_: A) -> #result<#name, A::Error>
+            #visit_seq
+
+            fn visit_map<A>(self, mut map_: A) -> #result<#value_type, A::Error>
             where
                 A: de::MapAccess<'de>
             {
                 #(
-                    let mut #fields = #repeat_none;
+                    let mut #direct_fields = #repeat_none;
                 )*
+                #present_fields_init
+                #errors_init
+                #flattened_init
 
                 while let #some(field_) = map_.next_key()? {
                     match field_ {
-                        #(
-                            Field_::#field_variants => #fields = #repeat_some(map_.next_value()?),
-                        )*
-                        Field_::Unknown_ => {
-                            map_.next_value::<de::IgnoredAny>()?;
-                        }
+                        #(#field_arms)*
+                        #unknown_arm
                     }
                 }
 
+                #flattened_finish
+                #finish
+            }
+        }
+
+        struct InPlaceVisitor_ #in_place_lifetime(&'a mut #name #lifetime);
+
+        impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_ #in_place_lifetime {
+            type Value = ();
+
+            fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                fmt.write_str(#expecting)
+            }
+
+            #in_place_visit_seq
+
+            fn visit_map<A>(self, mut map_: A) -> #result<(), A::Error>
+            where
+                A: de::MapAccess<'de>
+            {
+                #place_init
                 #(
-                    let #fields = match #fields2 {
-                        #repeat_some2(v) => v,
-                        #repeat_none2 => #missing_fields,
-                    };
+                    let mut #direct_fields2 = false;
                 )*
+                #present_fields_init
+                #in_place_errors_init
+                #flattened_init
+
+                while let #some(field_) = map_.next_key()? {
+                    match field_ {
+                        #(#in_place_field_arms)*
+                        #unknown_arm
+                    }
+                }
+
+                #(#in_place_missing_fields)*
+                #in_place_flattened_assign
+
+                #present_fields_assign
 
-                #ok(#name {
-                    #(#fields,)*
-                })
+                #ok(())
             }
         }
     }
 }
 
-fn generate_field(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
-    let result = ctx.result_ident(def.type_name());
+// When collecting errors, the visitor produces the object's invalid and missing fields rather than failing at the first
+// of them, leaving their reporting to the deserializer.
+fn collecting_finish(ctx: &Context, def: &ObjectDefinition, value: &TokenStream) -> TokenStream {
     let ok = ctx.ok_ident(def.type_name());
+    let some = ctx.some_ident(def.type_name());
+    let none = ctx.none_ident(def.type_name());
+    let err = ctx.err_ident(def.type_name());
+    let default = ctx.default_ident(def.type_name());
 
-    let field_variants = &def
+    let optional_fields = &def
         .fields()
         .iter()
-        .map(|f| ctx.type_name(f.field_name()))
+        .filter(|f| !ctx.is_required(f.type_()))
+        .map(|f| ctx.field_name(f.field_name()))
         .collect::<Vec<_>>();
+    let optional_fields2 = optional_fields;
+    let repeat_some = iter::repeat(&some);
+    let repeat_none = iter::repeat(&none);
+    let repeat_default = iter::repeat(&default);
+    let optional_values = quote! {
+        #(
+            let #optional_fields = match #optional_fields2 {
+                #repeat_some(v) => v,
+                #repeat_none => #repeat_default::default(),
+            };
+        )*
+    };
 
-    let keys = &def
+    let required_fields = def
         .fields()
         .iter()
-        .map(|f| &f.field_name().0)
+        .filter(|f| ctx.is_required(f.type_()))
+        .collect::<Vec<_>>();
+    if required_fields.is_empty() {
+        return quote! {
+            #optional_values
+            #ok(errors_.finish(#value))
+        };
+    }
+
+    let required = &required_fields
+        .iter()
+        .map(|f| ctx.field_name(f.field_name()))
         .collect::<Vec<_>>();
+    let required2 = required;
+    let repeat_some = iter::repeat(&some);
 
-    quote! {
-        enum Field_ {
-            #(#field_variants,)*
-            Unknown_
+    // flattened objects have already reported their own missing fields
+    let direct_required = required_fields
+        .iter()
+        .filter(|f| !ctx.is_flattened_field(def.type_name(), f))
+        .collect::<Vec<_>>();
+    let missing_patterns = required_fields.iter().map(|f| {
+        if ctx.is_flattened_field(def.type_name(), f) {
+            quote!(_)
+        } else {
+            let field = ctx.field_name(f.field_name());
+            quote!(#field)
         }
+    });
+    let direct_required_idents = direct_required
+        .iter()
+        .map(|f| ctx.field_name(f.field_name()));
+    let direct_required_names = direct_required.iter().map(|f| &f.field_name().0);
 
-        impl<'de> de::Deserialize<'de> for Field_ {
-            fn deserialize<D>(d: D) -> #result<Field_, D::Error>
-            where
-                D: de::Deserializer<'de>
-            {
-                d.deserialize_str(FieldVisitor_)
+    quote! {
+        #optional_values
+        match (#(#required,)*) {
+            (#(#repeat_some(#required2),)*) => #ok(errors_.finish(#value)),
+            (#(#missing_patterns,)*) => {
+                #(
+                    errors_.missing_field(#direct_required_idents.is_some(), #direct_required_names);
+                )*
+                #ok(#err(errors_))
             }
         }
+    }
+}
 
-        struct FieldVisitor_;
+// Objects deserialized positionally are read as a sequence of their field values in declaration order. Trailing
+// elements may be omitted for fields which aren't required.
+fn generate_visit_seq(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    let name = ctx.type_name(def.type_name().name());
+    let result = ctx.result_ident(def.type_name());
+    let ok = ctx.ok_ident(def.type_name());
+    let err = ctx.err_ident(def.type_name());
+    let some = ctx.some_ident(def.type_name());
+    let none = ctx.none_ident(def.type_name());
+
+    let lifetime = lifetime(ctx, def, quote!('de));
+    let expecting = format!("a sequence of {} elements", def.fields().len());
+    let len = Literal::usize_unsuffixed(def.fields().len());
+
+    let fields = def
+        .fields()
+        .iter()
+        .map(|f| ctx.field_name(f.field_name()))
+        .collect::<Vec<_>>();
+
+    let elements = def.fields().iter().enumerate().map(|(i, f)| {
+        let field = ctx.field_name(f.field_name());
+        let value = if let Some(borrowed) = borrowed_string(ctx, def, f) {
+            let (wrapper, unwrap) = borrowed_string_wrapper(borrowed);
+            quote!(seq_.next_element::<#wrapper>()?.map(#unwrap))
+        } else if is_optional_string(ctx, f) {
+            quote! {
+                seq_.next_element::<conjure_object::private::OptionalString_<_>>()?.map(|v| v.0)
+            }
+        } else if ctx.is_set(f.type_()) {
+            quote!(seq_.next_element::<conjure_object::private::UniqueSet_<_>>()?.map(|v| v.0))
+        } else if ctx.is_optional_set(f.type_()) {
+            let option = ctx.option_ident(def.type_name());
+            quote! {
+                seq_.next_element::<#option<conjure_object::private::UniqueSet_<_>>>()?.map(|v| v.map(|v| v.0))
+            }
+        } else if ctx.is_nested_optional(f.type_()) {
+            let some = ctx.some_ident(def.type_name());
+            quote!(seq_.next_element()?.map(#some))
+        } else {
+            quote!(seq_.next_element()?)
+        };
+        let mark_present = if ctx.field_presence() {
+            let word = Literal::usize_unsuffixed(i / 64);
+            let bit = Literal::usize_unsuffixed(i % 64);
+            quote!(present_fields_.0[#word] |= 1 << #bit;)
+        } else {
+            quote!()
+        };
+        let missing = if ctx.is_required(f.type_()) {
+            let i = Literal::usize_unsuffixed(i);
+            quote!(return #err(de::Error::invalid_length(#i, &#expecting)))
+        } else {
+            let default = ctx.default_ident(def.type_name());
+            quote!(#default::default())
+        };
+        quote! {
+            let #field = match #value {
+                #some(v) => {
+                    #mark_present
+                    v
+                }
+                #none => #missing,
+            };
+        }
+    });
+
+    let (present_fields_init, present_fields_value) = if ctx.field_presence() {
+        let fields_present = fields_present_type(ctx, def);
+        let words = fields_present_words(def);
+        let mut_ = if def.fields().is_empty() {
+            quote!()
+        } else {
+            quote!(mut)
+        };
+        (
+            quote!(let #mut_ present_fields_ = #fields_present([0; #words]);),
+            quote!(present_fields_: conjure_object::private::Ignored_(present_fields_),),
+        )
+    } else {
+        (quote!(), quote!())
+    };
+
+    let value = quote! {
+        #name {
+            #(#fields,)*
+            #present_fields_value
+        }
+    };
+    // elements are read as usual when collecting errors, so the sequence's value is always the object
+    let (value_type, value) = if ctx.collect_errors() {
+        (
+            quote!(#result<#name #lifetime, conjure_object::private::FieldErrors_>),
+            quote!(#ok(#value)),
+        )
+    } else {
+        (quote!(#name #lifetime), value)
+    };
+
+    quote! {
+        fn visit_seq<A>(self, mut seq_: A) -> #result<#value_type, A::Error>
+        where
+            A: de::SeqAccess<'de>
+        {
+            #present_fields_init
+
+            #(#elements)*
+
+            if seq_.next_element::<de::IgnoredAny>()?.is_some() {
+                return #err(de::Error::invalid_length(#len + 1, &#expecting));
+            }
+
+            #ok(#value)
+        }
+    }
+}
+
+// Strings and lists are deserialized in place so their existing allocations can be reused.
+fn deserializes_in_place(def: &Type) -> bool {
+    match def {
+        Type::Primitive(PrimitiveType::String) | Type::List(_) => true,
+        Type::External(def) => deserializes_in_place(def.fallback()),
+        _ => false,
+    }
+}
+
+// The shapes of field which borrow strings from the deserializer's input.
+enum BorrowedString {
+    Required,
+    Optional,
+    List,
+}
+
+// Returns `Some` if the field holds strings borrowed from the deserializer's input.
+fn borrowed_string(
+    ctx: &Context,
+    def: &ObjectDefinition,
+    field: &FieldDefinition,
+) -> Option<BorrowedString> {
+    if !ctx.borrows_strings(def.type_name()) {
+        return None;
+    }
+
+    if ctx.is_string(field.type_()) {
+        return Some(BorrowedString::Required);
+    }
+
+    if is_optional_string(ctx, field) {
+        return Some(BorrowedString::Optional);
+    }
+
+    match ctx.list_item_type(field.type_()) {
+        Some(def) if ctx.is_string(def) => Some(BorrowedString::List),
+        _ => None,
+    }
+}
+
+// The server deserializer can be configured to treat empty optional strings as absent values.
+fn is_optional_string(ctx: &Context, field: &FieldDefinition) -> bool {
+    match ctx.option_inner_type(field.type_()) {
+        Some(def) => ctx.is_string(def),
+        None => false,
+    }
+}
+
+// Returns the wrapper type a borrowed string field is deserialized through, along with the function unwrapping it.
+fn borrowed_string_wrapper(borrowed: BorrowedString) -> (TokenStream, TokenStream) {
+    match borrowed {
+        BorrowedString::Required => (
+            quote!(conjure_object::private::BorrowedStr_),
+            quote!(|v| v.0),
+        ),
+        BorrowedString::Optional => (
+            quote!(conjure_object::private::OptionalString_<conjure_object::private::BorrowedStr_>),
+            quote!(|v| v.0.map(|v| v.0)),
+        ),
+        BorrowedString::List => (
+            quote!(conjure_object::private::BorrowedStrList_),
+            quote!(|v| v.0),
+        ),
+    }
+}
+
+// Sets are deserialized through a wrapper which rejects duplicate values rather than silently dropping them, borrowed
+// strings through one which borrows them where possible, and optional strings through one which identifies them to the
+// server deserializer. The returned expression evaluates to a `Result`.
+fn next_value(ctx: &Context, def: &ObjectDefinition, field: &FieldDefinition) -> TokenStream {
+    let (type_, convert) = field_value(ctx, def, field);
+    let value = match type_ {
+        Some(type_) => quote!(map_.next_value::<#type_>()),
+        None => quote!(map_.next_value()),
+    };
+    match convert {
+        Some(convert) => quote!(#value.map(#convert)),
+        None => value,
+    }
+}
+
+// When collecting errors, the value is `None` if it was invalid and the deserializer has reported its error.
+fn collect_next_value(
+    ctx: &Context,
+    def: &ObjectDefinition,
+    field: &FieldDefinition,
+) -> TokenStream {
+    let (type_, convert) = field_value(ctx, def, field);
+    let type_ = type_.unwrap_or_else(|| quote!(_));
+    let value =
+        quote!(map_.next_value_seed(conjure_object::private::Collect_::<#type_>::default()));
+    match convert {
+        Some(convert) => quote!(#value.map(|v| v.map(#convert))),
+        None => value,
+    }
+}
+
+// The type a field's value is deserialized as, if it needs to be named, and the conversion from it to the field's type.
+fn field_value(
+    ctx: &Context,
+    def: &ObjectDefinition,
+    field: &FieldDefinition,
+) -> (Option<TokenStream>, Option<TokenStream>) {
+    if let Some(borrowed) = borrowed_string(ctx, def, field) {
+        let (wrapper, unwrap) = borrowed_string_wrapper(borrowed);
+        (Some(wrapper), Some(unwrap))
+    } else if is_optional_string(ctx, field) {
+        (
+            Some(quote!(conjure_object::private::OptionalString_<_>)),
+            Some(quote!(|v| v.0)),
+        )
+    } else if ctx.is_set(field.type_()) {
+        (
+            Some(quote!(conjure_object::private::UniqueSet_<_>)),
+            Some(quote!(|v| v.0)),
+        )
+    } else if ctx.is_optional_set(field.type_()) {
+        let option = ctx.option_ident(def.type_name());
+        (
+            Some(quote!(#option<conjure_object::private::UniqueSet_<_>>)),
+            Some(quote!(|v| v.map(|v| v.0))),
+        )
+    } else if ctx.is_nested_optional(field.type_()) {
+        // a present field is never the outer `None`, so an explicit null is distinguished from a missing field
+        let some = ctx.some_ident(def.type_name());
+        (None, Some(quote!(#some)))
+    } else {
+        (None, None)
+    }
+}
+
+// The keys of the entries the object may contain, with flattened fields replaced by the keys of their objects.
+fn wire_keys<'a>(ctx: &'a Context, def: &'a ObjectDefinition) -> Vec<&'a str> {
+    let mut keys = vec![];
+    for field in def.fields() {
+        if ctx.is_flattened_field(def.type_name(), field) {
+            let child = ctx.flattened_object(field.type_());
+            keys.extend(child.fields().iter().map(|f| &*f.field_name().0));
+        } else {
+            keys.push(&*field.field_name().0);
+        }
+    }
+    keys
+}
+
+// The object's fields which are deserialized from their own entries, along with their indices in the object.
+fn direct_fields_enumerated<'a>(
+    ctx: &'a Context,
+    def: &'a ObjectDefinition,
+) -> impl Iterator<Item = (usize, &'a FieldDefinition)> + 'a {
+    def.fields()
+        .iter()
+        .enumerate()
+        .filter(move |(_, f)| !ctx.is_flattened_field(def.type_name(), f))
+}
+
+// The match pattern for a field's key and any aliases, which is written against a `&str` scrutinee.
+fn key_pattern(ctx: &Context, def: &ObjectDefinition, field: &FieldDefinition) -> TokenStream {
+    let keys = iter::once(&field.field_name().0)
+        .chain(ctx.field_aliases(def.type_name(), field))
+        .collect::<Vec<_>>();
+
+    if ctx.case_insensitive_fields(def.type_name()) {
+        quote!(key_ if #(key_.eq_ignore_ascii_case(#keys))||*)
+    } else {
+        quote!(#(#keys)|*)
+    }
+}
+
+fn flattened_visitor(ctx: &Context, field: &FieldDefinition) -> Ident {
+    Ident::new(
+        &format!("{}_flattened_", ctx.field_name(field.field_name())),
+        Span::call_site(),
+    )
+}
+
+// Objects which are the values of flattened fields expose hidden hooks which their parents' generated
+// implementations use to write and read the object's entries directly to and from the parent's map. Entries are
+// offered to the visitor by key, and once the parent's map is exhausted `finish_` returns the object. When collecting
+// errors, it instead returns `None` if a required field was missing, having recorded the field in the parent's errors.
+fn generate_flattened(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    let vis = ctx.visibility();
+    let name = ctx.type_name(def.type_name().name());
+    let result = ctx.result_ident(def.type_name());
+    let ok = ctx.ok_ident(def.type_name());
+    let err = ctx.err_ident(def.type_name());
+    let some = ctx.some_ident(def.type_name());
+    let none = ctx.none_ident(def.type_name());
+    let option = ctx.option_ident(def.type_name());
+
+    let (size, empty_checks, _) = serialize_entries(ctx, def);
+    let size_mut = if empty_checks.is_empty() {
+        quote!()
+    } else {
+        quote!(mut)
+    };
+    let serialize_calls = def
+        .fields()
+        .iter()
+        .filter(|f| !ctx.is_write_only_field(def.type_name(), f))
+        .map(|f| {
+            let field = ctx.field_name(f.field_name());
+            let key = &f.field_name().0;
+            let value = ctx.serialize_value(f.type_(), quote!(&self.#field));
+            match ctx.is_empty_method(f.type_()) {
+                Some(is_empty) => quote! {
+                    if !self.#field.#is_empty() {
+                        map.serialize_entry(&#key, #value)?;
+                    }
+                },
+                None => quote!(map.serialize_entry(&#key, #value)?;),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let fields = &def
+        .fields()
+        .iter()
+        .map(|f| ctx.field_name(f.field_name()))
+        .collect::<Vec<_>>();
+    let field_types = def.fields().iter().map(|f| {
+        let type_ = ctx.stored_rust_type(def.type_name(), f.type_());
+        quote!(#option<#type_>)
+    });
+
+    let (present_fields_decl, present_fields_value) = if ctx.field_presence() {
+        let fields_present = fields_present_type(ctx, def);
+        let words = fields_present_words(def);
+        (
+            quote!(present_fields_: [u64; #words],),
+            quote!(present_fields_: conjure_object::private::Ignored_(#fields_present(self.present_fields_)),),
+        )
+    } else {
+        (quote!(), quote!())
+    };
+
+    let entry_arms = def.fields().iter().enumerate().map(|(i, f)| {
+        let field = ctx.field_name(f.field_name());
+        let key = &f.field_name().0;
+        let mark_present = if ctx.field_presence() {
+            let word = Literal::usize_unsuffixed(i / 64);
+            let bit = Literal::usize_unsuffixed(i % 64);
+            quote!(self.present_fields_[#word] |= 1 << #bit;)
+        } else {
+            quote!()
+        };
+        let pattern = key_pattern(ctx, def, f);
+        let assign = if ctx.collect_errors() {
+            let value = collect_next_value(ctx, def, f);
+            quote! {
+                match #value? {
+                    #some(value_) => {
+                        self.#field = #some(value_);
+                        #mark_present
+                    }
+                    #none => errors_.invalid_field(#key),
+                }
+            }
+        } else {
+            let value = next_value(ctx, def, f);
+            quote! {
+                self.#field = #some(#value?);
+                #mark_present
+            }
+        };
+        quote! {
+            #pattern => {
+                if self.#field.is_some() {
+                    return #err(de::Error::duplicate_field(#key));
+                }
+                #assign
+            }
+        }
+    });
+
+    let required_fields = def
+        .fields()
+        .iter()
+        .filter(|f| ctx.is_required(f.type_()))
+        .collect::<Vec<_>>();
+    let required_field_idents = &required_fields
+        .iter()
+        .map(|f| ctx.field_name(f.field_name()))
+        .collect::<Vec<_>>();
+    let required_field_idents2 = required_field_idents;
+    let required_field_names = required_fields.iter().map(|f| &f.field_name().0);
+    let repeat_some = iter::repeat(&some);
+
+    let values = def.fields().iter().map(|f| {
+        let field = ctx.field_name(f.field_name());
+        if ctx.is_required(f.type_()) {
+            quote!(#field,)
+        } else {
+            let default = ctx.default_ident(def.type_name());
+            quote!(#field: self.#field.unwrap_or_else(#default::default),)
+        }
+    });
+
+    let allow_unused = if def.fields().is_empty() {
+        quote!(#[allow(unused_variables)])
+    } else {
+        quote!()
+    };
+
+    let construct = quote! {
+        #name {
+            #(#values)*
+            #present_fields_value
+        }
+    };
+
+    let (errors_param, finish) = if ctx.collect_errors() {
+        let finish_body = if required_fields.is_empty() {
+            quote!(#some(#construct))
+        } else {
+            let required_field_idents3 = required_field_idents;
+            let required_field_idents4 = required_field_idents;
+            quote! {
+                match (#(self.#required_field_idents,)*) {
+                    (#(#repeat_some(#required_field_idents2),)*) => #some(#construct),
+                    (#(#required_field_idents3,)*) => {
+                        #(
+                            errors_.missing_field(
+                                #required_field_idents4.is_some(),
+                                #required_field_names,
+                            );
+                        )*
+                        #none
+                    }
+                }
+            }
+        };
+        let allow_unused_errors = if required_fields.is_empty() {
+            quote!(#[allow(unused_variables)])
+        } else {
+            quote!()
+        };
+        (
+            quote!(errors_: &mut conjure_object::private::FieldErrors_,),
+            quote! {
+                #allow_unused_errors
+                #vis fn finish_(
+                    self,
+                    errors_: &mut conjure_object::private::FieldErrors_,
+                ) -> #option<#name> {
+                    #finish_body
+                }
+            },
+        )
+    } else {
+        let repeat_none = iter::repeat(&none);
+        let repeat_err = iter::repeat(&err);
+        (
+            quote!(),
+            quote! {
+                #vis fn finish_<E>(self) -> #result<#name, E>
+                where
+                    E: de::Error,
+                {
+                    #(
+                        let #required_field_idents = match self.#required_field_idents2 {
+                            #repeat_some(v) => v,
+                            #repeat_none => return #repeat_err(E::missing_field(#required_field_names)),
+                        };
+                    )*
+
+                    #ok(#construct)
+                }
+            },
+        )
+    };
+    let allow_unused_map = if serialize_calls.is_empty() {
+        quote!(#[allow(unused_variables)])
+    } else {
+        quote!()
+    };
+
+    quote! {
+        impl #name {
+            #[doc(hidden)]
+            #vis fn flattened_len_(&self) -> usize {
+                let #size_mut size = #size;
+                #(#empty_checks)*
+                size
+            }
+
+            #[doc(hidden)]
+            #allow_unused_map
+            #vis fn serialize_flattened_<M>(&self, map: &mut M) -> #result<(), M::Error>
+            where
+                M: ser::SerializeMap,
+            {
+                #(#serialize_calls)*
+                #ok(())
+            }
+
+            #[doc(hidden)]
+            #vis fn flattened_visitor_() -> FlattenedVisitor_ {
+                FlattenedVisitor_::default()
+            }
+        }
+
+        #[doc(hidden)]
+        #[derive(Default)]
+        #vis struct FlattenedVisitor_ {
+            #(
+                #fields: #field_types,
+            )*
+            #present_fields_decl
+        }
+
+        impl FlattenedVisitor_ {
+            #allow_unused
+            #vis fn visit_entry_<'de, A>(
+                &mut self,
+                key_: &str,
+                map_: &mut A,
+                #errors_param
+            ) -> #result<bool, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                match key_ {
+                    #(#entry_arms)*
+                    _ => return #ok(false),
+                }
+                #ok(true)
+            }
+
+            #finish
+        }
+    }
+}
+
+fn generate_field(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    let result = ctx.result_ident(def.type_name());
+    let ok = ctx.ok_ident(def.type_name());
+
+    let field_variants = &direct_fields_enumerated(ctx, def)
+        .map(|(_, f)| ctx.type_name(f.field_name()))
+        .collect::<Vec<_>>();
+
+    let keys = &direct_fields_enumerated(ctx, def)
+        .map(|(_, f)| key_pattern(ctx, def, f))
+        .collect::<Vec<_>>();
+
+    // unknown keys are retained when they may need to be checked against flattened objects' fields
+    let has_flattened = def
+        .fields()
+        .iter()
+        .any(|f| ctx.is_flattened_field(def.type_name(), f));
+    let (unknown_variant, unknown_value) =
+        if ctx.deny_unknown_fields(def.type_name()) || has_flattened {
+            let string = ctx.string_ident(def.type_name());
+            (
+                quote!(Unknown_(#string)),
+                quote!(Field_::Unknown_(value.to_string())),
+            )
+        } else {
+            (quote!(Unknown_), quote!(Field_::Unknown_))
+        };
+
+    quote! {
+        enum Field_ {
+            #(#field_variants,)*
+            #unknown_variant
+        }
+
+        impl<'de> de::Deserialize<'de> for Field_ {
+            fn deserialize<D>(d: D) -> #result<Field_, D::Error>
+            where
+                D: de::Deserializer<'de>
+            {
+                d.deserialize_str(FieldVisitor_)
+            }
+        }
+
+        struct FieldVisitor_;
 
         impl<'de> de::Visitor<'de> for FieldVisitor_ {
             type Value = Field_;
@@ -731,7 +2326,7 @@ fn generate_field(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
                     #(
                         #keys => Field_::#field_variants,
                     )*
-                    _ => Field_::Unknown_,
+                    _ => #unknown_value,
                 };
 
                 #ok(v)
@@ -739,3 +2334,73 @@ fn generate_field(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
         }
     }
 }
+
+fn fields_present_words(def: &ObjectDefinition) -> Literal {
+    Literal::usize_unsuffixed(def.fields().len() / 64 + 1)
+}
+
+// flattened fields are skipped since their own fields appear on the wire in their place
+fn generate_field_names(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    let vis = ctx.visibility();
+
+    let consts = direct_fields_enumerated(ctx, def).map(|(_, f)| {
+        let name = ctx.field_name(f.field_name()).to_string().to_uppercase();
+        let name = Ident::new(&name, Span::call_site());
+        let wire_name = &f.field_name().0;
+        let doc = format!("The wire name of the `{}` field.", wire_name);
+
+        quote! {
+            #[doc = #doc]
+            #vis const #name: &str = #wire_name;
+        }
+    });
+
+    quote! {
+        /// The wire names of the object's fields.
+        #vis mod fields {
+            #(#consts)*
+        }
+    }
+}
+
+fn generate_fields_present(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    let vis = ctx.visibility();
+    if !ctx.field_presence() {
+        return quote!();
+    }
+
+    let fields_present = fields_present_type(ctx, def);
+    let words = fields_present_words(def);
+    let doc = format!(
+        "Records which fields of a `{}` were present when it was deserialized.",
+        ctx.type_name(def.type_name().name()),
+    );
+
+    let accessors = def.fields().iter().enumerate().map(|(i, f)| {
+        let name = ctx.field_name(f.field_name());
+        let doc = format!(
+            "Returns `true` if the `{}` field was present.",
+            f.field_name().0
+        );
+        let word = Literal::usize_unsuffixed(i / 64);
+        let bit = Literal::usize_unsuffixed(i % 64);
+
+        quote! {
+            #[doc = #doc]
+            #[inline]
+            #vis fn #name(&self) -> bool {
+                self.0[#word] & (1 << #bit) != 0
+            }
+        }
+    });
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        #vis struct #fields_present([u64; #words]);
+
+        impl #fields_present {
+            #(#accessors)*
+        }
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::context::{self, Context};
+use crate::types::TypeDefinition;
+
+pub fn generate(ctx: &Context, def: &TypeDefinition) -> TokenStream {
+    let type_name = context::definition_name(def);
+    // values borrowing from the deserializer's input can't be converted into types outliving it
+    if ctx.borrows_strings(type_name) {
+        return quote!();
+    }
+
+    let vis = ctx.visibility();
+    let name = ctx.type_name(type_name.name());
+    let result = ctx.result_ident(type_name);
+    let into = ctx.into_ident(type_name);
+
+    quote! {
+        impl #name {
+            /// Serializes a value convertible into this type in this type's wire format.
+            ///
+            /// This is intended for use with serde's `serialize_with` attribute.
+            #vis fn serialize_remote<T, S>(value: &T, s: S) -> #result<S::Ok, S::Error>
+            where
+                for<'a> &'a T: #into<#name>,
+                S: conjure_object::serde::Serializer,
+            {
+                conjure_object::serde::Serialize::serialize(&#into::<#name>::into(value), s)
+            }
+
+            /// Deserializes a value convertible from this type from this type's wire format.
+            ///
+            /// This is intended for use with serde's `deserialize_with` attribute.
+            #vis fn deserialize_remote<'de, T, D>(d: D) -> #result<T, D::Error>
+            where
+                #name: #into<T>,
+                D: conjure_object::serde::Deserializer<'de>,
+            {
+                <#name as conjure_object::serde::Deserialize<'de>>::deserialize(d).map(#into::into)
+            }
+        }
+    }
+}
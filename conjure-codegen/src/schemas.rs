@@ -0,0 +1,206 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::context::Context;
+use crate::types::{
+    AliasDefinition, Documentation, EnumDefinition, ObjectDefinition, PrimitiveType, Type,
+    TypeDefinition, TypeName, UnionDefinition,
+};
+
+pub fn generate(ctx: &Context, def: &TypeDefinition) -> TokenStream {
+    match def {
+        TypeDefinition::Enum(def) => generate_enum(ctx, def),
+        TypeDefinition::Alias(def) => generate_alias(ctx, def),
+        TypeDefinition::Union(def) => generate_union(ctx, def),
+        TypeDefinition::Object(def) => generate_object(ctx, def),
+    }
+}
+
+fn generate_enum(ctx: &Context, def: &EnumDefinition) -> TokenStream {
+    let values = def.values().iter().map(|v| v.value());
+
+    let body = quote! {
+        conjure_ext::private::enum_schema(&[#(#values,)*])
+    };
+
+    generate_impl(ctx, def.type_name(), def.docs(), false, body)
+}
+
+fn generate_alias(ctx: &Context, def: &AliasDefinition) -> TokenStream {
+    let body = schema(ctx, def.alias());
+
+    generate_impl(
+        ctx,
+        def.type_name(),
+        def.docs(),
+        uses_generator(ctx, def.alias()),
+        body,
+    )
+}
+
+fn generate_union(ctx: &Context, def: &UnionDefinition) -> TokenStream {
+    let variants = def.union_().iter().map(|f| {
+        let name = &f.field_name().0;
+        let schema = schema(ctx, f.type_());
+        quote!((#name, #schema))
+    });
+
+    let body = quote! {
+        conjure_ext::private::union_schema(vec![#(#variants,)*])
+    };
+    let uses_generator = def.union_().iter().any(|f| uses_generator(ctx, f.type_()));
+
+    generate_impl(ctx, def.type_name(), def.docs(), uses_generator, body)
+}
+
+fn generate_object(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    // the entries of flattened fields' objects appear directly in the object
+    let entries = def
+        .fields()
+        .iter()
+        .flat_map(|f| {
+            if ctx.is_flattened_field(def.type_name(), f) {
+                let object = ctx.flattened_object(f.type_());
+                object
+                    .fields()
+                    .iter()
+                    .map(|f| (object.type_name(), f))
+                    .collect()
+            } else {
+                vec![(def.type_name(), f)]
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let fields = entries.iter().map(|(type_name, f)| {
+        let name = &f.field_name().0;
+        let mut schema = schema(ctx, f.type_());
+        if ctx.is_write_only_field(type_name, f) {
+            schema = quote!(conjure_ext::private::write_only_schema(#schema));
+        }
+        // fields which serialize as empty may be omitted entirely
+        let required = ctx.is_empty_method(f.type_()).is_none();
+        quote!((#name, #schema, #required))
+    });
+
+    let body = quote! {
+        conjure_ext::private::object_schema(vec![#(#fields,)*])
+    };
+    let uses_generator = entries.iter().any(|(_, f)| uses_generator(ctx, f.type_()));
+
+    generate_impl(ctx, def.type_name(), def.docs(), uses_generator, body)
+}
+
+fn generate_impl(
+    ctx: &Context,
+    type_name: &TypeName,
+    docs: Option<&Documentation>,
+    uses_generator: bool,
+    body: TokenStream,
+) -> TokenStream {
+    let name = ctx.type_name(type_name.name());
+    let string = ctx.string_ident(type_name);
+    let schema_name = type_name.name();
+    let lifetime = if ctx.borrows_strings(type_name) {
+        quote!(<'a>)
+    } else {
+        quote!()
+    };
+    let gen = if uses_generator {
+        quote!(gen)
+    } else {
+        quote!(_)
+    };
+
+    let body = match docs {
+        Some(docs) => {
+            let docs = &**docs;
+            quote!(conjure_ext::private::describe_schema(#body, #docs))
+        }
+        None => body,
+    };
+
+    quote! {
+        impl #lifetime conjure_ext::schemars::JsonSchema for #name #lifetime {
+            fn schema_name() -> #string {
+                #schema_name.to_string()
+            }
+
+            fn json_schema(
+                #gen: &mut conjure_ext::schemars::gen::SchemaGenerator,
+            ) -> conjure_ext::schemars::schema::Schema {
+                #body
+            }
+        }
+    }
+}
+
+// Returns an expression evaluating to the schema of the type. References to other Conjure types go through the
+// generator so that they're emitted as shared definitions.
+fn schema(ctx: &Context, def: &Type) -> TokenStream {
+    match def {
+        Type::Primitive(def) => match *def {
+            PrimitiveType::String => quote!(conjure_ext::schema::string()),
+            PrimitiveType::Datetime => quote!(conjure_ext::schema::date_time()),
+            PrimitiveType::Integer => quote!(conjure_ext::schema::integer()),
+            PrimitiveType::Double => quote!(conjure_ext::schema::double()),
+            PrimitiveType::Safelong => quote!(conjure_ext::schema::safe_long()),
+            PrimitiveType::Binary => quote!(conjure_ext::schema::binary()),
+            PrimitiveType::Any => quote!(conjure_ext::schema::any()),
+            PrimitiveType::Boolean => quote!(conjure_ext::schema::boolean()),
+            PrimitiveType::Uuid => quote!(conjure_ext::schema::uuid()),
+            PrimitiveType::Rid => quote!(conjure_ext::schema::resource_identifier()),
+            PrimitiveType::Bearertoken => quote!(conjure_ext::schema::bearer_token()),
+        },
+        Type::Optional(def) => {
+            let item = schema(ctx, def.item_type());
+            quote!(conjure_ext::schema::optional(#item))
+        }
+        Type::List(def) => {
+            let item = schema(ctx, def.item_type());
+            quote!(conjure_ext::schema::list(#item))
+        }
+        Type::Set(def) => {
+            let item = schema(ctx, def.item_type());
+            quote!(conjure_ext::schema::set(#item))
+        }
+        Type::Map(def) => {
+            let value = schema(ctx, def.value_type());
+            quote!(conjure_ext::schema::map(#value))
+        }
+        Type::Reference(def) => {
+            let type_ = ctx.type_path(def);
+            quote!(gen.subschema_for::<#type_>())
+        }
+        Type::External(def) if ctx.is_custom(def) => {
+            let type_ = ctx.custom_rust_type(def);
+            quote!(gen.subschema_for::<#type_>())
+        }
+        Type::External(def) => schema(ctx, def.fallback()),
+    }
+}
+
+fn uses_generator(ctx: &Context, def: &Type) -> bool {
+    match def {
+        Type::Primitive(_) => false,
+        Type::Optional(def) => uses_generator(ctx, def.item_type()),
+        Type::List(def) => uses_generator(ctx, def.item_type()),
+        Type::Set(def) => uses_generator(ctx, def.item_type()),
+        Type::Map(def) => uses_generator(ctx, def.value_type()),
+        Type::Reference(_) => true,
+        Type::External(def) => ctx.is_custom(def) || uses_generator(ctx, def.fallback()),
+    }
+}
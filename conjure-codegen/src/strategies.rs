@@ -0,0 +1,266 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use failure::{bail, Error};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::iter;
+
+use crate::context::{self, Context};
+use crate::objects;
+use crate::types::{
+    AliasDefinition, ConjureDefinition, EnumDefinition, ObjectDefinition, PrimitiveType, Type,
+    TypeDefinition, TypeName, UnionDefinition,
+};
+use crate::unions;
+
+// proptest implements Strategy for tuples of up to 10 elements, so object fields are grouped into chunks of this size.
+const TUPLE_CHUNK_SIZE: usize = 10;
+
+// Collections are kept small to avoid blowing up the size of nested values.
+const MAX_COLLECTION_SIZE: usize = 4;
+
+pub fn generate(ctx: &Context, defs: &ConjureDefinition) -> Result<TokenStream, Error> {
+    let defs = defs
+        .types()
+        .iter()
+        .filter(|def| !ctx.is_extern(context::definition_name(def)))
+        .collect::<Vec<_>>();
+
+    let strategies = defs
+        .iter()
+        .map(|def| match def {
+            TypeDefinition::Enum(def) => Ok(generate_enum(ctx, def)),
+            TypeDefinition::Alias(def) => Ok(generate_alias(ctx, def)),
+            TypeDefinition::Union(def) => generate_union(ctx, def),
+            TypeDefinition::Object(def) => Ok(generate_object(ctx, def)),
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    Ok(quote! {
+        //! Proptest strategies for the generated types.
+        use conjure_object::proptest::strategy::{Just, Strategy};
+
+        #(#strategies)*
+    })
+}
+
+fn generate_enum(ctx: &Context, def: &EnumDefinition) -> TokenStream {
+    let name = ctx.type_name(def.type_name().name());
+    let variants = def.values().iter().map(|v| ctx.type_name(v.value()));
+    let name_repeat = iter::repeat(&name);
+
+    let body = quote! {
+        conjure_object::proptest::sample::select(vec![#(super::#name_repeat::#variants,)*])
+    };
+
+    generate_fn(ctx, def.type_name(), body)
+}
+
+fn generate_alias(ctx: &Context, def: &AliasDefinition) -> TokenStream {
+    let name = ctx.type_name(def.type_name().name());
+    let inner = strategy(ctx, def.type_name(), def.alias());
+
+    let body = quote! {
+        #inner.prop_map(super::#name)
+    };
+
+    generate_fn(ctx, def.type_name(), body)
+}
+
+fn generate_union(ctx: &Context, def: &UnionDefinition) -> Result<TokenStream, Error> {
+    let name = ctx.type_name(def.type_name().name());
+
+    // Variants which lead back to the union itself are skipped to ensure generated values are finite.
+    let variants = def
+        .union_()
+        .iter()
+        .filter(|f| !ctx.references(f.type_(), def.type_name()))
+        .map(|f| {
+            let variant = unions::variant_path(
+                ctx,
+                def,
+                &quote!(super::#name),
+                &ctx.type_name(f.field_name()),
+            );
+            let strategy = strategy(ctx, def.type_name(), f.type_());
+            let constructor = if ctx.is_boxed(def.type_name(), f.type_()) {
+                let value = ctx.box_value(def.type_name(), f.type_(), quote!(v));
+                quote!(|v| #variant(#value))
+            } else {
+                variant
+            };
+            quote! {
+                #strategy.prop_map(#constructor).boxed()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // proptest's Union panics when it has no options
+    if variants.is_empty() {
+        bail!(
+            "every variant of union {} leads back to it, so no strategy can be generated for it",
+            def.type_name().name()
+        );
+    }
+
+    let body = quote! {
+        conjure_object::proptest::strategy::Union::new(vec![#(#variants,)*])
+    };
+
+    Ok(generate_fn(ctx, def.type_name(), body))
+}
+
+fn generate_object(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    let name = ctx.type_name(def.type_name().name());
+    let builder_method = objects::builder_method(ctx, def);
+    let build_method = objects::build_method(ctx, def);
+
+    if def.fields().is_empty() {
+        let body = quote! {
+            Just(super::#name::#builder_method().#build_method())
+        };
+        return generate_fn(ctx, def.type_name(), body);
+    }
+
+    let mut strategies = vec![];
+    let mut patterns = vec![];
+    for chunk in def.fields().chunks(TUPLE_CHUNK_SIZE) {
+        let chunk_strategies = chunk
+            .iter()
+            .map(|f| strategy(ctx, def.type_name(), f.type_()));
+        let chunk_patterns = chunk.iter().map(|f| ctx.field_name(f.field_name()));
+
+        strategies.push(quote!((#(#chunk_strategies,)*)));
+        patterns.push(quote!((#(#chunk_patterns,)*)));
+    }
+
+    let (strategies, patterns) = if strategies.len() == 1 {
+        (strategies.pop().unwrap(), patterns.pop().unwrap())
+    } else {
+        (quote!((#(#strategies,)*)), quote!((#(#patterns,)*)))
+    };
+
+    let setters = def.fields().iter().map(|f| {
+        let field = ctx.field_name(f.field_name());
+        quote!(.#field(#field))
+    });
+
+    let body = quote! {
+        #strategies.prop_map(|#patterns| {
+            super::#name::#builder_method()
+                #(#setters)*
+                .#build_method()
+        })
+    };
+
+    generate_fn(ctx, def.type_name(), body)
+}
+
+fn generate_fn(ctx: &Context, type_name: &TypeName, body: TokenStream) -> TokenStream {
+    let vis = ctx.visibility();
+    let name = ctx.type_name(type_name.name());
+    let fn_name = ctx.strategy_name(type_name);
+    let docs = format!("Returns a strategy generating `{}` values.", name);
+    // generated values of objects borrowing strings own them
+    let lifetime = if ctx.borrows_strings(type_name) {
+        quote!(<'static>)
+    } else {
+        quote!()
+    };
+
+    quote! {
+        #[doc = #docs]
+        #vis fn #fn_name() -> impl Strategy<Value = super::#name #lifetime> {
+            #body
+        }
+    }
+}
+
+// Returns a strategy generating values of the type's unboxed Rust representation. Optional values and collections
+// which lead back to `this_type` are always empty to ensure generated values are finite.
+fn strategy(ctx: &Context, this_type: &TypeName, def: &Type) -> TokenStream {
+    match def {
+        Type::Primitive(def) => match *def {
+            PrimitiveType::String if ctx.borrows_strings(this_type) => quote! {
+                conjure_object::proptest::arbitrary::any::<String>().prop_map(std::borrow::Cow::Owned)
+            },
+            PrimitiveType::String => quote!(conjure_object::proptest::arbitrary::any::<String>()),
+            PrimitiveType::Datetime => quote!(conjure_object::strategy::date_time()),
+            PrimitiveType::Integer => quote!(conjure_object::proptest::arbitrary::any::<i32>()),
+            PrimitiveType::Double => quote!(conjure_object::strategy::double()),
+            PrimitiveType::Safelong => quote!(conjure_object::strategy::safe_long()),
+            PrimitiveType::Binary => quote!(conjure_object::strategy::byte_buf()),
+            PrimitiveType::Any if ctx.raw_any_fields(this_type) => {
+                quote!(conjure_object::strategy::raw_value())
+            }
+            PrimitiveType::Any => quote!(conjure_object::strategy::value()),
+            PrimitiveType::Boolean => quote!(conjure_object::proptest::arbitrary::any::<bool>()),
+            PrimitiveType::Uuid => quote!(conjure_object::strategy::uuid()),
+            PrimitiveType::Rid => quote!(conjure_object::strategy::resource_identifier()),
+            PrimitiveType::Bearertoken => quote!(conjure_object::strategy::bearer_token()),
+        },
+        Type::Optional(def) => {
+            if ctx.references(def.item_type(), this_type) {
+                let item = ctx.rust_type(this_type, def.item_type());
+                quote!(Just(Option::<#item>::None))
+            } else {
+                let item = strategy(ctx, this_type, def.item_type());
+                quote!(conjure_object::proptest::option::of(#item))
+            }
+        }
+        Type::List(def) => {
+            if ctx.references(def.item_type(), this_type) {
+                quote!(Just(vec![]))
+            } else {
+                let item = strategy(ctx, this_type, def.item_type());
+                quote!(conjure_object::proptest::collection::vec(#item, 0..=#MAX_COLLECTION_SIZE))
+            }
+        }
+        Type::Set(def) => {
+            if ctx.references(def.item_type(), this_type) {
+                quote!(Just(std::collections::BTreeSet::new()))
+            } else {
+                let item = strategy(ctx, this_type, def.item_type());
+                quote! {
+                    conjure_object::proptest::collection::btree_set(#item, 0..=#MAX_COLLECTION_SIZE)
+                }
+            }
+        }
+        Type::Map(def) => {
+            if ctx.references(def.key_type(), this_type)
+                || ctx.references(def.value_type(), this_type)
+            {
+                quote!(Just(std::collections::BTreeMap::new()))
+            } else {
+                let key = strategy(ctx, this_type, def.key_type());
+                let value = strategy(ctx, this_type, def.value_type());
+                quote! {
+                    conjure_object::proptest::collection::btree_map(#key, #value, 0..=#MAX_COLLECTION_SIZE)
+                }
+            }
+        }
+        Type::Reference(def) => {
+            let fn_name = ctx.strategy_name(def);
+            match ctx.extern_path(def) {
+                Some(path) => quote!(#path::strategies::#fn_name()),
+                None => quote!(#fn_name()),
+            }
+        }
+        Type::External(def) if ctx.is_custom(def) => {
+            let type_ = ctx.custom_rust_type(def);
+            quote!(conjure_object::proptest::arbitrary::any::<#type_>())
+        }
+        Type::External(def) => strategy(ctx, this_type, def.fallback()),
+    }
+}
@@ -10,6 +10,10 @@ pub struct ArgumentDefinition {
     markers: Vec<super::Type>,
 }
 impl ArgumentDefinition {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "ArgumentDefinition";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
     #[doc = r" Returns a new builder."]
     #[inline]
     pub fn builder() -> Builder {
@@ -35,6 +39,42 @@ impl ArgumentDefinition {
     pub fn markers(&self) -> &[super::Type] {
         &*self.markers
     }
+    #[doc = "Returns the `arg_name` field, consuming the object."]
+    #[inline]
+    pub fn into_arg_name(self) -> super::ArgumentName {
+        self.arg_name
+    }
+    #[doc = "Returns the `type_` field, consuming the object."]
+    #[inline]
+    pub fn into_type(self) -> super::Type {
+        *self.type_
+    }
+    #[doc = "Returns the `param_type` field, consuming the object."]
+    #[inline]
+    pub fn into_param_type(self) -> super::ParameterType {
+        *self.param_type
+    }
+    #[doc = "Returns the `docs` field, consuming the object."]
+    #[inline]
+    pub fn into_docs(self) -> Option<super::Documentation> {
+        self.docs
+    }
+    #[doc = "Returns the `markers` field, consuming the object."]
+    #[inline]
+    pub fn into_markers(self) -> Vec<super::Type> {
+        self.markers
+    }
+    #[doc = "Returns the `docs` field, or an error if it is absent."]
+    #[inline]
+    pub fn require_docs(&self) -> Result<&super::Documentation, conjure_object::MissingField> {
+        match &self.docs {
+            Some(o) => Ok(&*o),
+            None => Err(conjure_object::MissingField::new(
+                "ArgumentDefinition",
+                "docs",
+            )),
+        }
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -47,9 +87,11 @@ pub struct Builder {
 impl Builder {
     #[doc = r""]
     #[doc = r" Required."]
-    #[inline]
-    pub fn arg_name(&mut self, arg_name: super::ArgumentName) -> &mut Self {
-        self.arg_name = Some(arg_name);
+    pub fn arg_name<T>(&mut self, arg_name: T) -> &mut Self
+    where
+        T: Into<super::ArgumentName>,
+    {
+        self.arg_name = Some(arg_name.into());
         self
     }
     #[doc = r""]
@@ -91,6 +133,29 @@ impl Builder {
         self.markers.push(value);
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.arg_name.is_some() {
+            self.arg_name = other.arg_name;
+        }
+        if other.type_.is_some() {
+            self.type_ = other.type_;
+        }
+        if other.param_type.is_some() {
+            self.param_type = other.param_type;
+        }
+        if !other.docs.is_none() {
+            self.docs = other.docs;
+        }
+        if !other.markers.is_empty() {
+            self.markers = other.markers;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -160,6 +225,16 @@ impl<'de> de::Deserialize<'de> for ArgumentDefinition {
             Visitor_,
         )
     }
+    fn deserialize_in_place<D>(d: D, place: &mut ArgumentDefinition) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct(
+            "ArgumentDefinition",
+            &["argName", "type", "paramType", "docs", "markers"],
+            InPlaceVisitor_(place),
+        )
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -178,11 +253,36 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut markers = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::ArgName => arg_name = Some(map_.next_value()?),
-                Field_::Type => type_ = Some(map_.next_value()?),
-                Field_::ParamType => param_type = Some(map_.next_value()?),
-                Field_::Docs => docs = Some(map_.next_value()?),
-                Field_::Markers => markers = Some(map_.next_value()?),
+                Field_::ArgName => {
+                    if arg_name.is_some() {
+                        return Err(de::Error::duplicate_field("argName"));
+                    }
+                    arg_name = Some(map_.next_value()?);
+                }
+                Field_::Type => {
+                    if type_.is_some() {
+                        return Err(de::Error::duplicate_field("type"));
+                    }
+                    type_ = Some(map_.next_value()?);
+                }
+                Field_::ParamType => {
+                    if param_type.is_some() {
+                        return Err(de::Error::duplicate_field("paramType"));
+                    }
+                    param_type = Some(map_.next_value()?);
+                }
+                Field_::Docs => {
+                    if docs.is_some() {
+                        return Err(de::Error::duplicate_field("docs"));
+                    }
+                    docs = Some(map_.next_value()?);
+                }
+                Field_::Markers => {
+                    if markers.is_some() {
+                        return Err(de::Error::duplicate_field("markers"));
+                    }
+                    markers = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -217,6 +317,84 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut ArgumentDefinition);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut arg_name = false;
+        let mut type_ = false;
+        let mut param_type = false;
+        let mut docs = false;
+        let mut markers = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::ArgName => {
+                    if arg_name {
+                        return Err(de::Error::duplicate_field("argName"));
+                    }
+                    place_.arg_name = map_.next_value()?;
+                    arg_name = true;
+                }
+                Field_::Type => {
+                    if type_ {
+                        return Err(de::Error::duplicate_field("type"));
+                    }
+                    place_.type_ = map_.next_value()?;
+                    type_ = true;
+                }
+                Field_::ParamType => {
+                    if param_type {
+                        return Err(de::Error::duplicate_field("paramType"));
+                    }
+                    place_.param_type = map_.next_value()?;
+                    param_type = true;
+                }
+                Field_::Docs => {
+                    if docs {
+                        return Err(de::Error::duplicate_field("docs"));
+                    }
+                    place_.docs = map_.next_value()?;
+                    docs = true;
+                }
+                Field_::Markers => {
+                    if markers {
+                        return Err(de::Error::duplicate_field("markers"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(
+                        &mut place_.markers,
+                    ))?;
+                    markers = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !arg_name {
+            return Err(de::Error::missing_field("argName"));
+        }
+        if !type_ {
+            return Err(de::Error::missing_field("type"));
+        }
+        if !param_type {
+            return Err(de::Error::missing_field("paramType"));
+        }
+        if !docs {
+            place_.docs = Default::default();
+        }
+        if !markers {
+            place_.markers.clear();
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     ArgName,
     Type,
@@ -254,3 +432,16 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `argName` field."]
+    pub const ARG_NAME: &str = "argName";
+    #[doc = "The wire name of the `type` field."]
+    pub const TYPE_: &str = "type";
+    #[doc = "The wire name of the `paramType` field."]
+    pub const PARAM_TYPE: &str = "paramType";
+    #[doc = "The wire name of the `docs` field."]
+    pub const DOCS: &str = "docs";
+    #[doc = "The wire name of the `markers` field."]
+    pub const MARKERS: &str = "markers";
+}
@@ -2,11 +2,90 @@ use conjure_object::serde::{de, ser};
 #[doc = "Must be in lowerCamelCase. Numbers are permitted, but not at the beginning of a word. Allowed argument names: \"fooBar\", \"build2Request\". Disallowed names: \"FooBar\", \"2BuildRequest\"."]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
 pub struct ArgumentName(pub String);
+impl ArgumentName {
+    #[doc = r" Constructs a new instance of the type."]
+    #[inline]
+    pub fn new(inner: String) -> ArgumentName {
+        ArgumentName(inner)
+    }
+    #[doc = r" Returns the wrapped value."]
+    #[inline]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
 impl std::fmt::Display for ArgumentName {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(&self.0, fmt)
     }
 }
+impl<'a> From<&'a str> for ArgumentName {
+    #[inline]
+    fn from(v: &'a str) -> ArgumentName {
+        ArgumentName(v.to_string())
+    }
+}
+impl From<String> for ArgumentName {
+    #[inline]
+    fn from(v: String) -> ArgumentName {
+        ArgumentName(v)
+    }
+}
+impl std::cmp::PartialEq<str> for ArgumentName {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+impl<'a> std::cmp::PartialEq<&'a str> for ArgumentName {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.0 == *other
+    }
+}
+impl std::cmp::PartialEq<String> for ArgumentName {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        self.0 == *other
+    }
+}
+impl std::cmp::PartialEq<ArgumentName> for str {
+    #[inline]
+    fn eq(&self, other: &ArgumentName) -> bool {
+        *self == other.0
+    }
+}
+impl<'a> std::cmp::PartialEq<ArgumentName> for &'a str {
+    #[inline]
+    fn eq(&self, other: &ArgumentName) -> bool {
+        **self == other.0
+    }
+}
+impl std::cmp::PartialEq<ArgumentName> for String {
+    #[inline]
+    fn eq(&self, other: &ArgumentName) -> bool {
+        *self == other.0
+    }
+}
+impl conjure_object::PlainEncode for ArgumentName {
+    #[inline]
+    fn encode_plain(&self) -> String {
+        conjure_object::PlainEncode::encode_plain(&self.0)
+    }
+}
+impl conjure_object::PlainDecode for ArgumentName {
+    #[inline]
+    fn decode_plain(s: &str) -> Result<ArgumentName, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s).map(ArgumentName)
+    }
+}
+impl std::str::FromStr for ArgumentName {
+    type Err = conjure_object::plain::ParseError;
+    #[inline]
+    fn from_str(s: &str) -> Result<ArgumentName, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s)
+    }
+}
 impl std::ops::Deref for ArgumentName {
     type Target = String;
     #[inline]
@@ -21,6 +100,7 @@ impl std::ops::DerefMut for ArgumentName {
     }
 }
 impl ser::Serialize for ArgumentName {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -29,6 +109,7 @@ impl ser::Serialize for ArgumentName {
     }
 }
 impl<'de> de::Deserialize<'de> for ArgumentName {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<ArgumentName, D::Error>
     where
         D: de::Deserializer<'de>,
@@ -2,11 +2,17 @@ use conjure_object::private::{UnionField_, UnionTypeField_};
 use conjure_object::serde::ser::SerializeMap as SerializeMap_;
 use conjure_object::serde::{de, ser};
 use std::fmt;
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AuthType {
     Header(super::HeaderAuthType),
     Cookie(super::CookieAuthType),
 }
+impl AuthType {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "AuthType";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
+}
 impl ser::Serialize for AuthType {
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
@@ -26,6 +32,29 @@ impl ser::Serialize for AuthType {
         map.end()
     }
 }
+impl std::cmp::PartialOrd for AuthType {
+    #[inline]
+    fn partial_cmp(&self, other: &AuthType) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl std::cmp::Ord for AuthType {
+    fn cmp(&self, other: &AuthType) -> std::cmp::Ordering {
+        match (self, other) {
+            (AuthType::Header(a), AuthType::Header(b)) => a.cmp(b),
+            (AuthType::Cookie(a), AuthType::Cookie(b)) => a.cmp(b),
+            _ => {
+                fn index(v: &AuthType) -> usize {
+                    match v {
+                        AuthType::Header(_) => 0,
+                        AuthType::Cookie(_) => 1,
+                    }
+                }
+                index(self).cmp(&index(other))
+            }
+        }
+    }
+}
 impl<'de> de::Deserialize<'de> for AuthType {
     fn deserialize<D>(d: D) -> Result<AuthType, D::Error>
     where
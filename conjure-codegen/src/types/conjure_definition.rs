@@ -9,6 +9,39 @@ pub struct ConjureDefinition {
     services: Vec<super::ServiceDefinition>,
 }
 impl ConjureDefinition {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "ConjureDefinition";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
+    #[doc = r" Constructs an instance of the type from a tuple of its fields, in declaration order."]
+    #[inline]
+    pub fn from_parts(
+        parts: (
+            i32,
+            Vec<super::ErrorDefinition>,
+            Vec<super::TypeDefinition>,
+            Vec<super::ServiceDefinition>,
+        ),
+    ) -> ConjureDefinition {
+        ConjureDefinition {
+            version: parts.0,
+            errors: parts.1,
+            types: parts.2,
+            services: parts.3,
+        }
+    }
+    #[doc = r" Returns a tuple of the object's fields, in declaration order, consuming the object."]
+    #[inline]
+    pub fn into_parts(
+        self,
+    ) -> (
+        i32,
+        Vec<super::ErrorDefinition>,
+        Vec<super::TypeDefinition>,
+        Vec<super::ServiceDefinition>,
+    ) {
+        (self.version, self.errors, self.types, self.services)
+    }
     #[doc = r" Returns a new builder."]
     #[inline]
     pub fn builder() -> Builder {
@@ -30,6 +63,21 @@ impl ConjureDefinition {
     pub fn services(&self) -> &[super::ServiceDefinition] {
         &*self.services
     }
+    #[doc = "Returns the `errors` field, consuming the object."]
+    #[inline]
+    pub fn into_errors(self) -> Vec<super::ErrorDefinition> {
+        self.errors
+    }
+    #[doc = "Returns the `types` field, consuming the object."]
+    #[inline]
+    pub fn into_types(self) -> Vec<super::TypeDefinition> {
+        self.types
+    }
+    #[doc = "Returns the `services` field, consuming the object."]
+    #[inline]
+    pub fn into_services(self) -> Vec<super::ServiceDefinition> {
+        self.services
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -100,6 +148,26 @@ impl Builder {
         self.services.push(value);
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.version.is_some() {
+            self.version = other.version;
+        }
+        if !other.errors.is_empty() {
+            self.errors = other.errors;
+        }
+        if !other.types.is_empty() {
+            self.types = other.types;
+        }
+        if !other.services.is_empty() {
+            self.services = other.services;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -169,6 +237,16 @@ impl<'de> de::Deserialize<'de> for ConjureDefinition {
             Visitor_,
         )
     }
+    fn deserialize_in_place<D>(d: D, place: &mut ConjureDefinition) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct(
+            "ConjureDefinition",
+            &["version", "errors", "types", "services"],
+            InPlaceVisitor_(place),
+        )
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -186,10 +264,30 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut services = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::Version => version = Some(map_.next_value()?),
-                Field_::Errors => errors = Some(map_.next_value()?),
-                Field_::Types => types = Some(map_.next_value()?),
-                Field_::Services => services = Some(map_.next_value()?),
+                Field_::Version => {
+                    if version.is_some() {
+                        return Err(de::Error::duplicate_field("version"));
+                    }
+                    version = Some(map_.next_value()?);
+                }
+                Field_::Errors => {
+                    if errors.is_some() {
+                        return Err(de::Error::duplicate_field("errors"));
+                    }
+                    errors = Some(map_.next_value()?);
+                }
+                Field_::Types => {
+                    if types.is_some() {
+                        return Err(de::Error::duplicate_field("types"));
+                    }
+                    types = Some(map_.next_value()?);
+                }
+                Field_::Services => {
+                    if services.is_some() {
+                        return Err(de::Error::duplicate_field("services"));
+                    }
+                    services = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -219,6 +317,75 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut ConjureDefinition);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut version = false;
+        let mut errors = false;
+        let mut types = false;
+        let mut services = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::Version => {
+                    if version {
+                        return Err(de::Error::duplicate_field("version"));
+                    }
+                    place_.version = map_.next_value()?;
+                    version = true;
+                }
+                Field_::Errors => {
+                    if errors {
+                        return Err(de::Error::duplicate_field("errors"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(
+                        &mut place_.errors,
+                    ))?;
+                    errors = true;
+                }
+                Field_::Types => {
+                    if types {
+                        return Err(de::Error::duplicate_field("types"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(&mut place_.types))?;
+                    types = true;
+                }
+                Field_::Services => {
+                    if services {
+                        return Err(de::Error::duplicate_field("services"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(
+                        &mut place_.services,
+                    ))?;
+                    services = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !version {
+            return Err(de::Error::missing_field("version"));
+        }
+        if !errors {
+            place_.errors.clear();
+        }
+        if !types {
+            place_.types.clear();
+        }
+        if !services {
+            place_.services.clear();
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     Version,
     Errors,
@@ -254,3 +421,14 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `version` field."]
+    pub const VERSION: &str = "version";
+    #[doc = "The wire name of the `errors` field."]
+    pub const ERRORS: &str = "errors";
+    #[doc = "The wire name of the `types` field."]
+    pub const TYPES: &str = "types";
+    #[doc = "The wire name of the `services` field."]
+    pub const SERVICES: &str = "services";
+}
@@ -6,6 +6,10 @@ pub struct CookieAuthType {
     cookie_name: String,
 }
 impl CookieAuthType {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "CookieAuthType";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
     pub fn new<T>(cookie_name: T) -> CookieAuthType
@@ -25,6 +29,11 @@ impl CookieAuthType {
     pub fn cookie_name(&self) -> &str {
         &*self.cookie_name
     }
+    #[doc = "Returns the `cookie_name` field, consuming the object."]
+    #[inline]
+    pub fn into_cookie_name(self) -> String {
+        self.cookie_name
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -40,6 +49,17 @@ impl Builder {
         self.cookie_name = Some(cookie_name.into());
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.cookie_name.is_some() {
+            self.cookie_name = other.cookie_name;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -64,6 +84,7 @@ impl From<CookieAuthType> for Builder {
     }
 }
 impl ser::Serialize for CookieAuthType {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -75,12 +96,19 @@ impl ser::Serialize for CookieAuthType {
     }
 }
 impl<'de> de::Deserialize<'de> for CookieAuthType {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<CookieAuthType, D::Error>
     where
         D: de::Deserializer<'de>,
     {
         d.deserialize_struct("CookieAuthType", &["cookieName"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut CookieAuthType) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct("CookieAuthType", &["cookieName"], InPlaceVisitor_(place))
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -95,7 +123,12 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut cookie_name = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::CookieName => cookie_name = Some(map_.next_value()?),
+                Field_::CookieName => {
+                    if cookie_name.is_some() {
+                        return Err(de::Error::duplicate_field("cookieName"));
+                    }
+                    cookie_name = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -108,6 +141,40 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(CookieAuthType { cookie_name })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut CookieAuthType);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut cookie_name = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::CookieName => {
+                    if cookie_name {
+                        return Err(de::Error::duplicate_field("cookieName"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(
+                        &mut place_.cookie_name,
+                    ))?;
+                    cookie_name = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !cookie_name {
+            return Err(de::Error::missing_field("cookieName"));
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     CookieName,
     Unknown_,
@@ -137,3 +204,8 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `cookieName` field."]
+    pub const COOKIE_NAME: &str = "cookieName";
+}
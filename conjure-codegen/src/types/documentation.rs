@@ -1,11 +1,90 @@
 use conjure_object::serde::{de, ser};
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
 pub struct Documentation(pub String);
+impl Documentation {
+    #[doc = r" Constructs a new instance of the type."]
+    #[inline]
+    pub fn new(inner: String) -> Documentation {
+        Documentation(inner)
+    }
+    #[doc = r" Returns the wrapped value."]
+    #[inline]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
 impl std::fmt::Display for Documentation {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(&self.0, fmt)
     }
 }
+impl<'a> From<&'a str> for Documentation {
+    #[inline]
+    fn from(v: &'a str) -> Documentation {
+        Documentation(v.to_string())
+    }
+}
+impl From<String> for Documentation {
+    #[inline]
+    fn from(v: String) -> Documentation {
+        Documentation(v)
+    }
+}
+impl std::cmp::PartialEq<str> for Documentation {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+impl<'a> std::cmp::PartialEq<&'a str> for Documentation {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.0 == *other
+    }
+}
+impl std::cmp::PartialEq<String> for Documentation {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        self.0 == *other
+    }
+}
+impl std::cmp::PartialEq<Documentation> for str {
+    #[inline]
+    fn eq(&self, other: &Documentation) -> bool {
+        *self == other.0
+    }
+}
+impl<'a> std::cmp::PartialEq<Documentation> for &'a str {
+    #[inline]
+    fn eq(&self, other: &Documentation) -> bool {
+        **self == other.0
+    }
+}
+impl std::cmp::PartialEq<Documentation> for String {
+    #[inline]
+    fn eq(&self, other: &Documentation) -> bool {
+        *self == other.0
+    }
+}
+impl conjure_object::PlainEncode for Documentation {
+    #[inline]
+    fn encode_plain(&self) -> String {
+        conjure_object::PlainEncode::encode_plain(&self.0)
+    }
+}
+impl conjure_object::PlainDecode for Documentation {
+    #[inline]
+    fn decode_plain(s: &str) -> Result<Documentation, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s).map(Documentation)
+    }
+}
+impl std::str::FromStr for Documentation {
+    type Err = conjure_object::plain::ParseError;
+    #[inline]
+    fn from_str(s: &str) -> Result<Documentation, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s)
+    }
+}
 impl std::ops::Deref for Documentation {
     type Target = String;
     #[inline]
@@ -20,6 +99,7 @@ impl std::ops::DerefMut for Documentation {
     }
 }
 impl ser::Serialize for Documentation {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -28,6 +108,7 @@ impl ser::Serialize for Documentation {
     }
 }
 impl<'de> de::Deserialize<'de> for Documentation {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<Documentation, D::Error>
     where
         D: de::Deserializer<'de>,
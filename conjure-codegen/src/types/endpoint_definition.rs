@@ -14,6 +14,10 @@ pub struct EndpointDefinition {
     markers: Vec<super::Type>,
 }
 impl EndpointDefinition {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "EndpointDefinition";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
     #[doc = r" Returns a new builder."]
     #[inline]
     pub fn builder() -> Builder {
@@ -55,6 +59,92 @@ impl EndpointDefinition {
     pub fn markers(&self) -> &[super::Type] {
         &*self.markers
     }
+    #[doc = "Returns the `endpoint_name` field, consuming the object."]
+    #[inline]
+    pub fn into_endpoint_name(self) -> super::EndpointName {
+        self.endpoint_name
+    }
+    #[doc = "Returns the `http_path` field, consuming the object."]
+    #[inline]
+    pub fn into_http_path(self) -> super::HttpPath {
+        self.http_path
+    }
+    #[doc = "Returns the `auth` field, consuming the object."]
+    #[inline]
+    pub fn into_auth(self) -> Option<Box<super::AuthType>> {
+        self.auth
+    }
+    #[doc = "Returns the `args` field, consuming the object."]
+    #[inline]
+    pub fn into_args(self) -> Vec<super::ArgumentDefinition> {
+        self.args
+    }
+    #[doc = "Returns the `returns` field, consuming the object."]
+    #[inline]
+    pub fn into_returns(self) -> Option<Box<super::Type>> {
+        self.returns
+    }
+    #[doc = "Returns the `docs` field, consuming the object."]
+    #[inline]
+    pub fn into_docs(self) -> Option<super::Documentation> {
+        self.docs
+    }
+    #[doc = "Returns the `deprecated` field, consuming the object."]
+    #[inline]
+    pub fn into_deprecated(self) -> Option<super::Documentation> {
+        self.deprecated
+    }
+    #[doc = "Returns the `markers` field, consuming the object."]
+    #[inline]
+    pub fn into_markers(self) -> Vec<super::Type> {
+        self.markers
+    }
+    #[doc = "Returns the `auth` field, or an error if it is absent."]
+    #[inline]
+    pub fn require_auth(&self) -> Result<&super::AuthType, conjure_object::MissingField> {
+        match &self.auth {
+            Some(o) => Ok(&**o),
+            None => Err(conjure_object::MissingField::new(
+                "EndpointDefinition",
+                "auth",
+            )),
+        }
+    }
+    #[doc = "Returns the `returns` field, or an error if it is absent."]
+    #[inline]
+    pub fn require_returns(&self) -> Result<&super::Type, conjure_object::MissingField> {
+        match &self.returns {
+            Some(o) => Ok(&**o),
+            None => Err(conjure_object::MissingField::new(
+                "EndpointDefinition",
+                "returns",
+            )),
+        }
+    }
+    #[doc = "Returns the `docs` field, or an error if it is absent."]
+    #[inline]
+    pub fn require_docs(&self) -> Result<&super::Documentation, conjure_object::MissingField> {
+        match &self.docs {
+            Some(o) => Ok(&*o),
+            None => Err(conjure_object::MissingField::new(
+                "EndpointDefinition",
+                "docs",
+            )),
+        }
+    }
+    #[doc = "Returns the `deprecated` field, or an error if it is absent."]
+    #[inline]
+    pub fn require_deprecated(
+        &self,
+    ) -> Result<&super::Documentation, conjure_object::MissingField> {
+        match &self.deprecated {
+            Some(o) => Ok(&*o),
+            None => Err(conjure_object::MissingField::new(
+                "EndpointDefinition",
+                "deprecated",
+            )),
+        }
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -71,9 +161,11 @@ pub struct Builder {
 impl Builder {
     #[doc = r""]
     #[doc = r" Required."]
-    #[inline]
-    pub fn endpoint_name(&mut self, endpoint_name: super::EndpointName) -> &mut Self {
-        self.endpoint_name = Some(endpoint_name);
+    pub fn endpoint_name<T>(&mut self, endpoint_name: T) -> &mut Self
+    where
+        T: Into<super::EndpointName>,
+    {
+        self.endpoint_name = Some(endpoint_name.into());
         self
     }
     #[doc = r""]
@@ -85,9 +177,11 @@ impl Builder {
     }
     #[doc = r""]
     #[doc = r" Required."]
-    #[inline]
-    pub fn http_path(&mut self, http_path: super::HttpPath) -> &mut Self {
-        self.http_path = Some(http_path);
+    pub fn http_path<T>(&mut self, http_path: T) -> &mut Self
+    where
+        T: Into<super::HttpPath>,
+    {
+        self.http_path = Some(http_path.into());
         self
     }
     pub fn auth<T>(&mut self, auth: T) -> &mut Self
@@ -154,6 +248,41 @@ impl Builder {
         self.markers.push(value);
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.endpoint_name.is_some() {
+            self.endpoint_name = other.endpoint_name;
+        }
+        if other.http_method.is_some() {
+            self.http_method = other.http_method;
+        }
+        if other.http_path.is_some() {
+            self.http_path = other.http_path;
+        }
+        if !other.auth.is_none() {
+            self.auth = other.auth;
+        }
+        if !other.args.is_empty() {
+            self.args = other.args;
+        }
+        if !other.returns.is_none() {
+            self.returns = other.returns;
+        }
+        if !other.docs.is_none() {
+            self.docs = other.docs;
+        }
+        if !other.deprecated.is_none() {
+            self.deprecated = other.deprecated;
+        }
+        if !other.markers.is_empty() {
+            self.markers = other.markers;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -272,6 +401,26 @@ impl<'de> de::Deserialize<'de> for EndpointDefinition {
             Visitor_,
         )
     }
+    fn deserialize_in_place<D>(d: D, place: &mut EndpointDefinition) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct(
+            "EndpointDefinition",
+            &[
+                "endpointName",
+                "httpMethod",
+                "httpPath",
+                "auth",
+                "args",
+                "returns",
+                "docs",
+                "deprecated",
+                "markers",
+            ],
+            InPlaceVisitor_(place),
+        )
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -294,15 +443,60 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut markers = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::EndpointName => endpoint_name = Some(map_.next_value()?),
-                Field_::HttpMethod => http_method = Some(map_.next_value()?),
-                Field_::HttpPath => http_path = Some(map_.next_value()?),
-                Field_::Auth => auth = Some(map_.next_value()?),
-                Field_::Args => args = Some(map_.next_value()?),
-                Field_::Returns => returns = Some(map_.next_value()?),
-                Field_::Docs => docs = Some(map_.next_value()?),
-                Field_::Deprecated => deprecated = Some(map_.next_value()?),
-                Field_::Markers => markers = Some(map_.next_value()?),
+                Field_::EndpointName => {
+                    if endpoint_name.is_some() {
+                        return Err(de::Error::duplicate_field("endpointName"));
+                    }
+                    endpoint_name = Some(map_.next_value()?);
+                }
+                Field_::HttpMethod => {
+                    if http_method.is_some() {
+                        return Err(de::Error::duplicate_field("httpMethod"));
+                    }
+                    http_method = Some(map_.next_value()?);
+                }
+                Field_::HttpPath => {
+                    if http_path.is_some() {
+                        return Err(de::Error::duplicate_field("httpPath"));
+                    }
+                    http_path = Some(map_.next_value()?);
+                }
+                Field_::Auth => {
+                    if auth.is_some() {
+                        return Err(de::Error::duplicate_field("auth"));
+                    }
+                    auth = Some(map_.next_value()?);
+                }
+                Field_::Args => {
+                    if args.is_some() {
+                        return Err(de::Error::duplicate_field("args"));
+                    }
+                    args = Some(map_.next_value()?);
+                }
+                Field_::Returns => {
+                    if returns.is_some() {
+                        return Err(de::Error::duplicate_field("returns"));
+                    }
+                    returns = Some(map_.next_value()?);
+                }
+                Field_::Docs => {
+                    if docs.is_some() {
+                        return Err(de::Error::duplicate_field("docs"));
+                    }
+                    docs = Some(map_.next_value()?);
+                }
+                Field_::Deprecated => {
+                    if deprecated.is_some() {
+                        return Err(de::Error::duplicate_field("deprecated"));
+                    }
+                    deprecated = Some(map_.next_value()?);
+                }
+                Field_::Markers => {
+                    if markers.is_some() {
+                        return Err(de::Error::duplicate_field("markers"));
+                    }
+                    markers = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -357,6 +551,128 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut EndpointDefinition);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut endpoint_name = false;
+        let mut http_method = false;
+        let mut http_path = false;
+        let mut auth = false;
+        let mut args = false;
+        let mut returns = false;
+        let mut docs = false;
+        let mut deprecated = false;
+        let mut markers = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::EndpointName => {
+                    if endpoint_name {
+                        return Err(de::Error::duplicate_field("endpointName"));
+                    }
+                    place_.endpoint_name = map_.next_value()?;
+                    endpoint_name = true;
+                }
+                Field_::HttpMethod => {
+                    if http_method {
+                        return Err(de::Error::duplicate_field("httpMethod"));
+                    }
+                    place_.http_method = map_.next_value()?;
+                    http_method = true;
+                }
+                Field_::HttpPath => {
+                    if http_path {
+                        return Err(de::Error::duplicate_field("httpPath"));
+                    }
+                    place_.http_path = map_.next_value()?;
+                    http_path = true;
+                }
+                Field_::Auth => {
+                    if auth {
+                        return Err(de::Error::duplicate_field("auth"));
+                    }
+                    place_.auth = map_.next_value()?;
+                    auth = true;
+                }
+                Field_::Args => {
+                    if args {
+                        return Err(de::Error::duplicate_field("args"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(&mut place_.args))?;
+                    args = true;
+                }
+                Field_::Returns => {
+                    if returns {
+                        return Err(de::Error::duplicate_field("returns"));
+                    }
+                    place_.returns = map_.next_value()?;
+                    returns = true;
+                }
+                Field_::Docs => {
+                    if docs {
+                        return Err(de::Error::duplicate_field("docs"));
+                    }
+                    place_.docs = map_.next_value()?;
+                    docs = true;
+                }
+                Field_::Deprecated => {
+                    if deprecated {
+                        return Err(de::Error::duplicate_field("deprecated"));
+                    }
+                    place_.deprecated = map_.next_value()?;
+                    deprecated = true;
+                }
+                Field_::Markers => {
+                    if markers {
+                        return Err(de::Error::duplicate_field("markers"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(
+                        &mut place_.markers,
+                    ))?;
+                    markers = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !endpoint_name {
+            return Err(de::Error::missing_field("endpointName"));
+        }
+        if !http_method {
+            return Err(de::Error::missing_field("httpMethod"));
+        }
+        if !http_path {
+            return Err(de::Error::missing_field("httpPath"));
+        }
+        if !auth {
+            place_.auth = Default::default();
+        }
+        if !args {
+            place_.args.clear();
+        }
+        if !returns {
+            place_.returns = Default::default();
+        }
+        if !docs {
+            place_.docs = Default::default();
+        }
+        if !deprecated {
+            place_.deprecated = Default::default();
+        }
+        if !markers {
+            place_.markers.clear();
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     EndpointName,
     HttpMethod,
@@ -402,3 +718,24 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `endpointName` field."]
+    pub const ENDPOINT_NAME: &str = "endpointName";
+    #[doc = "The wire name of the `httpMethod` field."]
+    pub const HTTP_METHOD: &str = "httpMethod";
+    #[doc = "The wire name of the `httpPath` field."]
+    pub const HTTP_PATH: &str = "httpPath";
+    #[doc = "The wire name of the `auth` field."]
+    pub const AUTH: &str = "auth";
+    #[doc = "The wire name of the `args` field."]
+    pub const ARGS: &str = "args";
+    #[doc = "The wire name of the `returns` field."]
+    pub const RETURNS: &str = "returns";
+    #[doc = "The wire name of the `docs` field."]
+    pub const DOCS: &str = "docs";
+    #[doc = "The wire name of the `deprecated` field."]
+    pub const DEPRECATED: &str = "deprecated";
+    #[doc = "The wire name of the `markers` field."]
+    pub const MARKERS: &str = "markers";
+}
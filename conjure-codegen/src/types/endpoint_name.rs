@@ -2,11 +2,90 @@ use conjure_object::serde::{de, ser};
 #[doc = "Should be in lowerCamelCase."]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
 pub struct EndpointName(pub String);
+impl EndpointName {
+    #[doc = r" Constructs a new instance of the type."]
+    #[inline]
+    pub fn new(inner: String) -> EndpointName {
+        EndpointName(inner)
+    }
+    #[doc = r" Returns the wrapped value."]
+    #[inline]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
 impl std::fmt::Display for EndpointName {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(&self.0, fmt)
     }
 }
+impl<'a> From<&'a str> for EndpointName {
+    #[inline]
+    fn from(v: &'a str) -> EndpointName {
+        EndpointName(v.to_string())
+    }
+}
+impl From<String> for EndpointName {
+    #[inline]
+    fn from(v: String) -> EndpointName {
+        EndpointName(v)
+    }
+}
+impl std::cmp::PartialEq<str> for EndpointName {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+impl<'a> std::cmp::PartialEq<&'a str> for EndpointName {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.0 == *other
+    }
+}
+impl std::cmp::PartialEq<String> for EndpointName {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        self.0 == *other
+    }
+}
+impl std::cmp::PartialEq<EndpointName> for str {
+    #[inline]
+    fn eq(&self, other: &EndpointName) -> bool {
+        *self == other.0
+    }
+}
+impl<'a> std::cmp::PartialEq<EndpointName> for &'a str {
+    #[inline]
+    fn eq(&self, other: &EndpointName) -> bool {
+        **self == other.0
+    }
+}
+impl std::cmp::PartialEq<EndpointName> for String {
+    #[inline]
+    fn eq(&self, other: &EndpointName) -> bool {
+        *self == other.0
+    }
+}
+impl conjure_object::PlainEncode for EndpointName {
+    #[inline]
+    fn encode_plain(&self) -> String {
+        conjure_object::PlainEncode::encode_plain(&self.0)
+    }
+}
+impl conjure_object::PlainDecode for EndpointName {
+    #[inline]
+    fn decode_plain(s: &str) -> Result<EndpointName, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s).map(EndpointName)
+    }
+}
+impl std::str::FromStr for EndpointName {
+    type Err = conjure_object::plain::ParseError;
+    #[inline]
+    fn from_str(s: &str) -> Result<EndpointName, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s)
+    }
+}
 impl std::ops::Deref for EndpointName {
     type Target = String;
     #[inline]
@@ -21,6 +100,7 @@ impl std::ops::DerefMut for EndpointName {
     }
 }
 impl ser::Serialize for EndpointName {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -29,6 +109,7 @@ impl ser::Serialize for EndpointName {
     }
 }
 impl<'de> de::Deserialize<'de> for EndpointName {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<EndpointName, D::Error>
     where
         D: de::Deserializer<'de>,
@@ -7,17 +7,35 @@ pub struct EnumValueDefinition {
     docs: Option<super::Documentation>,
 }
 impl EnumValueDefinition {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "EnumValueDefinition";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
-    pub fn new<T>(value: T, docs: super::Documentation) -> EnumValueDefinition
+    pub fn new<T, U>(value: T, docs: U) -> EnumValueDefinition
     where
         T: Into<String>,
+        U: Into<super::Documentation>,
     {
         EnumValueDefinition {
             value: value.into(),
-            docs: Some(docs),
+            docs: Some(docs.into()),
         }
     }
+    #[doc = r" Constructs an instance of the type from a tuple of its fields, in declaration order."]
+    #[inline]
+    pub fn from_parts(parts: (String, Option<super::Documentation>)) -> EnumValueDefinition {
+        EnumValueDefinition {
+            value: parts.0,
+            docs: parts.1,
+        }
+    }
+    #[doc = r" Returns a tuple of the object's fields, in declaration order, consuming the object."]
+    #[inline]
+    pub fn into_parts(self) -> (String, Option<super::Documentation>) {
+        (self.value, self.docs)
+    }
     #[doc = r" Returns a new builder."]
     #[inline]
     pub fn builder() -> Builder {
@@ -31,6 +49,27 @@ impl EnumValueDefinition {
     pub fn docs(&self) -> Option<&super::Documentation> {
         self.docs.as_ref().map(|o| &*o)
     }
+    #[doc = "Returns the `value` field, consuming the object."]
+    #[inline]
+    pub fn into_value(self) -> String {
+        self.value
+    }
+    #[doc = "Returns the `docs` field, consuming the object."]
+    #[inline]
+    pub fn into_docs(self) -> Option<super::Documentation> {
+        self.docs
+    }
+    #[doc = "Returns the `docs` field, or an error if it is absent."]
+    #[inline]
+    pub fn require_docs(&self) -> Result<&super::Documentation, conjure_object::MissingField> {
+        match &self.docs {
+            Some(o) => Ok(&*o),
+            None => Err(conjure_object::MissingField::new(
+                "EnumValueDefinition",
+                "docs",
+            )),
+        }
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -54,6 +93,20 @@ impl Builder {
         self.docs = docs.into();
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.value.is_some() {
+            self.value = other.value;
+        }
+        if !other.docs.is_none() {
+            self.docs = other.docs;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -101,6 +154,16 @@ impl<'de> de::Deserialize<'de> for EnumValueDefinition {
     {
         d.deserialize_struct("EnumValueDefinition", &["value", "docs"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut EnumValueDefinition) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct(
+            "EnumValueDefinition",
+            &["value", "docs"],
+            InPlaceVisitor_(place),
+        )
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -116,8 +179,18 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut docs = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::Value => value = Some(map_.next_value()?),
-                Field_::Docs => docs = Some(map_.next_value()?),
+                Field_::Value => {
+                    if value.is_some() {
+                        return Err(de::Error::duplicate_field("value"));
+                    }
+                    value = Some(map_.next_value()?);
+                }
+                Field_::Docs => {
+                    if docs.is_some() {
+                        return Err(de::Error::duplicate_field("docs"));
+                    }
+                    docs = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -134,6 +207,49 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(EnumValueDefinition { value, docs })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut EnumValueDefinition);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut value = false;
+        let mut docs = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::Value => {
+                    if value {
+                        return Err(de::Error::duplicate_field("value"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(&mut place_.value))?;
+                    value = true;
+                }
+                Field_::Docs => {
+                    if docs {
+                        return Err(de::Error::duplicate_field("docs"));
+                    }
+                    place_.docs = map_.next_value()?;
+                    docs = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !value {
+            return Err(de::Error::missing_field("value"));
+        }
+        if !docs {
+            place_.docs = Default::default();
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     Value,
     Docs,
@@ -165,3 +281,10 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `value` field."]
+    pub const VALUE: &str = "value";
+    #[doc = "The wire name of the `docs` field."]
+    pub const DOCS: &str = "docs";
+}
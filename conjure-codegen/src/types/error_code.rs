@@ -1,6 +1,6 @@
 use conjure_object::serde::{de, ser};
 use std::fmt;
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ErrorCode {
     PermissionDenied,
     InvalidArgument,
@@ -14,6 +14,10 @@ pub enum ErrorCode {
     CustomServer,
 }
 impl ErrorCode {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "ErrorCode";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
     #[doc = r" Returns the string representation of the enum."]
     #[inline]
     pub fn as_str(&self) -> &str {
@@ -30,6 +34,31 @@ impl ErrorCode {
             ErrorCode::CustomServer => "CUSTOM_SERVER",
         }
     }
+    #[doc = r" Returns a stable numeric identifier of the enum."]
+    #[doc = r""]
+    #[doc = r" The ordinal is a hash of the string representation rather than the position of the variant, so it is"]
+    #[doc = r" unaffected by reordering the enum's definition. Distinct variants are not guaranteed to have distinct"]
+    #[doc = r" ordinals."]
+    #[inline]
+    pub fn ordinal(&self) -> u32 {
+        match self {
+            ErrorCode::PermissionDenied => 1805790060,
+            ErrorCode::InvalidArgument => 508934190,
+            ErrorCode::NotFound => 1200116369,
+            ErrorCode::Conflict => 2850791503,
+            ErrorCode::RequestEntityTooLarge => 3581846781,
+            ErrorCode::FailedPrecondition => 4149796007,
+            ErrorCode::Internal => 3322539072,
+            ErrorCode::Timeout => 1629691752,
+            ErrorCode::CustomClient => 4238396340,
+            ErrorCode::CustomServer => 1309736384,
+        }
+    }
+    #[doc = r" Compares the enum to another by their string representations rather than by declaration order."]
+    #[inline]
+    pub fn cmp_by_name(&self, other: &ErrorCode) -> std::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
 }
 impl fmt::Display for ErrorCode {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
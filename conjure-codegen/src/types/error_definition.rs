@@ -11,6 +11,10 @@ pub struct ErrorDefinition {
     unsafe_args: Vec<super::FieldDefinition>,
 }
 impl ErrorDefinition {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "ErrorDefinition";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
     #[doc = r" Returns a new builder."]
     #[inline]
     pub fn builder() -> Builder {
@@ -40,6 +44,39 @@ impl ErrorDefinition {
     pub fn unsafe_args(&self) -> &[super::FieldDefinition] {
         &*self.unsafe_args
     }
+    #[doc = "Returns the `error_name` field, consuming the object."]
+    #[inline]
+    pub fn into_error_name(self) -> super::TypeName {
+        *self.error_name
+    }
+    #[doc = "Returns the `docs` field, consuming the object."]
+    #[inline]
+    pub fn into_docs(self) -> Option<super::Documentation> {
+        self.docs
+    }
+    #[doc = "Returns the `namespace` field, consuming the object."]
+    #[inline]
+    pub fn into_namespace(self) -> super::ErrorNamespace {
+        self.namespace
+    }
+    #[doc = "Returns the `safe_args` field, consuming the object."]
+    #[inline]
+    pub fn into_safe_args(self) -> Vec<super::FieldDefinition> {
+        self.safe_args
+    }
+    #[doc = "Returns the `unsafe_args` field, consuming the object."]
+    #[inline]
+    pub fn into_unsafe_args(self) -> Vec<super::FieldDefinition> {
+        self.unsafe_args
+    }
+    #[doc = "Returns the `docs` field, or an error if it is absent."]
+    #[inline]
+    pub fn require_docs(&self) -> Result<&super::Documentation, conjure_object::MissingField> {
+        match &self.docs {
+            Some(o) => Ok(&*o),
+            None => Err(conjure_object::MissingField::new("ErrorDefinition", "docs")),
+        }
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -67,9 +104,11 @@ impl Builder {
     }
     #[doc = r""]
     #[doc = r" Required."]
-    #[inline]
-    pub fn namespace(&mut self, namespace: super::ErrorNamespace) -> &mut Self {
-        self.namespace = Some(namespace);
+    pub fn namespace<T>(&mut self, namespace: T) -> &mut Self
+    where
+        T: Into<super::ErrorNamespace>,
+    {
+        self.namespace = Some(namespace.into());
         self
     }
     #[doc = r""]
@@ -115,6 +154,32 @@ impl Builder {
         self.unsafe_args.push(value);
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.error_name.is_some() {
+            self.error_name = other.error_name;
+        }
+        if !other.docs.is_none() {
+            self.docs = other.docs;
+        }
+        if other.namespace.is_some() {
+            self.namespace = other.namespace;
+        }
+        if other.code.is_some() {
+            self.code = other.code;
+        }
+        if !other.safe_args.is_empty() {
+            self.safe_args = other.safe_args;
+        }
+        if !other.unsafe_args.is_empty() {
+            self.unsafe_args = other.unsafe_args;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -200,6 +265,23 @@ impl<'de> de::Deserialize<'de> for ErrorDefinition {
             Visitor_,
         )
     }
+    fn deserialize_in_place<D>(d: D, place: &mut ErrorDefinition) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct(
+            "ErrorDefinition",
+            &[
+                "errorName",
+                "docs",
+                "namespace",
+                "code",
+                "safeArgs",
+                "unsafeArgs",
+            ],
+            InPlaceVisitor_(place),
+        )
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -219,12 +301,42 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut unsafe_args = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::ErrorName => error_name = Some(map_.next_value()?),
-                Field_::Docs => docs = Some(map_.next_value()?),
-                Field_::Namespace => namespace = Some(map_.next_value()?),
-                Field_::Code => code = Some(map_.next_value()?),
-                Field_::SafeArgs => safe_args = Some(map_.next_value()?),
-                Field_::UnsafeArgs => unsafe_args = Some(map_.next_value()?),
+                Field_::ErrorName => {
+                    if error_name.is_some() {
+                        return Err(de::Error::duplicate_field("errorName"));
+                    }
+                    error_name = Some(map_.next_value()?);
+                }
+                Field_::Docs => {
+                    if docs.is_some() {
+                        return Err(de::Error::duplicate_field("docs"));
+                    }
+                    docs = Some(map_.next_value()?);
+                }
+                Field_::Namespace => {
+                    if namespace.is_some() {
+                        return Err(de::Error::duplicate_field("namespace"));
+                    }
+                    namespace = Some(map_.next_value()?);
+                }
+                Field_::Code => {
+                    if code.is_some() {
+                        return Err(de::Error::duplicate_field("code"));
+                    }
+                    code = Some(map_.next_value()?);
+                }
+                Field_::SafeArgs => {
+                    if safe_args.is_some() {
+                        return Err(de::Error::duplicate_field("safeArgs"));
+                    }
+                    safe_args = Some(map_.next_value()?);
+                }
+                Field_::UnsafeArgs => {
+                    if unsafe_args.is_some() {
+                        return Err(de::Error::duplicate_field("unsafeArgs"));
+                    }
+                    unsafe_args = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -264,6 +376,97 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut ErrorDefinition);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut error_name = false;
+        let mut docs = false;
+        let mut namespace = false;
+        let mut code = false;
+        let mut safe_args = false;
+        let mut unsafe_args = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::ErrorName => {
+                    if error_name {
+                        return Err(de::Error::duplicate_field("errorName"));
+                    }
+                    place_.error_name = map_.next_value()?;
+                    error_name = true;
+                }
+                Field_::Docs => {
+                    if docs {
+                        return Err(de::Error::duplicate_field("docs"));
+                    }
+                    place_.docs = map_.next_value()?;
+                    docs = true;
+                }
+                Field_::Namespace => {
+                    if namespace {
+                        return Err(de::Error::duplicate_field("namespace"));
+                    }
+                    place_.namespace = map_.next_value()?;
+                    namespace = true;
+                }
+                Field_::Code => {
+                    if code {
+                        return Err(de::Error::duplicate_field("code"));
+                    }
+                    place_.code = map_.next_value()?;
+                    code = true;
+                }
+                Field_::SafeArgs => {
+                    if safe_args {
+                        return Err(de::Error::duplicate_field("safeArgs"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(
+                        &mut place_.safe_args,
+                    ))?;
+                    safe_args = true;
+                }
+                Field_::UnsafeArgs => {
+                    if unsafe_args {
+                        return Err(de::Error::duplicate_field("unsafeArgs"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(
+                        &mut place_.unsafe_args,
+                    ))?;
+                    unsafe_args = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !error_name {
+            return Err(de::Error::missing_field("errorName"));
+        }
+        if !docs {
+            place_.docs = Default::default();
+        }
+        if !namespace {
+            return Err(de::Error::missing_field("namespace"));
+        }
+        if !code {
+            return Err(de::Error::missing_field("code"));
+        }
+        if !safe_args {
+            place_.safe_args.clear();
+        }
+        if !unsafe_args {
+            place_.unsafe_args.clear();
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     ErrorName,
     Docs,
@@ -303,3 +506,18 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `errorName` field."]
+    pub const ERROR_NAME: &str = "errorName";
+    #[doc = "The wire name of the `docs` field."]
+    pub const DOCS: &str = "docs";
+    #[doc = "The wire name of the `namespace` field."]
+    pub const NAMESPACE: &str = "namespace";
+    #[doc = "The wire name of the `code` field."]
+    pub const CODE: &str = "code";
+    #[doc = "The wire name of the `safeArgs` field."]
+    pub const SAFE_ARGS: &str = "safeArgs";
+    #[doc = "The wire name of the `unsafeArgs` field."]
+    pub const UNSAFE_ARGS: &str = "unsafeArgs";
+}
@@ -1,11 +1,90 @@
 use conjure_object::serde::{de, ser};
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
 pub struct ErrorNamespace(pub String);
+impl ErrorNamespace {
+    #[doc = r" Constructs a new instance of the type."]
+    #[inline]
+    pub fn new(inner: String) -> ErrorNamespace {
+        ErrorNamespace(inner)
+    }
+    #[doc = r" Returns the wrapped value."]
+    #[inline]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
 impl std::fmt::Display for ErrorNamespace {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(&self.0, fmt)
     }
 }
+impl<'a> From<&'a str> for ErrorNamespace {
+    #[inline]
+    fn from(v: &'a str) -> ErrorNamespace {
+        ErrorNamespace(v.to_string())
+    }
+}
+impl From<String> for ErrorNamespace {
+    #[inline]
+    fn from(v: String) -> ErrorNamespace {
+        ErrorNamespace(v)
+    }
+}
+impl std::cmp::PartialEq<str> for ErrorNamespace {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+impl<'a> std::cmp::PartialEq<&'a str> for ErrorNamespace {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.0 == *other
+    }
+}
+impl std::cmp::PartialEq<String> for ErrorNamespace {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        self.0 == *other
+    }
+}
+impl std::cmp::PartialEq<ErrorNamespace> for str {
+    #[inline]
+    fn eq(&self, other: &ErrorNamespace) -> bool {
+        *self == other.0
+    }
+}
+impl<'a> std::cmp::PartialEq<ErrorNamespace> for &'a str {
+    #[inline]
+    fn eq(&self, other: &ErrorNamespace) -> bool {
+        **self == other.0
+    }
+}
+impl std::cmp::PartialEq<ErrorNamespace> for String {
+    #[inline]
+    fn eq(&self, other: &ErrorNamespace) -> bool {
+        *self == other.0
+    }
+}
+impl conjure_object::PlainEncode for ErrorNamespace {
+    #[inline]
+    fn encode_plain(&self) -> String {
+        conjure_object::PlainEncode::encode_plain(&self.0)
+    }
+}
+impl conjure_object::PlainDecode for ErrorNamespace {
+    #[inline]
+    fn decode_plain(s: &str) -> Result<ErrorNamespace, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s).map(ErrorNamespace)
+    }
+}
+impl std::str::FromStr for ErrorNamespace {
+    type Err = conjure_object::plain::ParseError;
+    #[inline]
+    fn from_str(s: &str) -> Result<ErrorNamespace, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s)
+    }
+}
 impl std::ops::Deref for ErrorNamespace {
     type Target = String;
     #[inline]
@@ -20,6 +99,7 @@ impl std::ops::DerefMut for ErrorNamespace {
     }
 }
 impl ser::Serialize for ErrorNamespace {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -28,6 +108,7 @@ impl ser::Serialize for ErrorNamespace {
     }
 }
 impl<'de> de::Deserialize<'de> for ErrorNamespace {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<ErrorNamespace, D::Error>
     where
         D: de::Deserializer<'de>,
@@ -7,6 +7,10 @@ pub struct ExternalReference {
     fallback: Box<super::Type>,
 }
 impl ExternalReference {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "ExternalReference";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
     pub fn new(external_reference: super::TypeName, fallback: super::Type) -> ExternalReference {
@@ -15,6 +19,19 @@ impl ExternalReference {
             fallback: Box::new(fallback),
         }
     }
+    #[doc = r" Constructs an instance of the type from a tuple of its fields, in declaration order."]
+    #[inline]
+    pub fn from_parts(parts: (super::TypeName, super::Type)) -> ExternalReference {
+        ExternalReference {
+            external_reference: Box::new(parts.0),
+            fallback: Box::new(parts.1),
+        }
+    }
+    #[doc = r" Returns a tuple of the object's fields, in declaration order, consuming the object."]
+    #[inline]
+    pub fn into_parts(self) -> (super::TypeName, super::Type) {
+        (*self.external_reference, *self.fallback)
+    }
     #[doc = r" Returns a new builder."]
     #[inline]
     pub fn builder() -> Builder {
@@ -30,6 +47,16 @@ impl ExternalReference {
     pub fn fallback(&self) -> &super::Type {
         &*self.fallback
     }
+    #[doc = "Returns the `external_reference` field, consuming the object."]
+    #[inline]
+    pub fn into_external_reference(self) -> super::TypeName {
+        *self.external_reference
+    }
+    #[doc = "Returns the `fallback` field, consuming the object."]
+    #[inline]
+    pub fn into_fallback(self) -> super::Type {
+        *self.fallback
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -53,6 +80,20 @@ impl Builder {
         self.fallback = Some(Box::new(fallback));
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.external_reference.is_some() {
+            self.external_reference = other.external_reference;
+        }
+        if other.fallback.is_some() {
+            self.fallback = other.fallback;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -101,6 +142,16 @@ impl<'de> de::Deserialize<'de> for ExternalReference {
             Visitor_,
         )
     }
+    fn deserialize_in_place<D>(d: D, place: &mut ExternalReference) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct(
+            "ExternalReference",
+            &["externalReference", "fallback"],
+            InPlaceVisitor_(place),
+        )
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -116,8 +167,18 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut fallback = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::ExternalReference => external_reference = Some(map_.next_value()?),
-                Field_::Fallback => fallback = Some(map_.next_value()?),
+                Field_::ExternalReference => {
+                    if external_reference.is_some() {
+                        return Err(de::Error::duplicate_field("externalReference"));
+                    }
+                    external_reference = Some(map_.next_value()?);
+                }
+                Field_::Fallback => {
+                    if fallback.is_some() {
+                        return Err(de::Error::duplicate_field("fallback"));
+                    }
+                    fallback = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -137,6 +198,49 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut ExternalReference);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut external_reference = false;
+        let mut fallback = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::ExternalReference => {
+                    if external_reference {
+                        return Err(de::Error::duplicate_field("externalReference"));
+                    }
+                    place_.external_reference = map_.next_value()?;
+                    external_reference = true;
+                }
+                Field_::Fallback => {
+                    if fallback {
+                        return Err(de::Error::duplicate_field("fallback"));
+                    }
+                    place_.fallback = map_.next_value()?;
+                    fallback = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !external_reference {
+            return Err(de::Error::missing_field("externalReference"));
+        }
+        if !fallback {
+            return Err(de::Error::missing_field("fallback"));
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     ExternalReference,
     Fallback,
@@ -168,3 +272,10 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `externalReference` field."]
+    pub const EXTERNAL_REFERENCE: &str = "externalReference";
+    #[doc = "The wire name of the `fallback` field."]
+    pub const FALLBACK: &str = "fallback";
+}
@@ -8,19 +8,39 @@ pub struct FieldDefinition {
     docs: Option<super::Documentation>,
 }
 impl FieldDefinition {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "FieldDefinition";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
-    pub fn new(
-        field_name: super::FieldName,
-        type_: super::Type,
-        docs: super::Documentation,
-    ) -> FieldDefinition {
+    pub fn new<T, U>(field_name: T, type_: super::Type, docs: U) -> FieldDefinition
+    where
+        T: Into<super::FieldName>,
+        U: Into<super::Documentation>,
+    {
         FieldDefinition {
-            field_name: field_name,
+            field_name: field_name.into(),
             type_: Box::new(type_),
-            docs: Some(docs),
+            docs: Some(docs.into()),
+        }
+    }
+    #[doc = r" Constructs an instance of the type from a tuple of its fields, in declaration order."]
+    #[inline]
+    pub fn from_parts(
+        parts: (super::FieldName, super::Type, Option<super::Documentation>),
+    ) -> FieldDefinition {
+        FieldDefinition {
+            field_name: parts.0,
+            type_: Box::new(parts.1),
+            docs: parts.2,
         }
     }
+    #[doc = r" Returns a tuple of the object's fields, in declaration order, consuming the object."]
+    #[inline]
+    pub fn into_parts(self) -> (super::FieldName, super::Type, Option<super::Documentation>) {
+        (self.field_name, *self.type_, self.docs)
+    }
     #[doc = r" Returns a new builder."]
     #[inline]
     pub fn builder() -> Builder {
@@ -38,6 +58,29 @@ impl FieldDefinition {
     pub fn docs(&self) -> Option<&super::Documentation> {
         self.docs.as_ref().map(|o| &*o)
     }
+    #[doc = "Returns the `field_name` field, consuming the object."]
+    #[inline]
+    pub fn into_field_name(self) -> super::FieldName {
+        self.field_name
+    }
+    #[doc = "Returns the `type_` field, consuming the object."]
+    #[inline]
+    pub fn into_type(self) -> super::Type {
+        *self.type_
+    }
+    #[doc = "Returns the `docs` field, consuming the object."]
+    #[inline]
+    pub fn into_docs(self) -> Option<super::Documentation> {
+        self.docs
+    }
+    #[doc = "Returns the `docs` field, or an error if it is absent."]
+    #[inline]
+    pub fn require_docs(&self) -> Result<&super::Documentation, conjure_object::MissingField> {
+        match &self.docs {
+            Some(o) => Ok(&*o),
+            None => Err(conjure_object::MissingField::new("FieldDefinition", "docs")),
+        }
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -48,9 +91,11 @@ pub struct Builder {
 impl Builder {
     #[doc = r""]
     #[doc = r" Required."]
-    #[inline]
-    pub fn field_name(&mut self, field_name: super::FieldName) -> &mut Self {
-        self.field_name = Some(field_name);
+    pub fn field_name<T>(&mut self, field_name: T) -> &mut Self
+    where
+        T: Into<super::FieldName>,
+    {
+        self.field_name = Some(field_name.into());
         self
     }
     #[doc = r""]
@@ -67,6 +112,23 @@ impl Builder {
         self.docs = docs.into();
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.field_name.is_some() {
+            self.field_name = other.field_name;
+        }
+        if other.type_.is_some() {
+            self.type_ = other.type_;
+        }
+        if !other.docs.is_none() {
+            self.docs = other.docs;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -120,6 +182,16 @@ impl<'de> de::Deserialize<'de> for FieldDefinition {
     {
         d.deserialize_struct("FieldDefinition", &["fieldName", "type", "docs"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut FieldDefinition) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct(
+            "FieldDefinition",
+            &["fieldName", "type", "docs"],
+            InPlaceVisitor_(place),
+        )
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -136,9 +208,24 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut docs = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::FieldName => field_name = Some(map_.next_value()?),
-                Field_::Type => type_ = Some(map_.next_value()?),
-                Field_::Docs => docs = Some(map_.next_value()?),
+                Field_::FieldName => {
+                    if field_name.is_some() {
+                        return Err(de::Error::duplicate_field("fieldName"));
+                    }
+                    field_name = Some(map_.next_value()?);
+                }
+                Field_::Type => {
+                    if type_.is_some() {
+                        return Err(de::Error::duplicate_field("type"));
+                    }
+                    type_ = Some(map_.next_value()?);
+                }
+                Field_::Docs => {
+                    if docs.is_some() {
+                        return Err(de::Error::duplicate_field("docs"));
+                    }
+                    docs = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -163,6 +250,60 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut FieldDefinition);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut field_name = false;
+        let mut type_ = false;
+        let mut docs = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::FieldName => {
+                    if field_name {
+                        return Err(de::Error::duplicate_field("fieldName"));
+                    }
+                    place_.field_name = map_.next_value()?;
+                    field_name = true;
+                }
+                Field_::Type => {
+                    if type_ {
+                        return Err(de::Error::duplicate_field("type"));
+                    }
+                    place_.type_ = map_.next_value()?;
+                    type_ = true;
+                }
+                Field_::Docs => {
+                    if docs {
+                        return Err(de::Error::duplicate_field("docs"));
+                    }
+                    place_.docs = map_.next_value()?;
+                    docs = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !field_name {
+            return Err(de::Error::missing_field("fieldName"));
+        }
+        if !type_ {
+            return Err(de::Error::missing_field("type"));
+        }
+        if !docs {
+            place_.docs = Default::default();
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     FieldName,
     Type,
@@ -196,3 +337,12 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `fieldName` field."]
+    pub const FIELD_NAME: &str = "fieldName";
+    #[doc = "The wire name of the `type` field."]
+    pub const TYPE_: &str = "type";
+    #[doc = "The wire name of the `docs` field."]
+    pub const DOCS: &str = "docs";
+}
@@ -2,11 +2,90 @@ use conjure_object::serde::{de, ser};
 #[doc = "Should be in lowerCamelCase, but kebab-case and snake_case are also permitted."]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
 pub struct FieldName(pub String);
+impl FieldName {
+    #[doc = r" Constructs a new instance of the type."]
+    #[inline]
+    pub fn new(inner: String) -> FieldName {
+        FieldName(inner)
+    }
+    #[doc = r" Returns the wrapped value."]
+    #[inline]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
 impl std::fmt::Display for FieldName {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(&self.0, fmt)
     }
 }
+impl<'a> From<&'a str> for FieldName {
+    #[inline]
+    fn from(v: &'a str) -> FieldName {
+        FieldName(v.to_string())
+    }
+}
+impl From<String> for FieldName {
+    #[inline]
+    fn from(v: String) -> FieldName {
+        FieldName(v)
+    }
+}
+impl std::cmp::PartialEq<str> for FieldName {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+impl<'a> std::cmp::PartialEq<&'a str> for FieldName {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.0 == *other
+    }
+}
+impl std::cmp::PartialEq<String> for FieldName {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        self.0 == *other
+    }
+}
+impl std::cmp::PartialEq<FieldName> for str {
+    #[inline]
+    fn eq(&self, other: &FieldName) -> bool {
+        *self == other.0
+    }
+}
+impl<'a> std::cmp::PartialEq<FieldName> for &'a str {
+    #[inline]
+    fn eq(&self, other: &FieldName) -> bool {
+        **self == other.0
+    }
+}
+impl std::cmp::PartialEq<FieldName> for String {
+    #[inline]
+    fn eq(&self, other: &FieldName) -> bool {
+        *self == other.0
+    }
+}
+impl conjure_object::PlainEncode for FieldName {
+    #[inline]
+    fn encode_plain(&self) -> String {
+        conjure_object::PlainEncode::encode_plain(&self.0)
+    }
+}
+impl conjure_object::PlainDecode for FieldName {
+    #[inline]
+    fn decode_plain(s: &str) -> Result<FieldName, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s).map(FieldName)
+    }
+}
+impl std::str::FromStr for FieldName {
+    type Err = conjure_object::plain::ParseError;
+    #[inline]
+    fn from_str(s: &str) -> Result<FieldName, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s)
+    }
+}
 impl std::ops::Deref for FieldName {
     type Target = String;
     #[inline]
@@ -21,6 +100,7 @@ impl std::ops::DerefMut for FieldName {
     }
 }
 impl ser::Serialize for FieldName {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -29,6 +109,7 @@ impl ser::Serialize for FieldName {
     }
 }
 impl<'de> de::Deserialize<'de> for FieldName {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<FieldName, D::Error>
     where
         D: de::Deserializer<'de>,
@@ -1,6 +1,6 @@
 use conjure_object::serde::{de, ser};
 use std::fmt;
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum HttpMethod {
     Get,
     Post,
@@ -8,6 +8,10 @@ pub enum HttpMethod {
     Delete,
 }
 impl HttpMethod {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "HttpMethod";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
     #[doc = r" Returns the string representation of the enum."]
     #[inline]
     pub fn as_str(&self) -> &str {
@@ -18,6 +22,25 @@ impl HttpMethod {
             HttpMethod::Delete => "DELETE",
         }
     }
+    #[doc = r" Returns a stable numeric identifier of the enum."]
+    #[doc = r""]
+    #[doc = r" The ordinal is a hash of the string representation rather than the position of the variant, so it is"]
+    #[doc = r" unaffected by reordering the enum's definition. Distinct variants are not guaranteed to have distinct"]
+    #[doc = r" ordinals."]
+    #[inline]
+    pub fn ordinal(&self) -> u32 {
+        match self {
+            HttpMethod::Get => 2531704439,
+            HttpMethod::Post => 1929554311,
+            HttpMethod::Put => 3995708942,
+            HttpMethod::Delete => 4168191690,
+        }
+    }
+    #[doc = r" Compares the enum to another by their string representations rather than by declaration order."]
+    #[inline]
+    pub fn cmp_by_name(&self, other: &HttpMethod) -> std::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
 }
 impl fmt::Display for HttpMethod {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
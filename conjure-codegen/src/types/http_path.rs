@@ -1,11 +1,90 @@
 use conjure_object::serde::{de, ser};
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
 pub struct HttpPath(pub String);
+impl HttpPath {
+    #[doc = r" Constructs a new instance of the type."]
+    #[inline]
+    pub fn new(inner: String) -> HttpPath {
+        HttpPath(inner)
+    }
+    #[doc = r" Returns the wrapped value."]
+    #[inline]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
 impl std::fmt::Display for HttpPath {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(&self.0, fmt)
     }
 }
+impl<'a> From<&'a str> for HttpPath {
+    #[inline]
+    fn from(v: &'a str) -> HttpPath {
+        HttpPath(v.to_string())
+    }
+}
+impl From<String> for HttpPath {
+    #[inline]
+    fn from(v: String) -> HttpPath {
+        HttpPath(v)
+    }
+}
+impl std::cmp::PartialEq<str> for HttpPath {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+impl<'a> std::cmp::PartialEq<&'a str> for HttpPath {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.0 == *other
+    }
+}
+impl std::cmp::PartialEq<String> for HttpPath {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        self.0 == *other
+    }
+}
+impl std::cmp::PartialEq<HttpPath> for str {
+    #[inline]
+    fn eq(&self, other: &HttpPath) -> bool {
+        *self == other.0
+    }
+}
+impl<'a> std::cmp::PartialEq<HttpPath> for &'a str {
+    #[inline]
+    fn eq(&self, other: &HttpPath) -> bool {
+        **self == other.0
+    }
+}
+impl std::cmp::PartialEq<HttpPath> for String {
+    #[inline]
+    fn eq(&self, other: &HttpPath) -> bool {
+        *self == other.0
+    }
+}
+impl conjure_object::PlainEncode for HttpPath {
+    #[inline]
+    fn encode_plain(&self) -> String {
+        conjure_object::PlainEncode::encode_plain(&self.0)
+    }
+}
+impl conjure_object::PlainDecode for HttpPath {
+    #[inline]
+    fn decode_plain(s: &str) -> Result<HttpPath, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s).map(HttpPath)
+    }
+}
+impl std::str::FromStr for HttpPath {
+    type Err = conjure_object::plain::ParseError;
+    #[inline]
+    fn from_str(s: &str) -> Result<HttpPath, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s)
+    }
+}
 impl std::ops::Deref for HttpPath {
     type Target = String;
     #[inline]
@@ -20,6 +99,7 @@ impl std::ops::DerefMut for HttpPath {
     }
 }
 impl ser::Serialize for HttpPath {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -28,6 +108,7 @@ impl ser::Serialize for HttpPath {
     }
 }
 impl<'de> de::Deserialize<'de> for HttpPath {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<HttpPath, D::Error>
     where
         D: de::Deserializer<'de>,
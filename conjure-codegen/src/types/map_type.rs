@@ -7,6 +7,10 @@ pub struct MapType {
     value_type: Box<super::Type>,
 }
 impl MapType {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "MapType";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
     pub fn new(key_type: super::Type, value_type: super::Type) -> MapType {
@@ -15,6 +19,19 @@ impl MapType {
             value_type: Box::new(value_type),
         }
     }
+    #[doc = r" Constructs an instance of the type from a tuple of its fields, in declaration order."]
+    #[inline]
+    pub fn from_parts(parts: (super::Type, super::Type)) -> MapType {
+        MapType {
+            key_type: Box::new(parts.0),
+            value_type: Box::new(parts.1),
+        }
+    }
+    #[doc = r" Returns a tuple of the object's fields, in declaration order, consuming the object."]
+    #[inline]
+    pub fn into_parts(self) -> (super::Type, super::Type) {
+        (*self.key_type, *self.value_type)
+    }
     #[doc = r" Returns a new builder."]
     #[inline]
     pub fn builder() -> Builder {
@@ -28,6 +45,16 @@ impl MapType {
     pub fn value_type(&self) -> &super::Type {
         &*self.value_type
     }
+    #[doc = "Returns the `key_type` field, consuming the object."]
+    #[inline]
+    pub fn into_key_type(self) -> super::Type {
+        *self.key_type
+    }
+    #[doc = "Returns the `value_type` field, consuming the object."]
+    #[inline]
+    pub fn into_value_type(self) -> super::Type {
+        *self.value_type
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -49,6 +76,20 @@ impl Builder {
         self.value_type = Some(Box::new(value_type));
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.key_type.is_some() {
+            self.key_type = other.key_type;
+        }
+        if other.value_type.is_some() {
+            self.value_type = other.value_type;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -93,6 +134,12 @@ impl<'de> de::Deserialize<'de> for MapType {
     {
         d.deserialize_struct("MapType", &["keyType", "valueType"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut MapType) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct("MapType", &["keyType", "valueType"], InPlaceVisitor_(place))
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -108,8 +155,18 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut value_type = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::KeyType => key_type = Some(map_.next_value()?),
-                Field_::ValueType => value_type = Some(map_.next_value()?),
+                Field_::KeyType => {
+                    if key_type.is_some() {
+                        return Err(de::Error::duplicate_field("keyType"));
+                    }
+                    key_type = Some(map_.next_value()?);
+                }
+                Field_::ValueType => {
+                    if value_type.is_some() {
+                        return Err(de::Error::duplicate_field("valueType"));
+                    }
+                    value_type = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -129,6 +186,49 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut MapType);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut key_type = false;
+        let mut value_type = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::KeyType => {
+                    if key_type {
+                        return Err(de::Error::duplicate_field("keyType"));
+                    }
+                    place_.key_type = map_.next_value()?;
+                    key_type = true;
+                }
+                Field_::ValueType => {
+                    if value_type {
+                        return Err(de::Error::duplicate_field("valueType"));
+                    }
+                    place_.value_type = map_.next_value()?;
+                    value_type = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !key_type {
+            return Err(de::Error::missing_field("keyType"));
+        }
+        if !value_type {
+            return Err(de::Error::missing_field("valueType"));
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     KeyType,
     ValueType,
@@ -160,3 +260,10 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `keyType` field."]
+    pub const KEY_TYPE: &str = "keyType";
+    #[doc = "The wire name of the `valueType` field."]
+    pub const VALUE_TYPE: &str = "valueType";
+}
@@ -8,22 +8,49 @@ pub struct ObjectDefinition {
     docs: Option<super::Documentation>,
 }
 impl ObjectDefinition {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "ObjectDefinition";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
-    pub fn new<T>(
-        type_name: super::TypeName,
-        fields: T,
-        docs: super::Documentation,
-    ) -> ObjectDefinition
+    pub fn new<T, U>(type_name: super::TypeName, fields: T, docs: U) -> ObjectDefinition
     where
         T: IntoIterator<Item = super::FieldDefinition>,
+        U: Into<super::Documentation>,
     {
         ObjectDefinition {
             type_name: Box::new(type_name),
             fields: fields.into_iter().collect(),
-            docs: Some(docs),
+            docs: Some(docs.into()),
         }
     }
+    #[doc = r" Constructs an instance of the type from a tuple of its fields, in declaration order."]
+    #[inline]
+    pub fn from_parts(
+        parts: (
+            super::TypeName,
+            Vec<super::FieldDefinition>,
+            Option<super::Documentation>,
+        ),
+    ) -> ObjectDefinition {
+        ObjectDefinition {
+            type_name: Box::new(parts.0),
+            fields: parts.1,
+            docs: parts.2,
+        }
+    }
+    #[doc = r" Returns a tuple of the object's fields, in declaration order, consuming the object."]
+    #[inline]
+    pub fn into_parts(
+        self,
+    ) -> (
+        super::TypeName,
+        Vec<super::FieldDefinition>,
+        Option<super::Documentation>,
+    ) {
+        (*self.type_name, self.fields, self.docs)
+    }
     #[doc = r" Returns a new builder."]
     #[inline]
     pub fn builder() -> Builder {
@@ -41,6 +68,32 @@ impl ObjectDefinition {
     pub fn docs(&self) -> Option<&super::Documentation> {
         self.docs.as_ref().map(|o| &*o)
     }
+    #[doc = "Returns the `type_name` field, consuming the object."]
+    #[inline]
+    pub fn into_type_name(self) -> super::TypeName {
+        *self.type_name
+    }
+    #[doc = "Returns the `fields` field, consuming the object."]
+    #[inline]
+    pub fn into_fields(self) -> Vec<super::FieldDefinition> {
+        self.fields
+    }
+    #[doc = "Returns the `docs` field, consuming the object."]
+    #[inline]
+    pub fn into_docs(self) -> Option<super::Documentation> {
+        self.docs
+    }
+    #[doc = "Returns the `docs` field, or an error if it is absent."]
+    #[inline]
+    pub fn require_docs(&self) -> Result<&super::Documentation, conjure_object::MissingField> {
+        match &self.docs {
+            Some(o) => Ok(&*o),
+            None => Err(conjure_object::MissingField::new(
+                "ObjectDefinition",
+                "docs",
+            )),
+        }
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -81,6 +134,23 @@ impl Builder {
         self.docs = docs.into();
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.type_name.is_some() {
+            self.type_name = other.type_name;
+        }
+        if !other.fields.is_empty() {
+            self.fields = other.fields;
+        }
+        if !other.docs.is_none() {
+            self.docs = other.docs;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -141,6 +211,16 @@ impl<'de> de::Deserialize<'de> for ObjectDefinition {
             Visitor_,
         )
     }
+    fn deserialize_in_place<D>(d: D, place: &mut ObjectDefinition) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct(
+            "ObjectDefinition",
+            &["typeName", "fields", "docs"],
+            InPlaceVisitor_(place),
+        )
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -157,9 +237,24 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut docs = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::TypeName => type_name = Some(map_.next_value()?),
-                Field_::Fields => fields = Some(map_.next_value()?),
-                Field_::Docs => docs = Some(map_.next_value()?),
+                Field_::TypeName => {
+                    if type_name.is_some() {
+                        return Err(de::Error::duplicate_field("typeName"));
+                    }
+                    type_name = Some(map_.next_value()?);
+                }
+                Field_::Fields => {
+                    if fields.is_some() {
+                        return Err(de::Error::duplicate_field("fields"));
+                    }
+                    fields = Some(map_.next_value()?);
+                }
+                Field_::Docs => {
+                    if docs.is_some() {
+                        return Err(de::Error::duplicate_field("docs"));
+                    }
+                    docs = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -184,6 +279,62 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut ObjectDefinition);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut type_name = false;
+        let mut fields = false;
+        let mut docs = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::TypeName => {
+                    if type_name {
+                        return Err(de::Error::duplicate_field("typeName"));
+                    }
+                    place_.type_name = map_.next_value()?;
+                    type_name = true;
+                }
+                Field_::Fields => {
+                    if fields {
+                        return Err(de::Error::duplicate_field("fields"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(
+                        &mut place_.fields,
+                    ))?;
+                    fields = true;
+                }
+                Field_::Docs => {
+                    if docs {
+                        return Err(de::Error::duplicate_field("docs"));
+                    }
+                    place_.docs = map_.next_value()?;
+                    docs = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !type_name {
+            return Err(de::Error::missing_field("typeName"));
+        }
+        if !fields {
+            place_.fields.clear();
+        }
+        if !docs {
+            place_.docs = Default::default();
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     TypeName,
     Fields,
@@ -217,3 +368,12 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `typeName` field."]
+    pub const TYPE_NAME: &str = "typeName";
+    #[doc = "The wire name of the `fields` field."]
+    pub const FIELDS: &str = "fields";
+    #[doc = "The wire name of the `docs` field."]
+    pub const DOCS: &str = "docs";
+}
@@ -2,11 +2,90 @@ use conjure_object::serde::{de, ser};
 #[doc = "For header parameters, the parameter id must be in Upper-Kebab-Case. For query parameters, the parameter id must be in lowerCamelCase. Numbers are permitted, but not at the beginning of a word."]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
 pub struct ParameterId(pub String);
+impl ParameterId {
+    #[doc = r" Constructs a new instance of the type."]
+    #[inline]
+    pub fn new(inner: String) -> ParameterId {
+        ParameterId(inner)
+    }
+    #[doc = r" Returns the wrapped value."]
+    #[inline]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
 impl std::fmt::Display for ParameterId {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(&self.0, fmt)
     }
 }
+impl<'a> From<&'a str> for ParameterId {
+    #[inline]
+    fn from(v: &'a str) -> ParameterId {
+        ParameterId(v.to_string())
+    }
+}
+impl From<String> for ParameterId {
+    #[inline]
+    fn from(v: String) -> ParameterId {
+        ParameterId(v)
+    }
+}
+impl std::cmp::PartialEq<str> for ParameterId {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+impl<'a> std::cmp::PartialEq<&'a str> for ParameterId {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.0 == *other
+    }
+}
+impl std::cmp::PartialEq<String> for ParameterId {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        self.0 == *other
+    }
+}
+impl std::cmp::PartialEq<ParameterId> for str {
+    #[inline]
+    fn eq(&self, other: &ParameterId) -> bool {
+        *self == other.0
+    }
+}
+impl<'a> std::cmp::PartialEq<ParameterId> for &'a str {
+    #[inline]
+    fn eq(&self, other: &ParameterId) -> bool {
+        **self == other.0
+    }
+}
+impl std::cmp::PartialEq<ParameterId> for String {
+    #[inline]
+    fn eq(&self, other: &ParameterId) -> bool {
+        *self == other.0
+    }
+}
+impl conjure_object::PlainEncode for ParameterId {
+    #[inline]
+    fn encode_plain(&self) -> String {
+        conjure_object::PlainEncode::encode_plain(&self.0)
+    }
+}
+impl conjure_object::PlainDecode for ParameterId {
+    #[inline]
+    fn decode_plain(s: &str) -> Result<ParameterId, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s).map(ParameterId)
+    }
+}
+impl std::str::FromStr for ParameterId {
+    type Err = conjure_object::plain::ParseError;
+    #[inline]
+    fn from_str(s: &str) -> Result<ParameterId, conjure_object::plain::ParseError> {
+        conjure_object::PlainDecode::decode_plain(s)
+    }
+}
 impl std::ops::Deref for ParameterId {
     type Target = String;
     #[inline]
@@ -21,6 +100,7 @@ impl std::ops::DerefMut for ParameterId {
     }
 }
 impl ser::Serialize for ParameterId {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -29,6 +109,7 @@ impl ser::Serialize for ParameterId {
     }
 }
 impl<'de> de::Deserialize<'de> for ParameterId {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<ParameterId, D::Error>
     where
         D: de::Deserializer<'de>,
@@ -2,13 +2,19 @@ use conjure_object::private::{UnionField_, UnionTypeField_};
 use conjure_object::serde::ser::SerializeMap as SerializeMap_;
 use conjure_object::serde::{de, ser};
 use std::fmt;
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ParameterType {
     Body(super::BodyParameterType),
     Header(super::HeaderParameterType),
     Path(super::PathParameterType),
     Query(super::QueryParameterType),
 }
+impl ParameterType {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "ParameterType";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
+}
 impl ser::Serialize for ParameterType {
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
@@ -36,6 +42,33 @@ impl ser::Serialize for ParameterType {
         map.end()
     }
 }
+impl std::cmp::PartialOrd for ParameterType {
+    #[inline]
+    fn partial_cmp(&self, other: &ParameterType) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl std::cmp::Ord for ParameterType {
+    fn cmp(&self, other: &ParameterType) -> std::cmp::Ordering {
+        match (self, other) {
+            (ParameterType::Body(a), ParameterType::Body(b)) => a.cmp(b),
+            (ParameterType::Header(a), ParameterType::Header(b)) => a.cmp(b),
+            (ParameterType::Path(a), ParameterType::Path(b)) => a.cmp(b),
+            (ParameterType::Query(a), ParameterType::Query(b)) => a.cmp(b),
+            _ => {
+                fn index(v: &ParameterType) -> usize {
+                    match v {
+                        ParameterType::Body(_) => 0,
+                        ParameterType::Header(_) => 1,
+                        ParameterType::Path(_) => 2,
+                        ParameterType::Query(_) => 3,
+                    }
+                }
+                index(self).cmp(&index(other))
+            }
+        }
+    }
+}
 impl<'de> de::Deserialize<'de> for ParameterType {
     fn deserialize<D>(d: D) -> Result<ParameterType, D::Error>
     where
@@ -4,9 +4,13 @@ use std::fmt;
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Copy)]
 pub struct PathParameterType {}
 impl PathParameterType {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "PathParameterType";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
-    pub fn new() -> PathParameterType {
+    pub const fn new() -> PathParameterType {
         PathParameterType {}
     }
     #[doc = r" Returns a new builder."]
@@ -18,6 +22,14 @@ impl PathParameterType {
 #[derive(Debug, Clone, Default)]
 pub struct Builder {}
 impl Builder {
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, _other: Builder) -> &mut Self {
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -35,6 +47,7 @@ impl From<PathParameterType> for Builder {
     }
 }
 impl ser::Serialize for PathParameterType {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -45,12 +58,19 @@ impl ser::Serialize for PathParameterType {
     }
 }
 impl<'de> de::Deserialize<'de> for PathParameterType {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<PathParameterType, D::Error>
     where
         D: de::Deserializer<'de>,
     {
         d.deserialize_struct("PathParameterType", &[], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut PathParameterType) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct("PathParameterType", &[], InPlaceVisitor_(place))
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -72,6 +92,26 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(PathParameterType {})
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut PathParameterType);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     Unknown_,
 }
@@ -99,3 +139,5 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {}
@@ -1,6 +1,6 @@
 use conjure_object::serde::{de, ser};
 use std::fmt;
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum PrimitiveType {
     String,
     Datetime,
@@ -15,6 +15,10 @@ pub enum PrimitiveType {
     Bearertoken,
 }
 impl PrimitiveType {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "PrimitiveType";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
     #[doc = r" Returns the string representation of the enum."]
     #[inline]
     pub fn as_str(&self) -> &str {
@@ -32,6 +36,32 @@ impl PrimitiveType {
             PrimitiveType::Bearertoken => "BEARERTOKEN",
         }
     }
+    #[doc = r" Returns a stable numeric identifier of the enum."]
+    #[doc = r""]
+    #[doc = r" The ordinal is a hash of the string representation rather than the position of the variant, so it is"]
+    #[doc = r" unaffected by reordering the enum's definition. Distinct variants are not guaranteed to have distinct"]
+    #[doc = r" ordinals."]
+    #[inline]
+    pub fn ordinal(&self) -> u32 {
+        match self {
+            PrimitiveType::String => 4127814520,
+            PrimitiveType::Datetime => 2472002000,
+            PrimitiveType::Integer => 2367501349,
+            PrimitiveType::Double => 3751281736,
+            PrimitiveType::Safelong => 3383073062,
+            PrimitiveType::Binary => 1592469244,
+            PrimitiveType::Any => 2925960045,
+            PrimitiveType::Boolean => 2282454687,
+            PrimitiveType::Uuid => 628852786,
+            PrimitiveType::Rid => 3287093712,
+            PrimitiveType::Bearertoken => 593783941,
+        }
+    }
+    #[doc = r" Compares the enum to another by their string representations rather than by declaration order."]
+    #[inline]
+    pub fn cmp_by_name(&self, other: &PrimitiveType) -> std::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
 }
 impl fmt::Display for PrimitiveType {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
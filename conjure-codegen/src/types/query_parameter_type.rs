@@ -6,10 +6,19 @@ pub struct QueryParameterType {
     param_id: super::ParameterId,
 }
 impl QueryParameterType {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "QueryParameterType";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
-    pub fn new(param_id: super::ParameterId) -> QueryParameterType {
-        QueryParameterType { param_id: param_id }
+    pub fn new<T>(param_id: T) -> QueryParameterType
+    where
+        T: Into<super::ParameterId>,
+    {
+        QueryParameterType {
+            param_id: param_id.into(),
+        }
     }
     #[doc = r" Returns a new builder."]
     #[inline]
@@ -20,6 +29,11 @@ impl QueryParameterType {
     pub fn param_id(&self) -> &super::ParameterId {
         &self.param_id
     }
+    #[doc = "Returns the `param_id` field, consuming the object."]
+    #[inline]
+    pub fn into_param_id(self) -> super::ParameterId {
+        self.param_id
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -28,9 +42,22 @@ pub struct Builder {
 impl Builder {
     #[doc = r""]
     #[doc = r" Required."]
+    pub fn param_id<T>(&mut self, param_id: T) -> &mut Self
+    where
+        T: Into<super::ParameterId>,
+    {
+        self.param_id = Some(param_id.into());
+        self
+    }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
     #[inline]
-    pub fn param_id(&mut self, param_id: super::ParameterId) -> &mut Self {
-        self.param_id = Some(param_id);
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.param_id.is_some() {
+            self.param_id = other.param_id;
+        }
         self
     }
     #[doc = r" Constructs a new instance of the type."]
@@ -54,6 +81,7 @@ impl From<QueryParameterType> for Builder {
     }
 }
 impl ser::Serialize for QueryParameterType {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -65,12 +93,19 @@ impl ser::Serialize for QueryParameterType {
     }
 }
 impl<'de> de::Deserialize<'de> for QueryParameterType {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<QueryParameterType, D::Error>
     where
         D: de::Deserializer<'de>,
     {
         d.deserialize_struct("QueryParameterType", &["paramId"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut QueryParameterType) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct("QueryParameterType", &["paramId"], InPlaceVisitor_(place))
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -85,7 +120,12 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut param_id = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::ParamId => param_id = Some(map_.next_value()?),
+                Field_::ParamId => {
+                    if param_id.is_some() {
+                        return Err(de::Error::duplicate_field("paramId"));
+                    }
+                    param_id = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -98,6 +138,38 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(QueryParameterType { param_id })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut QueryParameterType);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut param_id = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::ParamId => {
+                    if param_id {
+                        return Err(de::Error::duplicate_field("paramId"));
+                    }
+                    place_.param_id = map_.next_value()?;
+                    param_id = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !param_id {
+            return Err(de::Error::missing_field("paramId"));
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     ParamId,
     Unknown_,
@@ -127,3 +199,8 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `paramId` field."]
+    pub const PARAM_ID: &str = "paramId";
+}
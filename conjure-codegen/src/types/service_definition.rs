@@ -8,22 +8,49 @@ pub struct ServiceDefinition {
     docs: Option<super::Documentation>,
 }
 impl ServiceDefinition {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "ServiceDefinition";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
-    pub fn new<T>(
-        service_name: super::TypeName,
-        endpoints: T,
-        docs: super::Documentation,
-    ) -> ServiceDefinition
+    pub fn new<T, U>(service_name: super::TypeName, endpoints: T, docs: U) -> ServiceDefinition
     where
         T: IntoIterator<Item = super::EndpointDefinition>,
+        U: Into<super::Documentation>,
     {
         ServiceDefinition {
             service_name: Box::new(service_name),
             endpoints: endpoints.into_iter().collect(),
-            docs: Some(docs),
+            docs: Some(docs.into()),
         }
     }
+    #[doc = r" Constructs an instance of the type from a tuple of its fields, in declaration order."]
+    #[inline]
+    pub fn from_parts(
+        parts: (
+            super::TypeName,
+            Vec<super::EndpointDefinition>,
+            Option<super::Documentation>,
+        ),
+    ) -> ServiceDefinition {
+        ServiceDefinition {
+            service_name: Box::new(parts.0),
+            endpoints: parts.1,
+            docs: parts.2,
+        }
+    }
+    #[doc = r" Returns a tuple of the object's fields, in declaration order, consuming the object."]
+    #[inline]
+    pub fn into_parts(
+        self,
+    ) -> (
+        super::TypeName,
+        Vec<super::EndpointDefinition>,
+        Option<super::Documentation>,
+    ) {
+        (*self.service_name, self.endpoints, self.docs)
+    }
     #[doc = r" Returns a new builder."]
     #[inline]
     pub fn builder() -> Builder {
@@ -41,6 +68,32 @@ impl ServiceDefinition {
     pub fn docs(&self) -> Option<&super::Documentation> {
         self.docs.as_ref().map(|o| &*o)
     }
+    #[doc = "Returns the `service_name` field, consuming the object."]
+    #[inline]
+    pub fn into_service_name(self) -> super::TypeName {
+        *self.service_name
+    }
+    #[doc = "Returns the `endpoints` field, consuming the object."]
+    #[inline]
+    pub fn into_endpoints(self) -> Vec<super::EndpointDefinition> {
+        self.endpoints
+    }
+    #[doc = "Returns the `docs` field, consuming the object."]
+    #[inline]
+    pub fn into_docs(self) -> Option<super::Documentation> {
+        self.docs
+    }
+    #[doc = "Returns the `docs` field, or an error if it is absent."]
+    #[inline]
+    pub fn require_docs(&self) -> Result<&super::Documentation, conjure_object::MissingField> {
+        match &self.docs {
+            Some(o) => Ok(&*o),
+            None => Err(conjure_object::MissingField::new(
+                "ServiceDefinition",
+                "docs",
+            )),
+        }
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -81,6 +134,23 @@ impl Builder {
         self.docs = docs.into();
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.service_name.is_some() {
+            self.service_name = other.service_name;
+        }
+        if !other.endpoints.is_empty() {
+            self.endpoints = other.endpoints;
+        }
+        if !other.docs.is_none() {
+            self.docs = other.docs;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -144,6 +214,16 @@ impl<'de> de::Deserialize<'de> for ServiceDefinition {
             Visitor_,
         )
     }
+    fn deserialize_in_place<D>(d: D, place: &mut ServiceDefinition) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct(
+            "ServiceDefinition",
+            &["serviceName", "endpoints", "docs"],
+            InPlaceVisitor_(place),
+        )
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -160,9 +240,24 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut docs = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::ServiceName => service_name = Some(map_.next_value()?),
-                Field_::Endpoints => endpoints = Some(map_.next_value()?),
-                Field_::Docs => docs = Some(map_.next_value()?),
+                Field_::ServiceName => {
+                    if service_name.is_some() {
+                        return Err(de::Error::duplicate_field("serviceName"));
+                    }
+                    service_name = Some(map_.next_value()?);
+                }
+                Field_::Endpoints => {
+                    if endpoints.is_some() {
+                        return Err(de::Error::duplicate_field("endpoints"));
+                    }
+                    endpoints = Some(map_.next_value()?);
+                }
+                Field_::Docs => {
+                    if docs.is_some() {
+                        return Err(de::Error::duplicate_field("docs"));
+                    }
+                    docs = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -187,6 +282,62 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut ServiceDefinition);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut service_name = false;
+        let mut endpoints = false;
+        let mut docs = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::ServiceName => {
+                    if service_name {
+                        return Err(de::Error::duplicate_field("serviceName"));
+                    }
+                    place_.service_name = map_.next_value()?;
+                    service_name = true;
+                }
+                Field_::Endpoints => {
+                    if endpoints {
+                        return Err(de::Error::duplicate_field("endpoints"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(
+                        &mut place_.endpoints,
+                    ))?;
+                    endpoints = true;
+                }
+                Field_::Docs => {
+                    if docs {
+                        return Err(de::Error::duplicate_field("docs"));
+                    }
+                    place_.docs = map_.next_value()?;
+                    docs = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !service_name {
+            return Err(de::Error::missing_field("serviceName"));
+        }
+        if !endpoints {
+            place_.endpoints.clear();
+        }
+        if !docs {
+            place_.docs = Default::default();
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     ServiceName,
     Endpoints,
@@ -220,3 +371,12 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `serviceName` field."]
+    pub const SERVICE_NAME: &str = "serviceName";
+    #[doc = "The wire name of the `endpoints` field."]
+    pub const ENDPOINTS: &str = "endpoints";
+    #[doc = "The wire name of the `docs` field."]
+    pub const DOCS: &str = "docs";
+}
@@ -6,6 +6,10 @@ pub struct SetType {
     item_type: Box<super::Type>,
 }
 impl SetType {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "SetType";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
     pub fn new(item_type: super::Type) -> SetType {
@@ -22,6 +26,11 @@ impl SetType {
     pub fn item_type(&self) -> &super::Type {
         &*self.item_type
     }
+    #[doc = "Returns the `item_type` field, consuming the object."]
+    #[inline]
+    pub fn into_item_type(self) -> super::Type {
+        *self.item_type
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -35,6 +44,17 @@ impl Builder {
         self.item_type = Some(Box::new(item_type));
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.item_type.is_some() {
+            self.item_type = other.item_type;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -56,6 +76,7 @@ impl From<SetType> for Builder {
     }
 }
 impl ser::Serialize for SetType {
+    #[inline]
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
@@ -67,12 +88,19 @@ impl ser::Serialize for SetType {
     }
 }
 impl<'de> de::Deserialize<'de> for SetType {
+    #[inline]
     fn deserialize<D>(d: D) -> Result<SetType, D::Error>
     where
         D: de::Deserializer<'de>,
     {
         d.deserialize_struct("SetType", &["itemType"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut SetType) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct("SetType", &["itemType"], InPlaceVisitor_(place))
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -87,7 +115,12 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut item_type = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::ItemType => item_type = Some(map_.next_value()?),
+                Field_::ItemType => {
+                    if item_type.is_some() {
+                        return Err(de::Error::duplicate_field("itemType"));
+                    }
+                    item_type = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -100,6 +133,38 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(SetType { item_type })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut SetType);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut item_type = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::ItemType => {
+                    if item_type {
+                        return Err(de::Error::duplicate_field("itemType"));
+                    }
+                    place_.item_type = map_.next_value()?;
+                    item_type = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !item_type {
+            return Err(de::Error::missing_field("itemType"));
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     ItemType,
     Unknown_,
@@ -129,3 +194,8 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `itemType` field."]
+    pub const ITEM_TYPE: &str = "itemType";
+}
@@ -2,7 +2,7 @@ use conjure_object::private::{UnionField_, UnionTypeField_};
 use conjure_object::serde::ser::SerializeMap as SerializeMap_;
 use conjure_object::serde::{de, ser};
 use std::fmt;
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Type {
     Primitive(super::PrimitiveType),
     Optional(super::OptionalType),
@@ -13,6 +13,12 @@ pub enum Type {
     Reference(super::TypeName),
     External(super::ExternalReference),
 }
+impl Type {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "Type";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
+}
 impl ser::Serialize for Type {
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
@@ -52,6 +58,39 @@ impl ser::Serialize for Type {
         map.end()
     }
 }
+impl std::cmp::PartialOrd for Type {
+    #[inline]
+    fn partial_cmp(&self, other: &Type) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl std::cmp::Ord for Type {
+    fn cmp(&self, other: &Type) -> std::cmp::Ordering {
+        match (self, other) {
+            (Type::Primitive(a), Type::Primitive(b)) => a.cmp(b),
+            (Type::Optional(a), Type::Optional(b)) => a.cmp(b),
+            (Type::List(a), Type::List(b)) => a.cmp(b),
+            (Type::Set(a), Type::Set(b)) => a.cmp(b),
+            (Type::Map(a), Type::Map(b)) => a.cmp(b),
+            (Type::Reference(a), Type::Reference(b)) => a.cmp(b),
+            (Type::External(a), Type::External(b)) => a.cmp(b),
+            _ => {
+                fn index(v: &Type) -> usize {
+                    match v {
+                        Type::Primitive(_) => 0,
+                        Type::Optional(_) => 1,
+                        Type::List(_) => 2,
+                        Type::Set(_) => 3,
+                        Type::Map(_) => 4,
+                        Type::Reference(_) => 5,
+                        Type::External(_) => 6,
+                    }
+                }
+                index(self).cmp(&index(other))
+            }
+        }
+    }
+}
 impl<'de> de::Deserialize<'de> for Type {
     fn deserialize<D>(d: D) -> Result<Type, D::Error>
     where
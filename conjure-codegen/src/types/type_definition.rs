@@ -2,13 +2,19 @@ use conjure_object::private::{UnionField_, UnionTypeField_};
 use conjure_object::serde::ser::SerializeMap as SerializeMap_;
 use conjure_object::serde::{de, ser};
 use std::fmt;
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TypeDefinition {
     Alias(super::AliasDefinition),
     Enum(super::EnumDefinition),
     Object(super::ObjectDefinition),
     Union(super::UnionDefinition),
 }
+impl TypeDefinition {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "TypeDefinition";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
+}
 impl ser::Serialize for TypeDefinition {
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
@@ -36,6 +42,33 @@ impl ser::Serialize for TypeDefinition {
         map.end()
     }
 }
+impl std::cmp::PartialOrd for TypeDefinition {
+    #[inline]
+    fn partial_cmp(&self, other: &TypeDefinition) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl std::cmp::Ord for TypeDefinition {
+    fn cmp(&self, other: &TypeDefinition) -> std::cmp::Ordering {
+        match (self, other) {
+            (TypeDefinition::Alias(a), TypeDefinition::Alias(b)) => a.cmp(b),
+            (TypeDefinition::Enum(a), TypeDefinition::Enum(b)) => a.cmp(b),
+            (TypeDefinition::Object(a), TypeDefinition::Object(b)) => a.cmp(b),
+            (TypeDefinition::Union(a), TypeDefinition::Union(b)) => a.cmp(b),
+            _ => {
+                fn index(v: &TypeDefinition) -> usize {
+                    match v {
+                        TypeDefinition::Alias(_) => 0,
+                        TypeDefinition::Enum(_) => 1,
+                        TypeDefinition::Object(_) => 2,
+                        TypeDefinition::Union(_) => 3,
+                    }
+                }
+                index(self).cmp(&index(other))
+            }
+        }
+    }
+}
 impl<'de> de::Deserialize<'de> for TypeDefinition {
     fn deserialize<D>(d: D) -> Result<TypeDefinition, D::Error>
     where
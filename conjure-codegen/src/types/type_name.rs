@@ -7,6 +7,10 @@ pub struct TypeName {
     package: String,
 }
 impl TypeName {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "TypeName";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
     pub fn new<T, U>(name: T, package: U) -> TypeName
@@ -19,6 +23,19 @@ impl TypeName {
             package: package.into(),
         }
     }
+    #[doc = r" Constructs an instance of the type from a tuple of its fields, in declaration order."]
+    #[inline]
+    pub fn from_parts(parts: (String, String)) -> TypeName {
+        TypeName {
+            name: parts.0,
+            package: parts.1,
+        }
+    }
+    #[doc = r" Returns a tuple of the object's fields, in declaration order, consuming the object."]
+    #[inline]
+    pub fn into_parts(self) -> (String, String) {
+        (self.name, self.package)
+    }
     #[doc = r" Returns a new builder."]
     #[inline]
     pub fn builder() -> Builder {
@@ -34,6 +51,16 @@ impl TypeName {
     pub fn package(&self) -> &str {
         &*self.package
     }
+    #[doc = "Returns the `name` field, consuming the object."]
+    #[inline]
+    pub fn into_name(self) -> String {
+        self.name
+    }
+    #[doc = "Returns the `package` field, consuming the object."]
+    #[inline]
+    pub fn into_package(self) -> String {
+        self.package
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -61,6 +88,20 @@ impl Builder {
         self.package = Some(package.into());
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.name.is_some() {
+            self.name = other.name;
+        }
+        if other.package.is_some() {
+            self.package = other.package;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -102,6 +143,12 @@ impl<'de> de::Deserialize<'de> for TypeName {
     {
         d.deserialize_struct("TypeName", &["name", "package"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut TypeName) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct("TypeName", &["name", "package"], InPlaceVisitor_(place))
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -117,8 +164,18 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut package = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::Name => name = Some(map_.next_value()?),
-                Field_::Package => package = Some(map_.next_value()?),
+                Field_::Name => {
+                    if name.is_some() {
+                        return Err(de::Error::duplicate_field("name"));
+                    }
+                    name = Some(map_.next_value()?);
+                }
+                Field_::Package => {
+                    if package.is_some() {
+                        return Err(de::Error::duplicate_field("package"));
+                    }
+                    package = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -135,6 +192,51 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         Ok(TypeName { name, package })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut TypeName);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut name = false;
+        let mut package = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::Name => {
+                    if name {
+                        return Err(de::Error::duplicate_field("name"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(&mut place_.name))?;
+                    name = true;
+                }
+                Field_::Package => {
+                    if package {
+                        return Err(de::Error::duplicate_field("package"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(
+                        &mut place_.package,
+                    ))?;
+                    package = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !name {
+            return Err(de::Error::missing_field("name"));
+        }
+        if !package {
+            return Err(de::Error::missing_field("package"));
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     Name,
     Package,
@@ -166,3 +268,10 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `name` field."]
+    pub const NAME: &str = "name";
+    #[doc = "The wire name of the `package` field."]
+    pub const PACKAGE: &str = "package";
+}
@@ -8,22 +8,49 @@ pub struct UnionDefinition {
     docs: Option<super::Documentation>,
 }
 impl UnionDefinition {
+    #[doc = r" The Conjure name of the type."]
+    pub const CONJURE_TYPE_NAME: &str = "UnionDefinition";
+    #[doc = r" The Conjure package of the type."]
+    pub const CONJURE_PACKAGE: &str = "com.palantir.conjure.spec";
     #[doc = r" Constructs a new instance of the type."]
     #[inline]
-    pub fn new<T>(
-        type_name: super::TypeName,
-        union_: T,
-        docs: super::Documentation,
-    ) -> UnionDefinition
+    pub fn new<T, U>(type_name: super::TypeName, union_: T, docs: U) -> UnionDefinition
     where
         T: IntoIterator<Item = super::FieldDefinition>,
+        U: Into<super::Documentation>,
     {
         UnionDefinition {
             type_name: Box::new(type_name),
             union_: union_.into_iter().collect(),
-            docs: Some(docs),
+            docs: Some(docs.into()),
         }
     }
+    #[doc = r" Constructs an instance of the type from a tuple of its fields, in declaration order."]
+    #[inline]
+    pub fn from_parts(
+        parts: (
+            super::TypeName,
+            Vec<super::FieldDefinition>,
+            Option<super::Documentation>,
+        ),
+    ) -> UnionDefinition {
+        UnionDefinition {
+            type_name: Box::new(parts.0),
+            union_: parts.1,
+            docs: parts.2,
+        }
+    }
+    #[doc = r" Returns a tuple of the object's fields, in declaration order, consuming the object."]
+    #[inline]
+    pub fn into_parts(
+        self,
+    ) -> (
+        super::TypeName,
+        Vec<super::FieldDefinition>,
+        Option<super::Documentation>,
+    ) {
+        (*self.type_name, self.union_, self.docs)
+    }
     #[doc = r" Returns a new builder."]
     #[inline]
     pub fn builder() -> Builder {
@@ -41,6 +68,29 @@ impl UnionDefinition {
     pub fn docs(&self) -> Option<&super::Documentation> {
         self.docs.as_ref().map(|o| &*o)
     }
+    #[doc = "Returns the `type_name` field, consuming the object."]
+    #[inline]
+    pub fn into_type_name(self) -> super::TypeName {
+        *self.type_name
+    }
+    #[doc = "Returns the `union_` field, consuming the object."]
+    #[inline]
+    pub fn into_union(self) -> Vec<super::FieldDefinition> {
+        self.union_
+    }
+    #[doc = "Returns the `docs` field, consuming the object."]
+    #[inline]
+    pub fn into_docs(self) -> Option<super::Documentation> {
+        self.docs
+    }
+    #[doc = "Returns the `docs` field, or an error if it is absent."]
+    #[inline]
+    pub fn require_docs(&self) -> Result<&super::Documentation, conjure_object::MissingField> {
+        match &self.docs {
+            Some(o) => Ok(&*o),
+            None => Err(conjure_object::MissingField::new("UnionDefinition", "docs")),
+        }
+    }
 }
 #[derive(Debug, Clone, Default)]
 pub struct Builder {
@@ -81,6 +131,23 @@ impl Builder {
         self.docs = docs.into();
         self
     }
+    #[doc = r" Merges the fields set in another builder into this one."]
+    #[doc = r""]
+    #[doc = r" Fields set in `other` take precedence over those set in this builder. Collection fields are considered"]
+    #[doc = r" set if they are nonempty."]
+    #[inline]
+    pub fn merge(&mut self, other: Builder) -> &mut Self {
+        if other.type_name.is_some() {
+            self.type_name = other.type_name;
+        }
+        if !other.union_.is_empty() {
+            self.union_ = other.union_;
+        }
+        if !other.docs.is_none() {
+            self.docs = other.docs;
+        }
+        self
+    }
     #[doc = r" Constructs a new instance of the type."]
     #[doc = r""]
     #[doc = r" # Panics"]
@@ -137,6 +204,16 @@ impl<'de> de::Deserialize<'de> for UnionDefinition {
     {
         d.deserialize_struct("UnionDefinition", &["typeName", "union", "docs"], Visitor_)
     }
+    fn deserialize_in_place<D>(d: D, place: &mut UnionDefinition) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct(
+            "UnionDefinition",
+            &["typeName", "union", "docs"],
+            InPlaceVisitor_(place),
+        )
+    }
 }
 struct Visitor_;
 impl<'de> de::Visitor<'de> for Visitor_ {
@@ -153,9 +230,24 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         let mut docs = None;
         while let Some(field_) = map_.next_key()? {
             match field_ {
-                Field_::TypeName => type_name = Some(map_.next_value()?),
-                Field_::Union => union_ = Some(map_.next_value()?),
-                Field_::Docs => docs = Some(map_.next_value()?),
+                Field_::TypeName => {
+                    if type_name.is_some() {
+                        return Err(de::Error::duplicate_field("typeName"));
+                    }
+                    type_name = Some(map_.next_value()?);
+                }
+                Field_::Union => {
+                    if union_.is_some() {
+                        return Err(de::Error::duplicate_field("union"));
+                    }
+                    union_ = Some(map_.next_value()?);
+                }
+                Field_::Docs => {
+                    if docs.is_some() {
+                        return Err(de::Error::duplicate_field("docs"));
+                    }
+                    docs = Some(map_.next_value()?);
+                }
                 Field_::Unknown_ => {
                     map_.next_value::<de::IgnoredAny>()?;
                 }
@@ -180,6 +272,62 @@ impl<'de> de::Visitor<'de> for Visitor_ {
         })
     }
 }
+struct InPlaceVisitor_<'a>(&'a mut UnionDefinition);
+impl<'a, 'de> de::Visitor<'de> for InPlaceVisitor_<'a> {
+    type Value = ();
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("map")
+    }
+    fn visit_map<A>(self, mut map_: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let place_ = self.0;
+        let mut type_name = false;
+        let mut union_ = false;
+        let mut docs = false;
+        while let Some(field_) = map_.next_key()? {
+            match field_ {
+                Field_::TypeName => {
+                    if type_name {
+                        return Err(de::Error::duplicate_field("typeName"));
+                    }
+                    place_.type_name = map_.next_value()?;
+                    type_name = true;
+                }
+                Field_::Union => {
+                    if union_ {
+                        return Err(de::Error::duplicate_field("union"));
+                    }
+                    map_.next_value_seed(conjure_object::private::InPlaceSeed_(
+                        &mut place_.union_,
+                    ))?;
+                    union_ = true;
+                }
+                Field_::Docs => {
+                    if docs {
+                        return Err(de::Error::duplicate_field("docs"));
+                    }
+                    place_.docs = map_.next_value()?;
+                    docs = true;
+                }
+                Field_::Unknown_ => {
+                    map_.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        if !type_name {
+            return Err(de::Error::missing_field("typeName"));
+        }
+        if !union_ {
+            place_.union_.clear();
+        }
+        if !docs {
+            place_.docs = Default::default();
+        }
+        Ok(())
+    }
+}
 enum Field_ {
     TypeName,
     Union,
@@ -213,3 +361,12 @@ impl<'de> de::Visitor<'de> for FieldVisitor_ {
         Ok(v)
     }
 }
+#[doc = r" The wire names of the object's fields."]
+pub mod fields {
+    #[doc = "The wire name of the `typeName` field."]
+    pub const TYPE_NAME: &str = "typeName";
+    #[doc = "The wire name of the `union` field."]
+    pub const UNION_: &str = "union";
+    #[doc = "The wire name of the `docs` field."]
+    pub const DOCS: &str = "docs";
+}
@@ -11,7 +11,8 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use proc_macro2::{Ident, TokenStream};
+use failure::{bail, Error};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::quote;
 use std::iter;
 
@@ -20,6 +21,8 @@ use crate::types::UnionDefinition;
 
 pub fn generate(ctx: &Context, def: &UnionDefinition) -> TokenStream {
     let enum_ = generate_enum(ctx, def);
+    let ord = generate_ord(ctx, def);
+    let hash = generate_hash(ctx, def);
     let deserialize = generate_deserialize(ctx, def);
     let variant = generate_variant(ctx, def);
     let unknown = generate_unknown(ctx, def);
@@ -31,6 +34,8 @@ pub fn generate(ctx: &Context, def: &UnionDefinition) -> TokenStream {
         use std::fmt;
 
         #enum_
+        #ord
+        #hash
         #deserialize
         #variant
         #unknown
@@ -44,6 +49,34 @@ fn variants(ctx: &Context, def: &UnionDefinition) -> Vec<Ident> {
         .collect()
 }
 
+/// Returns the path used to construct and match a variant of the union.
+///
+/// A flattened union is a newtype of its only variant's value, so the path is to the type itself.
+pub fn variant_path(
+    ctx: &Context,
+    def: &UnionDefinition,
+    name: &TokenStream,
+    variant: &Ident,
+) -> TokenStream {
+    if ctx.is_flattened(def) {
+        name.clone()
+    } else {
+        quote!(#name::#variant)
+    }
+}
+
+fn variant_paths(ctx: &Context, def: &UnionDefinition, name: &Ident) -> Vec<TokenStream> {
+    let name = quote!(#name);
+    variants(ctx, def)
+        .iter()
+        .map(|v| variant_path(ctx, def, &name, v))
+        .collect()
+}
+
+fn has_unknown(ctx: &Context, def: &UnionDefinition) -> bool {
+    !ctx.exhaustive() && !ctx.is_flattened(def)
+}
+
 fn unknown(ctx: &Context, def: &UnionDefinition) -> TokenStream {
     if variants(ctx, def).iter().any(|f| f == "Unknown") {
         quote!(Unknown_)
@@ -52,15 +85,21 @@ fn unknown(ctx: &Context, def: &UnionDefinition) -> TokenStream {
     }
 }
 
+fn has_double(ctx: &Context, def: &UnionDefinition) -> bool {
+    def.union_().iter().any(|v| ctx.has_double(v.type_()))
+}
+
 fn generate_enum(ctx: &Context, def: &UnionDefinition) -> TokenStream {
+    let vis = ctx.visibility();
     let name = ctx.type_name(def.type_name().name());
     let result = ctx.result_ident(def.type_name());
     let some = ctx.some_ident(def.type_name());
 
-    let mut derives = vec!["Debug", "Clone", "PartialEq", "PartialOrd"];
-    if !def.union_().iter().any(|v| ctx.has_double(v.type_())) {
+    let mut derives = vec!["Debug", "Clone", "PartialEq"];
+    if has_double(ctx, def) {
+        derives.push("PartialOrd");
+    } else {
         derives.push("Eq");
-        derives.push("Ord");
         derives.push("Hash");
     }
     let derives = derives.iter().map(|s| s.parse::<TokenStream>().unwrap());
@@ -76,16 +115,39 @@ fn generate_enum(ctx: &Context, def: &UnionDefinition) -> TokenStream {
         .collect::<Vec<_>>();
 
     let unknown = unknown(ctx, def);
-    let unknown_variant = if ctx.exhaustive() {
-        quote!()
-    } else {
+    let unknown_variant = if has_unknown(ctx, def) {
         quote! {
             /// An unknown variant.
             #unknown(#unknown),
         }
+    } else {
+        quote!()
     };
 
-    let serialize_unknown = if ctx.exhaustive() {
+    let type_ = if ctx.is_flattened(def) {
+        quote! {
+            #[derive(#(#derives),*)]
+            #vis struct #name(
+                #(
+                    #docs
+                    pub #types
+                )*
+            );
+        }
+    } else {
+        quote! {
+            #[derive(#(#derives),*)]
+            #vis enum #name {
+                #(
+                    #docs
+                    #variants(#types),
+                )*
+                #unknown_variant
+            }
+        }
+    };
+
+    let serialize_unknown = if !has_unknown(ctx, def) {
         quote!()
     } else {
         quote! {
@@ -102,16 +164,19 @@ fn generate_enum(ctx: &Context, def: &UnionDefinition) -> TokenStream {
         .map(|f| &f.field_name().0)
         .collect::<Vec<_>>();
     let variant_strs2 = variant_strs;
-    let name_repeat = iter::repeat(&name);
+    let values = def
+        .union_()
+        .iter()
+        .map(|f| ctx.serialize_value(f.type_(), quote!(value)));
+    let paths = variant_paths(ctx, def, &name);
+
+    let type_name_consts = ctx.type_name_consts(def.type_name());
 
     quote! {
-        #[derive(#(#derives),*)]
-        pub enum #name {
-            #(
-                #docs
-                #variants(#types),
-            )*
-            #unknown_variant
+        #type_
+
+        impl #name {
+            #type_name_consts
         }
 
         impl ser::Serialize for #name {
@@ -123,9 +188,9 @@ fn generate_enum(ctx: &Context, def: &UnionDefinition) -> TokenStream {
 
                 match self {
                     #(
-                        #name_repeat::#variants(value) => {
+                        #paths(value) => {
                             map.serialize_entry(&"type", &#variant_strs)?;
-                            map.serialize_entry(&#variant_strs2, value)?;
+                            map.serialize_entry(&#variant_strs2, #values)?;
                         }
                     )*
                     #serialize_unknown
@@ -137,6 +202,107 @@ fn generate_enum(ctx: &Context, def: &UnionDefinition) -> TokenStream {
     }
 }
 
+// Values are ordered first by the declaration order of their variants, and then by their payloads. Unknown variants
+// sort after all known variants, ordered by their type names.
+fn generate_ord(ctx: &Context, def: &UnionDefinition) -> TokenStream {
+    if has_double(ctx, def) {
+        return quote!();
+    }
+
+    let name = ctx.type_name(def.type_name().name());
+    let option = ctx.option_ident(def.type_name());
+    let some = ctx.some_ident(def.type_name());
+
+    let mut paths = variant_paths(ctx, def, &name);
+    if has_unknown(ctx, def) {
+        let unknown = unknown(ctx, def);
+        paths.push(quote!(#name::#unknown));
+    }
+    let paths = &paths;
+    let paths2 = paths;
+    let indices = (0..paths.len()).map(Literal::usize_unsuffixed);
+
+    let fallback = if paths.len() > 1 {
+        quote! {
+            _ => {
+                fn index(v: &#name) -> usize {
+                    match v {
+                        #(#paths(_) => #indices,)*
+                    }
+                }
+
+                index(self).cmp(&index(other))
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    quote! {
+        impl std::cmp::PartialOrd for #name {
+            #[inline]
+            fn partial_cmp(&self, other: &#name) -> #option<std::cmp::Ordering> {
+                #some(self.cmp(other))
+            }
+        }
+
+        impl std::cmp::Ord for #name {
+            fn cmp(&self, other: &#name) -> std::cmp::Ordering {
+                match (self, other) {
+                    #(
+                        (#paths(a), #paths2(b)) => a.cmp(b),
+                    )*
+                    #fallback
+                }
+            }
+        }
+    }
+}
+
+// Only the variants of unions containing doubles are hashed along with the payloads which don't contain doubles. Values
+// which compare equal are in the same variant and have equal non-double payloads, so this is consistent with `Eq`.
+fn generate_hash(ctx: &Context, def: &UnionDefinition) -> TokenStream {
+    if !ctx.union_double_hash() || !has_double(ctx, def) {
+        return quote!();
+    }
+
+    let name = ctx.type_name(def.type_name().name());
+
+    let paths = variant_paths(ctx, def, &name);
+    let mut arms = def
+        .union_()
+        .iter()
+        .zip(&paths)
+        .map(|(f, path)| {
+            if ctx.has_double(f.type_()) {
+                quote!(#path(_) => {})
+            } else {
+                quote!(#path(v) => std::hash::Hash::hash(v, hasher),)
+            }
+        })
+        .collect::<Vec<_>>();
+    if has_unknown(ctx, def) {
+        let unknown = unknown(ctx, def);
+        arms.push(quote!(#name::#unknown(v) => std::hash::Hash::hash(v, hasher),));
+    }
+
+    quote! {
+        impl std::cmp::Eq for #name {}
+
+        impl std::hash::Hash for #name {
+            fn hash<H>(&self, hasher: &mut H)
+            where
+                H: std::hash::Hasher,
+            {
+                std::hash::Hash::hash(&std::mem::discriminant(self), hasher);
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    }
+}
+
 fn generate_deserialize(ctx: &Context, def: &UnionDefinition) -> TokenStream {
     let name = ctx.type_name(def.type_name().name());
     let result = ctx.result_ident(def.type_name());
@@ -147,16 +313,15 @@ fn generate_deserialize(ctx: &Context, def: &UnionDefinition) -> TokenStream {
 
     let variants = &variants(ctx, def);
     let variants2 = variants;
-    let variants3 = variants;
+    let paths = &variant_paths(ctx, def, &name);
 
-    let name_repeat = iter::repeat(&name);
     let some_repeat = iter::repeat(&some);
 
     let unknown = unknown(ctx, def);
 
     let err = ctx.err_ident(def.type_name());
 
-    let unknown_match1 = if ctx.exhaustive() {
+    let unknown_match1 = if !has_unknown(ctx, def) {
         quote!()
     } else {
         quote! {
@@ -171,11 +336,22 @@ fn generate_deserialize(ctx: &Context, def: &UnionDefinition) -> TokenStream {
         }
     };
 
-    let none = ctx.none_ident(def.type_name());
+    // a union with a single known variant can't have a mismatched key
+    let mismatch = if variants.len() > 1 || has_unknown(ctx, def) {
+        quote! {
+            (variant, #some(key)) => {
+                return #err(
+                    de::Error::invalid_value(de::Unexpected::Str(key.as_str()), &variant.as_str()),
+                );
+            }
+        }
+    } else {
+        quote!()
+    };
 
-    let name_repeat2 = iter::repeat(&name);
+    let none = ctx.none_ident(def.type_name());
 
-    let unknown_match2 = if ctx.exhaustive() {
+    let unknown_match2 = if !has_unknown(ctx, def) {
         quote!()
     } else {
         quote! {
@@ -187,6 +363,7 @@ fn generate_deserialize(ctx: &Context, def: &UnionDefinition) -> TokenStream {
     };
 
     let ok = ctx.ok_ident(def.type_name());
+    let span = ctx.deserialize_span(def.type_name());
 
     quote! {
         impl<'de> de::Deserialize<'de> for #name {
@@ -194,6 +371,7 @@ fn generate_deserialize(ctx: &Context, def: &UnionDefinition) -> TokenStream {
             where
                 D: de::Deserializer<'de>
             {
+                #span
                 d.deserialize_map(Visitor_)
             }
         }
@@ -219,24 +397,23 @@ fn generate_deserialize(ctx: &Context, def: &UnionDefinition) -> TokenStream {
                             #(
                                 (Variant_::#variants, #some_repeat(Variant_::#variants2)) => {
                                     let value = map.next_value()?;
-                                    #name_repeat::#variants3(value)
+                                    #paths(value)
                                 }
                             )*
                             #unknown_match1
-                            (variant, #some(key)) => {
-                                return #err(
-                                    de::Error::invalid_value(de::Unexpected::Str(key.as_str()), &variant.as_str()),
-                                );
-                            }
+                            #mismatch
                             (variant, #none) => return #err(de::Error::missing_field(variant.as_str())),
                         }
                     }
+                    // The type tag can only be validated after the value when it comes second, since map entries
+                    // must be consumed in order. The value is deserialized directly into the variant's type rather
+                    // than buffered, so a mismatched tag costs no more than the value parse itself.
                     #some(UnionField_::Value(variant)) => {
                         let value = match &variant {
                             #(
                                 Variant_::#variants => {
                                     let value = map.next_value()?;
-                                    #name_repeat2::#variants2(value)
+                                    #paths(value)
                                 }
                             )*
                             #unknown_match2
@@ -273,7 +450,7 @@ fn generate_variant(ctx: &Context, def: &UnionDefinition) -> TokenStream {
 
     let unknown = unknown(ctx, def);
 
-    let unknown_variant = if ctx.exhaustive() {
+    let unknown_variant = if !has_unknown(ctx, def) {
         quote!()
     } else {
         let box_ = ctx.box_ident(def.type_name());
@@ -286,7 +463,7 @@ fn generate_variant(ctx: &Context, def: &UnionDefinition) -> TokenStream {
         .map(|f| &f.field_name().0)
         .collect::<Vec<_>>();
 
-    let unknown_as_str = if ctx.exhaustive() {
+    let unknown_as_str = if !has_unknown(ctx, def) {
         quote!()
     } else {
         quote! {
@@ -296,7 +473,7 @@ fn generate_variant(ctx: &Context, def: &UnionDefinition) -> TokenStream {
 
     let result = ctx.result_ident(def.type_name());
 
-    let unknown_de_visit_str = if ctx.exhaustive() {
+    let unknown_de_visit_str = if !has_unknown(ctx, def) {
         let err = ctx.err_ident(def.type_name());
         quote! {
             value => return #err(de::Error::unknown_variant(value, &[#(#variant_strs, )*])),
@@ -363,7 +540,8 @@ fn generate_variant(ctx: &Context, def: &UnionDefinition) -> TokenStream {
 }
 
 fn generate_unknown(ctx: &Context, def: &UnionDefinition) -> TokenStream {
-    if ctx.exhaustive() {
+    let vis = ctx.visibility();
+    if !has_unknown(ctx, def) {
         return quote!();
     }
 
@@ -374,11 +552,13 @@ fn generate_unknown(ctx: &Context, def: &UnionDefinition) -> TokenStream {
 
     let unknown = unknown(ctx, def);
     let box_ = ctx.box_ident(def.type_name());
+    let from = ctx.from_ident(def.type_name());
+    let string = ctx.string_ident(def.type_name());
 
     quote! {
         #[doc = #doc]
         #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-        pub struct #unknown {
+        #vis struct #unknown {
             type_: #box_<str>,
             value: conjure_object::Value,
         }
@@ -386,9 +566,143 @@ fn generate_unknown(ctx: &Context, def: &UnionDefinition) -> TokenStream {
         impl #unknown {
             /// Returns the unknown variant's type name.
             #[inline]
-            pub fn type_(&self) -> &str {
+            #vis fn type_(&self) -> &str {
                 &self.type_
             }
+
+            /// Returns the unknown variant's value.
+            #[inline]
+            #vis fn value(&self) -> &conjure_object::Value {
+                &self.value
+            }
+        }
+
+        impl #from<#unknown> for (#string, conjure_object::Value) {
+            #[inline]
+            fn from(v: #unknown) -> (#string, conjure_object::Value) {
+                (v.type_.into(), v.value)
+            }
         }
     }
 }
+
+pub fn iter_ext_name(ctx: &Context, def: &UnionDefinition) -> Ident {
+    let name = format!("{}IterExt", ctx.type_name(def.type_name().name()));
+    Ident::new(&name, Span::call_site())
+}
+
+pub fn generate_iter_ext(ctx: &Context, def: &UnionDefinition) -> TokenStream {
+    let vis = ctx.visibility();
+    let name = ctx.type_name(def.type_name().name());
+    let ext = iter_ext_name(ctx, def);
+    let option = ctx.option_ident(def.type_name());
+    let some = ctx.some_ident(def.type_name());
+    let none = ctx.none_ident(def.type_name());
+
+    let doc = format!("Extension methods for iterators over {} values.", name);
+
+    let fallback = if def.union_().len() == 1 && !has_unknown(ctx, def) {
+        quote!()
+    } else {
+        quote!(_ => #none,)
+    };
+
+    let methods = def.union_().iter().map(|f| {
+        let method = ctx.field_name(&format!("{}s", f.field_name().0));
+        let variant = variant_path(ctx, def, &quote!(#name), &ctx.type_name(f.field_name()));
+        let type_ = ctx.boxed_rust_type(def.type_name(), f.type_());
+        let doc = format!(
+            "Returns an iterator over the values of the `{}` variants.",
+            f.field_name().0
+        );
+
+        quote! {
+            #[doc = #doc]
+            #[inline]
+            fn #method(self) -> std::iter::FilterMap<Self, fn(#name) -> #option<#type_>> {
+                let f: fn(#name) -> #option<#type_> = |v| match v {
+                    #variant(v) => #some(v),
+                    #fallback
+                };
+                self.filter_map(f)
+            }
+        }
+    });
+
+    quote! {
+        #[doc = #doc]
+        #vis trait #ext: Iterator<Item = #name> + Sized {
+            #(#methods)*
+        }
+
+        impl<I> #ext for I
+        where
+            I: Iterator<Item = #name>,
+        {
+        }
+    }
+}
+
+pub fn generate_conversion(
+    ctx: &Context,
+    source: &UnionDefinition,
+    target: &UnionDefinition,
+) -> Result<TokenStream, Error> {
+    let vis = ctx.visibility();
+    let source_name = ctx.type_name(source.type_name().name());
+    let target_name = ctx.type_path(target.type_name());
+    let method = ctx.conversion_name(source.type_name());
+    let docs = format!(
+        "Converts a `{}` into the variant of this union with the same name, or returns it if there is none.",
+        source_name
+    );
+    let result = ctx.result_ident(source.type_name());
+    let ok = ctx.ok_ident(source.type_name());
+    let err = ctx.err_ident(source.type_name());
+
+    let mut arms = vec![];
+    for field in source.union_() {
+        let target_field = match target
+            .union_()
+            .iter()
+            .find(|f| f.field_name() == field.field_name())
+        {
+            Some(target_field) => target_field,
+            None => continue,
+        };
+
+        if target_field.type_() != field.type_() {
+            bail!(
+                "variant {} has different types in unions {} and {}",
+                field.field_name().0,
+                source.type_name().name(),
+                target.type_name().name(),
+            );
+        }
+
+        let variant = ctx.type_name(field.field_name());
+        let source_variant = variant_path(ctx, source, &quote!(#source_name), &variant);
+        let target_variant = variant_path(ctx, target, &target_name, &variant);
+        arms.push(quote! {
+            #source_variant(v) => #ok(#target_variant(v)),
+        });
+    }
+
+    let fallback = if arms.len() == source.union_().len() && !has_unknown(ctx, source) {
+        quote!()
+    } else {
+        quote!(v => #err(v),)
+    };
+
+    Ok(quote! {
+        impl #target_name {
+            #[doc = #docs]
+            #vis fn #method(v: #source_name) -> #result<#target_name, #source_name> {
+                match v {
+                    #(#arms)*
+                    #fallback
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,17 @@
+use std::env;
+use std::path::PathBuf;
+
+fn main() {
+    let input = "../conjure-test/test-ir.json";
+    let output = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("conjure");
+
+    println!("cargo:rerun-if-changed={}", input);
+    conjure_codegen::Config::new()
+        .run_rustfmt(false)
+        .json_schemas(true)
+        .tracing(true)
+        .write_only_field("WriteOnlyObject", "secret")
+        .write_only_field("WriteOnlyObject", "token")
+        .generate_files(input, output)
+        .unwrap();
+}
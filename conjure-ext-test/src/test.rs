@@ -0,0 +1,106 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use crate::types::*;
+
+#[test]
+fn json_schema() {
+    let schema = conjure_ext::schemars::schema_for!(ConstFields);
+    let schema = serde_json::to_value(&schema).unwrap();
+
+    assert_eq!(schema["title"], "ConstFields");
+    assert_eq!(schema["type"], "object");
+    assert_eq!(
+        schema["properties"]["integer"],
+        serde_json::json!({"type": "integer", "format": "int32"}),
+    );
+    assert_eq!(
+        schema["properties"]["optional"],
+        serde_json::json!({"anyOf": [{"type": "integer", "format": "int32"}, {"type": "null"}]}),
+    );
+    assert!(schema["properties"]["double"].is_object());
+    assert_eq!(schema["required"], serde_json::json!(["double", "integer"]));
+
+    let schema = conjure_ext::schemars::schema_for!(WriteOnlyObject);
+    let schema = serde_json::to_value(&schema).unwrap();
+    assert_eq!(schema["properties"]["secret"]["writeOnly"], true);
+    assert!(schema["properties"]["name"].get("writeOnly").is_none());
+}
+
+#[test]
+fn tracing_spans() {
+    use conjure_ext::tracing::field::{Field, Visit};
+    use conjure_ext::tracing::span::{Attributes, Id, Record};
+    use conjure_ext::tracing::{subscriber, Event, Metadata, Subscriber};
+    use std::fmt;
+    use std::sync::Mutex;
+
+    // records the types of the deserialize spans created and the order they were entered in
+    #[derive(Clone, Default)]
+    struct TestSubscriber {
+        spans: Arc<Mutex<Vec<String>>>,
+        entered: Arc<Mutex<Vec<String>>>,
+    }
+
+    struct TypeVisitor(String);
+
+    impl Visit for TypeVisitor {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "conjure_type" {
+                self.0 = value.to_string();
+            }
+        }
+
+        fn record_debug(&mut self, _: &Field, _: &dyn fmt::Debug) {}
+    }
+
+    impl Subscriber for TestSubscriber {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            assert_eq!(span.metadata().name(), "deserialize");
+            let mut visitor = TypeVisitor(String::new());
+            span.record(&mut visitor);
+            let mut spans = self.spans.lock().unwrap();
+            spans.push(visitor.0);
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, _: &Event<'_>) {}
+
+        fn enter(&self, span: &Id) {
+            let name = self.spans.lock().unwrap()[span.into_u64() as usize - 1].clone();
+            self.entered.lock().unwrap().push(name);
+        }
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    let test_subscriber = TestSubscriber::default();
+    let value = subscriber::with_default(test_subscriber.clone(), || {
+        conjure_serde::json::client_from_str::<ObjectAlias>(r#"{"foo":1}"#).unwrap()
+    });
+    assert_eq!(value, ObjectAlias(TestObject::new(1)));
+    assert_eq!(
+        *test_subscriber.entered.lock().unwrap(),
+        vec!["ObjectAlias".to_string(), "TestObject".to_string()]
+    );
+}
@@ -0,0 +1,37 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Optional third party integrations for Conjure types.
+//!
+//! This crate is a required dependency of crates containing Conjure-generated code which use the optional integrations
+//! of `conjure-codegen`. Each integration is enabled by a Cargo feature:
+//!
+//! * `schemars` - JSON schemas for generated types.
+//! * `tracing` - Spans around the deserialization of generated types.
+//!
+//! The integrations depend on crates requiring a newer version of Rust than the rest of Conjure, so unlike the other
+//! Conjure crates this one is not part of the workspace built with the minimum supported Rust version.
+#![warn(clippy::all, missing_docs)]
+#![doc(html_root_url = "https://docs.rs/conjure-ext/0.1")]
+
+#[cfg(feature = "schemars")]
+pub use schemars;
+#[cfg(feature = "tracing")]
+pub use tracing;
+
+#[cfg(feature = "schemars")]
+pub mod schema;
+
+#[doc(hidden)]
+pub mod private;
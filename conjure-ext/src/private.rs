@@ -0,0 +1,103 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#[cfg(feature = "schemars")]
+use schemars::schema::{InstanceType, Schema, SchemaObject, SubschemaValidation};
+
+#[cfg(feature = "schemars")]
+pub fn object_schema(fields: Vec<(&str, Schema, bool)>) -> Schema {
+    let mut schema = SchemaObject {
+        instance_type: Some(InstanceType::Object.into()),
+        ..SchemaObject::default()
+    };
+
+    let object = schema.object();
+    for (name, field, required) in fields {
+        object.properties.insert(name.to_string(), field);
+        if required {
+            object.required.insert(name.to_string());
+        }
+    }
+
+    schema.into()
+}
+
+#[cfg(feature = "schemars")]
+pub fn enum_schema(values: &[&str]) -> Schema {
+    SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        enum_values: Some(values.iter().map(|v| (*v).into()).collect()),
+        ..SchemaObject::default()
+    }
+    .into()
+}
+
+// Unions are serialized as a map containing a `type` field with the variant name and a field named after the variant
+// containing its value.
+#[cfg(feature = "schemars")]
+pub fn union_schema(variants: Vec<(&str, Schema)>) -> Schema {
+    let variants = variants
+        .into_iter()
+        .map(|(name, variant)| {
+            let mut schema = object_schema(vec![
+                ("type", enum_schema(&[name]), true),
+                (name, variant, true),
+            ])
+            .into_object();
+            schema.object().additional_properties = Some(Box::new(Schema::Bool(false)));
+            schema.into()
+        })
+        .collect();
+
+    SchemaObject {
+        subschemas: Some(Box::new(SubschemaValidation {
+            one_of: Some(variants),
+            ..SubschemaValidation::default()
+        })),
+        ..SchemaObject::default()
+    }
+    .into()
+}
+
+#[cfg(feature = "schemars")]
+pub fn describe_schema(schema: Schema, docs: &str) -> Schema {
+    let mut schema = annotatable_schema(schema);
+    schema.metadata().description = Some(docs.to_string());
+    schema.into()
+}
+
+// Write-only fields are accepted when deserialized but never serialized.
+#[cfg(feature = "schemars")]
+pub fn write_only_schema(schema: Schema) -> Schema {
+    let mut schema = annotatable_schema(schema);
+    schema.metadata().write_only = true;
+    schema.into()
+}
+
+#[cfg(feature = "schemars")]
+fn annotatable_schema(schema: Schema) -> SchemaObject {
+    let schema = schema.into_object();
+
+    // metadata can't be attached directly to a reference to another schema
+    if schema.is_ref() {
+        SchemaObject {
+            subschemas: Some(Box::new(SubschemaValidation {
+                all_of: Some(vec![schema.into()]),
+                ..SubschemaValidation::default()
+            })),
+            ..SchemaObject::default()
+        }
+    } else {
+        schema
+    }
+}
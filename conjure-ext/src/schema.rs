@@ -0,0 +1,164 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! JSON schemas for Conjure's builtin types.
+//!
+//! These are used by generated `JsonSchema` implementations for Conjure types, but can be used directly as well. This
+//! module is only present when the `schemars` Cargo feature is enabled, which requires Rust 1.60 or newer.
+use schemars::schema::{
+    ArrayValidation, InstanceType, NumberValidation, ObjectValidation, Schema, SchemaObject,
+    SubschemaValidation,
+};
+
+use conjure_object::SafeLong;
+
+fn typed(instance_type: InstanceType, format: Option<&str>) -> SchemaObject {
+    SchemaObject {
+        instance_type: Some(instance_type.into()),
+        format: format.map(str::to_string),
+        ..SchemaObject::default()
+    }
+}
+
+/// Returns a schema matching Conjure `string` values.
+pub fn string() -> Schema {
+    typed(InstanceType::String, None).into()
+}
+
+/// Returns a schema matching Conjure `datetime` values.
+pub fn date_time() -> Schema {
+    typed(InstanceType::String, Some("date-time")).into()
+}
+
+/// Returns a schema matching Conjure `integer` values.
+pub fn integer() -> Schema {
+    typed(InstanceType::Integer, Some("int32")).into()
+}
+
+/// Returns a schema matching Conjure `double` values.
+///
+/// Non-finite values are serialized as the strings `"NaN"`, `"Infinity"`, and `"-Infinity"`, so those are matched in
+/// addition to numbers.
+pub fn double() -> Schema {
+    let non_finite = SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        enum_values: Some(vec!["NaN".into(), "Infinity".into(), "-Infinity".into()]),
+        ..SchemaObject::default()
+    };
+
+    SchemaObject {
+        subschemas: Some(Box::new(SubschemaValidation {
+            any_of: Some(vec![
+                typed(InstanceType::Number, Some("double")).into(),
+                non_finite.into(),
+            ]),
+            ..SubschemaValidation::default()
+        })),
+        ..SchemaObject::default()
+    }
+    .into()
+}
+
+/// Returns a schema matching Conjure `safelong` values.
+pub fn safe_long() -> Schema {
+    SchemaObject {
+        number: Some(Box::new(NumberValidation {
+            minimum: Some(*SafeLong::min_value() as f64),
+            maximum: Some(*SafeLong::max_value() as f64),
+            ..NumberValidation::default()
+        })),
+        ..typed(InstanceType::Integer, Some("int64"))
+    }
+    .into()
+}
+
+/// Returns a schema matching Conjure `binary` values.
+pub fn binary() -> Schema {
+    typed(InstanceType::String, Some("byte")).into()
+}
+
+/// Returns a schema matching Conjure `any` values.
+pub fn any() -> Schema {
+    Schema::Bool(true)
+}
+
+/// Returns a schema matching Conjure `boolean` values.
+pub fn boolean() -> Schema {
+    typed(InstanceType::Boolean, None).into()
+}
+
+/// Returns a schema matching Conjure `uuid` values.
+pub fn uuid() -> Schema {
+    typed(InstanceType::String, Some("uuid")).into()
+}
+
+/// Returns a schema matching Conjure `rid` values.
+pub fn resource_identifier() -> Schema {
+    typed(InstanceType::String, None).into()
+}
+
+/// Returns a schema matching Conjure `bearertoken` values.
+pub fn bearer_token() -> Schema {
+    typed(InstanceType::String, None).into()
+}
+
+/// Returns a schema matching Conjure `optional` values of the provided item schema.
+pub fn optional(item: Schema) -> Schema {
+    SchemaObject {
+        subschemas: Some(Box::new(SubschemaValidation {
+            any_of: Some(vec![item, typed(InstanceType::Null, None).into()]),
+            ..SubschemaValidation::default()
+        })),
+        ..SchemaObject::default()
+    }
+    .into()
+}
+
+/// Returns a schema matching Conjure `list` values of the provided item schema.
+pub fn list(item: Schema) -> Schema {
+    SchemaObject {
+        array: Some(Box::new(ArrayValidation {
+            items: Some(item.into()),
+            ..ArrayValidation::default()
+        })),
+        ..typed(InstanceType::Array, None)
+    }
+    .into()
+}
+
+/// Returns a schema matching Conjure `set` values of the provided item schema.
+pub fn set(item: Schema) -> Schema {
+    SchemaObject {
+        array: Some(Box::new(ArrayValidation {
+            items: Some(item.into()),
+            unique_items: Some(true),
+            ..ArrayValidation::default()
+        })),
+        ..typed(InstanceType::Array, None)
+    }
+    .into()
+}
+
+/// Returns a schema matching Conjure `map` values of the provided value schema.
+///
+/// Map keys are always serialized as JSON strings.
+pub fn map(value: Schema) -> Schema {
+    SchemaObject {
+        object: Some(Box::new(ObjectValidation {
+            additional_properties: Some(Box::new(value)),
+            ..ObjectValidation::default()
+        })),
+        ..typed(InstanceType::Object, None)
+    }
+    .into()
+}
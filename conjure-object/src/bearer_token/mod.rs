@@ -13,12 +13,16 @@
 // limitations under the License.
 
 //! The Conjure `bearertoken` type.
-use serde::de::{self, Deserialize, Deserializer, Unexpected};
+use serde::de::{self, Deserialize, Deserializer, Unexpected, Visitor};
 use serde::ser::{Serialize, Serializer};
 use std::borrow::Borrow;
 use std::error::Error;
 use std::fmt;
+#[cfg(feature = "zeroize")]
+use std::mem;
 use std::str::FromStr;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
 
 #[cfg(test)]
 mod test;
@@ -59,7 +63,9 @@ static VALID_CHARS: [u8; 256] = [
 
 /// An authentication bearer token.
 ///
-/// Bearer tokens are strings which match the regular expression `^[A-Za-z0-9\-\._~\+/]+=*$`.
+/// Bearer tokens are strings which match the regular expression `^[A-Za-z0-9\-\._~\+/]+=*$`. Their contents are
+/// omitted from the `Debug` representation and from deserialization errors, and are overwritten with zeros when dropped
+/// if the `zeroize` Cargo feature is enabled. That feature requires a newer version of Rust than the rest of the crate.
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct BearerToken(String);
 
@@ -79,10 +85,27 @@ impl BearerToken {
     }
 
     /// Consumes the bearer token, returning its owned string representation.
+    #[cfg(not(feature = "zeroize"))]
     #[inline]
     pub fn into_string(self) -> String {
         self.0
     }
+
+    /// Consumes the bearer token, returning its owned string representation.
+    ///
+    /// The returned string will not be zeroed when dropped.
+    #[cfg(feature = "zeroize")]
+    #[inline]
+    pub fn into_string(mut self) -> String {
+        mem::replace(&mut self.0, String::new())
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for BearerToken {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
 }
 
 impl AsRef<str> for BearerToken {
@@ -131,17 +154,89 @@ impl<'de> Deserialize<'de> for BearerToken {
     where
         D: Deserializer<'de>,
     {
-        let s = String::deserialize(d)?;
+        // self-describing formats report values of the wrong type themselves when asked for a string, so we take any
+        // value to keep the contents of misplaced tokens out of the errors
+        if d.is_human_readable() {
+            d.deserialize_any(BearerTokenVisitor)
+        } else {
+            d.deserialize_string(BearerTokenVisitor)
+        }
+    }
+}
 
-        if is_valid(&s) {
-            Ok(BearerToken(s))
+struct BearerTokenVisitor;
+
+impl<'de> Visitor<'de> for BearerTokenVisitor {
+    type Value = BearerToken;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("a bearer token")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<BearerToken, E>
+    where
+        E: de::Error,
+    {
+        if is_valid(v) {
+            Ok(BearerToken(v.to_string()))
+        } else {
+            Err(invalid_value(&self))
+        }
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<BearerToken, E>
+    where
+        E: de::Error,
+    {
+        if is_valid(&v) {
+            Ok(BearerToken(v))
         } else {
-            Err(de::Error::invalid_value(
-                Unexpected::Str(&s),
-                &"a bearer token",
-            ))
+            Err(invalid_value(&self))
         }
     }
+
+    fn visit_bool<E>(self, _: bool) -> Result<BearerToken, E>
+    where
+        E: de::Error,
+    {
+        Err(E::invalid_type(Unexpected::Other("boolean"), &self))
+    }
+
+    fn visit_i64<E>(self, _: i64) -> Result<BearerToken, E>
+    where
+        E: de::Error,
+    {
+        Err(E::invalid_type(Unexpected::Other("integer"), &self))
+    }
+
+    fn visit_u64<E>(self, _: u64) -> Result<BearerToken, E>
+    where
+        E: de::Error,
+    {
+        Err(E::invalid_type(Unexpected::Other("integer"), &self))
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<BearerToken, E>
+    where
+        E: de::Error,
+    {
+        Err(E::invalid_type(Unexpected::Other("floating point"), &self))
+    }
+
+    fn visit_bytes<E>(self, _: &[u8]) -> Result<BearerToken, E>
+    where
+        E: de::Error,
+    {
+        Err(E::invalid_type(Unexpected::Other("byte array"), &self))
+    }
+}
+
+// Unlike serde's default, this doesn't include the value in the error.
+fn invalid_value<E>(visitor: &BearerTokenVisitor) -> E
+where
+    E: de::Error,
+{
+    E::invalid_value(Unexpected::Other("string"), visitor)
 }
 
 fn is_valid(s: &str) -> bool {
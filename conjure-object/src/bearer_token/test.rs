@@ -11,7 +11,7 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use proptest::{proptest, proptest_helper};
+use proptest::proptest;
 
 use super::*;
 
@@ -34,6 +34,22 @@ fn reject_bad_tokens() {
     "a\n".parse::<BearerToken>().unwrap_err();
 }
 
+#[test]
+fn parse_token() {
+    let token = "a-b.c_d~e+f/g==".parse::<BearerToken>().unwrap();
+    assert_eq!(token.as_str(), "a-b.c_d~e+f/g==");
+    assert_eq!(token.into_string(), "a-b.c_d~e+f/g==");
+
+    "a=b".parse::<BearerToken>().unwrap_err();
+}
+
+#[test]
+fn debug_redacted() {
+    let token = "secrettoken".parse::<BearerToken>().unwrap();
+    let debug = format!("{:?}", token);
+    assert!(!debug.contains("secrettoken"));
+}
+
 #[test]
 fn valid_chars_format() {
     for (i, &b) in VALID_CHARS.iter().enumerate() {
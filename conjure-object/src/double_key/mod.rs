@@ -0,0 +1,118 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A totally ordered wrapper of the Conjure `double` type.
+use serde::{de, ser};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+use crate::private::DoubleOrd_;
+
+#[cfg(test)]
+mod test;
+
+/// An f64 which implements `Eq`, `Ord`, and `Hash`, allowing it to be used in sets and as a map key.
+///
+/// Values are compared by their bit patterns according to IEEE 754's `totalOrder` predicate rather than numerically.
+/// As a result:
+///
+/// * `NaN` is equal to itself, though `NaN`s with different payloads or signs are distinct from each other.
+/// * `-0.0` and `0.0` are distinct, with `-0.0` ordered before `0.0`.
+/// * Positive `NaN`s are ordered after positive infinity, and negative `NaN`s before negative infinity.
+///
+/// It serializes and deserializes as the double it wraps.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct DoubleKey(pub f64);
+
+impl Deref for DoubleKey {
+    type Target = f64;
+
+    #[inline]
+    fn deref(&self) -> &f64 {
+        &self.0
+    }
+}
+
+impl PartialEq for DoubleKey {
+    #[inline]
+    fn eq(&self, other: &DoubleKey) -> bool {
+        self.0.eq_(&other.0)
+    }
+}
+
+impl Eq for DoubleKey {}
+
+impl PartialOrd for DoubleKey {
+    #[inline]
+    fn partial_cmp(&self, other: &DoubleKey) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DoubleKey {
+    #[inline]
+    fn cmp(&self, other: &DoubleKey) -> Ordering {
+        self.0.cmp_(&other.0)
+    }
+}
+
+impl Hash for DoubleKey {
+    #[inline]
+    fn hash<H>(&self, hasher: &mut H)
+    where
+        H: Hasher,
+    {
+        self.0.hash_(hasher)
+    }
+}
+
+impl fmt::Display for DoubleKey {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, fmt)
+    }
+}
+
+impl From<f64> for DoubleKey {
+    #[inline]
+    fn from(v: f64) -> DoubleKey {
+        DoubleKey(v)
+    }
+}
+
+impl From<DoubleKey> for f64 {
+    #[inline]
+    fn from(v: DoubleKey) -> f64 {
+        v.0
+    }
+}
+
+impl ser::Serialize for DoubleKey {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        self.0.serialize(s)
+    }
+}
+
+impl<'de> de::Deserialize<'de> for DoubleKey {
+    fn deserialize<D>(d: D) -> Result<DoubleKey, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        f64::deserialize(d).map(DoubleKey)
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use std::collections::{BTreeSet, HashSet};
+use std::f64;
+
+#[test]
+fn nan_in_set() {
+    let mut set = HashSet::new();
+    assert!(set.insert(DoubleKey(f64::NAN)));
+    assert!(!set.insert(DoubleKey(f64::NAN)));
+    assert!(set.contains(&DoubleKey(f64::NAN)));
+    assert!(set.insert(DoubleKey(-f64::NAN)));
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn signed_zeros_in_set() {
+    let mut set = HashSet::new();
+    assert!(set.insert(DoubleKey(0.0)));
+    assert!(set.insert(DoubleKey(-0.0)));
+    assert!(!set.insert(DoubleKey(0.0)));
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn total_order() {
+    let values = [
+        f64::NAN,
+        f64::INFINITY,
+        1.0,
+        0.0,
+        -0.0,
+        -1.0,
+        f64::NEG_INFINITY,
+        -f64::NAN,
+    ];
+    let sorted = values
+        .iter()
+        .map(|v| DoubleKey(*v))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|v| v.0.to_bits())
+        .collect::<Vec<_>>();
+    let expected = values.iter().rev().map(|v| v.to_bits()).collect::<Vec<_>>();
+    assert_eq!(sorted, expected);
+}
@@ -21,6 +21,8 @@
 #![doc(html_root_url = "https://docs.rs/conjure-object/0.1")]
 
 pub use chrono::{self, DateTime, Utc};
+#[cfg(feature = "proptest")]
+pub use proptest;
 pub use serde;
 pub use serde_bytes::{self, ByteBuf};
 pub use serde_value::{self, Value};
@@ -29,13 +31,36 @@ pub use uuid::{self, Uuid};
 #[doc(inline)]
 pub use crate::bearer_token::BearerToken;
 #[doc(inline)]
+pub use crate::double_key::DoubleKey;
+#[doc(inline)]
+pub use crate::missing_field::MissingField;
+#[doc(inline)]
+pub use crate::plain::{PlainDecode, PlainEncode};
+#[doc(inline)]
+pub use crate::raw_value::RawValue;
+#[doc(inline)]
 pub use crate::resource_identifier::ResourceIdentifier;
 #[doc(inline)]
 pub use crate::safe_long::SafeLong;
+#[doc(inline)]
+pub use crate::serializable_error::SerializableError;
+#[doc(inline)]
+pub use crate::uuid_ext::UuidExt;
+#[doc(inline)]
+pub use crate::value::{ValueExt, ValueIndex};
 
 pub mod bearer_token;
+pub mod double_key;
+pub mod missing_field;
+pub mod plain;
+pub mod raw_value;
 pub mod resource_identifier;
 pub mod safe_long;
+pub mod serializable_error;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+pub mod uuid_ext;
+pub mod value;
 
 #[doc(hidden)]
 pub mod private;
@@ -0,0 +1,61 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An error indicating that an optional field was absent.
+use std::error::Error;
+use std::fmt;
+
+/// The error returned from the `require_` accessors of generated objects when the optional field is absent.
+///
+/// It identifies the field by the Conjure names of the object type and of the field.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MissingField {
+    type_name: &'static str,
+    field_name: &'static str,
+}
+
+impl MissingField {
+    /// Creates a new error for the specified field.
+    #[inline]
+    pub fn new(type_name: &'static str, field_name: &'static str) -> MissingField {
+        MissingField {
+            type_name,
+            field_name,
+        }
+    }
+
+    /// Returns the name of the object type, such as `ManyFieldExample`.
+    #[inline]
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns the name of the absent field, such as `optionalItem`.
+    #[inline]
+    pub fn field_name(&self) -> &'static str {
+        self.field_name
+    }
+}
+
+impl fmt::Display for MissingField {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "field `{}` of `{}` is missing",
+            self.field_name, self.type_name
+        )
+    }
+}
+
+impl Error for MissingField {}
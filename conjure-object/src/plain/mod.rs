@@ -0,0 +1,188 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The Conjure PLAIN format.
+//!
+//! Conjure services encode values in path parameters, query parameters, and headers in the PLAIN format rather than
+//! as JSON. Strings, for example, are not quoted, and datetimes are written as bare ISO-8601 timestamps.
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::error::Error;
+use std::f64;
+use std::fmt;
+use uuid::Uuid;
+
+use crate::{BearerToken, ResourceIdentifier, SafeLong};
+
+#[cfg(test)]
+mod test;
+
+/// A trait for types which can be encoded in the PLAIN format.
+///
+/// Generated aliases of types implementing this trait also implement it.
+pub trait PlainEncode {
+    /// Returns the PLAIN representation of the value.
+    fn encode_plain(&self) -> String;
+}
+
+/// A trait for types which can be decoded from the PLAIN format.
+///
+/// Generated aliases of types implementing this trait also implement it.
+pub trait PlainDecode: Sized {
+    /// Parses a value from its PLAIN representation.
+    fn decode_plain(s: &str) -> Result<Self, ParseError>;
+}
+
+impl PlainEncode for String {
+    fn encode_plain(&self) -> String {
+        self.clone()
+    }
+}
+
+impl PlainDecode for String {
+    fn decode_plain(s: &str) -> Result<String, ParseError> {
+        Ok(s.to_string())
+    }
+}
+
+impl PlainEncode for DateTime<Utc> {
+    fn encode_plain(&self) -> String {
+        self.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+    }
+}
+
+impl PlainDecode for DateTime<Utc> {
+    fn decode_plain(s: &str) -> Result<DateTime<Utc>, ParseError> {
+        DateTime::parse_from_rfc3339(s)
+            .map(|v| v.with_timezone(&Utc))
+            .map_err(|_| ParseError(()))
+    }
+}
+
+impl PlainEncode for i32 {
+    fn encode_plain(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl PlainDecode for i32 {
+    fn decode_plain(s: &str) -> Result<i32, ParseError> {
+        s.parse().map_err(|_| ParseError(()))
+    }
+}
+
+// Non-finite values use the same names as in JSON, and are only accepted in that form.
+impl PlainEncode for f64 {
+    fn encode_plain(&self) -> String {
+        if self.is_nan() {
+            "NaN".to_string()
+        } else if *self == f64::INFINITY {
+            "Infinity".to_string()
+        } else if *self == f64::NEG_INFINITY {
+            "-Infinity".to_string()
+        } else {
+            self.to_string()
+        }
+    }
+}
+
+impl PlainDecode for f64 {
+    fn decode_plain(s: &str) -> Result<f64, ParseError> {
+        match s {
+            "NaN" => Ok(f64::NAN),
+            "Infinity" => Ok(f64::INFINITY),
+            "-Infinity" => Ok(f64::NEG_INFINITY),
+            // Rust also accepts other spellings of the non-finite values
+            s => s
+                .parse()
+                .ok()
+                .filter(|v: &f64| v.is_finite())
+                .ok_or(ParseError(())),
+        }
+    }
+}
+
+impl PlainEncode for SafeLong {
+    fn encode_plain(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl PlainDecode for SafeLong {
+    fn decode_plain(s: &str) -> Result<SafeLong, ParseError> {
+        s.parse()
+            .ok()
+            .and_then(|v| SafeLong::new(v).ok())
+            .ok_or(ParseError(()))
+    }
+}
+
+impl PlainEncode for bool {
+    fn encode_plain(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl PlainDecode for bool {
+    fn decode_plain(s: &str) -> Result<bool, ParseError> {
+        s.parse().map_err(|_| ParseError(()))
+    }
+}
+
+impl PlainEncode for Uuid {
+    fn encode_plain(&self) -> String {
+        self.to_hyphenated().to_string()
+    }
+}
+
+impl PlainDecode for Uuid {
+    fn decode_plain(s: &str) -> Result<Uuid, ParseError> {
+        Uuid::parse_str(s).map_err(|_| ParseError(()))
+    }
+}
+
+impl PlainEncode for ResourceIdentifier {
+    fn encode_plain(&self) -> String {
+        self.as_str().to_string()
+    }
+}
+
+impl PlainDecode for ResourceIdentifier {
+    fn decode_plain(s: &str) -> Result<ResourceIdentifier, ParseError> {
+        ResourceIdentifier::new(s).map_err(|_| ParseError(()))
+    }
+}
+
+impl PlainEncode for BearerToken {
+    fn encode_plain(&self) -> String {
+        self.as_str().to_string()
+    }
+}
+
+impl PlainDecode for BearerToken {
+    fn decode_plain(s: &str) -> Result<BearerToken, ParseError> {
+        BearerToken::new(s).map_err(|_| ParseError(()))
+    }
+}
+
+/// An error parsing a value from its PLAIN representation.
+#[derive(Debug)]
+pub struct ParseError(());
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("invalid PLAIN value")
+    }
+}
+
+impl Error for ParseError {}
@@ -0,0 +1,181 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use std::f64;
+use std::fmt::Debug;
+
+fn test_plain<T>(value: T, plain: &str)
+where
+    T: PlainEncode + PlainDecode + PartialEq + Debug,
+{
+    assert_eq!(value.encode_plain(), plain);
+    assert_eq!(T::decode_plain(plain).unwrap(), value);
+}
+
+#[test]
+fn string() {
+    test_plain("hello world".to_string(), "hello world");
+    test_plain(String::new(), "");
+}
+
+#[test]
+fn datetime() {
+    let value = "2017-01-02T03:04:05Z".parse::<DateTime<Utc>>().unwrap();
+    test_plain(value, "2017-01-02T03:04:05Z");
+    assert_eq!(
+        DateTime::<Utc>::decode_plain("2017-01-02T04:04:05+01:00").unwrap(),
+        value,
+    );
+
+    let value = "2017-01-02T03:04:05.006Z".parse::<DateTime<Utc>>().unwrap();
+    test_plain(value, "2017-01-02T03:04:05.006Z");
+
+    assert!(DateTime::<Utc>::decode_plain("2017-01-02").is_err());
+}
+
+#[test]
+fn integer() {
+    test_plain(-15, "-15");
+    assert!(i32::decode_plain("1.5").is_err());
+}
+
+#[test]
+fn double() {
+    test_plain(1.5, "1.5");
+    test_plain(f64::INFINITY, "Infinity");
+    test_plain(f64::NEG_INFINITY, "-Infinity");
+    assert_eq!(f64::NAN.encode_plain(), "NaN");
+    assert!(f64::decode_plain("NaN").unwrap().is_nan());
+}
+
+#[test]
+fn safelong() {
+    test_plain(SafeLong::max_value(), "9007199254740991");
+    assert!(SafeLong::decode_plain("9007199254740992").is_err());
+}
+
+#[test]
+fn boolean() {
+    test_plain(true, "true");
+    test_plain(false, "false");
+    assert!(bool::decode_plain("TRUE").is_err());
+}
+
+#[test]
+fn uuid() {
+    test_plain(
+        Uuid::parse_str("936DA01F9ABD4d9d80C702AF85C822A8").unwrap(),
+        "936da01f-9abd-4d9d-80c7-02af85c822a8",
+    );
+    assert!(Uuid::decode_plain("hello").is_err());
+}
+
+#[test]
+fn rid() {
+    test_plain(
+        ResourceIdentifier::new("ri.service.instance.type.locator").unwrap(),
+        "ri.service.instance.type.locator",
+    );
+    assert!(ResourceIdentifier::decode_plain("ri.service").is_err());
+}
+
+#[test]
+fn bearertoken() {
+    test_plain(BearerToken::new("fooBar=").unwrap(), "fooBar=");
+    assert!(BearerToken::decode_plain("foo bar").is_err());
+}
+
+fn test_round_trip<T>(values: &[T])
+where
+    T: PlainEncode + PlainDecode + PartialEq + Debug,
+{
+    for value in values {
+        assert_eq!(T::decode_plain(&value.encode_plain()).unwrap(), *value);
+    }
+}
+
+#[test]
+fn round_trip() {
+    test_round_trip(&[String::new(), "foo/bar?baz".to_string(), "☃".to_string()]);
+    test_round_trip(&[
+        "1970-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap(),
+        "2017-01-02T03:04:05.123456789Z"
+            .parse::<DateTime<Utc>>()
+            .unwrap(),
+        "9999-12-31T23:59:59.999Z".parse::<DateTime<Utc>>().unwrap(),
+    ]);
+    test_round_trip(&[0, -2_147_483_648, 2_147_483_647]);
+    test_round_trip(&[
+        0.,
+        -0.5,
+        1e300,
+        f64::MIN_POSITIVE,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ]);
+    test_round_trip(&[
+        SafeLong::new(0).unwrap(),
+        SafeLong::min_value(),
+        SafeLong::max_value(),
+    ]);
+    test_round_trip(&[true, false]);
+    test_round_trip(&[
+        Uuid::nil(),
+        Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8").unwrap(),
+    ]);
+    test_round_trip(&[
+        ResourceIdentifier::new("ri.service.instance.type.locator").unwrap(),
+        ResourceIdentifier::new("ri.a..b.c").unwrap(),
+    ]);
+    test_round_trip(&[
+        BearerToken::new("fooBar").unwrap(),
+        BearerToken::new("a-._~+/b==").unwrap(),
+    ]);
+}
+
+#[test]
+fn malformed() {
+    for s in &["", "2017-01-02T03:04:05", "2017-13-02T03:04:05Z"] {
+        assert!(DateTime::<Utc>::decode_plain(s).is_err(), "{}", s);
+    }
+    for s in &["", " 1", "2147483648", "0x10"] {
+        assert!(i32::decode_plain(s).is_err(), "{}", s);
+    }
+    for s in &["", "nan", "inf", "1.5.5"] {
+        assert!(f64::decode_plain(s).is_err(), "{}", s);
+    }
+    for s in &["", "1.0", "-9007199254740992"] {
+        assert!(SafeLong::decode_plain(s).is_err(), "{}", s);
+    }
+    for s in &["", "True", "1", "yes"] {
+        assert!(bool::decode_plain(s).is_err(), "{}", s);
+    }
+    for s in &[
+        "",
+        "936da01f-9abd-4d9d-80c7-02af85c822a",
+        "936da01f-9abd-4d9d-80c7-02af85c822ag",
+    ] {
+        assert!(Uuid::decode_plain(s).is_err(), "{}", s);
+    }
+    for s in &[
+        "",
+        "ri.service.instance.type",
+        "ri.Service.instance.type.locator",
+    ] {
+        assert!(ResourceIdentifier::decode_plain(s).is_err(), "{}", s);
+    }
+    for s in &["", "=", "foo bar", "foo\n"] {
+        assert!(BearerToken::decode_plain(s).is_err(), "{}", s);
+    }
+}
@@ -11,9 +11,20 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+use chrono::{DateTime, SecondsFormat, Utc};
 use serde::de::{self, IntoDeserializer};
+use serde::ser;
+use serde_value::Value;
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
+use uuid::Uuid;
+
+use crate::SerializableError;
 
 pub fn valid_enum_variant(s: &str) -> bool {
     if s.is_empty() {
@@ -26,6 +37,15 @@ pub fn valid_enum_variant(s: &str) -> bool {
     })
 }
 
+/// Computes the ordinal of an enum variant from its string representation.
+///
+/// This is the 32-bit FNV-1a hash of the string. Generated code embeds its results, so it must never change.
+pub fn enum_ordinal(value: &str) -> u32 {
+    value.bytes().fold(0x811c_9dc5, |hash, b| {
+        (hash ^ u32::from(b)).wrapping_mul(0x0100_0193)
+    })
+}
+
 pub enum UnionField_<T> {
     Type,
     Value(T),
@@ -96,3 +116,1173 @@ impl<'de> de::Visitor<'de> for UnionTypeFieldVisitor {
         }
     }
 }
+
+/// A set which fails to deserialize if the serialized sequence contains duplicate elements.
+pub struct UniqueSet_<T>(pub BTreeSet<T>);
+
+impl<'de, T> de::Deserialize<'de> for UniqueSet_<T>
+where
+    T: de::Deserialize<'de> + Ord,
+{
+    fn deserialize<D>(d: D) -> Result<UniqueSet_<T>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_seq(UniqueSetVisitor(PhantomData))
+    }
+}
+
+struct UniqueSetVisitor<T>(PhantomData<T>);
+
+impl<'de, T> de::Visitor<'de> for UniqueSetVisitor<T>
+where
+    T: de::Deserialize<'de> + Ord,
+{
+    type Value = UniqueSet_<T>;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("a sequence of unique values")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<UniqueSet_<T>, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let mut set = BTreeSet::new();
+        while let Some(value) = seq.next_element()? {
+            if !set.insert(value) {
+                return Err(de::Error::custom("set contains duplicate values"));
+            }
+        }
+
+        Ok(UniqueSet_(set))
+    }
+}
+
+/// A string which borrows from the deserializer's input where possible.
+pub struct BorrowedStr_<'a>(pub Cow<'a, str>);
+
+impl<'de> de::Deserialize<'de> for BorrowedStr_<'de> {
+    fn deserialize<D>(d: D) -> Result<BorrowedStr_<'de>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_str(BorrowedStrVisitor)
+    }
+}
+
+struct BorrowedStrVisitor;
+
+impl<'de> de::Visitor<'de> for BorrowedStrVisitor {
+    type Value = BorrowedStr_<'de>;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("a string")
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<BorrowedStr_<'de>, E>
+    where
+        E: de::Error,
+    {
+        Ok(BorrowedStr_(Cow::Borrowed(v)))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<BorrowedStr_<'de>, E>
+    where
+        E: de::Error,
+    {
+        Ok(BorrowedStr_(Cow::Owned(v.to_string())))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<BorrowedStr_<'de>, E>
+    where
+        E: de::Error,
+    {
+        Ok(BorrowedStr_(Cow::Owned(v)))
+    }
+}
+
+/// A list of strings which borrow from the deserializer's input where possible.
+pub struct BorrowedStrList_<'a>(pub Vec<Cow<'a, str>>);
+
+impl<'de> de::Deserialize<'de> for BorrowedStrList_<'de> {
+    fn deserialize<D>(d: D) -> Result<BorrowedStrList_<'de>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_seq(BorrowedStrListVisitor)
+    }
+}
+
+struct BorrowedStrListVisitor;
+
+impl<'de> de::Visitor<'de> for BorrowedStrListVisitor {
+    type Value = BorrowedStrList_<'de>;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("a sequence of strings")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<BorrowedStrList_<'de>, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let mut list = vec![];
+        while let Some(BorrowedStr_(value)) = seq.next_element()? {
+            list.push(value);
+        }
+
+        Ok(BorrowedStrList_(list))
+    }
+}
+
+/// The newtype struct name optional strings are deserialized through, identifying them to the Conjure server
+/// deserializer.
+pub const OPTIONAL_STRING_: &str = "$conjure_object::private::OptionalString_";
+
+/// An optional string, which the Conjure server deserializer can be configured to treat as absent when empty.
+pub struct OptionalString_<T>(pub Option<T>);
+
+impl<'de, T> de::Deserialize<'de> for OptionalString_<T>
+where
+    T: de::Deserialize<'de>,
+{
+    fn deserialize<D>(d: D) -> Result<OptionalString_<T>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_newtype_struct(OPTIONAL_STRING_, OptionalStringVisitor(PhantomData))
+    }
+}
+
+struct OptionalStringVisitor<T>(PhantomData<T>);
+
+impl<'de, T> de::Visitor<'de> for OptionalStringVisitor<T>
+where
+    T: de::Deserialize<'de>,
+{
+    type Value = OptionalString_<T>;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("an optional string")
+    }
+
+    fn visit_newtype_struct<D>(self, d: D) -> Result<OptionalString_<T>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        de::Deserialize::deserialize(d).map(OptionalString_)
+    }
+}
+
+/// A seed which deserializes a value in place, reusing its existing allocations.
+pub struct InPlaceSeed_<'a, T>(pub &'a mut T);
+
+impl<'a, 'de, T> de::DeserializeSeed<'de> for InPlaceSeed_<'a, T>
+where
+    T: de::Deserialize<'de>,
+{
+    type Value = ();
+
+    fn deserialize<D>(self, d: D) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        T::deserialize_in_place(d, self.0)
+    }
+}
+
+/// The newtype struct name generated objects and their fields are deserialized through, identifying them to the
+/// Conjure client deserializer when it is collecting errors.
+pub const COLLECT_: &str = "$conjure_object::private::Collect_";
+
+/// A seed deserializing the value of an object's field.
+///
+/// When the deserializer is collecting errors, an invalid value is `None` and its error is reported by the
+/// deserializer itself. Other deserializers fail at the invalid value as usual.
+pub struct Collect_<T>(PhantomData<T>);
+
+impl<T> Default for Collect_<T> {
+    fn default() -> Collect_<T> {
+        Collect_(PhantomData)
+    }
+}
+
+impl<'de, T> de::DeserializeSeed<'de> for Collect_<T>
+where
+    T: de::Deserialize<'de>,
+{
+    type Value = Option<T>;
+
+    fn deserialize<D>(self, d: D) -> Result<Option<T>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_newtype_struct(COLLECT_, CollectVisitor(PhantomData))
+    }
+}
+
+// A deserializer collecting errors visits a sequence of the number of errors already reported for the value, followed
+// by the value itself, which is absent if it was invalid.
+struct CollectVisitor<T>(PhantomData<T>);
+
+impl<'de, T> de::Visitor<'de> for CollectVisitor<T>
+where
+    T: de::Deserialize<'de>,
+{
+    type Value = Option<T>;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("a value")
+    }
+
+    fn visit_newtype_struct<D>(self, d: D) -> Result<Option<T>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        T::deserialize(d).map(Some)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Option<T>, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        seq.next_element::<usize>()?;
+        seq.next_element()
+    }
+}
+
+/// Deserializes a generated object from a struct, given a visitor producing either the object or the errors of its
+/// fields.
+///
+/// A deserializer collecting errors reports the object's missing fields one at a time, as it retries the
+/// deserialization of its input after each error. Otherwise, the first missing field is an error.
+pub fn deserialize_object_<'de, D, V, T>(
+    d: D,
+    name: &'static str,
+    fields: &'static [&'static str],
+    visitor: V,
+) -> Result<T, D::Error>
+where
+    D: de::Deserializer<'de>,
+    V: de::Visitor<'de, Value = Result<T, FieldErrors_>>,
+{
+    d.deserialize_newtype_struct(
+        COLLECT_,
+        ObjectVisitor {
+            name,
+            fields,
+            visitor,
+        },
+    )
+}
+
+struct ObjectVisitor<V> {
+    name: &'static str,
+    fields: &'static [&'static str],
+    visitor: V,
+}
+
+impl<'de, V, T> de::Visitor<'de> for ObjectVisitor<V>
+where
+    V: de::Visitor<'de, Value = Result<T, FieldErrors_>>,
+{
+    type Value = T;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        self.visitor.expecting(fmt)
+    }
+
+    fn visit_newtype_struct<D>(self, d: D) -> Result<T, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let missing = Cell::new(false);
+        let seed = ObjectSeed {
+            name: self.name,
+            fields: self.fields,
+            visitor: self.visitor,
+            reported: 0,
+            missing: &missing,
+        };
+
+        match de::DeserializeSeed::deserialize(seed, d)? {
+            Some(value) => Ok(value),
+            None => Err(FieldErrors_::default().into_error()),
+        }
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<T, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let reported = seq.next_element()?.unwrap_or(0);
+        let missing = Cell::new(false);
+        let seed = ObjectSeed {
+            name: self.name,
+            fields: self.fields,
+            visitor: self.visitor,
+            reported,
+            missing: &missing,
+        };
+
+        match seq.next_element_seed(seed) {
+            Ok(Some(Some(value))) => Ok(value),
+            // every error of the object has already been reported
+            Ok(_) => Err(FieldErrors_::default().into_error()),
+            Err(e) => {
+                // the deserializer is told that the error is one of the object's missing fields rather than an invalid
+                // value, so that the object is read again rather than skipped on the next attempt
+                if missing.get() {
+                    seq.next_element::<()>()?;
+                }
+                Err(e)
+            }
+        }
+    }
+}
+
+// Deserializes the object's struct, failing with the first of its missing fields which hasn't been reported yet.
+struct ObjectSeed<'a, V> {
+    name: &'static str,
+    fields: &'static [&'static str],
+    visitor: V,
+    reported: usize,
+    missing: &'a Cell<bool>,
+}
+
+impl<'a, 'de, V, T> de::DeserializeSeed<'de> for ObjectSeed<'a, V>
+where
+    V: de::Visitor<'de, Value = Result<T, FieldErrors_>>,
+{
+    type Value = Option<T>;
+
+    fn deserialize<D>(self, d: D) -> Result<Option<T>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_struct(self.name, self.fields, self)
+    }
+}
+
+impl<'a, 'de, V, T> de::Visitor<'de> for ObjectSeed<'a, V>
+where
+    V: de::Visitor<'de, Value = Result<T, FieldErrors_>>,
+{
+    type Value = Option<T>;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        self.visitor.expecting(fmt)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Option<T>, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let result = self.visitor.visit_seq(seq)?;
+        finish_object(result, self.reported, self.missing)
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Option<T>, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let result = self.visitor.visit_map(map)?;
+        finish_object(result, self.reported, self.missing)
+    }
+}
+
+fn finish_object<T, E>(
+    result: Result<T, FieldErrors_>,
+    reported: usize,
+    missing: &Cell<bool>,
+) -> Result<Option<T>, E>
+where
+    E: de::Error,
+{
+    let errors = match result {
+        Ok(value) => return Ok(Some(value)),
+        Err(errors) => errors,
+    };
+
+    match errors.missing.get(reported) {
+        Some(field) => {
+            missing.set(true);
+            Err(E::missing_field(field))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Tracks the fields of an object which were invalid or missing.
+#[derive(Default)]
+pub struct FieldErrors_ {
+    invalid: Vec<&'static str>,
+    missing: Vec<&'static str>,
+}
+
+impl FieldErrors_ {
+    pub fn invalid_field(&mut self, field: &'static str) {
+        self.invalid.push(field);
+    }
+
+    /// Records a required field as missing unless it was present or its value was invalid.
+    pub fn missing_field(&mut self, present: bool, field: &'static str) {
+        if !present && !self.invalid.contains(&field) {
+            self.missing.push(field);
+        }
+    }
+
+    /// Returns the object, unless any of its fields were invalid or missing.
+    pub fn finish<T>(self, value: T) -> Result<T, FieldErrors_> {
+        if self.invalid.is_empty() && self.missing.is_empty() {
+            Ok(value)
+        } else {
+            Err(self)
+        }
+    }
+
+    pub fn into_error<E>(self) -> E
+    where
+        E: de::Error,
+    {
+        match self.missing.first() {
+            Some(field) => E::missing_field(field),
+            None => E::custom("object contains invalid fields"),
+        }
+    }
+}
+
+/// A wrapper which is ignored by comparisons and hashing.
+#[derive(Clone, Copy, Default)]
+pub struct Ignored_<T>(pub T);
+
+impl<T> fmt::Debug for Ignored_<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, fmt)
+    }
+}
+
+impl<T> PartialEq for Ignored_<T> {
+    #[inline]
+    fn eq(&self, _: &Ignored_<T>) -> bool {
+        true
+    }
+}
+
+impl<T> Eq for Ignored_<T> {}
+
+impl<T> PartialOrd for Ignored_<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Ignored_<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Ignored_<T> {
+    #[inline]
+    fn cmp(&self, _: &Ignored_<T>) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl<T> Hash for Ignored_<T> {
+    #[inline]
+    fn hash<H>(&self, _: &mut H)
+    where
+        H: Hasher,
+    {
+    }
+}
+
+/// Comparisons and hashing for values containing doubles, treating each double by its bit pattern.
+///
+/// This defines a total order over doubles matching IEEE 754's `totalOrder` predicate, so `NaN` is equal to itself
+/// and `-0.0` is distinct from and less than `0.0`.
+pub trait DoubleOrd_ {
+    fn eq_(&self, other: &Self) -> bool;
+
+    fn cmp_(&self, other: &Self) -> Ordering;
+
+    fn hash_<H>(&self, hasher: &mut H)
+    where
+        H: Hasher;
+}
+
+impl DoubleOrd_ for f64 {
+    #[inline]
+    fn eq_(&self, other: &f64) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+
+    #[inline]
+    fn cmp_(&self, other: &f64) -> Ordering {
+        // flip the non-sign bits of negative values so the bit patterns compare as signed integers
+        fn key(v: f64) -> i64 {
+            let bits = v.to_bits() as i64;
+            bits ^ (((bits >> 63) as u64) >> 1) as i64
+        }
+
+        key(*self).cmp(&key(*other))
+    }
+
+    #[inline]
+    fn hash_<H>(&self, hasher: &mut H)
+    where
+        H: Hasher,
+    {
+        self.to_bits().hash(hasher)
+    }
+}
+
+impl<T> DoubleOrd_ for Option<T>
+where
+    T: DoubleOrd_,
+{
+    fn eq_(&self, other: &Option<T>) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.eq_(b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn cmp_(&self, other: &Option<T>) -> Ordering {
+        match (self, other) {
+            (Some(a), Some(b)) => a.cmp_(b),
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+        }
+    }
+
+    fn hash_<H>(&self, hasher: &mut H)
+    where
+        H: Hasher,
+    {
+        match self {
+            Some(v) => {
+                1u8.hash(hasher);
+                v.hash_(hasher);
+            }
+            None => 0u8.hash(hasher),
+        }
+    }
+}
+
+impl<T> DoubleOrd_ for [T]
+where
+    T: DoubleOrd_,
+{
+    fn eq_(&self, other: &[T]) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.eq_(b))
+    }
+
+    fn cmp_(&self, other: &[T]) -> Ordering {
+        for (a, b) in self.iter().zip(other) {
+            match a.cmp_(b) {
+                Ordering::Equal => {}
+                o => return o,
+            }
+        }
+
+        self.len().cmp(&other.len())
+    }
+
+    fn hash_<H>(&self, hasher: &mut H)
+    where
+        H: Hasher,
+    {
+        self.len().hash(hasher);
+        for v in self {
+            v.hash_(hasher);
+        }
+    }
+}
+
+impl<T> DoubleOrd_ for Vec<T>
+where
+    T: DoubleOrd_,
+{
+    #[inline]
+    fn eq_(&self, other: &Vec<T>) -> bool {
+        (**self).eq_(other)
+    }
+
+    #[inline]
+    fn cmp_(&self, other: &Vec<T>) -> Ordering {
+        (**self).cmp_(other)
+    }
+
+    #[inline]
+    fn hash_<H>(&self, hasher: &mut H)
+    where
+        H: Hasher,
+    {
+        (**self).hash_(hasher)
+    }
+}
+
+impl<T> DoubleOrd_ for Box<[T]>
+where
+    T: DoubleOrd_,
+{
+    #[inline]
+    fn eq_(&self, other: &Box<[T]>) -> bool {
+        (**self).eq_(other)
+    }
+
+    #[inline]
+    fn cmp_(&self, other: &Box<[T]>) -> Ordering {
+        (**self).cmp_(other)
+    }
+
+    #[inline]
+    fn hash_<H>(&self, hasher: &mut H)
+    where
+        H: Hasher,
+    {
+        (**self).hash_(hasher)
+    }
+}
+
+impl<K, V> DoubleOrd_ for BTreeMap<K, V>
+where
+    K: Ord + Hash,
+    V: DoubleOrd_,
+{
+    fn eq_(&self, other: &BTreeMap<K, V>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|((ak, av), (bk, bv))| ak == bk && av.eq_(bv))
+    }
+
+    fn cmp_(&self, other: &BTreeMap<K, V>) -> Ordering {
+        for ((ak, av), (bk, bv)) in self.iter().zip(other) {
+            match ak.cmp(bk).then_with(|| av.cmp_(bv)) {
+                Ordering::Equal => {}
+                o => return o,
+            }
+        }
+
+        self.len().cmp(&other.len())
+    }
+
+    fn hash_<H>(&self, hasher: &mut H)
+    where
+        H: Hasher,
+    {
+        self.len().hash(hasher);
+        for (k, v) in self {
+            k.hash(hasher);
+            v.hash_(hasher);
+        }
+    }
+}
+
+/// Comparisons and hashing for values containing doubles, treating each double by its value rounded to a number of
+/// decimal places.
+///
+/// The rounded doubles are compared as by `DoubleOrd_`, except that `-0.0` and `0.0` are equal.
+pub trait QuantizedDoubleOrd_ {
+    fn eq_(&self, other: &Self, places: u32) -> bool;
+
+    fn cmp_(&self, other: &Self, places: u32) -> Ordering;
+
+    fn hash_<H>(&self, places: u32, hasher: &mut H)
+    where
+        H: Hasher;
+}
+
+fn quantize(v: f64, places: u32) -> f64 {
+    // doubles of at least 2^52 have no fractional part to round away
+    const INTEGRAL: f64 = 4_503_599_627_370_496.;
+
+    let scale = 10f64.powi(places as i32);
+    let scaled = v * scale;
+    // adding zero normalizes -0.0 to 0.0
+    if v.abs() >= INTEGRAL || !scaled.is_finite() {
+        v + 0.0
+    } else {
+        scaled.round() / scale + 0.0
+    }
+}
+
+impl QuantizedDoubleOrd_ for f64 {
+    #[inline]
+    fn eq_(&self, other: &f64, places: u32) -> bool {
+        DoubleOrd_::eq_(&quantize(*self, places), &quantize(*other, places))
+    }
+
+    #[inline]
+    fn cmp_(&self, other: &f64, places: u32) -> Ordering {
+        DoubleOrd_::cmp_(&quantize(*self, places), &quantize(*other, places))
+    }
+
+    #[inline]
+    fn hash_<H>(&self, places: u32, hasher: &mut H)
+    where
+        H: Hasher,
+    {
+        DoubleOrd_::hash_(&quantize(*self, places), hasher)
+    }
+}
+
+impl<T> QuantizedDoubleOrd_ for Option<T>
+where
+    T: QuantizedDoubleOrd_,
+{
+    fn eq_(&self, other: &Option<T>, places: u32) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => QuantizedDoubleOrd_::eq_(a, b, places),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn cmp_(&self, other: &Option<T>, places: u32) -> Ordering {
+        match (self, other) {
+            (Some(a), Some(b)) => QuantizedDoubleOrd_::cmp_(a, b, places),
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+        }
+    }
+
+    fn hash_<H>(&self, places: u32, hasher: &mut H)
+    where
+        H: Hasher,
+    {
+        match self {
+            Some(v) => {
+                1u8.hash(hasher);
+                QuantizedDoubleOrd_::hash_(v, places, hasher);
+            }
+            None => 0u8.hash(hasher),
+        }
+    }
+}
+
+impl<T> QuantizedDoubleOrd_ for [T]
+where
+    T: QuantizedDoubleOrd_,
+{
+    fn eq_(&self, other: &[T], places: u32) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(a, b)| QuantizedDoubleOrd_::eq_(a, b, places))
+    }
+
+    fn cmp_(&self, other: &[T], places: u32) -> Ordering {
+        for (a, b) in self.iter().zip(other) {
+            match QuantizedDoubleOrd_::cmp_(a, b, places) {
+                Ordering::Equal => {}
+                o => return o,
+            }
+        }
+
+        self.len().cmp(&other.len())
+    }
+
+    fn hash_<H>(&self, places: u32, hasher: &mut H)
+    where
+        H: Hasher,
+    {
+        self.len().hash(hasher);
+        for v in self {
+            QuantizedDoubleOrd_::hash_(v, places, hasher);
+        }
+    }
+}
+
+impl<T> QuantizedDoubleOrd_ for Vec<T>
+where
+    T: QuantizedDoubleOrd_,
+{
+    #[inline]
+    fn eq_(&self, other: &Vec<T>, places: u32) -> bool {
+        QuantizedDoubleOrd_::eq_(&**self, &**other, places)
+    }
+
+    #[inline]
+    fn cmp_(&self, other: &Vec<T>, places: u32) -> Ordering {
+        QuantizedDoubleOrd_::cmp_(&**self, &**other, places)
+    }
+
+    #[inline]
+    fn hash_<H>(&self, places: u32, hasher: &mut H)
+    where
+        H: Hasher,
+    {
+        QuantizedDoubleOrd_::hash_(&**self, places, hasher)
+    }
+}
+
+impl<T> QuantizedDoubleOrd_ for Box<[T]>
+where
+    T: QuantizedDoubleOrd_,
+{
+    #[inline]
+    fn eq_(&self, other: &Box<[T]>, places: u32) -> bool {
+        QuantizedDoubleOrd_::eq_(&**self, &**other, places)
+    }
+
+    #[inline]
+    fn cmp_(&self, other: &Box<[T]>, places: u32) -> Ordering {
+        QuantizedDoubleOrd_::cmp_(&**self, &**other, places)
+    }
+
+    #[inline]
+    fn hash_<H>(&self, places: u32, hasher: &mut H)
+    where
+        H: Hasher,
+    {
+        QuantizedDoubleOrd_::hash_(&**self, places, hasher)
+    }
+}
+
+impl<K, V> QuantizedDoubleOrd_ for BTreeMap<K, V>
+where
+    K: Ord + Hash,
+    V: QuantizedDoubleOrd_,
+{
+    fn eq_(&self, other: &BTreeMap<K, V>, places: u32) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|((ak, av), (bk, bv))| ak == bk && QuantizedDoubleOrd_::eq_(av, bv, places))
+    }
+
+    fn cmp_(&self, other: &BTreeMap<K, V>, places: u32) -> Ordering {
+        for ((ak, av), (bk, bv)) in self.iter().zip(other) {
+            match ak
+                .cmp(bk)
+                .then_with(|| QuantizedDoubleOrd_::cmp_(av, bv, places))
+            {
+                Ordering::Equal => {}
+                o => return o,
+            }
+        }
+
+        self.len().cmp(&other.len())
+    }
+
+    fn hash_<H>(&self, places: u32, hasher: &mut H)
+    where
+        H: Hasher,
+    {
+        self.len().hash(hasher);
+        for (k, v) in self {
+            k.hash(hasher);
+            QuantizedDoubleOrd_::hash_(v, places, hasher);
+        }
+    }
+}
+
+/// Serializes the doubles contained in a value, failing if any of them are not finite.
+pub struct FiniteDouble_<'a, T>(pub &'a T)
+where
+    T: ?Sized;
+
+impl<'a, T> ser::Serialize for FiniteDouble_<'a, T>
+where
+    T: ?Sized + FiniteDoubles_,
+{
+    #[inline]
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        self.0.serialize_finite_(s)
+    }
+}
+
+pub trait FiniteDoubles_ {
+    fn serialize_finite_<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer;
+}
+
+impl FiniteDoubles_ for f64 {
+    fn serialize_finite_<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        if self.is_finite() {
+            s.serialize_f64(*self)
+        } else {
+            Err(ser::Error::custom(format_args!(
+                "non-finite double `{}` cannot be serialized",
+                self
+            )))
+        }
+    }
+}
+
+impl<T> FiniteDoubles_ for Option<T>
+where
+    T: FiniteDoubles_,
+{
+    fn serialize_finite_<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        match self {
+            Some(v) => s.serialize_some(&FiniteDouble_(v)),
+            None => s.serialize_none(),
+        }
+    }
+}
+
+impl<T> FiniteDoubles_ for Vec<T>
+where
+    T: FiniteDoubles_,
+{
+    fn serialize_finite_<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        s.collect_seq(self.iter().map(FiniteDouble_))
+    }
+}
+
+impl<T> FiniteDoubles_ for Box<[T]>
+where
+    T: FiniteDoubles_,
+{
+    fn serialize_finite_<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        s.collect_seq(self.iter().map(FiniteDouble_))
+    }
+}
+
+impl<T> FiniteDoubles_ for BTreeSet<T>
+where
+    T: FiniteDoubles_,
+{
+    fn serialize_finite_<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        s.collect_seq(self.iter().map(FiniteDouble_))
+    }
+}
+
+impl<K, V> FiniteDoubles_ for BTreeMap<K, V>
+where
+    K: ser::Serialize,
+    V: FiniteDoubles_,
+{
+    fn serialize_finite_<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        s.collect_map(self.iter().map(|(k, v)| (k, FiniteDouble_(v))))
+    }
+}
+
+/// Serializes the datetimes contained in a value with a fixed number of fractional second digits.
+pub struct DateTimeFormat_<'a, T>(pub &'a T, pub SecondsFormat)
+where
+    T: ?Sized;
+
+impl<'a, T> ser::Serialize for DateTimeFormat_<'a, T>
+where
+    T: ?Sized + DateTimeFormatted_,
+{
+    #[inline]
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        self.0.serialize_(self.1, s)
+    }
+}
+
+pub trait DateTimeFormatted_ {
+    fn serialize_<S>(&self, format: SecondsFormat, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer;
+}
+
+impl DateTimeFormatted_ for DateTime<Utc> {
+    fn serialize_<S>(&self, format: SecondsFormat, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        s.serialize_str(&self.to_rfc3339_opts(format, true))
+    }
+}
+
+impl<T> DateTimeFormatted_ for Option<T>
+where
+    T: DateTimeFormatted_,
+{
+    fn serialize_<S>(&self, format: SecondsFormat, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        match self {
+            Some(v) => s.serialize_some(&DateTimeFormat_(v, format)),
+            None => s.serialize_none(),
+        }
+    }
+}
+
+impl<T> DateTimeFormatted_ for Vec<T>
+where
+    T: DateTimeFormatted_,
+{
+    fn serialize_<S>(&self, format: SecondsFormat, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        s.collect_seq(self.iter().map(|v| DateTimeFormat_(v, format)))
+    }
+}
+
+impl<T> DateTimeFormatted_ for Box<[T]>
+where
+    T: DateTimeFormatted_,
+{
+    fn serialize_<S>(&self, format: SecondsFormat, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        s.collect_seq(self.iter().map(|v| DateTimeFormat_(v, format)))
+    }
+}
+
+impl<T> DateTimeFormatted_ for BTreeSet<T>
+where
+    T: DateTimeFormatted_,
+{
+    fn serialize_<S>(&self, format: SecondsFormat, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        s.collect_seq(self.iter().map(|v| DateTimeFormat_(v, format)))
+    }
+}
+
+// only values are formatted; datetime keys use the default format
+impl<K, V> DateTimeFormatted_ for BTreeMap<K, V>
+where
+    K: ser::Serialize,
+    V: DateTimeFormatted_,
+{
+    fn serialize_<S>(&self, format: SecondsFormat, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        s.collect_map(self.iter().map(|(k, v)| (k, DateTimeFormat_(v, format))))
+    }
+}
+
+pub fn error_param<T>(value: &T) -> Value
+where
+    T: ser::Serialize,
+{
+    serde_value::to_value(value).expect("error parameters are always serializable")
+}
+
+pub fn fmt_error(
+    fmt: &mut fmt::Formatter,
+    name: &str,
+    params: &BTreeMap<&'static str, Value>,
+) -> fmt::Result {
+    fmt.write_str(name)?;
+
+    if params.is_empty() {
+        return Ok(());
+    }
+
+    fmt.write_str(" (")?;
+    for (i, (key, value)) in params.iter().enumerate() {
+        if i != 0 {
+            fmt.write_str(", ")?;
+        }
+        write!(fmt, "{}: ", key)?;
+        fmt_param(fmt, value)?;
+    }
+    fmt.write_str(")")
+}
+
+pub fn serializable_error(
+    code: &str,
+    name: &str,
+    safe_params: BTreeMap<&'static str, Value>,
+    unsafe_params: BTreeMap<&'static str, Value>,
+) -> SerializableError {
+    let parameters = safe_params
+        .into_iter()
+        .chain(unsafe_params)
+        .filter_map(|(key, value)| param_string(value).map(|value| (key.to_string(), value)))
+        .collect();
+
+    SerializableError::new(code, name, Uuid::new_v4(), parameters)
+}
+
+// Parameters are rendered as strings in the error envelope. Strings are used directly and other values as JSON, while
+// absent optional values are omitted.
+fn param_string(value: Value) -> Option<String> {
+    match value {
+        Value::String(v) => Some(v),
+        Value::Unit | Value::Option(None) => None,
+        Value::Option(Some(v)) | Value::Newtype(v) => param_string(*v),
+        v => Some(serde_json::to_string(&v).expect("error parameters are always serializable")),
+    }
+}
+
+fn fmt_param(fmt: &mut fmt::Formatter, value: &Value) -> fmt::Result {
+    match value {
+        Value::Bool(v) => fmt::Display::fmt(v, fmt),
+        Value::U8(v) => fmt::Display::fmt(v, fmt),
+        Value::U16(v) => fmt::Display::fmt(v, fmt),
+        Value::U32(v) => fmt::Display::fmt(v, fmt),
+        Value::U64(v) => fmt::Display::fmt(v, fmt),
+        Value::I8(v) => fmt::Display::fmt(v, fmt),
+        Value::I16(v) => fmt::Display::fmt(v, fmt),
+        Value::I32(v) => fmt::Display::fmt(v, fmt),
+        Value::I64(v) => fmt::Display::fmt(v, fmt),
+        Value::F32(v) => fmt::Display::fmt(v, fmt),
+        Value::F64(v) => fmt::Display::fmt(v, fmt),
+        Value::Char(v) => fmt::Debug::fmt(v, fmt),
+        Value::String(v) => fmt::Debug::fmt(v, fmt),
+        Value::Bytes(v) => fmt::Debug::fmt(v, fmt),
+        Value::Unit | Value::Option(None) => fmt.write_str("null"),
+        Value::Option(Some(v)) | Value::Newtype(v) => fmt_param(fmt, v),
+        Value::Seq(v) => {
+            fmt.write_str("[")?;
+            for (i, value) in v.iter().enumerate() {
+                if i != 0 {
+                    fmt.write_str(", ")?;
+                }
+                fmt_param(fmt, value)?;
+            }
+            fmt.write_str("]")
+        }
+        Value::Map(v) => {
+            fmt.write_str("{")?;
+            for (i, (key, value)) in v.iter().enumerate() {
+                if i != 0 {
+                    fmt.write_str(", ")?;
+                }
+                fmt_param(fmt, key)?;
+                fmt.write_str(": ")?;
+                fmt_param(fmt, value)?;
+            }
+            fmt.write_str("}")
+        }
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The Conjure `any` type as unparsed JSON.
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use std::cmp::Ordering;
+use std::error;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+#[cfg(test)]
+mod test;
+
+/// A Conjure `any` value stored as unparsed JSON.
+///
+/// The value's JSON text is stored exactly as it appeared in the input and written back out unchanged when serialized,
+/// so values passed through a service are neither reparsed nor normalized. Parsing is deferred until the value is
+/// accessed with `parse`. Comparisons and hashing operate on the JSON text.
+///
+/// A `RawValue` can only be deserialized by a serde_json-based deserializer such as those of `conjure-serde`.
+#[derive(Clone)]
+pub struct RawValue(Box<serde_json::value::RawValue>);
+
+impl RawValue {
+    /// Creates a `RawValue` from a string of JSON, validating that it is well formed.
+    pub fn from_string(json: String) -> Result<RawValue, Error> {
+        serde_json::value::RawValue::from_string(json)
+            .map(RawValue)
+            .map_err(Error)
+    }
+
+    /// Creates a `RawValue` by serializing a value to JSON.
+    pub fn from_serialize<T>(value: &T) -> Result<RawValue, Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let json = serde_json::to_string(value).map_err(Error)?;
+        RawValue::from_string(json)
+    }
+
+    /// Returns the JSON text of the value.
+    #[inline]
+    pub fn get(&self) -> &str {
+        self.0.get()
+    }
+
+    /// Parses the value.
+    ///
+    /// The value is parsed with serde_json's standard behavior. Use `conjure-serde` to parse it with Conjure's
+    /// encoding instead.
+    pub fn parse<'a, T>(&'a self) -> Result<T, Error>
+    where
+        T: Deserialize<'a>,
+    {
+        serde_json::from_str(self.get()).map_err(Error)
+    }
+}
+
+impl fmt::Debug for RawValue {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple("RawValue").field(&self.get()).finish()
+    }
+}
+
+impl fmt::Display for RawValue {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(self.get())
+    }
+}
+
+impl PartialEq for RawValue {
+    #[inline]
+    fn eq(&self, other: &RawValue) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl Eq for RawValue {}
+
+impl PartialOrd for RawValue {
+    #[inline]
+    fn partial_cmp(&self, other: &RawValue) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RawValue {
+    #[inline]
+    fn cmp(&self, other: &RawValue) -> Ordering {
+        self.get().cmp(other.get())
+    }
+}
+
+impl Hash for RawValue {
+    #[inline]
+    fn hash<H>(&self, hasher: &mut H)
+    where
+        H: Hasher,
+    {
+        self.get().hash(hasher)
+    }
+}
+
+impl Serialize for RawValue {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(s)
+    }
+}
+
+impl<'de> Deserialize<'de> for RawValue {
+    fn deserialize<D>(d: D) -> Result<RawValue, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Box::<serde_json::value::RawValue>::deserialize(d).map(RawValue)
+    }
+}
+
+/// An error creating or parsing a `RawValue`.
+#[derive(Debug)]
+pub struct Error(serde_json::Error);
+
+impl fmt::Display for Error {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, fmt)
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
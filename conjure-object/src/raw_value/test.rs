@@ -0,0 +1,40 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+#[test]
+fn round_trip_unchanged() {
+    let json = r#"{ "b" : 1.50, "a" : [ "foo" ] }"#;
+    let value = RawValue::from_string(json.to_string()).unwrap();
+    assert_eq!(value.get(), json);
+    assert_eq!(serde_json::to_string(&value).unwrap(), json);
+
+    let value = serde_json::from_str::<RawValue>(json).unwrap();
+    assert_eq!(value.get(), json);
+}
+
+#[test]
+fn parse() {
+    let value = RawValue::from_string(r#"[1, 2, 3]"#.to_string()).unwrap();
+    assert_eq!(value.parse::<Vec<i32>>().unwrap(), vec![1, 2, 3]);
+    value.parse::<String>().unwrap_err();
+
+    RawValue::from_string("[1, 2".to_string()).unwrap_err();
+}
+
+#[test]
+fn from_serialize() {
+    let value = RawValue::from_serialize(&vec!["a", "b"]).unwrap();
+    assert_eq!(value.get(), r#"["a","b"]"#);
+}
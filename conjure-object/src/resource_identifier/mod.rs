@@ -97,6 +97,14 @@ impl ResourceIdentifier {
         format!("ri.{}.{}.{}.{}", service, instance, type_, locator).parse()
     }
 
+    /// Returns a builder which creates a resource identifier from its individual components.
+    ///
+    /// The instance component defaults to empty.
+    #[inline]
+    pub fn builder<'a>() -> Builder<'a> {
+        Builder::default()
+    }
+
     /// Returns the service component of the resource identifier.
     #[inline]
     pub fn service(&self) -> &str {
@@ -258,6 +266,53 @@ impl Hash for ResourceIdentifier {
     }
 }
 
+/// A builder for `ResourceIdentifier`s.
+#[derive(Debug, Clone, Default)]
+pub struct Builder<'a> {
+    service: &'a str,
+    instance: &'a str,
+    type_: &'a str,
+    locator: &'a str,
+}
+
+impl<'a> Builder<'a> {
+    /// Sets the service component.
+    #[inline]
+    pub fn service(&mut self, service: &'a str) -> &mut Builder<'a> {
+        self.service = service;
+        self
+    }
+
+    /// Sets the instance component.
+    #[inline]
+    pub fn instance(&mut self, instance: &'a str) -> &mut Builder<'a> {
+        self.instance = instance;
+        self
+    }
+
+    /// Sets the type component.
+    #[inline]
+    pub fn type_(&mut self, type_: &'a str) -> &mut Builder<'a> {
+        self.type_ = type_;
+        self
+    }
+
+    /// Sets the locator component.
+    #[inline]
+    pub fn locator(&mut self, locator: &'a str) -> &mut Builder<'a> {
+        self.locator = locator;
+        self
+    }
+
+    /// Creates the resource identifier.
+    ///
+    /// Returns an error if any component is invalid, including if the service, type, or locator was not set.
+    #[inline]
+    pub fn build(&self) -> Result<ResourceIdentifier, ParseError> {
+        ResourceIdentifier::from_components(self.service, self.instance, self.type_, self.locator)
+    }
+}
+
 /// An error returned from parsing an invalid resource identifier.
 #[derive(Debug)]
 pub struct ParseError(());
@@ -37,3 +37,56 @@ fn no_multi_components() {
     assert!(ResourceIdentifier::from_components("a", "b.c", "d", "e").is_err());
     assert!(ResourceIdentifier::from_components("a", "b", "c.d", "e").is_err());
 }
+
+#[test]
+fn from_components() {
+    let rid =
+        ResourceIdentifier::from_components("service", "instance", "type", "loc.ator").unwrap();
+    assert_eq!(rid.as_str(), "ri.service.instance.type.loc.ator");
+    assert_eq!(rid, ResourceIdentifier::new(rid.as_str()).unwrap());
+
+    let rid = ResourceIdentifier::from_components("service", "", "type", "locator").unwrap();
+    assert_eq!(rid.as_str(), "ri.service..type.locator");
+}
+
+#[test]
+fn invalid_components() {
+    assert!(ResourceIdentifier::from_components("Service", "b", "c", "d").is_err());
+    assert!(ResourceIdentifier::from_components("a", "b_c", "d", "e").is_err());
+    assert!(ResourceIdentifier::from_components("a", "b", "", "d").is_err());
+    assert!(ResourceIdentifier::from_components("a", "b", "c", "d/e").is_err());
+    assert!(ResourceIdentifier::from_components("", "b", "c", "d").is_err());
+    assert!(ResourceIdentifier::from_components("a", "b", "c", "").is_err());
+}
+
+#[test]
+fn builder() {
+    let rid = ResourceIdentifier::builder()
+        .service("service")
+        .type_("type")
+        .locator("locator")
+        .build()
+        .unwrap();
+    assert_eq!(rid.as_str(), "ri.service..type.locator");
+
+    let rid = ResourceIdentifier::builder()
+        .service("service")
+        .instance("instance")
+        .type_("type")
+        .locator("locator")
+        .build()
+        .unwrap();
+    assert_eq!(rid.instance(), "instance");
+
+    assert!(ResourceIdentifier::builder()
+        .service("service")
+        .locator("locator")
+        .build()
+        .is_err());
+    assert!(ResourceIdentifier::builder()
+        .service("service")
+        .type_("ty pe")
+        .locator("locator")
+        .build()
+        .is_err());
+}
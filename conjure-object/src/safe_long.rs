@@ -16,6 +16,7 @@
 use serde::{de, ser};
 use std::error::Error;
 use std::fmt;
+use std::iter::{Product, Sum};
 use std::ops::Deref;
 
 /// An i64 limited to a range safely representable in JSON.
@@ -121,6 +122,74 @@ macro_rules! impl_into {
 
 impl_into!(i64, i128);
 
+// Sums and products are computed as i128s so that only the final value needs to be in range.
+fn from_i128(value: Option<i128>, op: &str) -> SafeLong {
+    let min = i128::from(*SafeLong::min_value());
+    let max = i128::from(*SafeLong::max_value());
+    match value {
+        Some(value) if value >= min && value <= max => SafeLong(value as i64),
+        _ => panic!("{} was out of bounds of a safe long", op),
+    }
+}
+
+/// Sums `SafeLong`s.
+///
+/// # Panics
+///
+/// Panics if the sum is out of range, regardless of whether overflow checks are enabled.
+impl Sum for SafeLong {
+    fn sum<I>(iter: I) -> SafeLong
+    where
+        I: Iterator<Item = SafeLong>,
+    {
+        from_i128(Some(iter.map(|v| i128::from(v.0)).sum()), "sum")
+    }
+}
+
+/// Sums `SafeLong`s.
+///
+/// # Panics
+///
+/// Panics if the sum is out of range, regardless of whether overflow checks are enabled.
+impl<'a> Sum<&'a SafeLong> for SafeLong {
+    fn sum<I>(iter: I) -> SafeLong
+    where
+        I: Iterator<Item = &'a SafeLong>,
+    {
+        from_i128(Some(iter.map(|v| i128::from(v.0)).sum()), "sum")
+    }
+}
+
+/// Multiplies `SafeLong`s.
+///
+/// # Panics
+///
+/// Panics if the product is out of range, regardless of whether overflow checks are enabled.
+impl Product for SafeLong {
+    fn product<I>(mut iter: I) -> SafeLong
+    where
+        I: Iterator<Item = SafeLong>,
+    {
+        let product = iter.try_fold(1i128, |a, b| a.checked_mul(i128::from(b.0)));
+        from_i128(product, "product")
+    }
+}
+
+/// Multiplies `SafeLong`s.
+///
+/// # Panics
+///
+/// Panics if the product is out of range, regardless of whether overflow checks are enabled.
+impl<'a> Product<&'a SafeLong> for SafeLong {
+    fn product<I>(mut iter: I) -> SafeLong
+    where
+        I: Iterator<Item = &'a SafeLong>,
+    {
+        let product = iter.try_fold(1i128, |a, b| a.checked_mul(i128::from(b.0)));
+        from_i128(product, "product")
+    }
+}
+
 /// The error returned from constructing an out-of bounds `SafeLong`.
 #[derive(Debug, Clone)]
 pub struct BoundsError(());
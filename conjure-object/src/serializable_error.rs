@@ -0,0 +1,137 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The Conjure error response body.
+use serde::de::{self, Deserialize, Deserializer, IgnoredAny, MapAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::collections::BTreeMap;
+use std::fmt;
+use uuid::Uuid;
+
+/// The serialized form of a Conjure error, as sent in the body of an error response.
+///
+/// The error's code and name correspond to the `code` and `name` methods of generated error types, and its parameters
+/// are the error's safe and unsafe parameters rendered as strings.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SerializableError {
+    error_code: String,
+    error_name: String,
+    error_instance_id: Uuid,
+    parameters: BTreeMap<String, String>,
+}
+
+impl SerializableError {
+    /// Creates a new serializable error.
+    pub fn new(
+        error_code: &str,
+        error_name: &str,
+        error_instance_id: Uuid,
+        parameters: BTreeMap<String, String>,
+    ) -> SerializableError {
+        SerializableError {
+            error_code: error_code.to_string(),
+            error_name: error_name.to_string(),
+            error_instance_id,
+            parameters,
+        }
+    }
+
+    /// Returns the error's code, such as `INVALID_ARGUMENT`.
+    #[inline]
+    pub fn error_code(&self) -> &str {
+        &self.error_code
+    }
+
+    /// Returns the error's name, such as `Test:InvalidWidget`.
+    #[inline]
+    pub fn error_name(&self) -> &str {
+        &self.error_name
+    }
+
+    /// Returns the unique identifier of this instance of the error.
+    #[inline]
+    pub fn error_instance_id(&self) -> Uuid {
+        self.error_instance_id
+    }
+
+    /// Returns the error's parameters.
+    #[inline]
+    pub fn parameters(&self) -> &BTreeMap<String, String> {
+        &self.parameters
+    }
+}
+
+impl Serialize for SerializableError {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = s.serialize_map(Some(4))?;
+        map.serialize_entry("errorCode", &self.error_code)?;
+        map.serialize_entry("errorName", &self.error_name)?;
+        map.serialize_entry("errorInstanceId", &self.error_instance_id)?;
+        map.serialize_entry("parameters", &self.parameters)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for SerializableError {
+    fn deserialize<D>(d: D) -> Result<SerializableError, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_map(SerializableErrorVisitor)
+    }
+}
+
+struct SerializableErrorVisitor;
+
+impl<'de> Visitor<'de> for SerializableErrorVisitor {
+    type Value = SerializableError;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("a serializable error")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<SerializableError, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut error_code = None;
+        let mut error_name = None;
+        let mut error_instance_id = None;
+        let mut parameters = None;
+
+        // unknown fields are ignored so that errors from newer servers can still be read
+        while let Some(key) = map.next_key::<String>()? {
+            match &*key {
+                "errorCode" => error_code = Some(map.next_value()?),
+                "errorName" => error_name = Some(map.next_value()?),
+                "errorInstanceId" => error_instance_id = Some(map.next_value()?),
+                "parameters" => parameters = Some(map.next_value()?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        Ok(SerializableError {
+            error_code: error_code.ok_or_else(|| de::Error::missing_field("errorCode"))?,
+            error_name: error_name.ok_or_else(|| de::Error::missing_field("errorName"))?,
+            error_instance_id: error_instance_id
+                .ok_or_else(|| de::Error::missing_field("errorInstanceId"))?,
+            parameters: parameters.unwrap_or_default(),
+        })
+    }
+}
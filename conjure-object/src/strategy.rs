@@ -0,0 +1,98 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Proptest strategies for Conjure's builtin types.
+//!
+//! These are used by generated strategies for Conjure types, but can be used directly as well. This module is only
+//! present when the `proptest` Cargo feature is enabled.
+use chrono::TimeZone;
+use proptest::arbitrary::any;
+use proptest::collection;
+use proptest::num;
+use proptest::strategy::{Strategy, Union};
+
+use crate::{
+    BearerToken, ByteBuf, DateTime, RawValue, ResourceIdentifier, SafeLong, Utc, Uuid, Value,
+};
+
+// The largest timestamp which still has a 4 digit year, as required by RFC 3339.
+const MAX_TIMESTAMP: i64 = 253_402_300_799;
+
+/// Returns a strategy generating `SafeLong`s across their entire valid range.
+pub fn safe_long() -> impl Strategy<Value = SafeLong> {
+    (*SafeLong::min_value()..=*SafeLong::max_value()).prop_map(|v| SafeLong::new(v).unwrap())
+}
+
+/// Returns a strategy generating `DateTime<Utc>`s with years between 1970 and 9999.
+pub fn date_time() -> impl Strategy<Value = DateTime<Utc>> {
+    (0..=MAX_TIMESTAMP, 0..1_000_000_000u32).prop_map(|(secs, nanos)| Utc.timestamp(secs, nanos))
+}
+
+/// Returns a strategy generating `f64`s other than NaN.
+///
+/// NaN isn't equal to itself, so values containing it would never compare equal to themselves after e.g. a
+/// serialization round trip.
+pub fn double() -> impl Strategy<Value = f64> {
+    num::f64::POSITIVE
+        | num::f64::NEGATIVE
+        | num::f64::NORMAL
+        | num::f64::SUBNORMAL
+        | num::f64::ZERO
+        | num::f64::INFINITE
+}
+
+/// Returns a strategy generating arbitrary `Uuid`s.
+pub fn uuid() -> impl Strategy<Value = Uuid> {
+    any::<[u8; 16]>().prop_map(Uuid::from_bytes)
+}
+
+/// Returns a strategy generating short `ByteBuf`s.
+pub fn byte_buf() -> impl Strategy<Value = ByteBuf> {
+    collection::vec(any::<u8>(), 0..16).prop_map(ByteBuf::from)
+}
+
+/// Returns a strategy generating scalar `Value`s.
+///
+/// Only values which survive a round trip through JSON unchanged are generated.
+pub fn value() -> impl Strategy<Value = Value> {
+    Union::new(vec![
+        any::<bool>().prop_map(Value::Bool).boxed(),
+        any::<u64>().prop_map(Value::U64).boxed(),
+        (i64::min_value()..0).prop_map(Value::I64).boxed(),
+        any::<String>().prop_map(Value::String).boxed(),
+    ])
+}
+
+/// Returns a strategy generating scalar `RawValue`s.
+pub fn raw_value() -> impl Strategy<Value = RawValue> {
+    value().prop_map(|v| RawValue::from_serialize(&v).unwrap())
+}
+
+/// Returns a strategy generating valid `ResourceIdentifier`s.
+pub fn resource_identifier() -> impl Strategy<Value = ResourceIdentifier> {
+    (
+        "[a-z][a-z0-9\\-]{0,8}",
+        "([a-z0-9][a-z0-9\\-]{0,8})?",
+        "[a-z][a-z0-9\\-]{0,8}",
+        "[a-zA-Z0-9_\\-\\.]{1,16}",
+    )
+        .prop_map(|(service, instance, type_, locator)| {
+            ResourceIdentifier::from_components(&service, &instance, &type_, &locator).unwrap()
+        })
+}
+
+/// Returns a strategy generating valid `BearerToken`s.
+pub fn bearer_token() -> impl Strategy<Value = BearerToken> {
+    "[A-Za-z0-9\\-\\._~\\+/]{1,32}=*".prop_map(|s| BearerToken::new(&s).unwrap())
+}
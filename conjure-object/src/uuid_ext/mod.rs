@@ -0,0 +1,59 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Extensions to the Conjure `uuid` type.
+use crate::Uuid;
+
+#[cfg(test)]
+mod test;
+
+/// Extension methods for `Uuid`.
+///
+/// `Uuid` is defined in the `uuid` crate, so these methods are provided by an extension trait rather than inherently.
+/// Conversions to and from byte arrays are provided inherently by `Uuid::from_bytes` and `Uuid::as_bytes`.
+///
+/// The `uuid` crate's own `Uuid::from_u128`, enabled by its `u128` feature, takes precedence over the method of this
+/// trait and uses native rather than big-endian byte order. Call `UuidExt::from_u128` explicitly if that feature may
+/// be enabled.
+pub trait UuidExt: Sized {
+    /// Creates a UUID from a 128-bit integer.
+    ///
+    /// The integer is interpreted in big-endian byte order, so its hexadecimal representation matches the UUID's
+    /// string representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conjure_object::{Uuid, UuidExt};
+    ///
+    /// let uuid = Uuid::from_u128(0x936da01f_9abd_4d9d_80c7_02af85c822a8);
+    /// assert_eq!(uuid.to_string(), "936da01f-9abd-4d9d-80c7-02af85c822a8");
+    /// ```
+    fn from_u128(value: u128) -> Self;
+
+    /// Returns the UUID as a 128-bit integer.
+    ///
+    /// This is the inverse of `from_u128`.
+    fn as_u128(&self) -> u128;
+}
+
+impl UuidExt for Uuid {
+    fn from_u128(value: u128) -> Uuid {
+        Uuid::from_bytes(value.to_be_bytes())
+    }
+
+    fn as_u128(&self) -> u128 {
+        u128::from_be_bytes(*self.as_bytes())
+    }
+}
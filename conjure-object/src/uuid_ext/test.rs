@@ -0,0 +1,37 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+const UUID: &str = "936da01f-9abd-4d9d-80c7-02af85c822a8";
+const BYTES: [u8; 16] = [
+    0x93, 0x6d, 0xa0, 0x1f, 0x9a, 0xbd, 0x4d, 0x9d, 0x80, 0xc7, 0x02, 0xaf, 0x85, 0xc8, 0x22, 0xa8,
+];
+const U128: u128 = 0x936d_a01f_9abd_4d9d_80c7_02af_85c8_22a8;
+
+#[test]
+fn bytes_round_trip() {
+    let uuid = Uuid::from_bytes(BYTES);
+    assert_eq!(uuid.to_string(), UUID);
+    assert_eq!(*uuid.as_bytes(), BYTES);
+    assert_eq!(Uuid::from_bytes(*uuid.as_bytes()), uuid);
+}
+
+#[test]
+fn u128_round_trip() {
+    let uuid = Uuid::from_u128(U128);
+    assert_eq!(uuid.to_string(), UUID);
+    assert_eq!(uuid.as_u128(), U128);
+    assert_eq!(Uuid::from_u128(uuid.as_u128()), uuid);
+    assert_eq!(*uuid.as_bytes(), BYTES);
+}
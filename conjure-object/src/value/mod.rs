@@ -0,0 +1,296 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Extensions to the Conjure `any` type.
+use crate::Value;
+use std::collections::BTreeMap;
+use std::mem;
+
+#[cfg(test)]
+mod test;
+
+/// Extension methods for `Value`.
+///
+/// `Value` is defined in the `serde-value` crate, so these methods are provided by an extension trait rather than
+/// inherently.
+pub trait ValueExt {
+    /// Looks up a value by a JSON Pointer.
+    ///
+    /// A JSON Pointer, as defined in [RFC 6901], is a string of reference tokens each prefixed by `/`. Each token
+    /// selects either an index of a sequence or a key of a map, with `~1` and `~0` escaping `/` and `~` respectively.
+    /// The empty pointer refers to the value itself. Optional and newtype values are transparently traversed.
+    ///
+    /// Returns `None` if the pointer is malformed or does not refer to a value.
+    ///
+    /// [RFC 6901]: https://tools.ietf.org/html/rfc6901
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conjure_object::{Value, ValueExt};
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map = BTreeMap::new();
+    /// map.insert(
+    ///     Value::String("foo".to_string()),
+    ///     Value::Seq(vec![Value::I32(1), Value::I32(2)]),
+    /// );
+    /// let value = Value::Map(map);
+    ///
+    /// assert_eq!(value.pointer("/foo/1"), Some(&Value::I32(2)));
+    /// assert_eq!(value.pointer("/bar"), None);
+    /// ```
+    fn pointer(&self, pointer: &str) -> Option<&Value>;
+
+    /// Looks up a value by a key of a map or an index of a sequence.
+    ///
+    /// Optional and newtype values are transparently traversed, as with `pointer`.
+    ///
+    /// Returns `None` if the value is not a map or sequence, or if the key or index is not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conjure_object::{Value, ValueExt};
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map = BTreeMap::new();
+    /// map.insert(
+    ///     Value::String("foo".to_string()),
+    ///     Value::Seq(vec![Value::I32(1), Value::I32(2)]),
+    /// );
+    /// let value = Value::Map(map);
+    ///
+    /// assert_eq!(value.get("foo").and_then(|v| v.get(1)), Some(&Value::I32(2)));
+    /// assert_eq!(value.get("bar"), None);
+    /// ```
+    fn get<I>(&self, index: I) -> Option<&Value>
+    where
+        I: ValueIndex;
+
+    /// Looks up a value by a key of a map or an index of a sequence, returning `Value::Unit` if it is not present.
+    ///
+    /// This mirrors `serde_json::Value`'s `Index` implementations, which return `null` rather than panicking on a
+    /// missing key or index, so lookups can be chained without checking each step. `Value` is defined in the
+    /// `serde-value` crate, so `std::ops::Index` itself can't be implemented for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conjure_object::{Value, ValueExt};
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map = BTreeMap::new();
+    /// map.insert(
+    ///     Value::String("foo".to_string()),
+    ///     Value::Seq(vec![Value::I32(1), Value::I32(2)]),
+    /// );
+    /// let value = Value::Map(map);
+    ///
+    /// assert_eq!(value.index("foo").index(1), &Value::I32(2));
+    /// assert_eq!(value.index("bar").index(1), &Value::Unit);
+    /// ```
+    fn index<I>(&self, index: I) -> &Value
+    where
+        I: ValueIndex;
+
+    /// Returns a mutable reference to the entries of a map.
+    ///
+    /// Optional and newtype values are transparently traversed, as with `pointer`.
+    ///
+    /// Returns `None` if the value is not a map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conjure_object::{Value, ValueExt};
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut value = Value::Map(BTreeMap::new());
+    /// value
+    ///     .as_object_mut()
+    ///     .unwrap()
+    ///     .insert(Value::String("foo".to_string()), Value::I32(1));
+    ///
+    /// assert_eq!(value.get("foo"), Some(&Value::I32(1)));
+    /// assert_eq!(Value::I32(1).as_object_mut(), None);
+    /// ```
+    fn as_object_mut(&mut self) -> Option<&mut BTreeMap<Value, Value>>;
+
+    /// Returns a mutable reference to the elements of a sequence.
+    ///
+    /// Optional and newtype values are transparently traversed, as with `pointer`.
+    ///
+    /// Returns `None` if the value is not a sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conjure_object::{Value, ValueExt};
+    ///
+    /// let mut value = Value::Seq(vec![Value::I32(1)]);
+    /// value.as_array_mut().unwrap().push(Value::I32(2));
+    ///
+    /// assert_eq!(value, Value::Seq(vec![Value::I32(1), Value::I32(2)]));
+    /// ```
+    fn as_array_mut(&mut self) -> Option<&mut Vec<Value>>;
+
+    /// Takes the value out, leaving `Value::Unit`, which serializes as `null`, in its place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conjure_object::{Value, ValueExt};
+    ///
+    /// let mut value = Value::Seq(vec![Value::String("foo".to_string())]);
+    /// let taken = value.as_array_mut().unwrap()[0].take();
+    ///
+    /// assert_eq!(taken, Value::String("foo".to_string()));
+    /// assert_eq!(value, Value::Seq(vec![Value::Unit]));
+    /// ```
+    fn take(&mut self) -> Value;
+}
+
+/// A type which can be used to look up a value within a `Value`.
+///
+/// Strings index into maps, and `usize`s index into sequences. This trait is sealed and can't be implemented outside
+/// of this crate.
+pub trait ValueIndex: private::Sealed {
+    #[doc(hidden)]
+    fn index_into<'a>(&self, value: &'a Value) -> Option<&'a Value>;
+}
+
+impl ValueIndex for usize {
+    fn index_into<'a>(&self, value: &'a Value) -> Option<&'a Value> {
+        match unwrap(value) {
+            Value::Seq(values) => values.get(*self),
+            _ => None,
+        }
+    }
+}
+
+impl ValueIndex for str {
+    fn index_into<'a>(&self, value: &'a Value) -> Option<&'a Value> {
+        match unwrap(value) {
+            Value::Map(values) => values.get(&Value::String(self.to_string())),
+            _ => None,
+        }
+    }
+}
+
+impl ValueIndex for String {
+    fn index_into<'a>(&self, value: &'a Value) -> Option<&'a Value> {
+        (**self).index_into(value)
+    }
+}
+
+impl<T> ValueIndex for &T
+where
+    T: ?Sized + ValueIndex,
+{
+    fn index_into<'a>(&self, value: &'a Value) -> Option<&'a Value> {
+        (**self).index_into(value)
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for usize {}
+
+    impl Sealed for str {}
+
+    impl Sealed for String {}
+
+    impl<T> Sealed for &T where T: ?Sized + Sealed {}
+}
+
+static UNIT: Value = Value::Unit;
+
+impl ValueExt for Value {
+    fn pointer(&self, pointer: &str) -> Option<&Value> {
+        if pointer.is_empty() {
+            return Some(self);
+        }
+        if !pointer.starts_with('/') {
+            return None;
+        }
+
+        pointer[1..]
+            .split('/')
+            .map(|token| token.replace("~1", "/").replace("~0", "~"))
+            .try_fold(self, |value, token| match unwrap(value) {
+                Value::Seq(values) => parse_index(&token).and_then(|i| values.get(i)),
+                Value::Map(values) => values.get(&Value::String(token)),
+                _ => None,
+            })
+    }
+
+    fn get<I>(&self, index: I) -> Option<&Value>
+    where
+        I: ValueIndex,
+    {
+        index.index_into(self)
+    }
+
+    fn index<I>(&self, index: I) -> &Value
+    where
+        I: ValueIndex,
+    {
+        self.get(index).unwrap_or(&UNIT)
+    }
+
+    fn as_object_mut(&mut self) -> Option<&mut BTreeMap<Value, Value>> {
+        match unwrap_mut(self) {
+            Value::Map(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    fn as_array_mut(&mut self) -> Option<&mut Vec<Value>> {
+        match unwrap_mut(self) {
+            Value::Seq(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    fn take(&mut self) -> Value {
+        mem::replace(self, Value::Unit)
+    }
+}
+
+fn unwrap(mut value: &Value) -> &Value {
+    loop {
+        match value {
+            Value::Option(Some(v)) | Value::Newtype(v) => value = v,
+            _ => return value,
+        }
+    }
+}
+
+fn unwrap_mut(value: &mut Value) -> &mut Value {
+    match value {
+        Value::Option(Some(v)) | Value::Newtype(v) => unwrap_mut(v),
+        _ => value,
+    }
+}
+
+// indices must be unsigned decimal numbers without leading zeros
+fn parse_index(token: &str) -> Option<usize> {
+    if token.starts_with('+') || (token.starts_with('0') && token.len() > 1) {
+        return None;
+    }
+
+    token.parse().ok()
+}
@@ -0,0 +1,172 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+
+use super::*;
+
+fn map(entries: Vec<(&str, Value)>) -> Value {
+    Value::Map(
+        entries
+            .into_iter()
+            .map(|(k, v)| (Value::String(k.to_string()), v))
+            .collect::<BTreeMap<_, _>>(),
+    )
+}
+
+#[test]
+fn nested() {
+    let value = map(vec![
+        (
+            "foo",
+            Value::Seq(vec![
+                map(vec![("bar", Value::I32(1))]),
+                map(vec![("bar", Value::I32(2))]),
+            ]),
+        ),
+        ("baz", Value::Bool(true)),
+    ]);
+
+    assert_eq!(value.pointer(""), Some(&value));
+    assert_eq!(value.pointer("/baz"), Some(&Value::Bool(true)));
+    assert_eq!(value.pointer("/foo/0/bar"), Some(&Value::I32(1)));
+    assert_eq!(value.pointer("/foo/1/bar"), Some(&Value::I32(2)));
+    assert_eq!(
+        value.pointer("/foo/1"),
+        Some(&map(vec![("bar", Value::I32(2))]))
+    );
+}
+
+#[test]
+fn escapes() {
+    let value = map(vec![
+        ("a/b", Value::I32(1)),
+        ("m~n", Value::I32(2)),
+        ("~1", Value::I32(3)),
+        ("", Value::I32(4)),
+    ]);
+
+    assert_eq!(value.pointer("/a~1b"), Some(&Value::I32(1)));
+    assert_eq!(value.pointer("/m~0n"), Some(&Value::I32(2)));
+    assert_eq!(value.pointer("/~01"), Some(&Value::I32(3)));
+    assert_eq!(value.pointer("/"), Some(&Value::I32(4)));
+}
+
+#[test]
+fn wrappers() {
+    let value = Value::Option(Some(Box::new(Value::Newtype(Box::new(Value::Seq(vec![
+        Value::I32(1),
+    ]))))));
+
+    assert_eq!(value.pointer("/0"), Some(&Value::I32(1)));
+}
+
+#[test]
+fn misses() {
+    let value = map(vec![("foo", Value::Seq(vec![Value::I32(1)]))]);
+
+    assert_eq!(value.pointer("foo"), None);
+    assert_eq!(value.pointer("/bar"), None);
+    assert_eq!(value.pointer("/foo/1"), None);
+    assert_eq!(value.pointer("/foo/-"), None);
+    assert_eq!(value.pointer("/foo/00"), None);
+    assert_eq!(value.pointer("/foo/+0"), None);
+    assert_eq!(value.pointer("/foo/0/bar"), None);
+    assert_eq!(value.pointer("/foo/bar"), None);
+}
+
+#[test]
+fn index_object() {
+    let value = map(vec![
+        ("foo", map(vec![("bar", Value::I32(1))])),
+        (
+            "baz",
+            Value::Newtype(Box::new(map(vec![("qux", Value::I32(2))]))),
+        ),
+    ]);
+
+    assert_eq!(value.get("foo"), Some(&map(vec![("bar", Value::I32(1))])));
+    assert_eq!(value.index("foo").index("bar"), &Value::I32(1));
+    assert_eq!(value.index("baz".to_string()).index("qux"), &Value::I32(2));
+    assert_eq!(value.get("bar"), None);
+    assert_eq!(value.index("bar"), &Value::Unit);
+    assert_eq!(value.index("bar").index("foo"), &Value::Unit);
+    assert_eq!(value.get(0), None);
+    assert_eq!(value.index(0), &Value::Unit);
+}
+
+#[test]
+fn index_array() {
+    let value = Value::Seq(vec![
+        Value::I32(1),
+        Value::Option(Some(Box::new(Value::Seq(vec![Value::I32(2)])))),
+    ]);
+
+    assert_eq!(value.get(0), Some(&Value::I32(1)));
+    assert_eq!(value.index(1).index(0), &Value::I32(2));
+    assert_eq!(value.get(2), None);
+    assert_eq!(value.index(2), &Value::Unit);
+    assert_eq!(value.get("foo"), None);
+    assert_eq!(value.index("foo"), &Value::Unit);
+}
+
+#[test]
+fn mutate_nested() {
+    let mut value = map(vec![(
+        "foo",
+        Value::Newtype(Box::new(map(vec![(
+            "bar",
+            Value::Seq(vec![Value::I32(1)]),
+        )]))),
+    )]);
+
+    let foo = value.as_object_mut().unwrap();
+    let foo = foo
+        .get_mut(&Value::String("foo".to_string()))
+        .unwrap()
+        .as_object_mut()
+        .unwrap();
+    foo.insert(Value::String("baz".to_string()), Value::Bool(true));
+    let bar = foo
+        .get_mut(&Value::String("bar".to_string()))
+        .unwrap()
+        .as_array_mut()
+        .unwrap();
+    bar.push(Value::I32(2));
+
+    assert_eq!(value.pointer("/foo/baz"), Some(&Value::Bool(true)));
+    assert_eq!(
+        value.pointer("/foo/bar"),
+        Some(&Value::Seq(vec![Value::I32(1), Value::I32(2)]))
+    );
+
+    assert_eq!(value.index("foo").clone().as_array_mut(), None);
+    assert_eq!(Value::Bool(true).as_object_mut(), None);
+}
+
+#[test]
+fn take() {
+    let mut value = map(vec![("foo", map(vec![("bar", Value::I32(1))]))]);
+
+    let taken = value
+        .as_object_mut()
+        .unwrap()
+        .get_mut(&Value::String("foo".to_string()))
+        .unwrap()
+        .take();
+
+    assert_eq!(taken, map(vec![("bar", Value::I32(1))]));
+    assert_eq!(value, map(vec![("foo", Value::Unit)]));
+    assert_eq!(value.take(), map(vec![("foo", Value::Unit)]));
+    assert_eq!(value, Value::Unit);
+}
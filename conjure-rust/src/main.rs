@@ -37,6 +37,15 @@ struct Args {
     #[structopt(long = "exhaustive")]
     /// Generate exhaustively matchable enums and unions
     exhaustive: bool,
+    #[structopt(long = "strategies")]
+    /// Generate proptest strategies for the generated types
+    strategies: bool,
+    #[structopt(long = "json-schemas")]
+    /// Generate schemars JSON schemas for the generated types
+    json_schemas: bool,
+    #[structopt(long = "field-presence")]
+    /// Track which fields of objects were present when deserialized
+    field_presence: bool,
     #[structopt(name = "input-json", parse(from_os_str))]
     /// Path to a JSON-formatted Conjure IR file
     input_json: PathBuf,
@@ -50,6 +59,9 @@ fn main() {
 
     let r = conjure_codegen::Config::new()
         .exhaustive(args.exhaustive)
+        .strategies(args.strategies)
+        .json_schemas(args.json_schemas)
+        .field_presence(args.field_presence)
         .generate_files(&args.input_json, &args.output_directory);
 
     if let Err(e) = r {
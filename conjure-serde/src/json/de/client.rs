@@ -11,15 +11,22 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use serde::de;
+use conjure_object::private;
+use conjure_object::SerializableError;
+use serde::de::{self, Deserialize, IntoDeserializer};
 use serde_json::de::{IoRead, Read, SliceRead, StrRead};
+use serde_json::error::Category;
 use serde_json::Error;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::f32;
 use std::f64;
 use std::fmt;
 use std::io;
 
-use crate::json::de::{ByteBufVisitor, F32Visitor, F64Visitor};
+use crate::json::de::{
+    ByteBufVisitor, F32Visitor, F64Visitor, IntegerBoolVisitor, DEFAULT_MAX_DEPTH,
+};
 
 /// Deserializes a value from a reader of JSON data.
 pub fn client_from_reader<R, T>(reader: R) -> Result<T, Error>
@@ -45,6 +52,11 @@ where
 }
 
 /// Deserializes a value from a slice of JSON data.
+///
+/// Arbitrary input, including invalid UTF-8 and deeply nested values, results in an error rather than a panic or a
+/// stack overflow, which makes this suitable as the entry point of a fuzz target. Nesting is limited to the default
+/// depth of `ClientDeserializer::max_depth`. The deserialized type's own `Deserialize` implementation must not panic
+/// either.
 pub fn client_from_slice<'a, T>(s: &'a [u8]) -> Result<T, Error>
 where
     T: de::Deserialize<'a>,
@@ -55,11 +67,375 @@ where
     Ok(value)
 }
 
+/// Deserializes a value from a buffer of JSON data, such as a `bytes::Bytes`.
+///
+/// The value's lifetime is tied to the buffer, so types which borrow from their input, such as `&str` and objects
+/// generated with borrowed strings, can avoid copying strings out of it. The data is otherwise deserialized as in
+/// `client_from_slice`.
+pub fn from_bytes<'a, B, T>(bytes: &'a B) -> Result<T, Error>
+where
+    B: AsRef<[u8]> + ?Sized,
+    T: de::Deserialize<'a>,
+{
+    client_from_slice(bytes.as_ref())
+}
+
+/// Deserializes a Conjure error from the body of an error response.
+///
+/// The body is expected to contain the standard error envelope of `errorCode`, `errorName`, `errorInstanceId`, and
+/// `parameters` fields. As with other client deserialization, unknown fields are ignored.
+pub fn deserialize_error(s: &[u8]) -> Result<SerializableError, Error> {
+    client_from_slice(s)
+}
+
+/// Deserializes a value from a slice of JSON data, tolerating a leading UTF-8 byte order mark.
+///
+/// This is intended for JSON read from files, which may have been written by tools that add a byte order mark. Other
+/// than stripping it, the data is deserialized as in `client_from_slice`.
+pub fn from_slice_lenient<'a, T>(s: &'a [u8]) -> Result<T, Error>
+where
+    T: de::Deserialize<'a>,
+{
+    let s = if s.starts_with(UTF8_BOM) {
+        &s[UTF8_BOM.len()..]
+    } else {
+        s
+    };
+
+    client_from_slice(s)
+}
+
+/// Deserializes a value from a string of hand-written JSON data, tolerating trailing commas.
+///
+/// A comma followed only by whitespace before the `]` or `}` closing an array or object is ignored, as is common in
+/// configuration files edited by hand. The data is otherwise deserialized as in `client_from_str`. This is not
+/// conformant with the Conjure wire format, and should not be used for data received from other services.
+///
+/// Requires the `relaxed` Cargo feature.
+#[cfg(feature = "relaxed")]
+pub fn from_str_relaxed<T>(s: &str) -> Result<T, Error>
+where
+    T: de::DeserializeOwned,
+{
+    client_from_str(&strip_trailing_commas(s))
+}
+
+// trailing commas are replaced with spaces rather than removed so that error positions match the original input
+#[cfg(feature = "relaxed")]
+fn strip_trailing_commas(s: &str) -> String {
+    let mut out = s.as_bytes().to_vec();
+    let mut in_string = false;
+    let mut escaped = false;
+    // the last non-whitespace byte outside of a string, or the opening quote of the last string
+    let mut prev = None;
+
+    for (i, b) in s.bytes().enumerate() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if b == b'\\' {
+                escaped = true;
+            } else if b == b'"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        if b == b'"' {
+            in_string = true;
+        } else if b == b',' {
+            // a comma only trails a value, not the start of the array or object or another separator
+            let follows_value = match prev {
+                Some(b'[') | Some(b'{') | Some(b',') | Some(b':') | None => false,
+                Some(_) => true,
+            };
+            let next = s.as_bytes()[i + 1..].iter().find(|b| !is_whitespace(**b));
+            if follows_value && (next == Some(&b']') || next == Some(&b'}')) {
+                out[i] = b' ';
+            }
+        }
+
+        if !is_whitespace(b) {
+            prev = Some(b);
+        }
+    }
+
+    // only ASCII commas were replaced with ASCII spaces
+    String::from_utf8(out).unwrap()
+}
+
+/// Deserializes a value from a slice of JSON data, reporting every error rather than just the first.
+///
+/// Conjure objects generated with `collect_errors` enabled continue past fields with invalid values and check for all
+/// missing required fields, so each of those problems is reported as a separate error. Deserialization of other types
+/// still stops at their first error. The data is otherwise deserialized as in `client_from_slice`, and the errors are
+/// the same, including their positions in the input.
+///
+/// The input is deserialized again after each error, skipping the values already found to be invalid, so this is
+/// intended for validating small documents such as hand-written requests rather than for bulk data.
+pub fn client_from_slice_collecting<T>(s: &[u8]) -> Result<T, Vec<Error>>
+where
+    T: de::DeserializeOwned,
+{
+    let collector = Collector::default();
+    let mut errors = vec![];
+
+    loop {
+        let mut de = serde_json::Deserializer::from_slice(s);
+        let config = Config {
+            collector: Some(&collector),
+            ..Config::default()
+        };
+        let result = T::deserialize(WrapDeserializer(&mut de, config)).and_then(|value| {
+            de.end()?;
+            Ok(value)
+        });
+
+        let e = match result {
+            Ok(_) if !errors.is_empty() => return Err(errors),
+            Ok(value) => return Ok(value),
+            // syntax errors would be hit again when skipping past the value
+            Err(e) if e.classify() != Category::Data => {
+                errors.push(e);
+                return Err(errors);
+            }
+            Err(e) => e,
+        };
+
+        let mut state = collector.state.borrow_mut();
+        match state.outcome.take() {
+            Some(Outcome::Invalid(path)) => {
+                errors.push(e);
+                state.skipped.insert(path);
+            }
+            Some(Outcome::Missing(path)) => {
+                errors.push(e);
+                *state.reported.entry(path).or_insert(0) += 1;
+            }
+            Some(Outcome::Interrupted(path)) => {
+                state.skipped.insert(path);
+            }
+            Some(Outcome::Reported(_)) => return Err(errors),
+            None => {
+                errors.push(e);
+                return Err(errors);
+            }
+        }
+    }
+}
+
+// The state of a deserialization collecting errors, which is shared by every level of the deserializer.
+//
+// Objects and their fields are deserialized through `Collect_` seeds, which are identified by their positions in the
+// input. Deserialization fails at the first new error, after which the input is deserialized again, skipping any value
+// already found to be invalid and advancing past the object's missing fields which have been reported.
+#[derive(Default)]
+struct Collector {
+    state: RefCell<CollectorState>,
+}
+
+#[derive(Default)]
+struct CollectorState {
+    // the indices of the entries and elements leading to the value being deserialized
+    path: Vec<usize>,
+    skipped: HashSet<Vec<usize>>,
+    reported: HashMap<Vec<usize>, usize>,
+    outcome: Option<Outcome>,
+}
+
+// How the error returned from a `Collect_` seed relates to the value at a position.
+enum Outcome {
+    // the value is invalid, and the error is new
+    Invalid(Vec<usize>),
+    // the error is the next of the object's missing fields
+    Missing(Vec<usize>),
+    // the error stands in for those already reported from within the value, which was read entirely
+    Reported(Vec<usize>),
+    // the error stands in for those already reported from within the value, which may have only been read in part
+    Interrupted(Vec<usize>),
+}
+
+fn collect<'a, 'de, D, V>(
+    deserializer: D,
+    config: Config<'a>,
+    collector: &'a Collector,
+    visitor: V,
+) -> Result<V::Value, D::Error>
+where
+    D: de::Deserializer<'de>,
+    V: de::Visitor<'de>,
+{
+    let path = collector.state.borrow().path.clone();
+    let mut access = CollectAccess {
+        deserializer: Some(deserializer),
+        config,
+        collector,
+        path,
+        index: 0,
+        read: false,
+    };
+
+    let result = visitor.visit_seq(&mut access);
+    if result.is_err() && access.read {
+        let mut state = collector.state.borrow_mut();
+        if state.outcome.is_none() {
+            state.outcome = Some(Outcome::Reported(access.path));
+        }
+    }
+    result
+}
+
+// The sequence visited by a `Collect_` seed, of the number of the value's missing fields already reported, the value
+// itself, and optionally a unit marking the value's error as one of its missing fields.
+struct CollectAccess<'a, D> {
+    deserializer: Option<D>,
+    config: Config<'a>,
+    collector: &'a Collector,
+    path: Vec<usize>,
+    index: usize,
+    read: bool,
+}
+
+impl<'a, 'de, D> CollectAccess<'a, D>
+where
+    D: de::Deserializer<'de>,
+{
+    fn value<S>(&mut self, seed: S) -> Result<Option<S::Value>, D::Error>
+    where
+        S: de::DeserializeSeed<'de>,
+    {
+        let deserializer = match self.deserializer.take() {
+            Some(deserializer) => deserializer,
+            None => return Ok(None),
+        };
+
+        if self.collector.state.borrow().skipped.contains(&self.path) {
+            de::IgnoredAny::deserialize(deserializer)?;
+            self.read = true;
+            return Ok(None);
+        }
+
+        let e = match seed.deserialize(WrapDeserializer(deserializer, self.config)) {
+            Ok(value) => {
+                self.read = true;
+                return Ok(Some(value));
+            }
+            Err(e) => e,
+        };
+
+        let mut state = self.collector.state.borrow_mut();
+        state.outcome = match state.outcome.take() {
+            // the errors were reported from within an object which is this value
+            Some(Outcome::Reported(ref path)) if *path == self.path => {
+                self.read = true;
+                return Ok(None);
+            }
+            // the errors were reported from within an element or entry of this value, which was abandoned
+            Some(Outcome::Reported(_)) => Some(Outcome::Interrupted(self.path.clone())),
+            Some(outcome) => Some(outcome),
+            None => Some(Outcome::Invalid(self.path.clone())),
+        };
+        Err(e)
+    }
+}
+
+impl<'a, 'b, 'de, D> de::SeqAccess<'de> for &'b mut CollectAccess<'a, D>
+where
+    D: de::Deserializer<'de>,
+{
+    type Error = D::Error;
+
+    fn next_element_seed<S>(&mut self, seed: S) -> Result<Option<S::Value>, D::Error>
+    where
+        S: de::DeserializeSeed<'de>,
+    {
+        let index = self.index;
+        self.index += 1;
+
+        match index {
+            0 => {
+                let reported = self
+                    .collector
+                    .state
+                    .borrow()
+                    .reported
+                    .get(&self.path)
+                    .cloned()
+                    .unwrap_or(0);
+                seed.deserialize(reported.into_deserializer()).map(Some)
+            }
+            1 => self.value(seed),
+            _ => {
+                let mut state = self.collector.state.borrow_mut();
+                if let Some(Outcome::Invalid(path)) = state.outcome.take() {
+                    state.outcome = Some(Outcome::Missing(path));
+                }
+                Ok(None)
+            }
+        }
+    }
+}
+
+const UTF8_BOM: &[u8] = b"\xef\xbb\xbf";
+
+// whitespace as defined by the JSON spec
+#[cfg(feature = "relaxed")]
+fn is_whitespace(b: u8) -> bool {
+    [b' ', b'\t', b'\n', b'\r'].contains(&b)
+}
+
 /// A serde JSON deserializer appropriate for use by Conjure clients.
 ///
 /// In contrast to serde_json, the f32 and f64 types can be deserialized from the strings `"Infinity"`, `"-Infinity"`,
-/// and `"NaN"`, and bytes are deserialized from base64 encoded strings. Unknown object fields are ignored.
-pub struct ClientDeserializer<R>(serde_json::Deserializer<R>);
+/// and `"NaN"`, and bytes are deserialized from base64 encoded strings, ignoring whitespace. Unknown object fields
+/// are ignored.
+pub struct ClientDeserializer<R>(serde_json::Deserializer<R>, Config<'static>);
+
+#[derive(Copy, Clone)]
+struct Config<'a> {
+    remaining_depth: usize,
+    integer_booleans: bool,
+    collector: Option<&'a Collector>,
+}
+
+impl<'a> Default for Config<'a> {
+    fn default() -> Config<'a> {
+        Config {
+            remaining_depth: DEFAULT_MAX_DEPTH,
+            integer_booleans: false,
+            collector: None,
+        }
+    }
+}
+
+impl<'a> Config<'a> {
+    fn nest<E>(self) -> Result<Config<'a>, E>
+    where
+        E: de::Error,
+    {
+        Ok(Config {
+            remaining_depth: super::nest(self.remaining_depth)?,
+            ..self
+        })
+    }
+
+    // Runs the deserialization of the entry or element at an index of the current value, tracking its position when
+    // collecting errors.
+    fn at_index<T, F>(self, index: usize, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        let collector = match self.collector {
+            Some(collector) => collector,
+            None => return f(),
+        };
+
+        collector.state.borrow_mut().path.push(index);
+        let value = f();
+        collector.state.borrow_mut().path.pop();
+        value
+    }
+}
 
 impl<R> ClientDeserializer<IoRead<R>>
 where
@@ -67,14 +443,20 @@ where
 {
     /// Creates a Conjure JSON client deserializer from an `io::Read`.
     pub fn from_reader(reader: R) -> ClientDeserializer<IoRead<R>> {
-        ClientDeserializer(serde_json::Deserializer::from_reader(reader))
+        ClientDeserializer(
+            serde_json::Deserializer::from_reader(reader),
+            Config::default(),
+        )
     }
 }
 
 impl<'a> ClientDeserializer<SliceRead<'a>> {
     /// Creates a Conjure JSON client deserializer from a `&[u8]`.
     pub fn from_slice(bytes: &'a [u8]) -> ClientDeserializer<SliceRead<'a>> {
-        ClientDeserializer(serde_json::Deserializer::from_slice(bytes))
+        ClientDeserializer(
+            serde_json::Deserializer::from_slice(bytes),
+            Config::default(),
+        )
     }
 }
 
@@ -82,7 +464,7 @@ impl<'a> ClientDeserializer<StrRead<'a>> {
     /// Creates a Conjure JSON client deserializer from a `&str`.
     #[allow(clippy::should_implement_trait)] // match serde_json's API
     pub fn from_str(s: &'a str) -> ClientDeserializer<StrRead<'a>> {
-        ClientDeserializer(serde_json::Deserializer::from_str(s))
+        ClientDeserializer(serde_json::Deserializer::from_str(s), Config::default())
     }
 }
 
@@ -90,6 +472,28 @@ impl<'de, R> ClientDeserializer<R>
 where
     R: Read<'de>,
 {
+    /// Configures the maximum depth of nested values.
+    ///
+    /// Each array and object in the input counts as one level of nesting; optional and newtype values don't.
+    /// Deserialization fails once the limit is exceeded rather than risking a stack overflow on deeply nested input.
+    /// Defaults to 128.
+    ///
+    /// `serde_json` separately enforces its own limit of 128 levels, so this can only lower the effective limit. Larger
+    /// values behave the same as the default.
+    pub fn max_depth(mut self, max_depth: usize) -> ClientDeserializer<R> {
+        self.1.remaining_depth = max_depth;
+        self
+    }
+
+    /// Configures the deserialization of booleans from integers.
+    ///
+    /// If enabled, the integers `0` and `1` are accepted as `false` and `true` respectively to interoperate with
+    /// producers which encode booleans that way. Other integers are still rejected. Defaults to `false`.
+    pub fn integer_booleans(mut self, integer_booleans: bool) -> ClientDeserializer<R> {
+        self.1.integer_booleans = integer_booleans;
+        self
+    }
+
     /// Validates that the input stream is at the end or that it only has trailing whitespace.
     pub fn end(&mut self) -> Result<(), Error> {
         self.0.end()
@@ -103,7 +507,7 @@ macro_rules! delegate_deserialize {
             where
                 V: de::Visitor<'de>
             {
-                (self.0).$method(Visitor(visitor))
+                (self.0).$method(Visitor(visitor, self.1))
             }
         )*
     }
@@ -117,7 +521,6 @@ where
 
     delegate_deserialize!(
         deserialize_any,
-        deserialize_bool,
         deserialize_i8,
         deserialize_i16,
         deserialize_i32,
@@ -139,6 +542,17 @@ where
         deserialize_u128,
     );
 
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        if self.1.integer_booleans {
+            self.0.deserialize_any(IntegerBoolVisitor(visitor))
+        } else {
+            self.0.deserialize_bool(Visitor(visitor, self.1))
+        }
+    }
+
     fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value, Error>
     where
         V: de::Visitor<'de>,
@@ -164,14 +578,18 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_str(ByteBufVisitor(visitor))
+        self.0.deserialize_str(ByteBufVisitor {
+            visitor,
+            strict: false,
+        })
     }
 
     fn deserialize_unit_struct<V>(self, name: &'static str, visitor: V) -> Result<V::Value, Error>
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_unit_struct(name, Visitor(visitor))
+        self.0
+            .deserialize_unit_struct(name, Visitor(visitor, self.1))
     }
 
     fn deserialize_newtype_struct<V>(
@@ -182,14 +600,15 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_newtype_struct(name, Visitor(visitor))
+        self.0
+            .deserialize_newtype_struct(name, Visitor(visitor, self.1))
     }
 
     fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, Error>
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_tuple(len, Visitor(visitor))
+        self.0.deserialize_tuple(len, Visitor(visitor, self.1))
     }
 
     fn deserialize_tuple_struct<V>(
@@ -201,7 +620,8 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_tuple_struct(name, len, Visitor(visitor))
+        self.0
+            .deserialize_tuple_struct(name, len, Visitor(visitor, self.1))
     }
 
     fn deserialize_struct<V>(
@@ -213,7 +633,8 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_struct(name, fields, Visitor(visitor))
+        self.0
+            .deserialize_struct(name, fields, Visitor(visitor, self.1))
     }
 
     fn deserialize_enum<V>(
@@ -225,7 +646,8 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_enum(name, variants, Visitor(visitor))
+        self.0
+            .deserialize_enum(name, variants, Visitor(visitor, self.1))
     }
 
     // we can't delegate this due to the signature, but luckily we know the answer
@@ -234,7 +656,91 @@ where
     }
 }
 
-struct WrapDeserializer<T>(T);
+/// An iterator over a stream of concatenated Conjure JSON values, such as a log of JSON objects.
+///
+/// Values may be separated by whitespace or directly adjacent, and each is deserialized as by a `ClientDeserializer`.
+/// Iteration ends at the end of the input, or after the first error.
+pub struct StreamDeserializer<'de, R, T>(serde_json::StreamDeserializer<'de, R, StreamValue<T>>);
+
+impl<'de, R, T> StreamDeserializer<'de, IoRead<R>, T>
+where
+    R: io::Read,
+    T: de::Deserialize<'de>,
+{
+    /// Creates a stream deserializer from an `io::Read`.
+    pub fn from_reader(reader: R) -> StreamDeserializer<'de, IoRead<R>, T> {
+        StreamDeserializer(serde_json::StreamDeserializer::new(IoRead::new(reader)))
+    }
+}
+
+impl<'a, T> StreamDeserializer<'a, SliceRead<'a>, T>
+where
+    T: de::Deserialize<'a>,
+{
+    /// Creates a stream deserializer from a `&[u8]`.
+    pub fn from_slice(bytes: &'a [u8]) -> StreamDeserializer<'a, SliceRead<'a>, T> {
+        StreamDeserializer(serde_json::StreamDeserializer::new(SliceRead::new(bytes)))
+    }
+}
+
+impl<'a, T> StreamDeserializer<'a, StrRead<'a>, T>
+where
+    T: de::Deserialize<'a>,
+{
+    /// Creates a stream deserializer from a `&str`.
+    #[allow(clippy::should_implement_trait)] // match serde_json's API
+    pub fn from_str(s: &'a str) -> StreamDeserializer<'a, StrRead<'a>, T> {
+        StreamDeserializer(serde_json::StreamDeserializer::new(StrRead::new(s)))
+    }
+}
+
+impl<'de, R, T> StreamDeserializer<'de, R, T>
+where
+    R: Read<'de>,
+    T: de::Deserialize<'de>,
+{
+    /// Returns the number of bytes consumed so far.
+    ///
+    /// After an error, this is the offset of the start of the value which failed to deserialize.
+    pub fn byte_offset(&self) -> usize {
+        self.0.byte_offset()
+    }
+}
+
+impl<'de, R, T> Iterator for StreamDeserializer<'de, R, T>
+where
+    R: Read<'de>,
+    T: de::Deserialize<'de>,
+{
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Result<T, Error>> {
+        self.0.next().map(|r| r.map(|v| v.0))
+    }
+}
+
+// serde_json's stream deserializer handles the separation of values, and this applies Conjure's semantics to each.
+struct StreamValue<T>(T);
+
+impl<'de, T> de::Deserialize<'de> for StreamValue<T>
+where
+    T: de::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<StreamValue<T>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        T::deserialize(WrapDeserializer::new(deserializer)).map(StreamValue)
+    }
+}
+
+pub(crate) struct WrapDeserializer<'a, T>(T, Config<'a>);
+
+impl<T> WrapDeserializer<'static, T> {
+    pub(crate) fn new(deserializer: T) -> WrapDeserializer<'static, T> {
+        WrapDeserializer(deserializer, Config::default())
+    }
+}
 
 macro_rules! delegate_wrap_deserialize {
     ($($method:ident,)*) => {
@@ -243,13 +749,13 @@ macro_rules! delegate_wrap_deserialize {
             where
                 V: de::Visitor<'de>
             {
-                (self.0).$method(Visitor(visitor))
+                (self.0).$method(Visitor(visitor, self.1))
             }
         )*
     }
 }
 
-impl<'de, T> de::Deserializer<'de> for WrapDeserializer<T>
+impl<'a, 'de, T> de::Deserializer<'de> for WrapDeserializer<'a, T>
 where
     T: de::Deserializer<'de>,
 {
@@ -257,7 +763,6 @@ where
 
     delegate_wrap_deserialize!(
         deserialize_any,
-        deserialize_bool,
         deserialize_i8,
         deserialize_i16,
         deserialize_i32,
@@ -279,6 +784,17 @@ where
         deserialize_u128,
     );
 
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, T::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        if self.1.integer_booleans {
+            self.0.deserialize_any(IntegerBoolVisitor(visitor))
+        } else {
+            self.0.deserialize_bool(Visitor(visitor, self.1))
+        }
+    }
+
     fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value, T::Error>
     where
         V: de::Visitor<'de>,
@@ -304,7 +820,15 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_str(ByteBufVisitor(visitor))
+        // only human readable formats encode bytes as base64 strings
+        if !self.0.is_human_readable() {
+            return self.0.deserialize_byte_buf(Visitor(visitor, self.1));
+        }
+
+        self.0.deserialize_str(ByteBufVisitor {
+            visitor,
+            strict: false,
+        })
     }
 
     fn deserialize_unit_struct<V>(
@@ -315,7 +839,8 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_unit_struct(name, Visitor(visitor))
+        self.0
+            .deserialize_unit_struct(name, Visitor(visitor, self.1))
     }
 
     fn deserialize_newtype_struct<V>(
@@ -326,14 +851,21 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_newtype_struct(name, Visitor(visitor))
+        if name == private::COLLECT_ {
+            if let Some(collector) = self.1.collector {
+                return collect(self.0, self.1, collector, visitor);
+            }
+        }
+
+        self.0
+            .deserialize_newtype_struct(name, Visitor(visitor, self.1))
     }
 
     fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, T::Error>
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_tuple(len, Visitor(visitor))
+        self.0.deserialize_tuple(len, Visitor(visitor, self.1))
     }
 
     fn deserialize_tuple_struct<V>(
@@ -345,7 +877,8 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_tuple_struct(name, len, Visitor(visitor))
+        self.0
+            .deserialize_tuple_struct(name, len, Visitor(visitor, self.1))
     }
 
     fn deserialize_struct<V>(
@@ -357,7 +890,8 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_struct(name, fields, Visitor(visitor))
+        self.0
+            .deserialize_struct(name, fields, Visitor(visitor, self.1))
     }
 
     fn deserialize_enum<V>(
@@ -369,7 +903,8 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_enum(name, variants, Visitor(visitor))
+        self.0
+            .deserialize_enum(name, variants, Visitor(visitor, self.1))
     }
 
     fn is_human_readable(&self) -> bool {
@@ -377,7 +912,7 @@ where
     }
 }
 
-struct Visitor<T>(T);
+struct Visitor<'a, T>(T, Config<'a>);
 
 macro_rules! delegate_visit {
     ($($method:ident = $ty:ty,)*) => {
@@ -392,7 +927,7 @@ macro_rules! delegate_visit {
     };
 }
 
-impl<'de, T> de::Visitor<'de> for Visitor<T>
+impl<'a, 'de, T> de::Visitor<'de> for Visitor<'a, T>
 where
     T: de::Visitor<'de>,
 {
@@ -408,12 +943,10 @@ where
         visit_i16 = i16,
         visit_i32 = i32,
         visit_i64 = i64,
-        visit_i128 = i128,
         visit_u8 = u8,
         visit_u16 = u16,
         visit_u32 = u32,
         visit_u64 = u64,
-        visit_u128 = u128,
         visit_f32 = f32,
         visit_f64 = f64,
         visit_char = char,
@@ -425,6 +958,20 @@ where
         visit_byte_buf = Vec<u8>,
     );
 
+    fn visit_i128<E>(self, v: i128) -> Result<T::Value, E>
+    where
+        E: de::Error,
+    {
+        super::visit_i128(self.0, v)
+    }
+
+    fn visit_u128<E>(self, v: u128) -> Result<T::Value, E>
+    where
+        E: de::Error,
+    {
+        super::visit_u128(self.0, v)
+    }
+
     fn visit_none<E>(self) -> Result<T::Value, E>
     where
         E: de::Error,
@@ -436,7 +983,7 @@ where
     where
         D: de::Deserializer<'de>,
     {
-        self.0.visit_some(WrapDeserializer(deserializer))
+        self.0.visit_some(WrapDeserializer(deserializer, self.1))
     }
 
     fn visit_unit<E>(self) -> Result<T::Value, E>
@@ -450,34 +997,38 @@ where
     where
         D: de::Deserializer<'de>,
     {
-        self.0.visit_newtype_struct(WrapDeserializer(deserializer))
+        self.0
+            .visit_newtype_struct(WrapDeserializer(deserializer, self.1))
     }
 
     fn visit_seq<A>(self, seq: A) -> Result<T::Value, A::Error>
     where
         A: de::SeqAccess<'de>,
     {
-        self.0.visit_seq(SeqAccess(seq))
+        let config = self.1.nest::<A::Error>()?;
+        self.0.visit_seq(SeqAccess(seq, config, 0))
     }
 
     fn visit_map<A>(self, map: A) -> Result<T::Value, A::Error>
     where
         A: de::MapAccess<'de>,
     {
-        self.0.visit_map(MapAccess(map))
+        let config = self.1.nest::<A::Error>()?;
+        self.0.visit_map(MapAccess(map, config, 0))
     }
 
     fn visit_enum<A>(self, data: A) -> Result<T::Value, A::Error>
     where
         A: de::EnumAccess<'de>,
     {
-        self.0.visit_enum(EnumAccess(data))
+        self.0.visit_enum(EnumAccess(data, self.1))
     }
 }
 
-struct SeqAccess<T>(T);
+// The index of the next element is tracked for the positions of values when collecting errors.
+struct SeqAccess<'a, T>(T, Config<'a>, usize);
 
-impl<'de, T> de::SeqAccess<'de> for SeqAccess<T>
+impl<'a, 'de, T> de::SeqAccess<'de> for SeqAccess<'a, T>
 where
     T: de::SeqAccess<'de>,
 {
@@ -487,7 +1038,12 @@ where
     where
         U: de::DeserializeSeed<'de>,
     {
-        self.0.next_element_seed(DeserializeSeed(seed))
+        let config = self.1;
+        let index = self.2;
+        self.2 += 1;
+        config.at_index(index, || {
+            self.0.next_element_seed(DeserializeSeed(seed, config))
+        })
     }
 
     fn size_hint(&self) -> Option<usize> {
@@ -495,9 +1051,10 @@ where
     }
 }
 
-struct MapAccess<T>(T);
+// The index of the next entry is tracked for the positions of values when collecting errors.
+struct MapAccess<'a, T>(T, Config<'a>, usize);
 
-impl<'de, T> de::MapAccess<'de> for MapAccess<T>
+impl<'a, 'de, T> de::MapAccess<'de> for MapAccess<'a, T>
 where
     T: de::MapAccess<'de>,
 {
@@ -507,14 +1064,19 @@ where
     where
         K: de::DeserializeSeed<'de>,
     {
-        self.0.next_key_seed(DeserializeSeed(seed))
+        self.0.next_key_seed(DeserializeSeed(seed, self.1))
     }
 
     fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, T::Error>
     where
         V: de::DeserializeSeed<'de>,
     {
-        self.0.next_value_seed(DeserializeSeed(seed))
+        let config = self.1;
+        let index = self.2;
+        self.2 += 1;
+        config.at_index(index, || {
+            self.0.next_value_seed(DeserializeSeed(seed, config))
+        })
     }
 
     #[allow(clippy::type_complexity)]
@@ -527,8 +1089,15 @@ where
         K: de::DeserializeSeed<'de>,
         V: de::DeserializeSeed<'de>,
     {
-        self.0
-            .next_entry_seed(DeserializeSeed(kseed), DeserializeSeed(vseed))
+        let config = self.1;
+        let index = self.2;
+        self.2 += 1;
+        config.at_index(index, || {
+            self.0.next_entry_seed(
+                DeserializeSeed(kseed, config),
+                DeserializeSeed(vseed, config),
+            )
+        })
     }
 
     fn size_hint(&self) -> Option<usize> {
@@ -536,29 +1105,30 @@ where
     }
 }
 
-struct EnumAccess<T>(T);
+struct EnumAccess<'a, T>(T, Config<'a>);
 
-impl<'de, T> de::EnumAccess<'de> for EnumAccess<T>
+impl<'a, 'de, T> de::EnumAccess<'de> for EnumAccess<'a, T>
 where
     T: de::EnumAccess<'de>,
 {
     type Error = T::Error;
-    type Variant = VariantAccess<T::Variant>;
+    type Variant = VariantAccess<'a, T::Variant>;
 
     #[allow(clippy::type_complexity)]
-    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, VariantAccess<T::Variant>), T::Error>
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, VariantAccess<'a, T::Variant>), T::Error>
     where
         V: de::DeserializeSeed<'de>,
     {
+        let config = self.1;
         self.0
-            .variant_seed(DeserializeSeed(seed))
-            .map(|(value, variant)| (value, VariantAccess(variant)))
+            .variant_seed(DeserializeSeed(seed, config))
+            .map(|(value, variant)| (value, VariantAccess(variant, config)))
     }
 }
 
-struct VariantAccess<T>(T);
+struct VariantAccess<'a, T>(T, Config<'a>);
 
-impl<'de, T> de::VariantAccess<'de> for VariantAccess<T>
+impl<'a, 'de, T> de::VariantAccess<'de> for VariantAccess<'a, T>
 where
     T: de::VariantAccess<'de>,
 {
@@ -572,14 +1142,14 @@ where
     where
         U: de::DeserializeSeed<'de>,
     {
-        self.0.newtype_variant_seed(DeserializeSeed(seed))
+        self.0.newtype_variant_seed(DeserializeSeed(seed, self.1))
     }
 
     fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, T::Error>
     where
         V: de::Visitor<'de>,
     {
-        self.0.tuple_variant(len, Visitor(visitor))
+        self.0.tuple_variant(len, Visitor(visitor, self.1))
     }
 
     fn struct_variant<V>(
@@ -590,13 +1160,13 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.struct_variant(fields, Visitor(visitor))
+        self.0.struct_variant(fields, Visitor(visitor, self.1))
     }
 }
 
-struct DeserializeSeed<T>(T);
+struct DeserializeSeed<'a, T>(T, Config<'a>);
 
-impl<'de, T> de::DeserializeSeed<'de> for DeserializeSeed<T>
+impl<'a, 'de, T> de::DeserializeSeed<'de> for DeserializeSeed<'a, T>
 where
     T: de::DeserializeSeed<'de>,
 {
@@ -606,6 +1176,6 @@ where
     where
         D: de::Deserializer<'de>,
     {
-        self.0.deserialize(WrapDeserializer(deserializer))
+        self.0.deserialize(WrapDeserializer(deserializer, self.1))
     }
 }
@@ -19,6 +19,19 @@ use std::fmt;
 pub mod client;
 pub mod server;
 
+// serde_json's own limit on the nesting of JSON values
+const DEFAULT_MAX_DEPTH: usize = 128;
+
+// Returns the depth remaining for values nested one level further, or an error if there is none left.
+fn nest<E>(remaining_depth: usize) -> Result<usize, E>
+where
+    E: de::Error,
+{
+    remaining_depth
+        .checked_sub(1)
+        .ok_or_else(|| E::custom("recursion limit exceeded"))
+}
+
 macro_rules! delegate_visit {
     ($($method:ident = $ty:ty,)*) => {
         $(
@@ -51,16 +64,28 @@ macro_rules! float_visitor {
                 visit_i16 = i16,
                 visit_i32 = i32,
                 visit_i64 = i64,
-                visit_i128 = i128,
                 visit_u8 = u8,
                 visit_u16 = u16,
                 visit_u32 = u32,
                 visit_u64 = u64,
-                visit_u128 = u128,
                 visit_f32 = f32,
                 visit_f64 = f64,
             );
 
+            fn visit_i128<E>(self, v: i128) -> Result<T::Value, E>
+            where
+                E: de::Error,
+            {
+                visit_i128(self.0, v)
+            }
+
+            fn visit_u128<E>(self, v: u128) -> Result<T::Value, E>
+            where
+                E: de::Error,
+            {
+                visit_u128(self.0, v)
+            }
+
             fn visit_str<E>(self, v: &str) -> Result<T::Value, E>
             where
                 E: de::Error,
@@ -76,10 +101,94 @@ macro_rules! float_visitor {
     };
 }
 
+// Conjure has no 128 bit integer types, so values are narrowed to the 64 bit visitor methods when they fit and
+// rejected otherwise rather than being handed to the inner visitor.
+fn visit_i128<'de, V, E>(visitor: V, v: i128) -> Result<V::Value, E>
+where
+    V: de::Visitor<'de>,
+    E: de::Error,
+{
+    if v >= i128::from(i64::min_value()) && v <= i128::from(i64::max_value()) {
+        visitor.visit_i64(v as i64)
+    } else if v >= 0 && v <= i128::from(u64::max_value()) {
+        visitor.visit_u64(v as u64)
+    } else {
+        Err(E::invalid_value(
+            de::Unexpected::Other("128 bit integer outside of the 64 bit range"),
+            &visitor,
+        ))
+    }
+}
+
+fn visit_u128<'de, V, E>(visitor: V, v: u128) -> Result<V::Value, E>
+where
+    V: de::Visitor<'de>,
+    E: de::Error,
+{
+    if v <= u128::from(u64::max_value()) {
+        visitor.visit_u64(v as u64)
+    } else {
+        Err(E::invalid_value(
+            de::Unexpected::Other("128 bit integer outside of the 64 bit range"),
+            &visitor,
+        ))
+    }
+}
+
 float_visitor!(F32Visitor, visit_f32, f32);
 float_visitor!(F64Visitor, visit_f64, f64);
 
-struct ByteBufVisitor<T>(T);
+/// A visitor which additionally accepts the integers `0` and `1` as `false` and `true`.
+struct IntegerBoolVisitor<T>(T);
+
+impl<'de, T> de::Visitor<'de> for IntegerBoolVisitor<T>
+where
+    T: de::Visitor<'de>,
+{
+    type Value = T::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a boolean, 0, or 1")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<T::Value, E>
+    where
+        E: de::Error,
+    {
+        self.0.visit_bool(v)
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<T::Value, E>
+    where
+        E: de::Error,
+    {
+        match v {
+            0 => self.0.visit_bool(false),
+            1 => self.0.visit_bool(true),
+            _ => Err(E::invalid_value(de::Unexpected::Signed(v), &self)),
+        }
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<T::Value, E>
+    where
+        E: de::Error,
+    {
+        match v {
+            0 => self.0.visit_bool(false),
+            1 => self.0.visit_bool(true),
+            _ => Err(E::invalid_value(de::Unexpected::Unsigned(v), &self)),
+        }
+    }
+}
+
+/// A visitor decoding base64 strings.
+///
+/// In strict mode, only the canonical padded encoding is accepted. Otherwise, whitespace is ignored and padding is
+/// optional.
+struct ByteBufVisitor<T> {
+    visitor: T,
+    strict: bool,
+}
 
 impl<'de, T> de::Visitor<'de> for ByteBufVisitor<T>
 where
@@ -95,9 +204,21 @@ where
     where
         E: de::Error,
     {
-        match base64::decode(v) {
-            Ok(v) => self.0.visit_byte_buf(v),
-            Err(_) => Err(E::invalid_value(de::Unexpected::Str(v), &self)),
+        let decoded = if self.strict {
+            base64::decode(v)
+                .ok()
+                .filter(|bytes| base64::encode(bytes) == v)
+        } else {
+            let v = v
+                .chars()
+                .filter(|c| !c.is_ascii_whitespace())
+                .collect::<String>();
+            base64::decode(&v).ok()
+        };
+
+        match decoded {
+            Some(bytes) => self.visitor.visit_byte_buf(bytes),
+            None => Err(E::invalid_value(de::Unexpected::Str(v), &self)),
         }
     }
 }
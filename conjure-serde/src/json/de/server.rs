@@ -11,13 +11,15 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use serde::de;
+use conjure_object::private;
+use serde::de::{self, IntoDeserializer};
 use serde_json::de::{IoRead, Read, SliceRead, StrRead};
 use serde_json::Error;
+use std::borrow::Cow;
 use std::fmt;
 use std::io;
 
-use crate::json::de::{ByteBufVisitor, F32Visitor, F64Visitor};
+use crate::json::de::{ByteBufVisitor, F32Visitor, F64Visitor, DEFAULT_MAX_DEPTH};
 
 /// Deserializes a value from a reader of JSON data.
 pub fn server_from_reader<R, T>(reader: R) -> Result<T, Error>
@@ -56,8 +58,48 @@ where
 /// A serde JSON deserializer appropriate for use by Conjure servers.
 ///
 /// In contrast to serde_json, the f32 and f64 types can be deserialized from the strings `"Infinity"`, `"-Infinity"`,
-/// and `"NaN"`, and bytes are deserialized from base64 encoded strings. Unknown object fields trigger errors.
-pub struct ServerDeserializer<R>(serde_json::Deserializer<R>);
+/// and `"NaN"`, and bytes are deserialized from canonical base64 encoded strings. Unknown object fields trigger
+/// errors, and errors caused by an object field's value identify the field.
+pub struct ServerDeserializer<R>(serde_json::Deserializer<R>, Config);
+
+#[derive(Copy, Clone)]
+struct Config {
+    empty_string_as_null: bool,
+    optional_string: bool,
+    strict_base64: bool,
+    remaining_depth: usize,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            empty_string_as_null: false,
+            optional_string: false,
+            strict_base64: true,
+            remaining_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+}
+
+impl Config {
+    fn nest<E>(self) -> Result<Config, E>
+    where
+        E: de::Error,
+    {
+        Ok(Config {
+            remaining_depth: super::nest(self.remaining_depth)?,
+            ..self
+        })
+    }
+
+    // Marks the value as an optional string, which is deserialized through `OptionVisitor`.
+    fn optional_string(self) -> Config {
+        Config {
+            optional_string: true,
+            ..self
+        }
+    }
+}
 
 impl<R> ServerDeserializer<IoRead<R>>
 where
@@ -65,14 +107,20 @@ where
 {
     /// Creates a Conjure JSON server deserializer from an `io::Read`.
     pub fn from_reader(reader: R) -> ServerDeserializer<IoRead<R>> {
-        ServerDeserializer(serde_json::Deserializer::from_reader(reader))
+        ServerDeserializer(
+            serde_json::Deserializer::from_reader(reader),
+            Config::default(),
+        )
     }
 }
 
 impl<'a> ServerDeserializer<SliceRead<'a>> {
     /// Creates a Conjure JSON server deserializer from a `&[u8]`.
     pub fn from_slice(bytes: &'a [u8]) -> ServerDeserializer<SliceRead<'a>> {
-        ServerDeserializer(serde_json::Deserializer::from_slice(bytes))
+        ServerDeserializer(
+            serde_json::Deserializer::from_slice(bytes),
+            Config::default(),
+        )
     }
 }
 
@@ -80,7 +128,7 @@ impl<'a> ServerDeserializer<StrRead<'a>> {
     /// Creates a Conjure JSON server deserializer from a `&str`.
     #[allow(clippy::should_implement_trait)] // match serde_json's API
     pub fn from_str(s: &'a str) -> ServerDeserializer<StrRead<'a>> {
-        ServerDeserializer(serde_json::Deserializer::from_str(s))
+        ServerDeserializer(serde_json::Deserializer::from_str(s), Config::default())
     }
 }
 
@@ -88,6 +136,37 @@ impl<'de, R> ServerDeserializer<R>
 where
     R: Read<'de>,
 {
+    /// Configures the deserializer to treat an empty string as an absent optional string.
+    ///
+    /// Only the optional string fields of generated Conjure types are affected; required strings are still deserialized
+    /// as empty strings, and other optional values still reject empty strings. Defaults to `false`.
+    pub fn empty_string_as_null(mut self, empty_string_as_null: bool) -> ServerDeserializer<R> {
+        self.1.empty_string_as_null = empty_string_as_null;
+        self
+    }
+
+    /// Configures the deserializer to only accept canonical base64 encodings of binary values.
+    ///
+    /// When enabled, encodings with missing padding or embedded whitespace are rejected. When disabled, bytes are
+    /// decoded as leniently as by the client deserializer. Defaults to `true`.
+    pub fn strict_base64(mut self, strict_base64: bool) -> ServerDeserializer<R> {
+        self.1.strict_base64 = strict_base64;
+        self
+    }
+
+    /// Configures the maximum depth of nested values.
+    ///
+    /// Each array and object in the input counts as one level of nesting; optional and newtype values don't.
+    /// Deserialization fails once the limit is exceeded rather than risking a stack overflow on deeply nested input.
+    /// Defaults to 128.
+    ///
+    /// `serde_json` separately enforces its own limit of 128 levels, so this can only lower the effective limit. Larger
+    /// values behave the same as the default.
+    pub fn max_depth(mut self, max_depth: usize) -> ServerDeserializer<R> {
+        self.1.remaining_depth = max_depth;
+        self
+    }
+
     /// Validates that the input stream is at the end or that it only has trailing whitespace.
     pub fn end(&mut self) -> Result<(), Error> {
         self.0.end()
@@ -101,7 +180,7 @@ macro_rules! delegate_deserialize {
             where
                 V: de::Visitor<'de>
             {
-                (self.0).$method(Visitor(visitor))
+                (self.0).$method(Visitor(visitor, self.1))
             }
         )*
     }
@@ -127,7 +206,6 @@ where
         deserialize_char,
         deserialize_str,
         deserialize_string,
-        deserialize_option,
         deserialize_unit,
         deserialize_seq,
         deserialize_map,
@@ -151,6 +229,13 @@ where
         self.0.deserialize_any(F64Visitor(visitor))
     }
 
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.0.deserialize_option(Visitor(visitor, self.1))
+    }
+
     fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Error>
     where
         V: de::Visitor<'de>,
@@ -162,14 +247,18 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_str(ByteBufVisitor(visitor))
+        self.0.deserialize_str(ByteBufVisitor {
+            visitor,
+            strict: self.1.strict_base64,
+        })
     }
 
     fn deserialize_unit_struct<V>(self, name: &'static str, visitor: V) -> Result<V::Value, Error>
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_unit_struct(name, Visitor(visitor))
+        self.0
+            .deserialize_unit_struct(name, Visitor(visitor, self.1))
     }
 
     fn deserialize_newtype_struct<V>(
@@ -180,14 +269,20 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_newtype_struct(name, Visitor(visitor))
+        if name == private::OPTIONAL_STRING_ && self.1.empty_string_as_null {
+            return visitor
+                .visit_newtype_struct(WrapDeserializer(&mut self.0, self.1.optional_string()));
+        }
+
+        self.0
+            .deserialize_newtype_struct(name, Visitor(visitor, self.1))
     }
 
     fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, Error>
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_tuple(len, Visitor(visitor))
+        self.0.deserialize_tuple(len, Visitor(visitor, self.1))
     }
 
     fn deserialize_tuple_struct<V>(
@@ -199,7 +294,8 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_tuple_struct(name, len, Visitor(visitor))
+        self.0
+            .deserialize_tuple_struct(name, len, Visitor(visitor, self.1))
     }
 
     fn deserialize_struct<V>(
@@ -211,8 +307,15 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0
-            .deserialize_struct(name, fields, StructVisitor { visitor, fields })
+        self.0.deserialize_struct(
+            name,
+            fields,
+            StructVisitor {
+                visitor,
+                fields,
+                config: self.1,
+            },
+        )
     }
 
     fn deserialize_enum<V>(
@@ -224,7 +327,8 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_enum(name, variants, Visitor(visitor))
+        self.0
+            .deserialize_enum(name, variants, Visitor(visitor, self.1))
     }
 
     // we can't delegate this due to the signature, but luckily we know the answer
@@ -233,7 +337,14 @@ where
     }
 }
 
-struct WrapDeserializer<T>(T);
+pub(crate) struct WrapDeserializer<T>(T, Config);
+
+impl<T> WrapDeserializer<T> {
+    #[cfg(test)]
+    pub(crate) fn new(deserializer: T) -> WrapDeserializer<T> {
+        WrapDeserializer(deserializer, Config::default())
+    }
+}
 
 macro_rules! delegate_wrap_deserialize {
     ($($method:ident,)*) => {
@@ -242,7 +353,7 @@ macro_rules! delegate_wrap_deserialize {
             where
                 V: de::Visitor<'de>
             {
-                (self.0).$method(Visitor(visitor))
+                (self.0).$method(Visitor(visitor, self.1))
             }
         )*
     }
@@ -268,7 +379,6 @@ where
         deserialize_char,
         deserialize_str,
         deserialize_string,
-        deserialize_option,
         deserialize_unit,
         deserialize_seq,
         deserialize_map,
@@ -292,6 +402,21 @@ where
         self.0.deserialize_any(F64Visitor(visitor))
     }
 
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, T::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        if self.1.optional_string {
+            let config = Config {
+                optional_string: false,
+                ..self.1
+            };
+            self.0.deserialize_any(OptionVisitor(visitor, config))
+        } else {
+            self.0.deserialize_option(Visitor(visitor, self.1))
+        }
+    }
+
     fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, T::Error>
     where
         V: de::Visitor<'de>,
@@ -303,7 +428,15 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_str(ByteBufVisitor(visitor))
+        // only human readable formats encode bytes as base64 strings
+        if !self.0.is_human_readable() {
+            return self.0.deserialize_byte_buf(Visitor(visitor, self.1));
+        }
+
+        self.0.deserialize_str(ByteBufVisitor {
+            visitor,
+            strict: self.1.strict_base64,
+        })
     }
 
     fn deserialize_unit_struct<V>(
@@ -314,7 +447,8 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_unit_struct(name, Visitor(visitor))
+        self.0
+            .deserialize_unit_struct(name, Visitor(visitor, self.1))
     }
 
     fn deserialize_newtype_struct<V>(
@@ -325,14 +459,20 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_newtype_struct(name, Visitor(visitor))
+        if name == private::OPTIONAL_STRING_ && self.1.empty_string_as_null {
+            return visitor
+                .visit_newtype_struct(WrapDeserializer(self.0, self.1.optional_string()));
+        }
+
+        self.0
+            .deserialize_newtype_struct(name, Visitor(visitor, self.1))
     }
 
     fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, T::Error>
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_tuple(len, Visitor(visitor))
+        self.0.deserialize_tuple(len, Visitor(visitor, self.1))
     }
 
     fn deserialize_tuple_struct<V>(
@@ -344,7 +484,8 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_tuple_struct(name, len, Visitor(visitor))
+        self.0
+            .deserialize_tuple_struct(name, len, Visitor(visitor, self.1))
     }
 
     fn deserialize_struct<V>(
@@ -356,8 +497,15 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0
-            .deserialize_struct(name, fields, StructVisitor { visitor, fields })
+        self.0.deserialize_struct(
+            name,
+            fields,
+            StructVisitor {
+                visitor,
+                fields,
+                config: self.1,
+            },
+        )
     }
 
     fn deserialize_enum<V>(
@@ -369,7 +517,8 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.deserialize_enum(name, variants, Visitor(visitor))
+        self.0
+            .deserialize_enum(name, variants, Visitor(visitor, self.1))
     }
 
     fn is_human_readable(&self) -> bool {
@@ -377,7 +526,7 @@ where
     }
 }
 
-struct Visitor<T>(T);
+struct Visitor<T>(T, Config);
 
 macro_rules! delegate_visit {
     ($($method:ident = $ty:ty,)*) => {
@@ -408,12 +557,10 @@ where
         visit_i16 = i16,
         visit_i32 = i32,
         visit_i64 = i64,
-        visit_i128 = i128,
         visit_u8 = u8,
         visit_u16 = u16,
         visit_u32 = u32,
         visit_u64 = u64,
-        visit_u128 = u128,
         visit_f32 = f32,
         visit_f64 = f64,
         visit_char = char,
@@ -425,6 +572,20 @@ where
         visit_byte_buf = Vec<u8>,
     );
 
+    fn visit_i128<E>(self, v: i128) -> Result<T::Value, E>
+    where
+        E: de::Error,
+    {
+        super::visit_i128(self.0, v)
+    }
+
+    fn visit_u128<E>(self, v: u128) -> Result<T::Value, E>
+    where
+        E: de::Error,
+    {
+        super::visit_u128(self.0, v)
+    }
+
     fn visit_none<E>(self) -> Result<T::Value, E>
     where
         E: de::Error,
@@ -436,7 +597,7 @@ where
     where
         D: de::Deserializer<'de>,
     {
-        self.0.visit_some(WrapDeserializer(deserializer))
+        self.0.visit_some(WrapDeserializer(deserializer, self.1))
     }
 
     fn visit_unit<E>(self) -> Result<T::Value, E>
@@ -450,34 +611,38 @@ where
     where
         D: de::Deserializer<'de>,
     {
-        self.0.visit_newtype_struct(WrapDeserializer(deserializer))
+        self.0
+            .visit_newtype_struct(WrapDeserializer(deserializer, self.1))
     }
 
     fn visit_seq<A>(self, seq: A) -> Result<T::Value, A::Error>
     where
         A: de::SeqAccess<'de>,
     {
-        self.0.visit_seq(SeqAccess(seq))
+        let config = self.1.nest::<A::Error>()?;
+        self.0.visit_seq(SeqAccess(seq, config))
     }
 
     fn visit_map<A>(self, map: A) -> Result<T::Value, A::Error>
     where
         A: de::MapAccess<'de>,
     {
-        self.0.visit_map(MapAccess(map))
+        let config = self.1.nest::<A::Error>()?;
+        self.0.visit_map(MapAccess(map, config))
     }
 
     fn visit_enum<A>(self, data: A) -> Result<T::Value, A::Error>
     where
         A: de::EnumAccess<'de>,
     {
-        self.0.visit_enum(EnumAccess(data))
+        self.0.visit_enum(EnumAccess(data, self.1))
     }
 }
 
 struct StructVisitor<T> {
     visitor: T,
     fields: &'static [&'static str],
+    config: Config,
 }
 
 macro_rules! delegate_struct_visit {
@@ -509,12 +674,10 @@ where
         visit_i16 = i16,
         visit_i32 = i32,
         visit_i64 = i64,
-        visit_i128 = i128,
         visit_u8 = u8,
         visit_u16 = u16,
         visit_u32 = u32,
         visit_u64 = u64,
-        visit_u128 = u128,
         visit_f32 = f32,
         visit_f64 = f64,
         visit_char = char,
@@ -526,6 +689,20 @@ where
         visit_byte_buf = Vec<u8>,
     );
 
+    fn visit_i128<E>(self, v: i128) -> Result<T::Value, E>
+    where
+        E: de::Error,
+    {
+        super::visit_i128(self.visitor, v)
+    }
+
+    fn visit_u128<E>(self, v: u128) -> Result<T::Value, E>
+    where
+        E: de::Error,
+    {
+        super::visit_u128(self.visitor, v)
+    }
+
     fn visit_none<E>(self) -> Result<T::Value, E>
     where
         E: de::Error,
@@ -537,7 +714,8 @@ where
     where
         D: de::Deserializer<'de>,
     {
-        self.visitor.visit_some(WrapDeserializer(deserializer))
+        self.visitor
+            .visit_some(WrapDeserializer(deserializer, self.config))
     }
 
     fn visit_unit<E>(self) -> Result<T::Value, E>
@@ -552,24 +730,27 @@ where
         D: de::Deserializer<'de>,
     {
         self.visitor
-            .visit_newtype_struct(WrapDeserializer(deserializer))
+            .visit_newtype_struct(WrapDeserializer(deserializer, self.config))
     }
 
     fn visit_seq<A>(self, seq: A) -> Result<T::Value, A::Error>
     where
         A: de::SeqAccess<'de>,
     {
-        self.visitor.visit_seq(SeqAccess(seq))
+        let config = self.config.nest::<A::Error>()?;
+        self.visitor.visit_seq(SeqAccess(seq, config))
     }
 
     fn visit_map<A>(self, map: A) -> Result<T::Value, A::Error>
     where
         A: de::MapAccess<'de>,
     {
+        let config = self.config.nest::<A::Error>()?;
         self.visitor.visit_map(StructMapAccess {
             map,
             fields: self.fields,
             key: None,
+            config,
         })
     }
 
@@ -577,11 +758,11 @@ where
     where
         A: de::EnumAccess<'de>,
     {
-        self.visitor.visit_enum(EnumAccess(data))
+        self.visitor.visit_enum(EnumAccess(data, self.config))
     }
 }
 
-struct SeqAccess<T>(T);
+struct SeqAccess<T>(T, Config);
 
 impl<'de, T> de::SeqAccess<'de> for SeqAccess<T>
 where
@@ -593,7 +774,7 @@ where
     where
         U: de::DeserializeSeed<'de>,
     {
-        self.0.next_element_seed(DeserializeSeed(seed))
+        self.0.next_element_seed(DeserializeSeed(seed, self.1))
     }
 
     fn size_hint(&self) -> Option<usize> {
@@ -601,7 +782,7 @@ where
     }
 }
 
-struct MapAccess<T>(T);
+struct MapAccess<T>(T, Config);
 
 impl<'de, T> de::MapAccess<'de> for MapAccess<T>
 where
@@ -613,14 +794,14 @@ where
     where
         K: de::DeserializeSeed<'de>,
     {
-        self.0.next_key_seed(DeserializeSeed(seed))
+        self.0.next_key_seed(DeserializeSeed(seed, self.1))
     }
 
     fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, T::Error>
     where
         V: de::DeserializeSeed<'de>,
     {
-        self.0.next_value_seed(DeserializeSeed(seed))
+        self.0.next_value_seed(DeserializeSeed(seed, self.1))
     }
 
     #[allow(clippy::type_complexity)]
@@ -633,8 +814,10 @@ where
         K: de::DeserializeSeed<'de>,
         V: de::DeserializeSeed<'de>,
     {
-        self.0
-            .next_entry_seed(DeserializeSeed(kseed), DeserializeSeed(vseed))
+        self.0.next_entry_seed(
+            DeserializeSeed(kseed, self.1),
+            DeserializeSeed(vseed, self.1),
+        )
     }
 
     fn size_hint(&self) -> Option<usize> {
@@ -642,13 +825,14 @@ where
     }
 }
 
-struct StructMapAccess<T> {
+struct StructMapAccess<'de, T> {
     map: T,
     fields: &'static [&'static str],
-    key: Option<String>,
+    key: Option<Cow<'de, str>>,
+    config: Config,
 }
 
-impl<'de, T> de::MapAccess<'de> for StructMapAccess<T>
+impl<'de, T> de::MapAccess<'de> for StructMapAccess<'de, T>
 where
     T: de::MapAccess<'de>,
 {
@@ -662,6 +846,7 @@ where
         self.map.next_key_seed(KeyDeserializeSeed {
             seed,
             key: &mut self.key,
+            config: self.config,
         })
     }
 
@@ -673,6 +858,7 @@ where
             seed,
             fields: self.fields,
             key: &self.key,
+            config: self.config,
         })
     }
 
@@ -681,7 +867,7 @@ where
     }
 }
 
-struct EnumAccess<T>(T);
+struct EnumAccess<T>(T, Config);
 
 impl<'de, T> de::EnumAccess<'de> for EnumAccess<T>
 where
@@ -695,13 +881,14 @@ where
     where
         V: de::DeserializeSeed<'de>,
     {
+        let config = self.1;
         self.0
-            .variant_seed(DeserializeSeed(seed))
-            .map(|(value, variant)| (value, VariantAccess(variant)))
+            .variant_seed(DeserializeSeed(seed, config))
+            .map(|(value, variant)| (value, VariantAccess(variant, config)))
     }
 }
 
-struct VariantAccess<T>(T);
+struct VariantAccess<T>(T, Config);
 
 impl<'de, T> de::VariantAccess<'de> for VariantAccess<T>
 where
@@ -717,14 +904,14 @@ where
     where
         U: de::DeserializeSeed<'de>,
     {
-        self.0.newtype_variant_seed(DeserializeSeed(seed))
+        self.0.newtype_variant_seed(DeserializeSeed(seed, self.1))
     }
 
     fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, T::Error>
     where
         V: de::Visitor<'de>,
     {
-        self.0.tuple_variant(len, Visitor(visitor))
+        self.0.tuple_variant(len, Visitor(visitor, self.1))
     }
 
     fn struct_variant<V>(
@@ -735,11 +922,11 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.0.struct_variant(fields, Visitor(visitor))
+        self.0.struct_variant(fields, Visitor(visitor, self.1))
     }
 }
 
-struct DeserializeSeed<T>(T);
+struct DeserializeSeed<T>(T, Config);
 
 impl<'de, T> de::DeserializeSeed<'de> for DeserializeSeed<T>
 where
@@ -751,16 +938,17 @@ where
     where
         D: de::Deserializer<'de>,
     {
-        self.0.deserialize(WrapDeserializer(deserializer))
+        self.0.deserialize(WrapDeserializer(deserializer, self.1))
     }
 }
 
-struct KeyDeserializeSeed<'a, T> {
+struct KeyDeserializeSeed<'a, 'de, T> {
     seed: T,
-    key: &'a mut Option<String>,
+    key: &'a mut Option<Cow<'de, str>>,
+    config: Config,
 }
 
-impl<'de, 'a, T> de::DeserializeSeed<'de> for KeyDeserializeSeed<'a, T>
+impl<'de, 'a, T> de::DeserializeSeed<'de> for KeyDeserializeSeed<'a, 'de, T>
 where
     T: de::DeserializeSeed<'de>,
 {
@@ -773,13 +961,15 @@ where
         self.seed.deserialize(KeyDeserializer {
             deserializer,
             key: self.key,
+            config: self.config,
         })
     }
 }
 
-struct KeyDeserializer<'a, T> {
+struct KeyDeserializer<'a, 'de, T> {
     deserializer: T,
-    key: &'a mut Option<String>,
+    key: &'a mut Option<Cow<'de, str>>,
+    config: Config,
 }
 
 macro_rules! delegate_key_deserialize {
@@ -789,13 +979,13 @@ macro_rules! delegate_key_deserialize {
             where
                 V: de::Visitor<'de>
             {
-                self.deserializer.$method(KeyVisitor { visitor, key: self.key })
+                self.deserializer.$method(KeyVisitor { visitor, key: self.key, config: self.config })
             }
         )*
     }
 }
 
-impl<'de, 'a, T> de::Deserializer<'de> for KeyDeserializer<'a, T>
+impl<'de, 'a, T> de::Deserializer<'de> for KeyDeserializer<'a, 'de, T>
 where
     T: de::Deserializer<'de>,
 {
@@ -842,6 +1032,7 @@ where
             KeyVisitor {
                 visitor,
                 key: self.key,
+                config: self.config,
             },
         )
     }
@@ -859,6 +1050,7 @@ where
             KeyVisitor {
                 visitor,
                 key: self.key,
+                config: self.config,
             },
         )
     }
@@ -872,6 +1064,7 @@ where
             KeyVisitor {
                 visitor,
                 key: self.key,
+                config: self.config,
             },
         )
     }
@@ -891,6 +1084,7 @@ where
             KeyVisitor {
                 visitor,
                 key: self.key,
+                config: self.config,
             },
         )
     }
@@ -905,8 +1099,15 @@ where
         V: de::Visitor<'de>,
     {
         // FIXME this is a bit awkward...
-        self.deserializer
-            .deserialize_struct(name, fields, StructVisitor { visitor, fields })
+        self.deserializer.deserialize_struct(
+            name,
+            fields,
+            StructVisitor {
+                visitor,
+                fields,
+                config: self.config,
+            },
+        )
     }
 
     fn deserialize_enum<V>(
@@ -924,6 +1125,7 @@ where
             KeyVisitor {
                 visitor,
                 key: self.key,
+                config: self.config,
             },
         )
     }
@@ -933,12 +1135,13 @@ where
     }
 }
 
-struct KeyVisitor<'a, T> {
+struct KeyVisitor<'a, 'de, T> {
     visitor: T,
-    key: &'a mut Option<String>,
+    key: &'a mut Option<Cow<'de, str>>,
+    config: Config,
 }
 
-impl<'de, 'a, T> de::Visitor<'de> for KeyVisitor<'a, T>
+impl<'de, 'a, T> de::Visitor<'de> for KeyVisitor<'a, 'de, T>
 where
     T: de::Visitor<'de>,
 {
@@ -954,12 +1157,10 @@ where
         visit_i16 = i16,
         visit_i32 = i32,
         visit_i64 = i64,
-        visit_i128 = i128,
         visit_u8 = u8,
         visit_u16 = u16,
         visit_u32 = u32,
         visit_u64 = u64,
-        visit_u128 = u128,
         visit_f32 = f32,
         visit_f64 = f64,
         visit_char = char,
@@ -968,11 +1169,25 @@ where
         visit_byte_buf = Vec<u8>,
     );
 
+    fn visit_i128<E>(self, v: i128) -> Result<T::Value, E>
+    where
+        E: de::Error,
+    {
+        super::visit_i128(self.visitor, v)
+    }
+
+    fn visit_u128<E>(self, v: u128) -> Result<T::Value, E>
+    where
+        E: de::Error,
+    {
+        super::visit_u128(self.visitor, v)
+    }
+
     fn visit_str<E>(self, value: &str) -> Result<T::Value, E>
     where
         E: de::Error,
     {
-        *self.key = Some(value.to_string());
+        *self.key = Some(Cow::Owned(value.to_string()));
         self.visitor.visit_str(value)
     }
 
@@ -980,7 +1195,7 @@ where
     where
         E: de::Error,
     {
-        *self.key = Some(value.to_string());
+        *self.key = Some(Cow::Borrowed(value));
         self.visitor.visit_borrowed_str(value)
     }
 
@@ -988,7 +1203,7 @@ where
     where
         E: de::Error,
     {
-        *self.key = Some(value.clone());
+        *self.key = Some(Cow::Owned(value.clone()));
         self.visitor.visit_string(value)
     }
 
@@ -1003,7 +1218,8 @@ where
     where
         D: de::Deserializer<'de>,
     {
-        self.visitor.visit_some(WrapDeserializer(deserializer))
+        self.visitor
+            .visit_some(WrapDeserializer(deserializer, self.config))
     }
 
     fn visit_unit<E>(self) -> Result<T::Value, E>
@@ -1018,35 +1234,38 @@ where
         D: de::Deserializer<'de>,
     {
         self.visitor
-            .visit_newtype_struct(WrapDeserializer(deserializer))
+            .visit_newtype_struct(WrapDeserializer(deserializer, self.config))
     }
 
     fn visit_seq<A>(self, seq: A) -> Result<T::Value, A::Error>
     where
         A: de::SeqAccess<'de>,
     {
-        self.visitor.visit_seq(SeqAccess(seq))
+        let config = self.config.nest::<A::Error>()?;
+        self.visitor.visit_seq(SeqAccess(seq, config))
     }
 
     fn visit_map<A>(self, map: A) -> Result<T::Value, A::Error>
     where
         A: de::MapAccess<'de>,
     {
-        self.visitor.visit_map(MapAccess(map))
+        let config = self.config.nest::<A::Error>()?;
+        self.visitor.visit_map(MapAccess(map, config))
     }
 
     fn visit_enum<A>(self, data: A) -> Result<T::Value, A::Error>
     where
         A: de::EnumAccess<'de>,
     {
-        self.visitor.visit_enum(EnumAccess(data))
+        self.visitor.visit_enum(EnumAccess(data, self.config))
     }
 }
 
 struct ValueDeserializeSeed<'a, T> {
     seed: T,
     fields: &'static [&'static str],
-    key: &'a Option<String>,
+    key: &'a Option<Cow<'a, str>>,
+    config: Config,
 }
 
 impl<'de, 'a, T> de::DeserializeSeed<'de> for ValueDeserializeSeed<'a, T>
@@ -1060,7 +1279,7 @@ where
         D: de::Deserializer<'de>,
     {
         self.seed.deserialize(ValueDeserializer {
-            deserializer,
+            deserializer: WrapDeserializer(deserializer, self.config),
             fields: self.fields,
             key: self.key,
         })
@@ -1070,7 +1289,17 @@ where
 struct ValueDeserializer<'a, T> {
     deserializer: T,
     fields: &'static [&'static str],
-    key: &'a Option<String>,
+    key: &'a Option<Cow<'a, str>>,
+}
+
+impl<'a, T> ValueDeserializer<'a, T> {
+    fn field_visitor<V>(&self, visitor: V) -> FieldVisitor<'a, V> {
+        FieldVisitor {
+            visitor,
+            fields: self.fields,
+            key: self.key,
+        }
+    }
 }
 
 macro_rules! delegate_value_deserialize {
@@ -1080,7 +1309,24 @@ macro_rules! delegate_value_deserialize {
             where
                 V: de::Visitor<'de>
             {
-                self.deserializer.$method(Visitor(visitor))
+                let visitor = self.field_visitor(visitor);
+                self.deserializer.$method(visitor)
+            }
+        )*
+    }
+}
+
+// serde_json reports type mismatches for these itself with the position already attached, so we instead go through
+// deserialize_any to have the visitor produce the error and annotate it with the field name.
+macro_rules! delegate_value_deserialize_any {
+    ($($method:ident,)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, T::Error>
+            where
+                V: de::Visitor<'de>
+            {
+                let visitor = self.field_visitor(visitor);
+                self.deserializer.deserialize_any(visitor)
             }
         )*
     }
@@ -1093,6 +1339,17 @@ where
     type Error = T::Error;
 
     delegate_value_deserialize!(
+        deserialize_bytes,
+        deserialize_byte_buf,
+        deserialize_identifier,
+        deserialize_i128,
+        deserialize_u128,
+        deserialize_f32,
+        deserialize_f64,
+        deserialize_option,
+    );
+
+    delegate_value_deserialize_any!(
         deserialize_any,
         deserialize_bool,
         deserialize_i8,
@@ -1103,20 +1360,12 @@ where
         deserialize_u16,
         deserialize_u32,
         deserialize_u64,
-        deserialize_f32,
-        deserialize_f64,
         deserialize_char,
         deserialize_str,
         deserialize_string,
-        deserialize_bytes,
-        deserialize_byte_buf,
-        deserialize_option,
         deserialize_unit,
         deserialize_seq,
         deserialize_map,
-        deserialize_identifier,
-        deserialize_i128,
-        deserialize_u128,
     );
 
     fn deserialize_unit_struct<V>(
@@ -1127,8 +1376,8 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.deserializer
-            .deserialize_unit_struct(name, Visitor(visitor))
+        let visitor = self.field_visitor(visitor);
+        self.deserializer.deserialize_unit_struct(name, visitor)
     }
 
     fn deserialize_newtype_struct<V>(
@@ -1139,15 +1388,16 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.deserializer
-            .deserialize_newtype_struct(name, Visitor(visitor))
+        let visitor = self.field_visitor(visitor);
+        self.deserializer.deserialize_newtype_struct(name, visitor)
     }
 
     fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, T::Error>
     where
         V: de::Visitor<'de>,
     {
-        self.deserializer.deserialize_tuple(len, Visitor(visitor))
+        let visitor = self.field_visitor(visitor);
+        self.deserializer.deserialize_tuple(len, visitor)
     }
 
     fn deserialize_tuple_struct<V>(
@@ -1159,8 +1409,9 @@ where
     where
         V: de::Visitor<'de>,
     {
+        let visitor = self.field_visitor(visitor);
         self.deserializer
-            .deserialize_tuple_struct(name, len, Visitor(visitor))
+            .deserialize_tuple_struct(name, len, visitor)
     }
 
     fn deserialize_struct<V>(
@@ -1172,8 +1423,8 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.deserializer
-            .deserialize_struct(name, fields, Visitor(visitor))
+        let visitor = self.field_visitor(visitor);
+        self.deserializer.deserialize_struct(name, fields, visitor)
     }
 
     fn deserialize_enum<V>(
@@ -1185,8 +1436,8 @@ where
     where
         V: de::Visitor<'de>,
     {
-        self.deserializer
-            .deserialize_enum(name, variants, Visitor(visitor))
+        let visitor = self.field_visitor(visitor);
+        self.deserializer.deserialize_enum(name, variants, visitor)
     }
 
     fn deserialize_ignored_any<V>(self, _: V) -> Result<V::Value, T::Error>
@@ -1198,10 +1449,300 @@ where
             None => "<unknown>",
         };
 
-        Err(de::Error::unknown_field(key, self.fields))
+        Err(unknown_field(key, self.fields))
     }
 
     fn is_human_readable(&self) -> bool {
         self.deserializer.is_human_readable()
     }
 }
+
+/// Deserializes an optional string, treating an empty string as an absent value.
+struct OptionVisitor<T>(T, Config);
+
+macro_rules! delegate_option_visit {
+    ($($method:ident = $ty:ty,)*) => {
+        $(
+            fn $method<E>(self, v: $ty) -> Result<T::Value, E>
+            where
+                E: de::Error,
+            {
+                self.0.visit_some(WrapDeserializer(v.into_deserializer(), self.1))
+            }
+        )*
+    };
+}
+
+impl<'de, T> de::Visitor<'de> for OptionVisitor<T>
+where
+    T: de::Visitor<'de>,
+{
+    type Value = T::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.0.expecting(formatter)
+    }
+
+    delegate_option_visit!(
+        visit_bool = bool,
+        visit_i64 = i64,
+        visit_u64 = u64,
+        visit_f64 = f64,
+    );
+
+    fn visit_str<E>(self, v: &str) -> Result<T::Value, E>
+    where
+        E: de::Error,
+    {
+        if v.is_empty() {
+            self.0.visit_none()
+        } else {
+            self.0
+                .visit_some(WrapDeserializer(v.into_deserializer(), self.1))
+        }
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<T::Value, E>
+    where
+        E: de::Error,
+    {
+        if v.is_empty() {
+            self.0.visit_none()
+        } else {
+            self.0.visit_some(WrapDeserializer(
+                de::value::BorrowedStrDeserializer::new(v),
+                self.1,
+            ))
+        }
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<T::Value, E>
+    where
+        E: de::Error,
+    {
+        if v.is_empty() {
+            self.0.visit_none()
+        } else {
+            self.0
+                .visit_some(WrapDeserializer(v.into_deserializer(), self.1))
+        }
+    }
+
+    fn visit_unit<E>(self) -> Result<T::Value, E>
+    where
+        E: de::Error,
+    {
+        self.0.visit_none()
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<T::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let config = self.1.nest::<A::Error>()?;
+        self.0.visit_some(WrapDeserializer(
+            de::value::SeqAccessDeserializer::new(seq),
+            config,
+        ))
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<T::Value, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let config = self.1.nest::<A::Error>()?;
+        self.0.visit_some(WrapDeserializer(
+            de::value::MapAccessDeserializer::new(map),
+            config,
+        ))
+    }
+}
+
+/// Like `de::Error::unknown_field`, but also suggests the expected field closest to the key to help diagnose typos.
+fn unknown_field<E>(key: &str, fields: &'static [&'static str]) -> E
+where
+    E: de::Error,
+{
+    let e = E::unknown_field(key, fields);
+    match closest_field(key, fields) {
+        Some(field) => E::custom(format_args!("{}, did you mean `{}`?", e, field)),
+        None => e,
+    }
+}
+
+// Only fields within a third of the key's length in edit distance are suggested, since anything further off is unlikely
+// to be what was meant.
+fn closest_field(key: &str, fields: &'static [&'static str]) -> Option<&'static str> {
+    let max_distance = std::cmp::max(key.chars().count() / 3, 1);
+
+    fields
+        .iter()
+        .map(|field| (edit_distance(key, field), *field))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, field)| field)
+}
+
+/// Returns the Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+
+    // row[j] is the distance between the prefix of `a` processed so far and the first `j` characters of `b`
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if a == *b {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+
+    row[b.len()]
+}
+
+/// A visitor used for struct field values which annotates errors from the value itself with the field's name.
+///
+/// Errors from within nested collections and objects are left alone, since they've either already been annotated by
+/// a nested struct or are reported by serde_json along with their position.
+struct FieldVisitor<'a, T> {
+    visitor: T,
+    fields: &'static [&'static str],
+    key: &'a Option<Cow<'a, str>>,
+}
+
+fn field_error<E>(key: &Option<Cow<'_, str>>, e: E) -> E
+where
+    E: de::Error,
+{
+    match key {
+        Some(key) => E::custom(format_args!("{} for field `{}`", e, key)),
+        None => e,
+    }
+}
+
+macro_rules! delegate_field_visit {
+    ($($method:ident = $ty:ty,)*) => {
+        $(
+            fn $method<E>(self, v: $ty) -> Result<T::Value, E>
+            where
+                E: de::Error,
+            {
+                let key = self.key;
+                self.visitor.$method(v).map_err(|e| field_error(key, e))
+            }
+        )*
+    };
+}
+
+impl<'de, 'a, T> de::Visitor<'de> for FieldVisitor<'a, T>
+where
+    T: de::Visitor<'de>,
+{
+    type Value = T::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.visitor.expecting(formatter)
+    }
+
+    delegate_field_visit!(
+        visit_bool = bool,
+        visit_i8 = i8,
+        visit_i16 = i16,
+        visit_i32 = i32,
+        visit_i64 = i64,
+        visit_u8 = u8,
+        visit_u16 = u16,
+        visit_u32 = u32,
+        visit_u64 = u64,
+        visit_f32 = f32,
+        visit_f64 = f64,
+        visit_char = char,
+        visit_str = &str,
+        visit_borrowed_str = &'de str,
+        visit_string = String,
+        visit_bytes = &[u8],
+        visit_borrowed_bytes = &'de [u8],
+        visit_byte_buf = Vec<u8>,
+    );
+
+    fn visit_i128<E>(self, v: i128) -> Result<T::Value, E>
+    where
+        E: de::Error,
+    {
+        let key = self.key;
+        super::visit_i128(self.visitor, v).map_err(|e| field_error(key, e))
+    }
+
+    fn visit_u128<E>(self, v: u128) -> Result<T::Value, E>
+    where
+        E: de::Error,
+    {
+        let key = self.key;
+        super::visit_u128(self.visitor, v).map_err(|e| field_error(key, e))
+    }
+
+    fn visit_none<E>(self) -> Result<T::Value, E>
+    where
+        E: de::Error,
+    {
+        let key = self.key;
+        self.visitor.visit_none().map_err(|e| field_error(key, e))
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<T::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        self.visitor.visit_some(ValueDeserializer {
+            deserializer,
+            fields: self.fields,
+            key: self.key,
+        })
+    }
+
+    fn visit_unit<E>(self) -> Result<T::Value, E>
+    where
+        E: de::Error,
+    {
+        let key = self.key;
+        self.visitor.visit_unit().map_err(|e| field_error(key, e))
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<T::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        self.visitor.visit_newtype_struct(ValueDeserializer {
+            deserializer,
+            fields: self.fields,
+            key: self.key,
+        })
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<T::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        self.visitor.visit_seq(seq)
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<T::Value, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        self.visitor.visit_map(map)
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<T::Value, A::Error>
+    where
+        A: de::EnumAccess<'de>,
+    {
+        self.visitor.visit_enum(data)
+    }
+}
@@ -11,13 +11,16 @@
 //! This crate provides `Serializer` and `Deserializer` implementations which wrap serde_json's and handle these special
 //! behaviors.
 
+#[cfg(feature = "relaxed")]
+pub use crate::json::de::client::from_str_relaxed;
 pub use crate::json::de::client::{
-    client_from_reader, client_from_slice, client_from_str, ClientDeserializer,
+    client_from_reader, client_from_slice, client_from_slice_collecting, client_from_str,
+    deserialize_error, from_bytes, from_slice_lenient, ClientDeserializer, StreamDeserializer,
 };
 pub use crate::json::de::server::{
     server_from_reader, server_from_slice, server_from_str, ServerDeserializer,
 };
-pub use crate::json::ser::{to_string, to_vec, to_writer, Serializer};
+pub use crate::json::ser::{to_canonical_vec, to_string, to_vec, to_writer, Serializer};
 
 mod de;
 mod ser;
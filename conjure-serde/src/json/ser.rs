@@ -14,7 +14,7 @@
 use base64::display::Base64Display;
 use serde::ser;
 use serde_json::ser::{CompactFormatter, Formatter, PrettyFormatter};
-use serde_json::Error;
+use serde_json::{Error, Value};
 use std::f32;
 use std::f64;
 use std::fmt;
@@ -44,11 +44,49 @@ where
 pub fn to_writer<W, T>(writer: W, value: &T) -> Result<(), Error>
 where
     W: Write,
-    T: ser::Serialize,
+    T: ?Sized + ser::Serialize,
 {
     value.serialize(&mut Serializer::new(writer))
 }
 
+/// Serializes a value as canonical JSON into a byte buffer.
+///
+/// The output is the standard Conjure encoding with the keys of every JSON object sorted, no insignificant whitespace,
+/// and doubles with integral values in the range of a `safelong` written as integers. Semantically equal values
+/// therefore always serialize to identical bytes, making the output suitable for signing and caching. This is
+/// significantly slower than `to_vec`.
+pub fn to_canonical_vec<T>(value: &T) -> Result<Vec<u8>, Error>
+where
+    T: ?Sized + ser::Serialize,
+{
+    let buf = to_vec(value)?;
+    let value = serde_json::from_slice(&buf)?;
+    serde_json::to_vec(&canonicalize(value))
+}
+
+fn canonicalize(value: Value) -> Value {
+    // the largest integer exactly representable by a double, which is also the bound of a safelong
+    const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.;
+
+    match value {
+        Value::Number(ref n) if n.is_f64() => match n.as_f64() {
+            Some(v) if v.fract() == 0. && v.abs() <= MAX_SAFE_INTEGER => Value::from(v as i64),
+            _ => value,
+        },
+        Value::Array(values) => Value::Array(values.into_iter().map(canonicalize).collect()),
+        // the map type's iteration order depends on serde_json's features, so sort explicitly
+        Value::Object(map) => {
+            let mut entries = map
+                .into_iter()
+                .map(|(k, v)| (k, canonicalize(v)))
+                .collect::<Vec<_>>();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            Value::Object(entries.into_iter().collect())
+        }
+        value => value,
+    }
+}
+
 /// A serde JSON serializer compatible with the Conjure specification.
 ///
 /// In contrast to serde_json, the f32 and f64 types are serialized as the strings `"Infinity"`, `"-Infinity"`, and
@@ -184,6 +222,8 @@ where
         self.0.serialize_str(v)
     }
 
+    // Base64Display encodes in fixed size chunks which serde_json writes directly to the output, so the full encoded
+    // string is never buffered
     fn serialize_bytes(self, v: &[u8]) -> Result<(), Error> {
         self.0
             .collect_str(&Base64Display::with_config(v, base64::STANDARD))
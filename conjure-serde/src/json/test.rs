@@ -11,11 +11,13 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use serde::de::DeserializeOwned;
-use serde::{Deserialize, Serialize};
+use serde::de::{self, DeserializeOwned, IntoDeserializer};
+use serde::{forward_to_deserialize_any, Deserialize, Serialize};
 use serde_bytes::ByteBuf;
+use std::collections::BTreeMap;
 use std::f64;
 use std::fmt::Debug;
+use std::io::{self, Write};
 
 fn serialize<T>(value: &T) -> String
 where
@@ -83,6 +85,124 @@ fn binary_serde() {
     test_serde(&ByteBuf::from(b"foobar".to_vec()), r#""Zm9vYmFy""#);
 }
 
+// A writer which records the length of the largest single write it receives.
+struct ChunkWriter {
+    buf: Vec<u8>,
+    max_write: usize,
+}
+
+impl Write for ChunkWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.max_write = usize::max(self.max_write, buf.len());
+        self.buf.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn large_binary_streams() {
+    let bytes = (0..1_000_000).map(|i| i as u8).collect::<Vec<_>>();
+    let encoded = base64::encode(&bytes);
+
+    let mut value = BTreeMap::new();
+    value.insert("binary", ByteBuf::from(bytes.clone()));
+
+    let mut writer = ChunkWriter {
+        buf: vec![],
+        max_write: 0,
+    };
+    crate::json::to_writer(&mut writer, &ByteBuf::from(bytes)).unwrap();
+    assert_eq!(writer.buf, format!(r#""{}""#, encoded).into_bytes());
+    assert!(writer.max_write < encoded.len() / 100);
+
+    let mut writer = ChunkWriter {
+        buf: vec![],
+        max_write: 0,
+    };
+    crate::json::to_writer(&mut writer, &value).unwrap();
+    assert_eq!(
+        writer.buf,
+        format!(r#"{{"binary":"{}"}}"#, encoded).into_bytes()
+    );
+    assert!(writer.max_write < encoded.len() / 100);
+}
+
+#[test]
+fn server_strict_base64() {
+    let expected = ByteBuf::from(b"foob".to_vec());
+    assert_eq!(deserialize_server::<ByteBuf>(r#""Zm9vYg==""#), expected);
+
+    for json in &[r#""Zm9v YmFy""#, r#""Zm9vYg\n==""#, r#""Zm9vYg""#] {
+        crate::json::server_from_str::<ByteBuf>(json).unwrap_err();
+    }
+
+    let mut de = crate::json::ServerDeserializer::from_str(r#""Zm9vYg""#).strict_base64(false);
+    assert_eq!(ByteBuf::deserialize(&mut de).unwrap(), expected);
+}
+
+#[test]
+fn client_lenient_base64() {
+    let expected = ByteBuf::from(b"foob".to_vec());
+    assert_eq!(deserialize_client::<ByteBuf>(r#""Zm9vYg==""#), expected);
+    assert_eq!(deserialize_client::<ByteBuf>(r#""Zm9v Yg==""#), expected);
+    assert_eq!(deserialize_client::<ByteBuf>(r#""Zm9vYg\n==""#), expected);
+    assert_eq!(deserialize_client::<ByteBuf>(r#""Zm9vYg""#), expected);
+}
+
+// A deserializer of a single byte string, encoded as base64 if human readable and as raw bytes otherwise.
+struct BytesDeserializer {
+    bytes: &'static [u8],
+    human_readable: bool,
+}
+
+impl<'de> de::Deserializer<'de> for BytesDeserializer {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, de::value::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        if self.human_readable {
+            visitor.visit_string(base64::encode(self.bytes))
+        } else {
+            visitor.visit_bytes(self.bytes)
+        }
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.human_readable
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option unit
+        unit_struct newtype_struct seq tuple tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+#[test]
+fn wrapped_binary() {
+    let expected = ByteBuf::from(b"foob".to_vec());
+    for &human_readable in &[true, false] {
+        let de = BytesDeserializer {
+            bytes: b"foob",
+            human_readable,
+        };
+        let value = ByteBuf::deserialize(crate::json::de::client::WrapDeserializer::new(de));
+        assert_eq!(value.unwrap(), expected);
+
+        let de = BytesDeserializer {
+            bytes: b"foob",
+            human_readable,
+        };
+        let value = ByteBuf::deserialize(crate::json::de::server::WrapDeserializer::new(de));
+        assert_eq!(value.unwrap(), expected);
+    }
+}
+
 #[allow(clippy::float_cmp)]
 fn test_doubles(value: f64, string: &str) {
     let json = format!(r#""{}""#, string);
@@ -102,6 +222,120 @@ fn nonfinite_doubles() {
     test_doubles(f64::NAN, "NaN");
 }
 
+#[test]
+fn integer128_in_range() {
+    assert_eq!(
+        deserialize_client::<u128>("18446744073709551615"),
+        u128::from(u64::max_value())
+    );
+    assert_eq!(
+        deserialize_server::<u128>("18446744073709551615"),
+        u128::from(u64::max_value())
+    );
+    assert_eq!(
+        deserialize_client::<i128>("-9223372036854775808"),
+        i128::from(i64::min_value())
+    );
+    assert_eq!(
+        deserialize_server::<i128>("-9223372036854775808"),
+        i128::from(i64::min_value())
+    );
+}
+
+#[test]
+fn integer128_out_of_range() {
+    let e = u128::deserialize(&mut crate::json::ClientDeserializer::from_str(
+        "18446744073709551616",
+    ))
+    .err()
+    .unwrap();
+    assert!(e.is_data());
+    assert!(e.to_string().contains("128 bit integer"));
+
+    let e = u128::deserialize(&mut crate::json::ServerDeserializer::from_str(
+        "18446744073709551616",
+    ))
+    .err()
+    .unwrap();
+    assert!(e.is_data());
+    assert!(e.to_string().contains("128 bit integer"));
+
+    let e = i128::deserialize(&mut crate::json::ClientDeserializer::from_str(
+        "-9223372036854775809",
+    ))
+    .err()
+    .unwrap();
+    assert!(e.is_data());
+
+    let e = i128::deserialize(&mut crate::json::ServerDeserializer::from_str(
+        "-9223372036854775809",
+    ))
+    .err()
+    .unwrap();
+    assert!(e.is_data());
+}
+
+#[test]
+fn wrapped_integer128() {
+    fn client<T>(value: T) -> Result<i64, de::value::Error>
+    where
+        T: IntoDeserializer<'static, de::value::Error>,
+    {
+        i64::deserialize(crate::json::de::client::WrapDeserializer::new(
+            value.into_deserializer(),
+        ))
+    }
+
+    fn server<T>(value: T) -> Result<i64, de::value::Error>
+    where
+        T: IntoDeserializer<'static, de::value::Error>,
+    {
+        i64::deserialize(crate::json::de::server::WrapDeserializer::new(
+            value.into_deserializer(),
+        ))
+    }
+
+    assert_eq!(client(1u128).unwrap(), 1);
+    assert_eq!(server(1u128).unwrap(), 1);
+    assert_eq!(client(-1i128).unwrap(), -1);
+    assert_eq!(server(-1i128).unwrap(), -1);
+
+    let value = u128::from(u64::max_value()) + 1;
+    let e = client(value).unwrap_err();
+    assert!(e.to_string().contains("outside of the 64 bit range"));
+    let e = server(value).unwrap_err();
+    assert!(e.to_string().contains("outside of the 64 bit range"));
+
+    let value = i128::from(i64::min_value()) - 1;
+    let e = client(value).unwrap_err();
+    assert!(e.to_string().contains("outside of the 64 bit range"));
+    let e = server(value).unwrap_err();
+    assert!(e.to_string().contains("outside of the 64 bit range"));
+}
+
+#[derive(Serialize)]
+struct Doubles {
+    finite: f64,
+    infinite: f64,
+    binary: ByteBuf,
+}
+
+#[test]
+fn to_writer() {
+    let value = Doubles {
+        finite: 1.5,
+        infinite: f64::NEG_INFINITY,
+        binary: ByteBuf::from(b"foobar".to_vec()),
+    };
+
+    let mut buf = vec![];
+    crate::to_writer(&mut buf, &value).unwrap();
+    assert_eq!(
+        buf,
+        br#"{"finite":1.5,"infinite":"-Infinity","binary":"Zm9vYmFy"}"#.to_vec()
+    );
+}
+
 #[derive(Deserialize, Debug, PartialEq)]
 struct Foo {
     foo: i32,
@@ -137,3 +371,545 @@ fn server_unknown_fields() {
     assert!(e.to_string().contains("foo"));
     assert!(e.to_string().contains("bogus"));
 }
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct CamelCase {
+    #[serde(rename = "fieldName")]
+    field_name: i32,
+    #[serde(rename = "otherField")]
+    other_field: i32,
+}
+
+#[test]
+fn server_unknown_field_suggestions() {
+    let e = CamelCase::deserialize(&mut crate::json::ServerDeserializer::from_str(
+        r#"{"fieldNam": 1, "otherField": 2}"#,
+    ))
+    .err()
+    .unwrap();
+    assert!(e.is_data());
+    assert!(e.to_string().contains(
+        "unknown field `fieldNam`, expected `fieldName` or `otherField`, did you mean `fieldName`?"
+    ));
+
+    let e = CamelCase::deserialize(&mut crate::json::ServerDeserializer::from_str(
+        r#"{"bogus": 1}"#,
+    ))
+    .err()
+    .unwrap();
+    assert!(!e.to_string().contains("did you mean"));
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct OptionalFoo {
+    #[serde(deserialize_with = "optional_string")]
+    foo: Option<String>,
+    bar: String,
+    baz: Option<Vec<i32>>,
+}
+
+fn optional_string<'de, D>(d: D) -> Result<Option<String>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    conjure_object::private::OptionalString_::deserialize(d).map(|v| v.0)
+}
+
+fn deserialize_server_empty_string_as_null<T>(json: &str) -> T
+where
+    T: DeserializeOwned,
+{
+    let mut de = crate::json::ServerDeserializer::from_str(json).empty_string_as_null(true);
+    let value = T::deserialize(&mut de).unwrap();
+    de.end().unwrap();
+    value
+}
+
+#[test]
+fn server_empty_string_as_null() {
+    let json = r#"{"foo": "", "bar": "", "baz": [1]}"#;
+
+    let deserialized = deserialize_server_empty_string_as_null::<OptionalFoo>(json);
+    assert_eq!(
+        OptionalFoo {
+            foo: None,
+            bar: String::new(),
+            baz: Some(vec![1]),
+        },
+        deserialized
+    );
+
+    let deserialized = deserialize_server::<OptionalFoo>(json);
+    assert_eq!(
+        OptionalFoo {
+            foo: Some(String::new()),
+            bar: String::new(),
+            baz: Some(vec![1]),
+        },
+        deserialized
+    );
+
+    let deserialized = deserialize_server_empty_string_as_null::<OptionalFoo>(
+        r#"{"foo": "hello", "bar": "", "baz": null}"#,
+    );
+    assert_eq!(
+        OptionalFoo {
+            foo: Some("hello".to_string()),
+            bar: String::new(),
+            baz: None,
+        },
+        deserialized
+    );
+
+    let deserialized = deserialize_server_empty_string_as_null::<Option<f64>>(r#""NaN""#);
+    assert!(deserialized.unwrap().is_nan());
+}
+
+#[test]
+fn server_empty_string_as_null_non_string_optionals() {
+    fn deserialize<T>(json: &str) -> Result<T, serde_json::Error>
+    where
+        T: DeserializeOwned,
+    {
+        T::deserialize(
+            &mut crate::json::ServerDeserializer::from_str(json).empty_string_as_null(true),
+        )
+    }
+
+    deserialize::<Option<conjure_object::SafeLong>>(r#""""#).unwrap_err();
+    deserialize::<Option<conjure_object::Uuid>>(r#""""#).unwrap_err();
+    deserialize::<Option<conjure_object::DateTime<conjure_object::Utc>>>(r#""""#).unwrap_err();
+    assert_eq!(
+        deserialize::<Option<ByteBuf>>(r#""""#).unwrap(),
+        Some(ByteBuf::new())
+    );
+    assert_eq!(
+        deserialize::<Option<String>>(r#""""#).unwrap(),
+        Some(String::new())
+    );
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Outer {
+    outer: Foo,
+    optional: Option<i32>,
+}
+
+#[test]
+fn server_field_errors() {
+    let e = Foo::deserialize(&mut crate::json::ServerDeserializer::from_str(
+        r#"{"foo": "hello"}"#,
+    ))
+    .err()
+    .unwrap();
+    assert!(e.is_data());
+    assert!(e
+        .to_string()
+        .contains(r#"invalid type: string "hello", expected i32 for field `foo`"#));
+
+    let e = Outer::deserialize(&mut crate::json::ServerDeserializer::from_str(
+        r#"{"outer": {"foo": true}}"#,
+    ))
+    .err()
+    .unwrap();
+    assert!(e.is_data());
+    assert!(e
+        .to_string()
+        .contains("invalid type: boolean `true`, expected i32 for field `foo`"));
+
+    let e = Outer::deserialize(&mut crate::json::ServerDeserializer::from_str(
+        r#"{"outer": {"foo": 1}, "optional": "hello"}"#,
+    ))
+    .err()
+    .unwrap();
+    assert!(e.is_data());
+    assert!(e
+        .to_string()
+        .contains(r#"invalid type: string "hello", expected i32 for field `optional`"#));
+}
+
+#[test]
+fn server_nested_unknown_fields() {
+    let json = r#"
+    {
+        "outer": {
+            "foo": 1,
+            "bogus": "hello"
+        }
+    }
+    "#;
+
+    let e = Outer::deserialize(&mut crate::json::ServerDeserializer::from_str(json))
+        .err()
+        .unwrap();
+
+    assert!(e.is_data());
+    assert!(e.to_string().contains("bogus"));
+}
+
+#[test]
+fn canonical_key_order() {
+    #[derive(Serialize)]
+    struct Inner {
+        b: i32,
+        a: i32,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Foo {
+        z_field: Vec<Inner>,
+        a_field: f64,
+    }
+
+    #[derive(Serialize)]
+    struct InnerReversed {
+        a: i32,
+        b: i32,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct FooReversed {
+        a_field: i64,
+        z_field: Vec<InnerReversed>,
+    }
+
+    let foo = crate::json::to_canonical_vec(&Foo {
+        z_field: vec![Inner { b: 2, a: 1 }],
+        a_field: 3.,
+    })
+    .unwrap();
+    let foo_reversed = crate::json::to_canonical_vec(&FooReversed {
+        a_field: 3,
+        z_field: vec![InnerReversed { a: 1, b: 2 }],
+    })
+    .unwrap();
+
+    assert_eq!(foo, foo_reversed);
+    assert_eq!(foo, br#"{"aField":3,"zField":[{"a":1,"b":2}]}"#.to_vec());
+}
+
+#[test]
+fn canonical_doubles() {
+    let values = vec![1.5, -0., 2., f64::NAN, f64::INFINITY];
+    assert_eq!(
+        crate::json::to_canonical_vec(&values).unwrap(),
+        br#"[1.5,0,2,"NaN","Infinity"]"#.to_vec(),
+    );
+}
+
+#[test]
+fn lenient_bom() {
+    #[derive(Deserialize, PartialEq, Debug)]
+    struct Foo {
+        foo: i32,
+    }
+
+    let expected = Foo { foo: 1 };
+    let json = br#"{"foo":1}"#;
+
+    let mut bom = b"\xef\xbb\xbf".to_vec();
+    bom.extend_from_slice(json);
+    crate::json::client_from_slice::<Foo>(&bom).unwrap_err();
+    assert_eq!(
+        crate::json::from_slice_lenient::<Foo>(&bom).unwrap(),
+        expected
+    );
+
+    let mut newline = json.to_vec();
+    newline.extend_from_slice(b"\r\n");
+    assert_eq!(
+        crate::json::from_slice_lenient::<Foo>(&newline).unwrap(),
+        expected
+    );
+
+    bom.extend_from_slice(b" \n\t");
+    assert_eq!(
+        crate::json::from_slice_lenient::<Foo>(&bom).unwrap(),
+        expected
+    );
+
+    crate::json::from_slice_lenient::<Foo>(b"\xef\xbb\xbf\xef\xbb\xbf{\"foo\":1}").unwrap_err();
+    crate::json::from_slice_lenient::<Foo>(b"\xef\xbb\xbf \n").unwrap_err();
+}
+
+#[test]
+#[cfg(feature = "relaxed")]
+fn relaxed_trailing_commas() {
+    #[derive(Deserialize, PartialEq, Debug)]
+    struct Foo {
+        foo: Vec<i32>,
+        bar: BTreeMap<String, String>,
+        #[serde(default)]
+        baz: Option<f64>,
+    }
+
+    let json = r#"
+    {
+        "foo": [1, 2, 3,],
+        "bar": {"a": "x,]", "b": "\",}",},
+        "baz": "NaN",
+    }
+    "#;
+    crate::json::client_from_str::<Foo>(json).unwrap_err();
+
+    let value = crate::from_str_relaxed::<Foo>(json).unwrap();
+    assert_eq!(value.foo, vec![1, 2, 3]);
+    assert_eq!(value.bar["a"], "x,]");
+    assert_eq!(value.bar["b"], "\",}");
+    assert!(value.baz.unwrap().is_nan());
+
+    assert_eq!(
+        crate::from_str_relaxed::<Vec<Vec<i32>>>("[[1,\n],[],\t]").unwrap(),
+        vec![vec![1], vec![]]
+    );
+
+    crate::from_str_relaxed::<Foo>(r#"{"foo":[1] "bar":{}}"#).unwrap_err();
+
+    // a comma is only ignored after a value
+    for json in &["[,]", "[ , ]", "[[1],,]", "[[],,]", "[\n,\n1]"] {
+        let e = crate::from_str_relaxed::<Vec<Vec<i32>>>(json).unwrap_err();
+        assert!(e.is_syntax(), "{}: {}", json, e);
+    }
+    for json in &["{,}", "{ ,\n}", r#"{"a":,}"#, r#"{"a":[],,}"#] {
+        let e = crate::from_str_relaxed::<BTreeMap<String, Vec<i32>>>(json).unwrap_err();
+        assert!(e.is_syntax(), "{}: {}", json, e);
+    }
+
+    let e = crate::from_str_relaxed::<Vec<i32>>("[1,\n2,\nfalse,]").unwrap_err();
+    assert_eq!((e.line(), e.column()), (3, 5));
+}
+
+fn nested_arrays(depth: usize) -> String {
+    format!("{}{}", "[".repeat(depth), "]".repeat(depth))
+}
+
+#[derive(Deserialize, Debug)]
+#[allow(dead_code)]
+struct NestedObject {
+    child: Option<Box<NestedObject>>,
+}
+
+fn nested_objects(depth: usize) -> String {
+    format!(
+        "{}{{}}{}",
+        r#"{"child":"#.repeat(depth - 1),
+        "}".repeat(depth - 1)
+    )
+}
+
+#[test]
+fn max_depth() {
+    let json = nested_arrays(10);
+    let mut de = crate::json::ClientDeserializer::from_str(&json).max_depth(10);
+    serde_json::Value::deserialize(&mut de).unwrap();
+    let mut de = crate::json::ServerDeserializer::from_str(&json).max_depth(10);
+    serde_json::Value::deserialize(&mut de).unwrap();
+
+    let json = nested_arrays(11);
+    let mut de = crate::json::ClientDeserializer::from_str(&json).max_depth(10);
+    let e = serde_json::Value::deserialize(&mut de).unwrap_err();
+    assert!(e.to_string().contains("recursion limit exceeded"), "{}", e);
+    let mut de = crate::json::ServerDeserializer::from_str(&json).max_depth(10);
+    let e = serde_json::Value::deserialize(&mut de).unwrap_err();
+    assert!(e.to_string().contains("recursion limit exceeded"), "{}", e);
+
+    let json = format!("{{\"foo\":{}}}", nested_arrays(10));
+    let mut de = crate::json::ServerDeserializer::from_str(&json).max_depth(10);
+    let e = BTreeMap::<String, serde_json::Value>::deserialize(&mut de).unwrap_err();
+    assert!(e.to_string().contains("recursion limit exceeded"), "{}", e);
+
+    // optional values don't count towards the limit
+    let json = nested_objects(10);
+    let mut de = crate::json::ClientDeserializer::from_str(&json).max_depth(10);
+    NestedObject::deserialize(&mut de).unwrap();
+    let mut de = crate::json::ServerDeserializer::from_str(&json).max_depth(10);
+    NestedObject::deserialize(&mut de).unwrap();
+
+    let json = nested_objects(11);
+    let mut de = crate::json::ClientDeserializer::from_str(&json).max_depth(10);
+    let e = NestedObject::deserialize(&mut de).unwrap_err();
+    assert!(e.to_string().contains("recursion limit exceeded"), "{}", e);
+    let mut de = crate::json::ServerDeserializer::from_str(&json).max_depth(10);
+    let e = NestedObject::deserialize(&mut de).unwrap_err();
+    assert!(e.to_string().contains("recursion limit exceeded"), "{}", e);
+
+    // deeply nested input fails rather than overflowing the stack
+    let json = nested_arrays(100_000);
+    crate::json::client_from_str::<serde_json::Value>(&json).unwrap_err();
+    crate::json::server_from_str::<serde_json::Value>(&json).unwrap_err();
+}
+
+fn deserialize_integer_booleans<T>(json: &str) -> Result<T, serde_json::Error>
+where
+    T: DeserializeOwned,
+{
+    let mut de = crate::json::ClientDeserializer::from_str(json).integer_booleans(true);
+    T::deserialize(&mut de)
+}
+
+#[test]
+fn integer_booleans() {
+    assert!(!deserialize_integer_booleans::<bool>("0").unwrap());
+    assert!(deserialize_integer_booleans::<bool>("1").unwrap());
+    assert!(deserialize_integer_booleans::<bool>("true").unwrap());
+    assert!(!deserialize_integer_booleans::<bool>("false").unwrap());
+    deserialize_integer_booleans::<bool>("2").unwrap_err();
+    deserialize_integer_booleans::<bool>("-1").unwrap_err();
+    deserialize_integer_booleans::<bool>("1.0").unwrap_err();
+    deserialize_integer_booleans::<bool>(r#""true""#).unwrap_err();
+
+    let mut expected = BTreeMap::new();
+    expected.insert("a".to_string(), false);
+    expected.insert("b".to_string(), true);
+    expected.insert("c".to_string(), true);
+    assert_eq!(
+        deserialize_integer_booleans::<BTreeMap<String, bool>>(r#"{"a":0,"b":1,"c":true}"#)
+            .unwrap(),
+        expected,
+    );
+    deserialize_integer_booleans::<BTreeMap<String, bool>>(r#"{"a":2}"#).unwrap_err();
+
+    crate::json::client_from_str::<bool>("1").unwrap_err();
+}
+
+// A xorshift generator, keeping the fuzz-style test deterministic without pulling in a random number crate.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+struct FuzzTarget {
+    double: f64,
+    binary: Option<ByteBuf>,
+    flag: bool,
+    maps: Vec<BTreeMap<String, i64>>,
+    text: String,
+}
+
+fn fuzz_one(input: &[u8]) {
+    // the results are irrelevant; the calls just must not panic
+    let _ = crate::json::client_from_slice::<serde_json::Value>(input);
+    let _ = crate::json::client_from_slice::<FuzzTarget>(input);
+    let _ = crate::json::client_from_slice::<Vec<Option<f32>>>(input);
+    let _ = crate::json::client_from_slice::<ByteBuf>(input);
+}
+
+#[test]
+fn client_from_slice_never_panics() {
+    let seed =
+        br#"{"double":"NaN","binary":"Zm9vYg==","flag":true,"maps":[{"a":1},{}],"text":"\u00e9"}"#;
+    crate::json::client_from_slice::<FuzzTarget>(seed).unwrap();
+
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..5_000 {
+        let len = rng.below(64);
+        let input = (0..len).map(|_| rng.next() as u8).collect::<Vec<_>>();
+        fuzz_one(&input);
+
+        let mut input = seed.to_vec();
+        for _ in 0..=rng.below(4) {
+            let i = rng.below(input.len() + 1);
+            match rng.below(3) {
+                0 if i < input.len() => input[i] = rng.next() as u8,
+                1 if i < input.len() => {
+                    input.remove(i);
+                }
+                _ => input.insert(i, seed[rng.below(seed.len())]),
+            }
+        }
+        fuzz_one(&input);
+    }
+
+    fuzz_one(nested_arrays(100_000).as_bytes());
+    fuzz_one(format!("{}1", "{\"a\":".repeat(100_000)).as_bytes());
+}
+
+#[test]
+fn deserialize_error() {
+    let json = br#"
+    {
+        "errorCode": "INVALID_ARGUMENT",
+        "errorName": "Test:InvalidWidget",
+        "errorInstanceId": "00000000-0000-0000-0000-000000000001",
+        "parameters": {
+            "widgetId": "widget",
+            "count": "2"
+        },
+        "unknown": true
+    }
+    "#;
+
+    let error = crate::json::deserialize_error(json).unwrap();
+    assert_eq!(error.error_code(), "INVALID_ARGUMENT");
+    assert_eq!(error.error_name(), "Test:InvalidWidget");
+    assert_eq!(
+        error.error_instance_id().to_string(),
+        "00000000-0000-0000-0000-000000000001"
+    );
+    let mut parameters = BTreeMap::new();
+    parameters.insert("widgetId".to_string(), "widget".to_string());
+    parameters.insert("count".to_string(), "2".to_string());
+    assert_eq!(*error.parameters(), parameters);
+
+    let json = br#"{"errorCode":"NOT_FOUND","errorName":"Test:Missing","errorInstanceId":"00000000-0000-0000-0000-000000000002"}"#;
+    let error = crate::json::deserialize_error(json).unwrap();
+    assert_eq!(error.error_code(), "NOT_FOUND");
+    assert!(error.parameters().is_empty());
+    assert_eq!(
+        crate::json::to_string(&error).unwrap(),
+        r#"{"errorCode":"NOT_FOUND","errorName":"Test:Missing","errorInstanceId":"00000000-0000-0000-0000-000000000002","parameters":{}}"#
+    );
+
+    crate::json::deserialize_error(br#"{"errorCode":"NOT_FOUND","errorName":"Test:Missing"}"#)
+        .unwrap_err();
+}
+
+#[test]
+fn stream_deserializer() {
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Entry {
+        value: f64,
+    }
+
+    let json = br#"{"value": 1.5}
+{"value": "NaN", "unknown": true}{"value": "Infinity"}
+"#;
+
+    let values = crate::json::StreamDeserializer::<_, Entry>::from_slice(json)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(values.len(), 3);
+    assert_eq!(values[0], Entry { value: 1.5 });
+    assert!(values[1].value.is_nan());
+    assert_eq!(
+        values[2],
+        Entry {
+            value: f64::INFINITY
+        }
+    );
+
+    let values = crate::json::StreamDeserializer::<_, Entry>::from_reader(&json[..])
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(values.len(), 3);
+
+    let mut stream =
+        crate::json::StreamDeserializer::<_, Entry>::from_str(r#"{"value": 1} {"value": "a"}"#);
+    assert_eq!(stream.next().unwrap().unwrap(), Entry { value: 1. });
+    stream.next().unwrap().unwrap_err();
+    assert!(stream.next().is_none());
+}
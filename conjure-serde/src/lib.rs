@@ -31,3 +31,9 @@
 #![doc(html_root_url = "https://docs.rs/conjure-serde/0.1")]
 
 pub mod json;
+
+#[cfg(feature = "relaxed")]
+pub use crate::json::from_str_relaxed;
+pub use crate::json::{
+    deserialize_error, from_bytes, from_slice_lenient, to_writer, StreamDeserializer,
+};
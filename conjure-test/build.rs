@@ -8,6 +8,43 @@ fn main() {
     println!("cargo:rerun-if-changed={}", input);
     conjure_codegen::Config::new()
         .run_rustfmt(false)
+        .strategies(true)
+        .field_presence(true)
+        .double_ord(true)
+        .union_double_hash(true)
+        .mutable_accessors(true)
+        .union_iterators(true)
+        .serde_remote(true)
+        .flatten_single_variant_unions(true)
+        .shared_recursion(true)
+        .collect_errors(true)
+        .union_conversion("SmallUnion", "TestUnion")
+        .union_conversion("TestUnion", "SmallUnion")
+        .deny_unknown_fields("StrictObject")
+        .positional_deserialization("PositionalObject")
+        .case_insensitive_fields("CaseInsensitiveObject")
+        .borrowed_strings("BorrowedStrings")
+        .compact_fields("CompactObject")
+        .raw_any_fields("PassthroughObject")
+        .field_type("CustomFieldObject", "tags", "crate::custom::CommaSeparated")
+        .flatten_field("FlattenedParent", "child")
+        .alias_field("AliasedObject", "name", "oldName")
+        .alias_field("AliasedObject", "name", "legacyName")
+        .alias_field("CaseInsensitiveObject", "count", "total")
+        .write_only_field("WriteOnlyObject", "secret")
+        .write_only_field("WriteOnlyObject", "token")
+        .generate_files(input, output)
+        .unwrap();
+
+    let output = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("conjure_crate");
+    conjure_codegen::Config::new()
+        .run_rustfmt(false)
+        .visibility(conjure_codegen::Visibility::Crate)
+        .datetime_precision(conjure_codegen::DateTimePrecision::Millis)
+        .strict_doubles(true)
+        .quantized_doubles(3)
+        .extern_package("com.palantir.conjure.external", "crate::types")
+        .rename_field("type", "kind")
         .generate_files(input, output)
         .unwrap();
 }
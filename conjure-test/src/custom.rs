@@ -0,0 +1,62 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use conjure_object::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A list of strings serialized as a single comma-separated string.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CommaSeparated(pub Vec<String>);
+
+impl Serialize for CommaSeparated {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_str(&self.0.join(","))
+    }
+}
+
+impl<'de> Deserialize<'de> for CommaSeparated {
+    fn deserialize<D>(d: D) -> Result<CommaSeparated, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(d)?;
+        if s.is_empty() {
+            return Ok(CommaSeparated(vec![]));
+        }
+
+        let values = s.split(',').map(str::to_string).collect::<Vec<_>>();
+        if values.iter().any(|v| v.is_empty()) {
+            return Err(de::Error::invalid_value(
+                de::Unexpected::Str(&s),
+                &"a comma-separated list",
+            ));
+        }
+
+        Ok(CommaSeparated(values))
+    }
+}
+
+impl conjure_object::proptest::arbitrary::Arbitrary for CommaSeparated {
+    type Parameters = ();
+    type Strategy = conjure_object::proptest::strategy::BoxedStrategy<CommaSeparated>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        use conjure_object::proptest::strategy::Strategy;
+
+        conjure_object::proptest::collection::vec("[a-z]+", 0..4)
+            .prop_map(CommaSeparated)
+            .boxed()
+    }
+}
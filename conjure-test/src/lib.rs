@@ -13,10 +13,17 @@
 // limitations under the License.
 #![warn(clippy::all)]
 
+mod custom;
+pub mod support;
 #[cfg(test)]
 mod test;
 
-#[allow(dead_code, clippy::all)]
+#[allow(dead_code, unused_imports, clippy::all)]
 mod types {
     include!(concat!(env!("OUT_DIR"), "/conjure/mod.rs"));
 }
+
+#[allow(dead_code, unused_imports, clippy::all)]
+mod crate_types {
+    include!(concat!(env!("OUT_DIR"), "/conjure_crate/mod.rs"));
+}
@@ -0,0 +1,72 @@
+// Copyright 2018 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support for testing generated types.
+use conjure_object::serde::de::DeserializeOwned;
+use conjure_object::serde::Serialize;
+use serde_json::Value;
+use std::fmt::Debug;
+
+const UNKNOWN_FIELD: &str = "__conjureTestUnknownField";
+
+/// Asserts that a value round trips through Conjure JSON.
+///
+/// The value is serialized and then deserialized with both the client and server deserializers, each of which must
+/// produce an equal value. If the value serializes to a JSON object with a fixed set of fields, the server
+/// deserializer must additionally reject it when an unknown field is added.
+///
+/// # Examples
+///
+/// ```ignore
+/// conjure_test::assert_roundtrip!(TestObject::new(1));
+/// ```
+#[macro_export]
+macro_rules! assert_roundtrip {
+    ($value:expr $(,)?) => {
+        $crate::support::assert_roundtrip(&$value)
+    };
+}
+
+/// The implementation of the `assert_roundtrip!` macro.
+pub fn assert_roundtrip<T>(value: &T)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let json = conjure_serde::json::to_vec(value).unwrap();
+
+    let client = conjure_serde::json::client_from_slice::<T>(&json).unwrap();
+    assert_eq!(*value, client, "client deserialization changed the value");
+
+    let server = conjure_serde::json::server_from_slice::<T>(&json).unwrap();
+    assert_eq!(*value, server, "server deserialization changed the value");
+
+    // Maps also serialize as JSON objects, but an additional entry changes the value the client deserializes rather
+    // than being ignored.
+    let mut json = serde_json::from_slice::<Value>(&json).unwrap();
+    let object = match json.as_object_mut() {
+        Some(object) => object,
+        None => return,
+    };
+    object.insert(UNKNOWN_FIELD.to_string(), Value::Bool(true));
+    let json = serde_json::to_vec(&json).unwrap();
+
+    match conjure_serde::json::client_from_slice::<T>(&json) {
+        Ok(ref client) if client == value => {}
+        _ => return,
+    }
+    assert!(
+        conjure_serde::json::server_from_slice::<T>(&json).is_err(),
+        "server deserialization accepted an unknown field"
+    );
+}
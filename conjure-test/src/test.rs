@@ -12,10 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use conjure_object::serde::de::DeserializeOwned;
-use conjure_object::serde::Serialize;
-use std::collections::{BTreeMap, BTreeSet};
+use conjure_object::serde::{Deserialize, Serialize};
+use conjure_object::{DateTime, PlainDecode, PlainEncode, SafeLong, SerializableError, Utc, Value};
+use proptest::proptest;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::error::Error;
+use std::f64;
 use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::sync::Arc;
 
+use crate::custom::CommaSeparated;
+use crate::types::strategies::*;
 use crate::types::*;
 
 fn serialize<T>(value: &T) -> String
@@ -168,6 +180,52 @@ fn transparent_aliases() {
     )
 }
 
+#[test]
+fn union_key_order() {
+    let cases = [
+        (
+            r#"{"type": "integer", "integer": 15}"#,
+            TestUnion::Integer(15),
+        ),
+        (
+            r#"{"integer": 15, "type": "integer"}"#,
+            TestUnion::Integer(15),
+        ),
+        (
+            r#"{"type": "object", "object": {"foo": 1}}"#,
+            TestUnion::Object(TestObject::builder().foo(1).build()),
+        ),
+        (
+            r#"{"object": {"foo": 1}, "type": "object"}"#,
+            TestUnion::Object(TestObject::builder().foo(1).build()),
+        ),
+    ];
+    for (json, expected) in &cases {
+        assert_eq!(deserialize::<TestUnion>(json), *expected);
+    }
+
+    match deserialize::<TestUnion>(r#"{"double": 14.3, "type": "double"}"#) {
+        TestUnion::Unknown(v) => assert_eq!(v.type_(), "double"),
+        _ => panic!("invalid variant"),
+    }
+}
+
+#[test]
+fn union_type_mismatch() {
+    let cases = [
+        r#"{"type": "integer", "string": "hi"}"#,
+        r#"{"string": "hi", "type": "integer"}"#,
+        r#"{"type": "double", "float": 14.3}"#,
+        r#"{"float": 14.3, "type": "double"}"#,
+    ];
+    for json in &cases {
+        let e = conjure_serde::json::client_from_str::<TestUnion>(json)
+            .err()
+            .unwrap();
+        assert!(e.is_data(), "{}", json);
+    }
+}
+
 #[test]
 fn union_trailing_fields() {
     let json = r#"
@@ -184,6 +242,464 @@ fn union_trailing_fields() {
     assert!(e.is_data());
 }
 
+#[test]
+fn flattened_unions() {
+    let json = r#"{"type":"foo","foo":"hi"}"#;
+    let flattened = SingleUnion("hi".to_string());
+    let enum_ = crate::crate_types::SingleUnion::Foo("hi".to_string());
+    test_serde(&flattened, json);
+    test_serde(&enum_, json);
+    assert_eq!(serialize(&flattened), serialize(&enum_));
+
+    assert_eq!(
+        deserialize::<SingleUnion>(r#"{"foo":"hi","type":"foo"}"#),
+        flattened
+    );
+
+    let e = conjure_serde::json::client_from_str::<SingleUnion>(r#"{"type":"bar","bar":1}"#)
+        .err()
+        .unwrap();
+    assert!(e.is_data());
+}
+
+#[test]
+fn present_fields() {
+    let json = r#"
+    {
+        "optional": 1,
+        "set": []
+    }
+    "#;
+
+    let value = conjure_serde::json::client_from_str::<EmptyFields>(json).unwrap();
+    let present = value.present_fields();
+    assert!(present.optional());
+    assert!(!present.list());
+    assert!(present.set());
+    assert!(!present.map());
+
+    let present = EmptyFields::builder().build().present_fields();
+    assert!(!present.optional());
+    assert!(!present.list());
+    assert!(!present.set());
+    assert!(!present.map());
+}
+
+#[test]
+fn present_null_fields() {
+    let absent = conjure_serde::json::client_from_str::<EmptyFields>("{}").unwrap();
+    assert_eq!(absent.optional(), None);
+    assert!(!absent.present_fields().optional());
+
+    let null =
+        conjure_serde::json::client_from_str::<EmptyFields>(r#"{"optional": null}"#).unwrap();
+    assert_eq!(null.optional(), None);
+    assert!(null.present_fields().optional());
+
+    let value = conjure_serde::json::client_from_str::<EmptyFields>(r#"{"optional": 1}"#).unwrap();
+    assert_eq!(value.optional(), Some(1));
+    assert!(value.present_fields().optional());
+}
+
+#[test]
+fn collection_wrappers() {
+    let mut list = vec!["a", "b"]
+        .into_iter()
+        .map(|s| s.to_string())
+        .collect::<ListWrapper>();
+    list.extend(vec!["c".to_string()]);
+    assert_eq!(
+        list,
+        ListWrapper::new(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+    );
+
+    let mut set = (0..3).collect::<SetWrapper>();
+    set.extend(vec![1, 5]);
+    assert_eq!(set, SetWrapper::new(vec![0, 1, 2, 5]));
+}
+
+#[test]
+fn collection_setters() {
+    let list = ListWrapper::builder()
+        .push_items("a")
+        .push_items("b".to_string())
+        .push_items("c")
+        .build();
+    assert_eq!(
+        list.items(),
+        &["a".to_string(), "b".to_string(), "c".to_string()]
+    );
+
+    let list = ListWrapper::builder()
+        .push_items("a")
+        .extend_items(vec!["b".to_string(), "c".to_string()])
+        .build();
+    assert_eq!(list.items().len(), 3);
+
+    let set = SetWrapper::builder()
+        .insert_items(2)
+        .extend_items(vec![1, 2])
+        .build();
+    assert_eq!(set, SetWrapper::new(vec![1, 2]));
+}
+
+#[test]
+fn declaration_order() {
+    let value = DeclarationOrder::builder()
+        .docs("docs".to_string())
+        .type_("string")
+        .field_name("foo")
+        .build();
+    assert_eq!(
+        serialize(&value),
+        r#"{"fieldName":"foo","type":"string","docs":"docs"}"#
+    );
+
+    let value = deserialize::<DeclarationOrder>(r#"{"type":"string","fieldName":"foo"}"#);
+    assert_eq!(serialize(&value), r#"{"fieldName":"foo","type":"string"}"#);
+}
+
+#[test]
+fn into_accessors() {
+    let error = InvalidWidget::new("widget", 3, "bob");
+    let widget_id: String = error.clone().into_widget_id();
+    assert_eq!(widget_id, "widget");
+    assert_eq!(error.into_owner(), "bob");
+
+    let child = RecursiveObject::builder().value(2).build();
+    let object = RecursiveObject::builder()
+        .value(1)
+        .child(child.clone())
+        .build();
+    assert_eq!(object.into_child(), Some(Box::new(child)));
+}
+
+#[derive(Debug, PartialEq)]
+struct LocalObject {
+    foo: i32,
+}
+
+impl<'a> From<&'a LocalObject> for TestObject {
+    fn from(v: &'a LocalObject) -> TestObject {
+        TestObject::new(v.foo)
+    }
+}
+
+impl From<TestObject> for LocalObject {
+    fn from(v: TestObject) -> LocalObject {
+        LocalObject { foo: v.foo() }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct RemoteFields {
+    #[serde(
+        serialize_with = "TestObject::serialize_remote",
+        deserialize_with = "TestObject::deserialize_remote"
+    )]
+    object: LocalObject,
+}
+
+#[test]
+fn serde_remote() {
+    test_serde(
+        &RemoteFields {
+            object: LocalObject { foo: 1 },
+        },
+        r#"{"object":{"foo":1}}"#,
+    );
+}
+
+#[test]
+fn errors() {
+    let error = InvalidWidget::new("widget", 3, "bob");
+
+    assert_eq!(error.code(), "INVALID_ARGUMENT");
+    assert_eq!(error.name(), "Test:InvalidWidget");
+    assert_eq!(
+        error.to_string(),
+        r#"Test:InvalidWidget (count: 3, widgetId: "widget")"#
+    );
+    assert!(error.source().is_none());
+
+    let mut safe_params = BTreeMap::new();
+    safe_params.insert("widgetId", Value::String("widget".to_string()));
+    safe_params.insert("count", Value::I32(3));
+    assert_eq!(error.safe_params(), safe_params);
+
+    let mut unsafe_params = BTreeMap::new();
+    unsafe_params.insert("owner", Value::String("bob".to_string()));
+    assert_eq!(error.unsafe_params(), unsafe_params);
+}
+
+#[test]
+fn error_params() {
+    let error = deserialize::<InvalidWidget>(
+        r#"{"widgetId": "widget", "count": 3, "owner": "bob", "unknown": true}"#,
+    );
+
+    assert_eq!(error.widget_id(), "widget");
+    assert_eq!(error.count(), 3);
+    assert_eq!(error.owner(), "bob");
+}
+
+#[test]
+fn serializable_error() {
+    let error = SerializableError::from(InvalidWidget::new("widget", 3, "bob"));
+
+    assert_eq!(error.error_code(), "INVALID_ARGUMENT");
+    assert_eq!(error.error_name(), "Test:InvalidWidget");
+    assert!(!error.error_instance_id().is_nil());
+
+    let mut parameters = BTreeMap::new();
+    parameters.insert("widgetId".to_string(), "widget".to_string());
+    parameters.insert("count".to_string(), "3".to_string());
+    parameters.insert("owner".to_string(), "bob".to_string());
+    assert_eq!(*error.parameters(), parameters);
+
+    let other = SerializableError::from(InvalidWidget::new("widget", 3, "bob"));
+    assert_ne!(error.error_instance_id(), other.error_instance_id());
+}
+
+#[test]
+fn duplicate_set_values() {
+    let e = conjure_serde::json::client_from_str::<EmptyFields>(r#"{"set": [1, 2, 1]}"#)
+        .err()
+        .unwrap();
+    assert!(e.is_data());
+
+    let e = conjure_serde::json::client_from_str::<SetAlias>("[1, 1]")
+        .err()
+        .unwrap();
+    assert!(e.is_data());
+
+    // sets nested directly within other collections aren't checked
+    let value =
+        conjure_serde::json::client_from_str::<NestedSets>(r#"{"list": [[1, 1]]}"#).unwrap();
+    assert_eq!(
+        value.list(),
+        &[vec![1].into_iter().collect::<BTreeSet<_>>()]
+    );
+}
+
+#[test]
+fn duplicate_fields() {
+    let json = r#"
+    {
+        "type": "foo",
+        "type": "bar"
+    }
+    "#;
+
+    let e = conjure_serde::json::client_from_str::<KeywordFields>(json)
+        .err()
+        .unwrap();
+    assert!(e.is_data());
+    assert!(e.to_string().contains("duplicate field `type`"));
+}
+
+#[test]
+fn map_keys() {
+    let json = r#"
+    {
+        "uuid": {
+            "7cb6a3ab-0ab2-4d5c-8b3e-4a6c1e2f9d10": 1
+        },
+        "rid": {
+            "ri.service.instance.type.locator": 2
+        },
+        "datetime": {
+            "2019-01-01T00:00:00Z": 3
+        }
+    }
+    "#;
+    let value = conjure_serde::json::client_from_str::<MapKeys>(json).unwrap();
+    assert_eq!(value.uuid().len(), 1);
+    assert_eq!(value.rid().len(), 1);
+    assert_eq!(value.datetime().len(), 1);
+
+    let bad_keys = [
+        r#"{"uuid": {"not-a-uuid": 1}}"#,
+        r#"{"rid": {"not-a-rid": 1}}"#,
+        r#"{"datetime": {"not-a-datetime": 1}}"#,
+    ];
+    for json in &bad_keys {
+        let e = conjure_serde::json::client_from_str::<MapKeys>(json)
+            .err()
+            .unwrap();
+        assert!(e.is_data());
+
+        let e = conjure_serde::json::server_from_str::<MapKeys>(json)
+            .err()
+            .unwrap();
+        assert!(e.is_data());
+    }
+}
+
+#[test]
+fn union_conversions() {
+    assert_eq!(
+        TestUnion::try_from_small_union(SmallUnion::Integer(1)).unwrap(),
+        TestUnion::Integer(1)
+    );
+    assert_eq!(
+        TestUnion::try_from_small_union(SmallUnion::String("foo".to_string())).unwrap(),
+        TestUnion::String("foo".to_string())
+    );
+
+    let unknown =
+        conjure_serde::json::client_from_str::<SmallUnion>(r#"{"type": "foo", "foo": 1}"#).unwrap();
+    assert_eq!(
+        TestUnion::try_from_small_union(unknown.clone()).unwrap_err(),
+        unknown
+    );
+
+    assert_eq!(
+        SmallUnion::try_from_test_union(TestUnion::Integer(1)).unwrap(),
+        SmallUnion::Integer(1)
+    );
+    let object = TestUnion::Object(TestObject::new(1));
+    assert_eq!(
+        SmallUnion::try_from_test_union(object.clone()).unwrap_err(),
+        object
+    );
+}
+
+#[test]
+fn deny_unknown_fields() {
+    let json = r#"
+    {
+        "foo": 1,
+        "bar": 2
+    }
+    "#;
+
+    let value = conjure_serde::json::client_from_str::<TestObject>(json).unwrap();
+    assert_eq!(value, TestObject::new(1));
+
+    let e = conjure_serde::json::client_from_str::<StrictObject>(json)
+        .err()
+        .unwrap();
+    assert!(e.is_data());
+    assert!(e.to_string().contains("unknown field `bar`"));
+}
+
+#[test]
+fn bearer_token_errors_redacted() {
+    let values = [
+        r#""hunter2 secret""#,
+        "73656372",
+        "7365.6372",
+        "true",
+        r#"["hunter2secret"]"#,
+    ];
+
+    for value in &values {
+        let json = format!(r#"{{"bearertoken": {}}}"#, value);
+
+        let e = conjure_serde::json::client_from_str::<BuiltinTypes>(&json)
+            .err()
+            .unwrap();
+        assert!(e.is_data(), "{}", e);
+        assert!(!e.to_string().contains("secret"), "{}", e);
+        assert!(!e.to_string().contains("6372"), "{}", e);
+        assert!(!e.to_string().contains("true"), "{}", e);
+
+        let e = conjure_serde::json::server_from_str::<BuiltinTypes>(&json)
+            .err()
+            .unwrap();
+        assert!(e.is_data(), "{}", e);
+        assert!(!e.to_string().contains("secret"), "{}", e);
+        assert!(!e.to_string().contains("6372"), "{}", e);
+        assert!(!e.to_string().contains("true"), "{}", e);
+    }
+}
+
+#[test]
+fn unknown_fields_short_circuit() {
+    // the input is truncated, so reading past the unknown field would fail with an EOF error instead
+    let json = br#"{"foo": 1, "bogus": "#;
+
+    let e = conjure_serde::json::server_from_slice::<TestObject>(json)
+        .err()
+        .unwrap();
+    assert!(e.is_data(), "{}", e);
+    assert!(e.to_string().contains("unknown field `bogus`"), "{}", e);
+
+    let e = conjure_serde::json::client_from_slice::<StrictObject>(json)
+        .err()
+        .unwrap();
+    assert!(e.is_data(), "{}", e);
+    assert!(e.to_string().contains("unknown field `bogus`"), "{}", e);
+}
+
+#[test]
+fn case_insensitive_fields() {
+    let object = CaseInsensitiveObject::builder()
+        .field_name("foo")
+        .count(1)
+        .build();
+    test_serde(&object, r#"{"fieldName":"foo","count":1}"#);
+    test_de(&object, r#"{"FIELDNAME":"foo","Count":1}"#);
+
+    let value = conjure_serde::json::server_from_str::<CaseInsensitiveObject>(
+        r#"{"FieldName":"foo","count":1}"#,
+    )
+    .unwrap();
+    assert_eq!(value, object);
+
+    let e = conjure_serde::json::client_from_str::<CaseInsensitiveObject>(
+        r#"{"fieldName":"foo","FIELDNAME":"bar"}"#,
+    )
+    .err()
+    .unwrap();
+    assert!(e.to_string().contains("duplicate field `fieldName`"));
+}
+
+#[test]
+fn field_aliases() {
+    let object = AliasedObject::builder().name("foo").count(1).build();
+    test_serde(&object, r#"{"name":"foo","count":1}"#);
+    test_de(&object, r#"{"oldName":"foo","count":1}"#);
+    test_de(&object, r#"{"legacyName":"foo","count":1}"#);
+
+    let value =
+        conjure_serde::json::server_from_str::<AliasedObject>(r#"{"oldName":"foo","count":1}"#)
+            .unwrap();
+    assert_eq!(value, object);
+
+    let e =
+        conjure_serde::json::client_from_str::<AliasedObject>(r#"{"name":"foo","oldName":"bar"}"#)
+            .err()
+            .unwrap();
+    assert!(e.to_string().contains("duplicate field `name`"));
+
+    let object = CaseInsensitiveObject::builder()
+        .field_name("foo")
+        .count(1)
+        .build();
+    test_de(&object, r#"{"fieldName":"foo","TOTAL":1}"#);
+}
+
+#[test]
+fn write_only_fields() {
+    let object = WriteOnlyObject::builder()
+        .name("foo")
+        .secret("hunter2")
+        .token("abc".to_string())
+        .build();
+    test_ser(&object, r#"{"name":"foo"}"#);
+
+    let value = conjure_serde::json::client_from_str::<WriteOnlyObject>(
+        r#"{"name":"foo","secret":"hunter2","token":"abc"}"#,
+    )
+    .unwrap();
+    assert_eq!(value, object);
+    assert_eq!(value.secret(), "hunter2");
+
+    conjure_serde::json::client_from_str::<WriteOnlyObject>(r#"{"name":"foo"}"#).unwrap_err();
+}
+
 #[test]
 fn optional_field_constructor() {
     let builder = OptionalConstructorFields::builder()
@@ -200,3 +716,1127 @@ fn optional_field_constructor() {
     let constructor = OptionalConstructorFields2::new(TestObject::new(0));
     assert_eq!(builder, constructor);
 }
+
+#[test]
+fn require_accessors() {
+    fn string(value: &OptionalConstructorFields) -> Result<&str, conjure_object::MissingField> {
+        let string = value.require_string()?;
+        Ok(string)
+    }
+
+    let value = OptionalConstructorFields::builder().integer(3).build();
+    let e = string(&value).unwrap_err();
+    assert_eq!(e.type_name(), "OptionalConstructorFields");
+    assert_eq!(e.field_name(), "string");
+    assert!(e.to_string().contains("`string`"), "{}", e);
+    assert_eq!(value.require_integer(), Ok(3));
+    assert!(value.require_list().is_err());
+
+    let value = OptionalConstructorFields::new(vec![1, 2], "hi", 3);
+    assert_eq!(string(&value), Ok("hi"));
+    assert_eq!(value.require_list(), Ok(&[1, 2][..]));
+
+    let value = OptionalConstructorFields2::new(TestObject::new(0));
+    assert_eq!(value.require_object(), Ok(&TestObject::new(0)));
+}
+
+#[test]
+fn server_empty_string_as_null() {
+    fn deserialize<T>(json: &str, empty_string_as_null: bool) -> Result<T, serde_json::Error>
+    where
+        T: DeserializeOwned,
+    {
+        let mut de = conjure_serde::json::ServerDeserializer::from_str(json)
+            .empty_string_as_null(empty_string_as_null);
+        let value = T::deserialize(&mut de)?;
+        de.end()?;
+        Ok(value)
+    }
+
+    let json = r#"{"list": [1], "string": ""}"#;
+    let value = deserialize::<OptionalConstructorFields>(json, true).unwrap();
+    assert_eq!(
+        value,
+        OptionalConstructorFields::builder().list(vec![1]).build()
+    );
+    let value = deserialize::<OptionalConstructorFields>(json, false).unwrap();
+    assert_eq!(value.string(), Some(""));
+
+    deserialize::<OptionalConstructorFields>(r#"{"integer": ""}"#, true).unwrap_err();
+    let json = r#"{"datetime": "2018-07-19T08:11:21Z", "optional": ""}"#;
+    deserialize::<DateTimeFields>(json, true).unwrap_err();
+}
+
+#[test]
+fn union_variant_boxing() {
+    test_serde(
+        &NestedUnion::Small(SmallUnion::Integer(1)),
+        r#"{"type": "small", "small": {"type": "integer", "integer": 1}}"#,
+    );
+    // the default configuration boxes recursive variants rather than sharing them
+    test_serde(
+        &crate::crate_types::SelfUnion::Child(Box::new(crate::crate_types::SelfUnion::Integer(1))),
+        r#"{"type": "child", "child": {"type": "integer", "integer": 1}}"#,
+    );
+}
+
+struct IsCopy<T>(PhantomData<T>);
+
+trait NotCopy {
+    fn is_copy(&self) -> bool {
+        false
+    }
+}
+
+impl<T> NotCopy for IsCopy<T> {}
+
+impl<T> IsCopy<T>
+where
+    T: Copy,
+{
+    fn is_copy(&self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn copy_objects() {
+    assert!(IsCopy::<CopyFields>(PhantomData).is_copy());
+    assert!(!IsCopy::<NonCopyFields>(PhantomData).is_copy());
+}
+
+#[test]
+fn const_constructor() {
+    const VALUE: ConstFields = ConstFields::new(1, 2.5, 3);
+
+    let builder = ConstFields::builder()
+        .integer(1)
+        .double(2.5)
+        .optional(3)
+        .build();
+    assert_eq!(VALUE, builder);
+}
+
+#[test]
+fn type_name_consts() {
+    assert_eq!(TestObject::CONJURE_TYPE_NAME, "TestObject");
+    assert_eq!(TestObject::CONJURE_PACKAGE, "com.palantir.conjure");
+    assert_eq!(TestUnion::CONJURE_TYPE_NAME, "TestUnion");
+    assert_eq!(TestUnion::CONJURE_PACKAGE, "com.palantir.conjure");
+    assert_eq!(TestEnum::CONJURE_TYPE_NAME, "TestEnum");
+    assert_eq!(TestEnum::CONJURE_PACKAGE, "com.palantir.conjure");
+}
+
+#[test]
+fn deserialize_in_place() {
+    let mut value = deserialize::<EmptyFields>(r#"{"list": [1, 2, 3, 4], "optional": 1}"#);
+    let ptr = value.list().as_ptr();
+
+    let mut de = conjure_serde::json::ClientDeserializer::from_str(r#"{"list": [5, 6]}"#);
+    EmptyFields::deserialize_in_place(&mut de, &mut value).unwrap();
+    de.end().unwrap();
+
+    assert_eq!(value, EmptyFields::builder().list(vec![5, 6]).build());
+    assert_eq!(value.list().as_ptr(), ptr);
+}
+
+#[test]
+fn string_alias_setters() {
+    let builder = StringAliasFields::builder()
+        .alias("foo")
+        .push_list("bar")
+        .push_list("baz".to_string())
+        .build();
+    let constructor = StringAliasFields::new(
+        "foo",
+        vec![StringAlias("bar".to_string()), StringAlias::from("baz")],
+    );
+    assert_eq!(builder, constructor);
+    assert_eq!(*builder.alias(), StringAlias("foo".to_string()));
+}
+
+#[test]
+fn string_alias_eq() {
+    let alias = StringAlias("a".to_string());
+    let string = "a".to_string();
+
+    assert!(alias == "a");
+    assert!("a" == alias);
+    assert!(alias == *"a");
+    assert!(*"a" == alias);
+    assert!(alias == string);
+    assert!(string == alias);
+    assert!(alias != "b");
+    assert!("b" != alias);
+}
+
+#[test]
+fn alias_new_into_inner() {
+    let alias = StringAlias::new("foo".to_string());
+    assert_eq!(alias, StringAlias("foo".to_string()));
+    assert_eq!(alias.into_inner(), "foo");
+}
+
+#[test]
+fn boolean_alias() {
+    let alias = BooleanAlias::from(true);
+    assert_eq!(!alias, BooleanAlias(false));
+    assert_eq!(!!alias, alias);
+    assert!(bool::from(alias));
+    assert!(!bool::from(!alias));
+
+    let value: bool = BooleanAlias(false).into();
+    assert!(!value);
+}
+
+#[test]
+fn numeric_alias_sum() {
+    let values = [
+        SafeLongAlias(SafeLong::new(1).unwrap()),
+        SafeLongAlias(SafeLong::new(2).unwrap()),
+    ];
+    assert_eq!(
+        values.iter().sum::<SafeLongAlias>(),
+        SafeLongAlias(SafeLong::new(3).unwrap())
+    );
+    assert_eq!(
+        values.iter().product::<SafeLongAlias>(),
+        SafeLongAlias(SafeLong::new(2).unwrap())
+    );
+
+    // only the final value needs to be in range
+    let sum = vec![
+        SafeLong::max_value(),
+        SafeLong::new(1).unwrap(),
+        SafeLong::new(-1).unwrap(),
+    ]
+    .into_iter()
+    .map(SafeLongAlias)
+    .sum::<SafeLongAlias>();
+    assert_eq!(sum, SafeLongAlias(SafeLong::max_value()));
+
+    assert_eq!(
+        vec![IntegerAlias(1), IntegerAlias(2), IntegerAlias(3)]
+            .into_iter()
+            .sum::<IntegerAlias>(),
+        IntegerAlias(6)
+    );
+    assert_eq!(
+        [DoubleAlias(1.5), DoubleAlias(2.)]
+            .iter()
+            .product::<DoubleAlias>(),
+        DoubleAlias(3.)
+    );
+}
+
+#[test]
+#[should_panic(expected = "sum was out of bounds of a safe long")]
+fn safe_long_alias_sum_overflow() {
+    vec![
+        SafeLongAlias(SafeLong::max_value()),
+        SafeLongAlias(SafeLong::new(1).unwrap()),
+    ]
+    .into_iter()
+    .sum::<SafeLongAlias>();
+}
+
+#[test]
+fn alias_plain() {
+    let alias = StringAlias("foo bar".to_string());
+    assert_eq!(alias.encode_plain(), "foo bar");
+    assert_eq!(StringAlias::decode_plain("foo bar").unwrap(), alias);
+
+    let alias = DateTimeAlias("2017-01-02T03:04:05.006Z".parse().unwrap());
+    assert_eq!(alias.encode_plain(), "2017-01-02T03:04:05.006Z");
+    assert_eq!(
+        DateTimeAlias::decode_plain("2017-01-02T03:04:05.006Z").unwrap(),
+        alias
+    );
+
+    assert_eq!(
+        SafeLongAlias(SafeLong::new(15).unwrap()).encode_plain(),
+        "15"
+    );
+    assert!(SafeLongAlias::decode_plain("9007199254740992").is_err());
+}
+
+#[test]
+fn alias_from_str() {
+    assert_eq!(
+        "foo".parse::<StringAlias>().unwrap(),
+        StringAlias("foo".to_string())
+    );
+    assert_eq!(
+        "15".parse::<SafeLongAlias>().unwrap(),
+        SafeLongAlias(SafeLong::new(15).unwrap())
+    );
+    assert!("9007199254740992".parse::<SafeLongAlias>().is_err());
+    assert!("2017-01-02".parse::<DateTimeAlias>().is_err());
+}
+
+#[test]
+fn double_ord() {
+    let value = |double| {
+        DoubleFields::builder()
+            .double(double)
+            .optional(double)
+            .push_list(double)
+            .insert_map("a", double)
+            .alias(DoubleAlias(double))
+            .string("b")
+            .build()
+    };
+
+    assert_eq!(value(f64::NAN), value(f64::NAN));
+    assert_ne!(value(0.0), value(-0.0));
+    assert!(value(-0.0) < value(0.0));
+    assert!(value(1.) < value(f64::NAN));
+    assert_eq!(DoubleAlias(f64::NAN), DoubleAlias(f64::NAN));
+
+    let mut set = HashSet::new();
+    assert!(set.insert(value(f64::NAN)));
+    assert!(!set.insert(value(f64::NAN)));
+    assert!(set.insert(value(0.0)));
+    assert!(set.insert(value(-0.0)));
+    assert!(set.contains(&value(f64::NAN)));
+    assert_eq!(set.len(), 3);
+}
+
+#[test]
+fn quantized_doubles() {
+    use crate::crate_types::{DoubleAlias, DoubleFields};
+
+    let value = |double| {
+        DoubleFields::builder()
+            .double(double)
+            .optional(double)
+            .push_list(double)
+            .insert_map("a", double)
+            .alias(DoubleAlias(double))
+            .string("b")
+            .build()
+    };
+
+    assert_eq!(value(1.230), value(1.230_000_1));
+    assert_ne!(value(1.230), value(1.231));
+    assert!(value(1.230) < value(1.231));
+    assert_eq!(value(0.0), value(-0.000_1));
+    assert_eq!(DoubleAlias(1.230), DoubleAlias(1.230_000_1));
+    assert_ne!(DoubleAlias(1.6e308), DoubleAlias(1.7e308));
+    assert!(DoubleAlias(1.6e308) < DoubleAlias(1.7e308));
+    assert!(DoubleAlias(-1.7e308) < DoubleAlias(-1.6e308));
+    assert!(DoubleAlias(4_503_599_627_370_495.5) < DoubleAlias(4_503_599_627_370_497.));
+
+    let mut set = HashSet::new();
+    assert!(set.insert(value(1.230)));
+    assert!(!set.insert(value(1.230_000_1)));
+    assert!(set.insert(value(1.231)));
+    assert!(set.contains(&value(1.229_999_9)));
+    assert!(set.insert(value(1.6e308)));
+    assert!(set.insert(value(1.7e308)));
+    assert_eq!(set.len(), 4);
+}
+
+#[test]
+fn union_double_hash() {
+    fn hash(value: &DoubleUnion) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    assert_eq!(
+        hash(&DoubleUnion::Double(1.5)),
+        hash(&DoubleUnion::Double(1.5))
+    );
+    assert_eq!(DoubleUnion::Double(0.0), DoubleUnion::Double(-0.0));
+    assert_eq!(
+        hash(&DoubleUnion::Double(0.0)),
+        hash(&DoubleUnion::Double(-0.0))
+    );
+    assert_eq!(
+        hash(&DoubleUnion::Integer(1)),
+        hash(&DoubleUnion::Integer(1))
+    );
+
+    let mut set = HashSet::new();
+    assert!(set.insert(DoubleUnion::Double(1.5)));
+    assert!(!set.insert(DoubleUnion::Double(1.5)));
+    assert!(set.insert(DoubleUnion::Double(2.5)));
+    assert!(set.insert(DoubleUnion::Integer(1)));
+    assert!(set.insert(DoubleUnion::Integer(2)));
+    assert_eq!(set.len(), 4);
+}
+
+#[test]
+fn mutable_accessors() {
+    let mut object = TestObject::builder().foo(1).build();
+    *object.foo_mut() += 1;
+    assert_eq!(object.foo(), 2);
+
+    let mut object = RecursiveObject::builder().value(1).build();
+    *object.child_mut() = Some(Box::new(RecursiveObject::builder().value(2).build()));
+    object.children_mut().push(RecursiveUnion::Integer(3));
+    assert_eq!(object.child().unwrap().value(), 2);
+    assert_eq!(object.children(), &[RecursiveUnion::Integer(3)]);
+}
+
+#[test]
+fn shared_recursion() {
+    let union = SelfUnion::Child(Arc::new(SelfUnion::Child(Arc::new(SelfUnion::Integer(1)))));
+    let clone = union.clone();
+    match (&union, &clone) {
+        (SelfUnion::Child(a), SelfUnion::Child(b)) => assert!(Arc::ptr_eq(a, b)),
+        _ => panic!("unexpected variants"),
+    }
+    test_serde(
+        &union,
+        r#"{"type":"child","child":{"type":"child","child":{"type":"integer","integer":1}}}"#,
+    );
+}
+
+#[test]
+fn safelong_alias_conversions() {
+    let max = *SafeLong::max_value();
+    assert_eq!(
+        SafeLongAlias::try_from_i64(max).unwrap(),
+        SafeLongAlias(SafeLong::max_value()),
+    );
+    SafeLongAlias::try_from_i64(max + 1).unwrap_err();
+    SafeLongAlias::try_from_i64(-max - 1).unwrap_err();
+
+    assert_eq!(
+        SafeLongAlias::from(SafeLong::new(1).unwrap()),
+        SafeLongAlias(SafeLong::new(1).unwrap()),
+    );
+    assert_eq!(IntegerAlias::from(1), IntegerAlias(1));
+}
+
+#[test]
+fn positional_deserialization() {
+    let map = deserialize::<PositionalObject>(
+        r#"{"fieldName": "name", "type": {"foo": 1}, "docs": "docs"}"#,
+    );
+    let seq = deserialize::<PositionalObject>(r#"["name", {"foo": 1}, "docs"]"#);
+    assert_eq!(map, seq);
+    assert_eq!(seq.present_fields(), map.present_fields());
+
+    let seq = deserialize::<PositionalObject>(r#"["name", {"foo": 1}]"#);
+    assert_eq!(seq.docs(), None);
+
+    let e = conjure_serde::json::client_from_str::<PositionalObject>(r#"["name"]"#)
+        .err()
+        .unwrap();
+    assert!(e.is_data());
+    let e = conjure_serde::json::client_from_str::<PositionalObject>(
+        r#"["name", {"foo": 1}, "docs", "extra"]"#,
+    )
+    .err()
+    .unwrap();
+    assert!(e.is_data());
+
+    assert_eq!(serialize(&seq), r#"{"fieldName":"name","type":{"foo":1}}"#);
+}
+
+#[test]
+fn union_iterators() {
+    let values = vec![
+        TestUnion::Integer(1),
+        TestUnion::String("foo".to_string()),
+        TestUnion::Integer(2),
+        TestUnion::Object(TestObject::builder().foo(3).build()),
+    ];
+
+    assert_eq!(
+        values.clone().into_iter().integers().collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+    assert_eq!(
+        values.into_iter().strings().collect::<Vec<_>>(),
+        vec!["foo".to_string()],
+    );
+}
+
+#[test]
+fn custom_field_type() {
+    let object = CustomFieldObject::builder()
+        .tags(CommaSeparated(vec!["a".to_string(), "b".to_string()]))
+        .name("c")
+        .build();
+    test_serde(&object, r#"{"tags": "a,b", "name": "c"}"#);
+    assert_eq!(object.tags().0, vec!["a".to_string(), "b".to_string()]);
+
+    test_serde(
+        &CustomFieldObject::new(CommaSeparated(vec![]), "c"),
+        r#"{"tags": "", "name": "c"}"#,
+    );
+
+    let e = conjure_serde::json::client_from_str::<CustomFieldObject>(
+        r#"{"tags": "a,,b", "name": "c"}"#,
+    )
+    .err()
+    .unwrap();
+    assert!(e.is_data());
+    let e = conjure_serde::json::client_from_str::<CustomFieldObject>(r#"{"name": "c"}"#)
+        .err()
+        .unwrap();
+    assert!(e.is_data());
+}
+
+#[test]
+fn builder_merge() {
+    let mut builder = PositionalObject::builder();
+    builder.field_name("a").docs("docs".to_string());
+    let mut other = PositionalObject::builder();
+    other
+        .field_name("b")
+        .type_(TestObject::builder().foo(1).build());
+
+    let object = builder.merge(other).build();
+    assert_eq!(object.field_name(), "b");
+    assert_eq!(object.type_().foo(), 1);
+    assert_eq!(object.docs(), Some("docs"));
+
+    let mut builder = EmptyFields::builder();
+    builder.optional(1).list(vec![1]).set(vec![1]);
+    let mut other = EmptyFields::builder();
+    other.list(vec![2]).map(vec![(3, 3)]);
+
+    let object = builder.merge(other).build();
+    assert_eq!(object.optional(), Some(1));
+    assert_eq!(object.list(), &[2]);
+    assert_eq!(object.set(), &Some(1).into_iter().collect::<BTreeSet<_>>());
+    assert_eq!(
+        object.map(),
+        &Some((3, 3)).into_iter().collect::<BTreeMap<_, _>>()
+    );
+}
+
+#[test]
+fn map_accessors() {
+    let object = EmptyFields::builder()
+        .insert_map(2, 20)
+        .insert_map(1, 10)
+        .build();
+
+    assert_eq!(
+        object.map_iter().collect::<Vec<_>>(),
+        vec![(&1, &10), (&2, &20)]
+    );
+    assert_eq!(object.map_keys().collect::<Vec<_>>(), vec![&1, &2]);
+    assert_eq!(object.map_values().sum::<i32>(), 30);
+    assert_eq!(object.map_iter().count(), object.map().len());
+}
+
+#[test]
+fn object_parts() {
+    let object = PositionalObject::builder()
+        .field_name("a")
+        .type_(TestObject::new(1))
+        .docs("docs".to_string())
+        .build();
+
+    let (field_name, type_, docs) = object.clone().into_parts();
+    assert_eq!(field_name, "a");
+    assert_eq!(type_, TestObject::new(1));
+    assert_eq!(docs, Some("docs".to_string()));
+
+    let parts = PositionalObject::from_parts((field_name, type_, docs));
+    assert_eq!(parts, object);
+
+    let object = CopyFields::from_parts((conjure_object::Uuid::nil(), 1));
+    assert_eq!(object.into_parts(), (conjure_object::Uuid::nil(), 1));
+}
+
+#[test]
+fn inlined_serde() {
+    let uuid = conjure_object::Uuid::nil();
+    test_serde(
+        &UuidExample::new(uuid),
+        r#"{"uuid": "00000000-0000-0000-0000-000000000000"}"#,
+    );
+    test_serde(&IntegerAlias(1), "1");
+}
+
+#[test]
+fn crate_visibility() {
+    let object = crate::crate_types::TestObject::builder().foo(1).build();
+    test_serde(&object, r#"{"foo":1}"#);
+    test_serde(&crate::crate_types::TestEnum::One, r#""ONE""#);
+}
+
+#[test]
+fn renamed_fields() {
+    let object = crate::crate_types::KeywordFields::builder()
+        .kind("foo")
+        .build();
+    assert_eq!(object.kind(), "foo");
+    test_serde(&object, r#"{"type":"foo"}"#);
+}
+
+#[test]
+fn field_name_constants() {
+    use crate::types::positional_object::fields;
+
+    assert_eq!(fields::FIELD_NAME, "fieldName");
+    assert_eq!(fields::TYPE_, "type");
+    assert_eq!(fields::DOCS, "docs");
+    assert_eq!(crate::crate_types::keyword_fields::fields::KIND, "type");
+}
+
+#[test]
+fn flattened_fields() {
+    let object = FlattenedParent::new(
+        "a",
+        FlattenedChild::builder()
+            .foo(1)
+            .bar("b".to_string())
+            .build(),
+    );
+    test_serde(&object, r#"{"id":"a","foo":1,"bar":"b"}"#);
+    test_de(&object, r#"{"foo":1,"unknown":true,"bar":"b","id":"a"}"#);
+
+    let value = deserialize::<FlattenedParent>(r#"{"id":"a","foo":1,"bar":"b"}"#);
+    assert!(value.present_fields().child());
+    assert!(value.child().present_fields().bar());
+    assert!(!value.child().present_fields().list());
+
+    let e = conjure_serde::json::client_from_str::<FlattenedParent>(r#"{"id":"a"}"#)
+        .err()
+        .unwrap();
+    assert!(e.to_string().contains("missing field `foo`"));
+
+    let e =
+        conjure_serde::json::client_from_str::<FlattenedParent>(r#"{"id":"a","foo":1,"foo":2}"#)
+            .err()
+            .unwrap();
+    assert!(e.to_string().contains("duplicate field `foo`"));
+
+    let e =
+        conjure_serde::json::server_from_str::<FlattenedParent>(r#"{"id":"a","foo":1,"baz":2}"#)
+            .err()
+            .unwrap();
+    assert!(e.is_data());
+
+    let mut value = deserialize::<FlattenedParent>(r#"{"id":"a","foo":1,"list":[1]}"#);
+    let mut de = conjure_serde::json::ClientDeserializer::from_str(r#"{"id":"b","foo":2}"#);
+    FlattenedParent::deserialize_in_place(&mut de, &mut value).unwrap();
+    de.end().unwrap();
+    assert_eq!(
+        value,
+        FlattenedParent::new("b", FlattenedChild::builder().foo(2).build())
+    );
+}
+
+#[test]
+fn datetime_precision() {
+    let datetime = "2018-01-01T00:00:00.123456789Z"
+        .parse::<DateTime<Utc>>()
+        .unwrap();
+    let truncated = "2018-01-01T00:00:00.123Z".parse::<DateTime<Utc>>().unwrap();
+
+    let object = DateTimeFields::builder()
+        .datetime(datetime)
+        .optional(datetime)
+        .push_list(datetime)
+        .insert_map("a", datetime)
+        .alias(DateTimeAlias(datetime))
+        .build();
+    test_ser(
+        &object,
+        r#"
+        {
+            "datetime": "2018-01-01T00:00:00.123456789Z",
+            "optional": "2018-01-01T00:00:00.123456789Z",
+            "list": ["2018-01-01T00:00:00.123456789Z"],
+            "map": {"a": "2018-01-01T00:00:00.123456789Z"},
+            "alias": "2018-01-01T00:00:00.123456789Z"
+        }
+        "#,
+    );
+
+    let object = crate::crate_types::DateTimeFields::builder()
+        .datetime(datetime)
+        .optional(datetime)
+        .push_list(datetime)
+        .insert_map("a", datetime)
+        .alias(crate::crate_types::DateTimeAlias(datetime))
+        .build();
+    let json = r#"
+    {
+        "datetime": "2018-01-01T00:00:00.123Z",
+        "optional": "2018-01-01T00:00:00.123Z",
+        "list": ["2018-01-01T00:00:00.123Z"],
+        "map": {"a": "2018-01-01T00:00:00.123Z"},
+        "alias": "2018-01-01T00:00:00.123Z"
+    }
+    "#;
+    test_ser(&object, json);
+    let object = crate::crate_types::DateTimeFields::builder()
+        .datetime(truncated)
+        .optional(truncated)
+        .push_list(truncated)
+        .insert_map("a", truncated)
+        .alias(crate::crate_types::DateTimeAlias(truncated))
+        .build();
+    test_de(&object, json);
+
+    test_serde(
+        &crate::crate_types::DateTimeUnion::Datetime(truncated),
+        r#"{"type":"datetime","datetime":"2018-01-01T00:00:00.123Z"}"#,
+    );
+    test_ser(
+        &crate::crate_types::DateTimeUnion::List(vec![datetime]),
+        r#"{"type":"list","list":["2018-01-01T00:00:00.123Z"]}"#,
+    );
+}
+
+#[test]
+fn strict_doubles() {
+    use crate::crate_types::{DoubleAlias, DoubleFields, DoubleUnion};
+
+    let object = DoubleFields::builder()
+        .double(1.5)
+        .optional(2.5)
+        .push_list(3.5)
+        .insert_map("a", 4.5)
+        .alias(DoubleAlias(5.5))
+        .string("b")
+        .build();
+    test_serde(
+        &object,
+        r#"{"double":1.5,"optional":2.5,"list":[3.5],"map":{"a":4.5},"alias":5.5,"string":"b"}"#,
+    );
+
+    let objects = vec![
+        DoubleFields::builder()
+            .double(f64::NAN)
+            .alias(DoubleAlias(1.))
+            .string("b")
+            .build(),
+        DoubleFields::builder()
+            .double(1.)
+            .optional(f64::INFINITY)
+            .alias(DoubleAlias(1.))
+            .string("b")
+            .build(),
+        DoubleFields::builder()
+            .double(1.)
+            .push_list(f64::NEG_INFINITY)
+            .alias(DoubleAlias(1.))
+            .string("b")
+            .build(),
+        DoubleFields::builder()
+            .double(1.)
+            .insert_map("a", f64::NAN)
+            .alias(DoubleAlias(1.))
+            .string("b")
+            .build(),
+        DoubleFields::builder()
+            .double(1.)
+            .alias(DoubleAlias(f64::NAN))
+            .string("b")
+            .build(),
+    ];
+    for object in objects {
+        let e = conjure_serde::json::to_string(&object).unwrap_err();
+        assert!(e.to_string().contains("non-finite double"), "{}", e);
+    }
+
+    conjure_serde::json::to_string(&DoubleUnion::Double(f64::NAN)).unwrap_err();
+
+    // non-finite doubles can still be deserialized
+    let object = deserialize::<DoubleFields>(r#"{"double":"NaN","alias":"Infinity","string":"b"}"#);
+    assert!(object.double().is_nan());
+}
+
+#[test]
+fn union_ordering() {
+    let unknown_b = deserialize::<TestUnion>(r#"{"type":"b","b":1}"#);
+    let unknown_a = deserialize::<TestUnion>(r#"{"type":"a","a":2}"#);
+
+    let mut values = vec![
+        unknown_b.clone(),
+        TestUnion::Object(TestObject::new(1)),
+        TestUnion::String("b".to_string()),
+        unknown_a.clone(),
+        TestUnion::Integer(2),
+        TestUnion::String("a".to_string()),
+        TestUnion::Integer(1),
+    ];
+    values.sort();
+
+    let expected = vec![
+        TestUnion::Integer(1),
+        TestUnion::Integer(2),
+        TestUnion::String("a".to_string()),
+        TestUnion::String("b".to_string()),
+        TestUnion::Object(TestObject::new(1)),
+        unknown_a,
+        unknown_b,
+    ];
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn assert_roundtrip_macro() {
+    crate::assert_roundtrip!(UuidExample::new(conjure_object::Uuid::nil()));
+    crate::assert_roundtrip!(TestUnion::Integer(1));
+    crate::assert_roundtrip!(TestEnum::One);
+    crate::assert_roundtrip!(StringAlias("foo".to_string()));
+    crate::assert_roundtrip!(EmptyFields::builder().insert_map(1, 2).build());
+    crate::assert_roundtrip!(MapAlias(vec![(1, 2)].into_iter().collect()));
+}
+
+#[test]
+#[should_panic(expected = "client deserialization changed the value")]
+fn assert_roundtrip_macro_mismatch() {
+    crate::assert_roundtrip!(CustomFieldObject::new(
+        CommaSeparated(vec!["a,b".to_string()]),
+        "c"
+    ));
+}
+
+#[test]
+fn extern_package() {
+    let object = crate::crate_types::ExternalReferences::builder()
+        .object(ExternalObject::new(1))
+        .optional(ExternalObject::new(2))
+        .push_list(ExternalObject::new(3))
+        .build();
+    test_serde(
+        &object,
+        r#"{"object":{"foo":1},"optional":{"foo":2},"list":[{"foo":3}]}"#,
+    );
+
+    let source = include_str!(concat!(
+        env!("OUT_DIR"),
+        "/conjure_crate/external_references.rs"
+    ))
+    .split_whitespace()
+    .collect::<String>();
+    assert!(source.contains("object:crate::types::ExternalObject,"));
+
+    let root = include_str!(concat!(env!("OUT_DIR"), "/conjure_crate/mod.rs"));
+    assert!(!root.contains("ExternalObject"));
+}
+
+#[test]
+fn nested_optional() {
+    let absent = NestedOptionalFields::builder().build();
+    test_serde(&absent, "{}");
+
+    let null = NestedOptionalFields::builder()
+        .alias(OptionalAlias(None))
+        .build();
+    test_serde(&null, r#"{"alias":null}"#);
+
+    let value = NestedOptionalFields::builder()
+        .alias(OptionalAlias(Some(1)))
+        .build();
+    test_serde(&value, r#"{"alias":1}"#);
+
+    let mut place = value;
+    let mut de = conjure_serde::json::ClientDeserializer::from_str(r#"{"alias":null}"#);
+    NestedOptionalFields::deserialize_in_place(&mut de, &mut place).unwrap();
+    de.end().unwrap();
+    assert_eq!(place, null);
+}
+
+#[test]
+fn union_unknown_value() {
+    let value = deserialize::<TestUnion>(r#"{"type": "other", "other": {"foo": [1]}}"#);
+    let unknown = match value {
+        TestUnion::Unknown(v) => v,
+        _ => panic!("invalid variant"),
+    };
+
+    let mut map = BTreeMap::new();
+    map.insert(
+        Value::String("foo".to_string()),
+        Value::Seq(vec![Value::U64(1)]),
+    );
+    let expected = Value::Map(map);
+    assert_eq!(unknown.value(), &expected);
+
+    let (type_, value) = <(String, Value)>::from(unknown);
+    assert_eq!(type_, "other");
+    assert_eq!(value, expected);
+}
+
+fn collect_errors<T>(json: &str) -> Vec<String>
+where
+    T: DeserializeOwned + Debug,
+{
+    conjure_serde::json::client_from_slice_collecting::<T>(json.as_bytes())
+        .unwrap_err()
+        .iter()
+        .map(|e| e.to_string())
+        .collect()
+}
+
+#[test]
+fn collecting_deserialization() {
+    assert_eq!(
+        collect_errors::<NonCopyFields>("{}"),
+        [
+            "missing field `uuid` at line 1 column 2",
+            "missing field `string` at line 1 column 2",
+        ],
+    );
+
+    let errors = collect_errors::<CopyFields>(r#"{"uuid": 1}"#);
+    assert_eq!(errors.len(), 2);
+    assert!(errors[0].starts_with("invalid type: integer `1`"));
+    assert!(errors[0].ends_with("at line 1 column 10"));
+    assert_eq!(errors[1], "missing field `integer` at line 1 column 11");
+
+    let errors = collect_errors::<RecursiveObject>(
+        r#"{
+            "value": "a",
+            "child": {"children": []},
+            "children": [
+                {"type": "object", "object": {"value": 1, "children": [true]}},
+                {"type": "integer", "integer": "b"}
+            ]
+        }"#,
+    );
+    // the list stops at the invalid union, but the errors within the element before it are kept
+    assert_eq!(
+        errors,
+        [
+            "invalid type: string \"a\", expected i32 at line 2 column 24",
+            "missing field `value` at line 3 column 37",
+            "invalid type: boolean `true`, expected union RecursiveUnion at line 5 column 75",
+        ],
+    );
+
+    let errors = collect_errors::<TestUnion>(r#"{"type": "object", "object": {}}"#);
+    assert_eq!(errors, ["missing field `foo` at line 1 column 31"]);
+
+    let errors =
+        conjure_serde::json::client_from_slice_collecting::<TestObject>(b"[]").unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].classify(), serde_json::error::Category::Data);
+    assert_eq!((errors[0].line(), errors[0].column()), (1, 2));
+
+    let errors = conjure_serde::json::client_from_slice_collecting::<TestObject>(b"{").unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].classify(), serde_json::error::Category::Eof);
+
+    let errors = conjure_serde::json::client_from_slice_collecting::<CopyFields>(br#"{"uuid": 1"#)
+        .unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].classify(), serde_json::error::Category::Data);
+    assert_eq!(errors[1].classify(), serde_json::error::Category::Eof);
+
+    let value = conjure_serde::json::client_from_slice_collecting::<NonCopyFields>(
+        br#"{"uuid": "ad0b3fd7-6ecd-4b9a-ab0e-2e4d3f1e1a4e", "string": "foo"}"#,
+    )
+    .unwrap();
+    assert_eq!(value.string(), "foo");
+}
+
+#[test]
+fn enum_ordinal() {
+    assert_eq!(TestEnum::One.ordinal(), 0xfd01_310f);
+    assert_eq!(TestEnum::Two.ordinal(), 0x405f_a049);
+
+    assert_eq!(TestEnum::One.ordinal(), ReorderedTestEnum::One.ordinal());
+    assert_eq!(TestEnum::Two.ordinal(), ReorderedTestEnum::Two.ordinal());
+
+    let unknown = deserialize::<TestEnum>(r#""THREE""#);
+    assert_eq!(unknown.ordinal(), 0x33c4_3963);
+    assert_eq!(
+        unknown.ordinal(),
+        deserialize::<ReorderedTestEnum>(r#""THREE""#).ordinal()
+    );
+}
+
+#[test]
+fn enum_ordering() {
+    assert_eq!(
+        ReorderedTestEnum::Two.cmp(&ReorderedTestEnum::One),
+        Ordering::Less
+    );
+    assert_eq!(
+        ReorderedTestEnum::Two.cmp_by_name(&ReorderedTestEnum::One),
+        Ordering::Greater
+    );
+
+    let unknown = deserialize::<ReorderedTestEnum>(r#""ABC""#);
+    assert_eq!(ReorderedTestEnum::One.cmp(&unknown), Ordering::Less);
+    assert_eq!(
+        ReorderedTestEnum::One.cmp_by_name(&unknown),
+        Ordering::Greater
+    );
+
+    let mut values = vec![
+        ReorderedTestEnum::One,
+        unknown.clone(),
+        ReorderedTestEnum::Two,
+    ];
+    values.sort();
+    assert_eq!(
+        values,
+        vec![
+            ReorderedTestEnum::Two,
+            ReorderedTestEnum::One,
+            unknown.clone()
+        ]
+    );
+    values.sort_by(ReorderedTestEnum::cmp_by_name);
+    assert_eq!(
+        values,
+        vec![unknown, ReorderedTestEnum::One, ReorderedTestEnum::Two]
+    );
+}
+
+fn borrows_from(s: &str, buf: &[u8]) -> bool {
+    let start = buf.as_ptr() as usize;
+    let ptr = s.as_ptr() as usize;
+    start <= ptr && ptr < start + buf.len()
+}
+
+#[test]
+fn borrowed_strings() {
+    let json = br#"{"string": "foo", "optional": "bar", "list": ["baz"]}"#.to_vec();
+    let value: BorrowedStrings = conjure_serde::from_bytes(&json).unwrap();
+    assert_eq!(value.string(), "foo");
+    assert!(borrows_from(value.string(), &json));
+    assert_eq!(value.optional(), Some("bar"));
+    assert!(borrows_from(value.optional().unwrap(), &json));
+    assert_eq!(value.list(), &[Cow::Borrowed("baz")][..]);
+
+    let expected = BorrowedStrings::builder()
+        .string("foo")
+        .optional(Some("bar".into()))
+        .list(vec!["baz".into()])
+        .build();
+    assert_eq!(value, expected);
+    assert_eq!(
+        serialize(&value),
+        r#"{"string":"foo","optional":"bar","list":["baz"]}"#
+    );
+
+    // escaped strings can't be borrowed
+    let json = br#"{"string": "f\u006fo"}"#.to_vec();
+    let value: BorrowedStrings = conjure_serde::from_bytes(&json).unwrap();
+    assert_eq!(value.string(), "foo");
+    assert!(!borrows_from(value.string(), &json));
+}
+
+#[test]
+fn borrowed_strings_from_str() {
+    let json = r#"{"string": "foo", "optional": "bar", "list": ["baz"]}"#;
+
+    let value: BorrowedStrings = conjure_serde::json::client_from_str(json).unwrap();
+    assert!(borrows_from(value.string(), json.as_bytes()));
+    assert!(borrows_from(value.optional().unwrap(), json.as_bytes()));
+    assert!(borrows_from(&value.list()[0], json.as_bytes()));
+
+    let value: BorrowedStrings = conjure_serde::json::server_from_str(json).unwrap();
+    assert!(borrows_from(value.string(), json.as_bytes()));
+    assert!(borrows_from(value.optional().unwrap(), json.as_bytes()));
+    assert!(borrows_from(&value.list()[0], json.as_bytes()));
+
+    let json = r#"{"string": "foo", "bogus": "bar"}"#;
+    let err = conjure_serde::json::server_from_str::<BorrowedStrings>(json)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("unknown field `bogus`"), "{}", err);
+}
+
+#[test]
+fn compact_fields() {
+    let object = CompactObject::builder()
+        .string("foo")
+        .optional("bar".to_string())
+        .push_list("baz")
+        .push_list("qux")
+        .build();
+    assert_eq!(object.string(), "foo");
+    assert_eq!(object.optional(), Some("bar"));
+    assert_eq!(object.list(), &["baz".to_string(), "qux".to_string()][..]);
+    test_serde(
+        &object,
+        r#"{"string":"foo","optional":"bar","list":["baz","qux"]}"#,
+    );
+
+    let constructed = CompactObject::new(
+        "foo",
+        "bar".to_string(),
+        vec!["baz".to_string(), "qux".to_string()],
+    );
+    assert_eq!(constructed, object);
+
+    let (string, optional, list): (Box<str>, Option<Box<str>>, Box<[String]>) =
+        object.clone().into_parts();
+    assert_eq!(&*string, "foo");
+    assert_eq!(optional, Some("bar".into()));
+    assert_eq!(list.len(), 2);
+
+    let mut builder = compact_object::Builder::from(object.clone());
+    builder.push_list("quux");
+    assert_eq!(builder.build().list().len(), 3);
+
+    let mut value = deserialize::<CompactObject>(r#"{"string":"a","list":["b"]}"#);
+    let mut de = conjure_serde::json::ClientDeserializer::from_str(r#"{"string":"foo"}"#);
+    CompactObject::deserialize_in_place(&mut de, &mut value).unwrap();
+    de.end().unwrap();
+    assert_eq!(value, CompactObject::builder().string("foo").build());
+}
+
+#[test]
+fn raw_any_fields() {
+    let json = r#"{"any":{"b" : 1.50, "a": [1e3]},"optional":"foo","list":[1.0,{ }]}"#;
+    let value = deserialize::<PassthroughObject>(json);
+    assert_eq!(value.any().get(), r#"{"b" : 1.50, "a": [1e3]}"#);
+    assert_eq!(value.optional().unwrap().parse::<String>().unwrap(), "foo");
+    assert_eq!(serialize(&value), json);
+
+    let value = conjure_serde::json::server_from_str::<PassthroughObject>(json).unwrap();
+    assert_eq!(serialize(&value), json);
+
+    let value = PassthroughObject::builder()
+        .any(vec!["a", "b"])
+        .push_list(1)
+        .build();
+    assert_eq!(value.any().parse::<Vec<String>>().unwrap(), vec!["a", "b"]);
+    assert_eq!(serialize(&value), r#"{"any":["a","b"],"list":[1]}"#);
+}
+
+fn test_round_trip<T>(value: &T)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let json = serialize(value);
+    assert_eq!(*value, deserialize::<T>(&json));
+}
+
+proptest! {
+    #[test]
+    fn builtin_types_round_trip(ref v in arb_builtin_types()) {
+        test_round_trip(v);
+    }
+
+    #[test]
+    fn transparent_aliases_round_trip(ref v in arb_transparent_aliases()) {
+        test_round_trip(v);
+    }
+
+    #[test]
+    fn test_union_round_trip(ref v in arb_test_union()) {
+        test_round_trip(v);
+    }
+
+    #[test]
+    fn recursive_object_round_trip(ref v in arb_recursive_object()) {
+        test_round_trip(v);
+    }
+
+    #[test]
+    fn recursive_union_round_trip(ref v in arb_recursive_union()) {
+        test_round_trip(v);
+    }
+}